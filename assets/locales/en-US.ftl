@@ -0,0 +1,587 @@
+# English (US) translations of QuantumLauncher.
+#
+# This is the fallback language, every key used in the
+# launcher must be here. To translate the launcher, copy this
+# file to `<language>.ftl` (eg. `de-DE.ftl`) and translate the values.
+# Syntax: https://projectfluent.org/fluent/guide/
+
+# The name of this language, in itself (shown in the language picker)
+language-name = English
+
+## Common
+
+common-back = Back
+common-loading = Loading
+common-loading-dots = Loading{ $dots }
+common-join-discord = Join Discord
+instance-delete = Delete Instance
+instance-deleted = Deleted Instance
+shortcut-command = Command + { $key }
+shortcut-control = Control + { $key }
+theme-light = Light
+theme-dark = Dark
+progress-downloaded = Downloaded { $downloaded } / { $total } ({ $jobs } files in progress)
+
+## File pickers
+
+dialog-select-instance = Select an instance...
+dialog-select-instances-folder = Select a folder of instances...
+dialog-select-preset = Select Mod Preset to Load
+dialog-select-skin = Select skin
+dialog-select-icon = Select an icon...
+dialog-select-optifine = Select OptiFine Installer
+dialog-select-certificate = Select CA certificate
+
+## Main screen
+
+launch-tab-play = Play
+launch-tab-log = Log
+launch-tab-edit = Edit
+launch-section-favorites = Favourites
+launch-section-ungrouped = Ungrouped
+launch-new = New
+launch-view-instances = View Instances...
+launch-view-servers = View Servers...
+launch-export = Export Instance
+launch-clone = Clone
+launch-shortcut = Create Shortcut
+launch-shortcut-tooltip = Adds a shortcut that plays this instance directly
+launch-shortcut-created = Created shortcut: { $path }
+launch-running = Running...
+launch-config-error = Error: Could not read config json!
+launch-select-instance = Select an instance
+launch-select-first = Select an instance first!
+launch-play = Play
+launch-kill = Kill
+launch-loading = Loading...
+launch-please-wait = Please wait...
+launch-files = Files
+launch-mods = Mods
+launch-server-start = Start
+launch-server-stop = Stop
+launch-server-eula = By starting the server, you agree to the EULA
+launch-username-empty = Username is empty!
+launch-username-spaces = Username contains spaces!
+launch-accounts = Accounts:
+launch-skin = Skin
+launch-logout = Logout
+launch-login-expired = Login expired
+launch-relogin = Log in again
+launch-saved-usernames = Saved usernames...
+launch-enter-username = Enter username...
+launch-forget = Forget
+launch-save = Save
+launch-copy-log = Copy Log
+launch-log-help = Having issues? Copy and send the game log for support
+launch-game-crashed = The game has crashed!
+launch-server-crashed = The server has crashed!
+launch-enter-command = Enter command...
+launch-no-logs = No logs found
+launch-experimental = Note: This version is experimental. If you want to get help join our discord
+launch-footer = A Minecraft Launcher by Mrmayman
+
+## Create instance
+
+create-import = Import Instance
+create-import-folder = Import Folder
+create-import-folder-tooltip = Import all instances from another launcher's folder
+create-import-folder-tooltip-long =
+    Import all instances from another launcher's folder
+    (eg: PrismLauncher/instances, .minecraft)
+create-loading-versions = Loading version list...
+create-select-version = Select a version...
+create-version-info = { $kind }, from { $source }
+create-version-info-date = { $kind } ({ $date }), from { $source }
+create-filter-since = Since version (eg: 1.16)
+create-name-placeholder = Enter instance name...
+create-download-assets = Download assets?
+create-download-assets-tooltip = If disabled, creating instance will be MUCH faster, but no sound or music will play in-game
+create-button = Create Instance
+create-loader-help = To install Fabric/Forge/OptiFine/Quilt, click on Mods after installing the instance
+create-32bit-warning = Warning: On your platform (Linux 32 bit) only Minecraft 1.16.5 and below are supported.
+create-32bit-help = If your computer isn't outdated, you might have wanted to download QuantumLauncher 64 bit ({ $platform })
+create-downloading = Downloading Instance..
+create-importing = Importing Instance..
+import-skipped = Imported, but these aren't supported yet and were left out: { $skipped }
+import-bulk = Imported { $count } instances
+import-bulk-skipped =
+    Imported { $count } instances, but these aren't supported yet and were left out:
+    { $skipped }
+
+server-create-title = Create new server
+server-create-name-placeholder = Enter server name...
+server-create-button = Create Server
+server-create-downloading = Creating Server...
+
+## Launcher updates
+
+update-downloading = Updating QuantumLauncher...
+update-found = A new launcher update has been found! Do you want to download it?
+update-download = Download
+update-website = Open Website
+update-package-manager = Note: If you installed this launcher from a package manager (flatpak/apt/dnf/pacman/..) it's recommended to update from there
+update-package-manager-help = If you just downloaded it from the website then continue from here.
+update-macos = Note: The updater may be broken on macOS, so download the new version from the website
+
+## Edit instance
+
+edit-client = Client
+edit-server = Server
+edit-rename = Rename
+edit-rename-placeholder = Rename Instance
+edit-change-version = Change game version
+edit-group = Group:
+edit-no-group = No group
+edit-favorite = Favourite (shown at the top of the list)
+edit-color = Colour:
+edit-color-none = None
+edit-icon = Icon:
+edit-icon-choose = Choose...
+edit-icon-remove = Remove
+edit-notes = Notes:
+edit-notes-placeholder = Anything you want to remember about this instance
+edit-java-override = Custom Java executable (full path)
+edit-java-override-placeholder = Leave blank if none
+edit-memory = Allocated memory
+edit-memory-normal = For normal Minecraft, allocate 2 - 3 GB
+edit-memory-old = For old versions, allocate 512 MB - 1 GB
+edit-memory-heavy = For heavy modpacks/very high render distances, allocate 4 - 8 GB
+edit-close-on-start = Close launcher after game opens
+edit-logger = DEBUG: Enable log system (recommended)
+edit-logger-desc =
+    Once disabled, logs will be printed in launcher STDOUT.
+    Run the launcher executable from the terminal/command prompt to see it
+edit-java-args = Java arguments:
+edit-game-args = Game arguments:
+edit-add-arg = Add
+edit-arg-placeholder = Enter argument...
+
+change-version-progress = Changing version...
+change-version-loading = Loading version list{ $dots }
+change-version-current = Current version: { $version }
+change-version-desc =
+    Worlds, settings, screenshots and mods are kept.
+    The mod loader is reinstalled for the new version, and mods from the store
+    are switched to compatible versions (or disabled if there are none).
+    Back up your worlds first, especially when downgrading!
+change-version-button = Change version
+change-version-done = Changed version to { $version }
+change-version-reinstall-loader = { $loader } has to be installed again manually (Mods -> Install)
+change-version-mods-updated = Updated mods: { $mods }
+change-version-mods-disabled = Disabled (not available for { $version }): { $mods }
+change-version-mods-error = Couldn't update mods: { $error }
+
+clone-progress = Cloning { $name }...
+clone-title = Clone { $name }
+clone-name-placeholder = Name of the copy
+clone-desc = Mods, configs, loaders and settings are always copied.
+clone-world = Copy world
+clone-worlds = Copy worlds
+clone-logs = Copy logs and crash reports
+clone-screenshots = Copy screenshots
+clone-button = Clone
+clone-done = Cloned to { $name }
+
+## Settings
+
+settings-title = Settings
+settings-tab-appearance = Appearance
+settings-tab-advanced = Advanced
+settings-tab-storage = Storage
+settings-tab-about = About
+settings-apply = Apply
+settings-none = None
+settings-default = Default
+settings-clear = Clear
+
+settings-ui-title = User Interface
+settings-language = Language:
+settings-language-system = System default
+settings-language-help = Add more languages as .ftl files in the "locales" folder of the launcher.
+settings-theme = Theme:
+settings-color-scheme = Color scheme:
+settings-theme-error = Error in theme { $error }
+settings-open-themes = Open Themes Folder
+settings-themes-help = Add .toml or .json themes here. Fonts apply after a restart.
+settings-ui-scale = UI Scale ({ $scale }x)
+settings-ui-scale-warning = Warning: slightly buggy
+
+settings-open-launcher-folder = Open Launcher Folder
+settings-clear-java = Clear Java installs
+settings-clear-java-desc =
+    Might fix some Java problems.
+    Perfectly safe, will be redownloaded.
+settings-downloads = Downloads
+settings-offline-mode = Offline mode
+settings-offline-mode-desc = Uses cached version lists, and skips Java installs, account refreshes and update checks.
+settings-parallel-downloads = Parallel downloads: { $count }
+settings-speed-limit = Speed limit (KB/s):
+settings-speed-unlimited = Unlimited
+settings-downloads-desc = Lower these if creating instances slows down your network.
+settings-mirrors = Mirrors
+settings-mirrors-desc =
+    Download from mirrors instead of the official servers (comma-separated, tried in order).
+    Use a base URL (https://mirror.example.com), a caching proxy (https://cache.example.com/{"{"}host{"}"}) or `bmclapi`.
+settings-mirror-official = Official
+settings-mirror-fallback = Fall back to official servers if mirrors fail
+settings-mirror-bmclapi = Use BMCLAPI
+settings-network = Network
+settings-network-restart = Changes apply after restarting the launcher.
+settings-proxy = Proxy:
+settings-proxy-placeholder = http://proxy.example.com:8080 or socks5://...
+settings-proxy-username = Proxy username:
+settings-proxy-password = Proxy password:
+settings-no-proxy = Bypass proxy for:
+settings-no-proxy-placeholder = localhost, .example.com
+settings-timeout = Timeout (seconds):
+settings-user-agent = User agent:
+settings-certificates = Extra CA certificates: { $certificates }
+settings-add-certificate = Add certificate
+settings-proxy-game = The proxy is also used by the game.
+
+settings-storage-rescan = Rescan
+settings-storage-scanning = Scanning...
+settings-storage-cleaning = Cleaning up...
+settings-storage-freed = Freed { $size }
+settings-storage-instance = { $name } - { $size }
+settings-storage-server = { $name } (server) - { $size }
+settings-storage-breakdown = Mods: { $mods }, Worlds: { $worlds }, Libraries: { $libraries }, Logs: { $logs }, Crash reports: { $crash_reports }, Screenshots: { $screenshots }, Jarmods: { $jarmods }, Other: { $other }
+settings-storage-java = Java installs: { $size }
+settings-storage-assets = Assets: { $size } ({ $unused } unused)
+settings-storage-libraries = Shared libraries: { $size }
+settings-storage-launcher-logs = Launcher logs: { $size }
+settings-storage-forge = Forge installer leftovers: { $size }
+settings-storage-prune-logs = Prune old logs
+settings-storage-prune-logs-desc = Deletes logs and crash reports older than 2 weeks
+settings-storage-delete-assets = Delete unused assets
+settings-storage-delete-assets-desc = Deletes sounds/textures not used by any downloaded version
+settings-storage-delete-forge = Delete Forge installer leftovers
+settings-storage-delete-forge-desc = Deletes temporary installer files, and Forge files of instances no longer using Forge
+settings-storage-clean-libraries = Clean up shared libraries
+settings-storage-clean-libraries-desc =
+    Deletes libraries no longer used by any instance.
+    Also done automatically when deleting instances.
+settings-storage-instances = Instances
+
+settings-about-title = About QuantumLauncher
+settings-about-website = Website
+settings-about-changelog = Changelog
+settings-about-welcome = Welcome Screen
+settings-about-licenses = Licenses
+settings-about-license = GNU GPLv3 License
+settings-about-free-software = QuantumLauncher is free and open source software under the
+settings-about-warranty =
+    No warranty is provided for this software.
+    You're free to share, modify, and redistribute it under the same license.
+settings-about-share =
+    If you like this launcher, consider sharing it with your friends.
+    Every new user motivates me to keep working on this :)
+
+## Keyboard shortcuts
+
+keys-no-shortcut = No shortcut
+keys-action-switcher = Quick switch instance
+keys-action-launch = Launch selected instance
+keys-action-kill = Kill selected instance
+keys-action-open-log = Open instance log
+keys-action-open-mods = Open mods menu
+keys-action-focus-search = Search the mod store
+keys-action-select-up = Previous item in list
+keys-action-select-down = Next item in list
+keys-action-back = Go back
+keys-action-quit = Quit launcher
+keys-action-cheatsheet = Show keyboard shortcuts
+keys-switcher-placeholder = Jump to instance...
+keys-switcher-no-results = No matching instances
+keys-switcher-help = Up/Down to choose, Enter to select, Escape to close
+keys-cheatsheet-title = Keyboard Shortcuts
+keys-cheatsheet-help = Shortcuts can be changed in the "keybinds" section of config.json (with the launcher closed), eg. "launch": "Ctrl+Enter"
+
+## Login
+
+login-title = Login
+login-with-microsoft = Login with Microsoft
+login-with-elyby = Login with ely.by
+login-with-authlib = Login with authlib-injector server
+login-authlib-examples = (LittleSkin, Blessing Skin, self-hosted, etc.)
+login-button = Login
+login-server = Server URL:
+login-server-placeholder = Eg: littleskin.cn
+login-username = Username/Email:
+login-username-placeholder = Enter Username/Email...
+login-password = Password:
+login-password-placeholder = Enter Password...
+login-show-password = Show Password
+login-otp = OTP:
+login-otp-placeholder = Enter OTP...
+login-or = Or
+login-create-account = Create an account
+login-ms-title = Login to Microsoft
+login-ms-help = Open this link and enter the code:
+login-ms-code = Code: { $code }
+login-ms-copy = Copy
+login-ms-link = Link: { $url }
+login-ms-open = Open
+login-ms-waiting = Waiting for login... (code expires in { $expires })
+login-ms-cancel = Cancel
+
+## Skins
+
+skins-default = Default skin
+skins-title = Skin: { $account }
+skins-model = Model: { $model }
+skins-server-only = Skins and capes can only be changed on the server's website
+skins-open-elyby = Open ely.by
+skins-updating = Updating{ $dots }
+skins-reset = Reset to default skin
+skins-save = Save to library
+skins-cape = Cape:
+skins-no-capes = No capes
+skins-cape-none = None
+skins-library = Skin Library
+skins-import-model = Model for imported skins:
+skins-import = Import PNG
+skins-library-empty = No saved skins. Import a PNG file to add one.
+skins-use = Use
+skins-delete = Delete
+
+## Mods
+
+curseforge-blocked =
+    Some Curseforge mods have blocked this launcher!
+    You need to manually download the files and add them to your mods
+curseforge-open-link = Open link
+curseforge-warning = Warning: Ignoring this may lead to crashes!
+curseforge-select-files = + Select above downloaded files
+curseforge-continue = Continue
+
+mods-updating = Updating mods
+mods-add-file = Add File
+mods-download-content = Download Content
+mods-presets = Mod Presets
+mods-jarmods = Jarmod Patches
+mods-undo = Undo Last Change
+mods-snapshots = Snapshots
+mods-drag-drop = Drag and drop mod files to add them
+mods-checking-updates = Checking for mod updates{ $dots }
+mods-updates-available = Mod Updates Available!
+mods-update-entry = { $name } ({ $old } -> { $new })
+mods-review-update = Review & Update
+mods-update-versions = { $old } -> { $new } (released { $date })
+mods-update-dependencies-changed = Warning: The dependencies of this mod have changed
+mods-no-changelog = No changelog provided
+mods-apply-updates = Apply Updates
+mods-review-title = Review Mod Updates
+mods-snapshots-title = Mod Snapshots
+mods-snapshots-desc =
+    A snapshot of your mods is taken before every install, update or delete.
+    Restore one to roll back your mods to that point.
+mods-snapshots-empty = No snapshots yet
+mods-snapshot-restore = Restore
+mods-snapshot-restoring = Restoring mods...
+mods-undoing = Undoing last change...
+mods-snapshot-entry = { $created } - Before: { $reason } ({ $count } mods)
+mods-install-loader = Install:
+mods-install-optifine = Install OptiFine
+mods-install-forge = Install Forge
+mods-coming-soon = Coming in a future launcher version...
+mods-unknown-type = Unknown mod type: { $mod_type }
+mods-uninstall-loader = Uninstall { $loader }
+mods-open-folder = Open Mods Folder
+mods-empty = Download some mods to get started
+mods-no-loader = You haven't installed any mod loader! Install Fabric/Forge/Quilt/NeoForge as per your mods
+mods-select-help = Select some mods to perform actions on them
+mods-delete = Delete
+mods-toggle = Toggle
+mods-select-all = Select All
+mods-unselect-all = Unselect All
+mods-disabled = (DISABLED) { $name }
+mods-dependency = - (DEPENDENCY) { $name }
+
+jarmods-open-folder = Open Folder
+jarmods-add-file = Add file
+jarmods-mcarchive = You can find some good jar mods at McArchive
+jarmods-open = Open
+jarmods-warning = WARNING: Jarmods are mainly for OLD Minecraft versions.
+jarmods-warning-help = This is easier than copying .class files into Minecraft's jar
+jarmods-warning-back = If you just want some mods (for newer Minecraft), click Back
+jarmods-drag-drop = Drag and drop jarmod files to add them
+jarmods-empty = Add some mods to get started
+jarmods-select-help = Select some jarmods to perform actions on them
+
+## Mod loaders
+
+optifine-installing-b173 = Installing OptiFine for Beta 1.7.3...
+optifine-step-1 = Step 1: Open the OptiFine download page and download the installer.
+optifine-step-1-warning = WARNING: Make sure to download the correct version.
+optifine-open-page = Open download page
+optifine-step-2 = Step 2: Select the installer file
+optifine-select-file = Select File
+fabric-loading = Loading { $loader } version list{ $dots }
+fabric-installing = Installing { $loader }...
+fabric-title = Install { $loader } (instance: { $instance })
+fabric-version = { $loader } version: (Ignore if you aren't sure)
+fabric-install = Install
+fabric-unsupported = { $loader } is unsupported for this Minecraft version.
+forge-installing = Installing Forge/NeoForge...
+
+## Mod store
+
+store-search = Search...
+store-select-store = Select store:
+store-select-type = Select Type:
+store-installing = Installing:
+store-installing-entry = - { $name }
+store-no-shader-mod =
+    You haven't installed any shader mod! Either install:
+    - Fabric + Sodium + Iris (recommended), or
+    - OptiFine
+store-no-loader = You haven't installed any mod loader! Install Fabric (recommended), Forge, Quilt or NeoForge
+store-old-version = Installing Mods for old versions is experimental and may be broken
+store-no-results = No results found.
+store-open-page = Open Mod Page
+store-copy-id = Copy ID
+store-copy = Copy
+store-loading-image = (Loading image...)
+
+## Export instance
+
+export-select = Select the contents of the ".minecraft" folder you want to keep
+export-format = Format:
+export-button = Export
+
+## Mod presets
+
+presets-installing = Installing mods
+presets-installing-help = Check debug log (at the bottom) for more info
+presets-building = Building Preset
+presets-tab-create = Create
+presets-tab-recommended = Recommended
+presets-import = Import
+presets-import-warning = Note: Sideloaded .jar mods in untrusted presets could have viruses
+presets-import-warning-help = To get rid of them, after installing remove all mods in the list ending in ".jar"
+presets-desc = Presets are small bundles of mods and their configuration that you can share with anyone.
+presets-desc-help = You can import presets, create them or download recommended mods (if you haven't installed any yet).
+presets-no-mods = You have no mods installed! Go to Recommended to find some good ones.
+presets-create-title = Create Preset
+presets-select-mods = Select Mods to keep
+presets-build = Build Preset
+presets-error = Error loading presets: { $error }
+presets-download-recommended = Download Recommended Mods
+
+## Links (quantumlauncher://) and opened files
+
+url-install-mod-title = Install mod from link
+url-install-pack-title = Install modpack from link
+url-install-mod = { $source } mod: { $id }
+url-install-pack = Modpack: { $url }
+url-install-file-title = Install file
+url-install-file = File: { $path }
+url-install-warning = Only install things from links you trust!
+url-install-instance = Install to:
+url-install-no-instances = Create an instance first to install this
+url-install-confirm = Install
+url-install-cancel = Cancel
+url-install-done = Installed to { $instance }
+
+## Welcome screen
+
+welcome-title = Welcome to QuantumLauncher!
+welcome-get-started = Get Started
+welcome-customize = Customize your launcher!
+welcome-theme = Select Theme:
+welcome-color-scheme = Select Color Scheme:
+welcome-also = Oh, and also...
+welcome-discord = Join our Discord
+welcome-continue = Continue
+welcome-or = OR
+welcome-login-microsoft = Login to Microsoft
+welcome-login-elyby = Login to ely.by
+welcome-login-authlib = Login to authlib-injector server
+
+## Changelog (one line per bullet point)
+
+changelog-title = QuantumLauncher v0.4.1 is here!
+changelog-tldr = TL;DR
+changelog-tldr-items =
+    - Shaders, Resource Pack, Modpack stores added
+    - Modpack support is here!
+    - Quality-of-life tweaks, keyboard navigation
+    - Portable mode, custom location support via qldir.txt
+    - Major bugfixes across modding, authentication, and platform compatibility
+changelog-modding = Modding
+changelog-modding-items =
+    - added Modpacks, Shaders and Resource Packs stores
+    - drag-and-drop support for mod files, modpacks and presets
+    - jarmod patching support (useful for old Minecraft versions)
+    - OptiFine support for b1.6.6, b1.7.3, 1.2.5 and 1.5.2
+changelog-ux = UX
+changelog-modding-ux-items =
+    - "Open Mod Page" in mod description now shows URL in tooltip
+    - added bullet/numbered list support in curseforge mod descriptions
+    - mod update checking is now up to 3-4x faster!
+changelog-portable = Portable Mode & Custom Paths
+changelog-portable-items =
+    - You can control where QuantumLauncher stores data by placing a qldir.txt file in one of the following locations:
+    -- Next to the launcher executable
+    -- In the working directory where it's run
+    -- In the global [QuantumLauncher folder](https://mrmayman.github.io/quantumlauncher/faq#files-location)
+    - Leave the file blank for full portable mode, or write a path inside it to set a custom data location.
+    - > Note: On macOS you may need to extract the .app from the DMG for portable mode.
+changelog-menus = Menu Overhauls
+changelog-menus-items =
+    - Welcome screen redesigned to guide new users more clearly
+    - Error screen looks better, now has a "Copy Log + Error" button
+    - Fabric Install screen is now simpler and less cluttered
+changelog-ui-ux = UI/UX
+changelog-ui-ux-items =
+    - new Catppuccin color scheme
+    - improved aesthetics of light themes
+    - animated pulsating "..." dots in a few "Loading" screens
+    - minor tweaks to buttons and layout in many screens
+    - added a "Clean Java Installs" button (for reinstalling java) in launcher settings
+    - old versions in the version list now have cleaner names ("beta/b1.7/b1.7.3" -> "b1.7.3")
+    - window size now respects UI scale
+changelog-keyboard = Keyboard Navigation
+changelog-keyboard-items =
+    - Up/Down arrow keys to select instance
+    - Ctrl+Enter -> launch instance
+    - Ctrl+Backspace -> kill instance
+    - Ctrl+Q -> exit launcher (only in main menu, when nothing's running)
+    - (note: on macOS it's Cmd instead of Ctrl)
+    - More coming in the future!
+changelog-crash = Game Crashed message
+changelog-crash-items =
+    - there is now a "Join Discord" button in Logs tab
+    - there's no longer a tip mentioning a "second terminal" upon crash (no longer needed)
+changelog-dependencies = Dependencies (Technical)
+changelog-dependencies-items =
+    - dropped OpenSSL and LZMA dependencies on linux
+    -- now depends only on libgcc_s and libc (glibc)
+    -- replaced OpenSSL with rustls
+changelog-fixes = Fixes
+changelog-fixes-items =
+    - crash messages now appear in Logs tab (critical!)
+    - forge now works after renaming instance
+    - multiplayer now works in 1.16.x for offline accounts
+    - mod updating and preset importing are now working again! (fixed error for curseforge, index corruption for modrinth)
+    - fixed extreme launcher log file spam
+changelog-fixes-ui-ux-items =
+    - UI scaling now has a minimum limit preventing unusable layouts
+    - many mod-related operations now return you to Mods screen, instead of launch screen
+    - pressing Esc in mod descriptions now exits the description only (not the entire store)
+    - fixed mod store "forgetting" scroll position when opening description and exiting
+changelog-platform = Platform-Specific
+changelog-platform-items =
+    - fixed 1.13-1.16 crashing on linux ARM
+    - fixed 1.8.9 crashing on macOS ARM
+    - fixed Java 8 (1.16.5 and below) compatibility on Windows ARM and macOS
+
+## Launcher log and errors
+
+log-open = Open launcher debug log (troubleshooting)
+log-close = Close launcher log
+error-message = Error: { $error }
+error-copy = Copy Error
+error-copy-log = Copy Error + Log
+error-discord = Join Discord for help
@@ -0,0 +1,347 @@
+//! Reports what's taking up space in the launcher
+//! folder, and cleans up stuff that's safe to delete.
+//!
+//! See [`get_disk_usage`] for the report, and
+//! [`prune_logs`], [`delete_orphan_assets`] and
+//! [`delete_forge_leftovers`] for cleaning up.
+
+use std::{
+    collections::HashSet,
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+use walkdir::WalkDir;
+
+use crate::{
+    file_utils, info,
+    json::{AssetIndexMap, InstanceConfigJson},
+    pt, InstanceSelection, IntoIoError, IoError, LAUNCHER_DIR,
+};
+
+/// Logs and crash reports older than this are deleted by [`prune_logs`].
+const LOG_MAX_AGE: Duration = Duration::from_secs(14 * 24 * 60 * 60);
+/// Logs are also trimmed to this size per folder by [`prune_logs`].
+const LOG_SIZE_LIMIT: u64 = 50 * 1024 * 1024;
+
+/// Files left behind in an instance's `forge` folder
+/// by the Forge/NeoForge installers.
+const FORGE_JUNK_FILES: &[&str] = &[
+    "ClientInstaller.class",
+    "ClientInstaller.java",
+    "ForgeInstaller.class",
+    "ForgeInstaller.java",
+    "launcher_profiles.json",
+    "launcher_profiles_microsoft_store.json",
+];
+
+/// Size (in bytes) of each part of an instance or server.
+#[derive(Debug, Clone)]
+pub struct InstanceDiskUsage {
+    pub instance: InstanceSelection,
+    pub mods: u64,
+    pub worlds: u64,
+    /// May be shared with other instances
+    /// (see `ql_instances::library_store`).
+    pub libraries: u64,
+    pub logs: u64,
+    pub crash_reports: u64,
+    pub screenshots: u64,
+    /// The jar built when jarmods are installed.
+    pub build_jar: u64,
+    /// The whole instance, including the above.
+    pub total: u64,
+}
+
+impl InstanceDiskUsage {
+    /// Everything not covered by the other fields.
+    #[must_use]
+    pub fn other(&self) -> u64 {
+        self.total.saturating_sub(
+            self.mods
+                + self.worlds
+                + self.libraries
+                + self.logs
+                + self.crash_reports
+                + self.screenshots
+                + self.build_jar,
+        )
+    }
+}
+
+/// Size (in bytes) of everything in the launcher folder.
+#[derive(Debug, Clone)]
+pub struct DiskUsage {
+    /// Sorted by total size, biggest first.
+    pub instances: Vec<InstanceDiskUsage>,
+    pub java_installs: u64,
+    pub assets: u64,
+    /// Asset objects not used by any asset index
+    /// (included in `assets`).
+    pub orphan_assets: u64,
+    pub shared_libraries: u64,
+    pub launcher_logs: u64,
+    /// Leftover files from Forge/NeoForge installers
+    /// (included in the instance sizes).
+    pub forge_leftovers: u64,
+}
+
+/// Scans the launcher folder and reports what's using space.
+///
+/// This can take a while for big launcher folders,
+/// so don't call it too often.
+///
+/// # Errors
+/// If the `instances` or `servers` folder couldn't be read.
+pub async fn get_disk_usage() -> Result<DiskUsage, IoError> {
+    info!("Calculating disk usage");
+    let mut instances = Vec::new();
+    for instance in list_all_instances().await? {
+        instances.push(get_instance_usage(instance).await);
+    }
+    instances.sort_by_key(|n| std::cmp::Reverse(n.total));
+
+    let forge_leftovers = find_forge_leftovers()
+        .await?
+        .iter()
+        .map(|n| dir_size(n))
+        .sum();
+
+    Ok(DiskUsage {
+        instances,
+        java_installs: dir_size(&LAUNCHER_DIR.join("java_installs")),
+        assets: dir_size(&LAUNCHER_DIR.join("assets")),
+        orphan_assets: find_orphan_assets()
+            .await?
+            .iter()
+            .map(|n| dir_size(n))
+            .sum(),
+        shared_libraries: dir_size(&LAUNCHER_DIR.join("libraries")),
+        launcher_logs: dir_size(&LAUNCHER_DIR.join("logs")),
+        forge_leftovers,
+    })
+}
+
+async fn get_instance_usage(instance: InstanceSelection) -> InstanceDiskUsage {
+    let instance_dir = instance.get_instance_path();
+    let dot_minecraft = instance.get_dot_minecraft_path();
+
+    let worlds = if instance.is_server() {
+        // Servers can have multiple world folders
+        // (world, world_nether, world_the_end, ...)
+        list_dirs(&dot_minecraft)
+            .await
+            .into_iter()
+            .filter(|n| n.join("level.dat").is_file())
+            .map(|n| dir_size(&n))
+            .sum()
+    } else {
+        dir_size(&dot_minecraft.join("saves"))
+    };
+
+    InstanceDiskUsage {
+        mods: dir_size(&dot_minecraft.join("mods")),
+        worlds,
+        libraries: dir_size(&instance_dir.join("libraries"))
+            + dir_size(&instance_dir.join("forge/libraries")),
+        logs: dir_size(&dot_minecraft.join("logs")),
+        crash_reports: dir_size(&dot_minecraft.join("crash-reports")),
+        screenshots: dir_size(&dot_minecraft.join("screenshots")),
+        build_jar: dir_size(&instance_dir.join("build.jar")),
+        total: dir_size(&instance_dir),
+        instance,
+    }
+}
+
+/// Deletes logs and crash reports that are older than
+/// two weeks, or over 50 MB, for the launcher and all instances.
+///
+/// Returns the number of bytes freed.
+///
+/// # Errors
+/// If a log folder couldn't be read or a file couldn't be deleted.
+pub async fn prune_logs() -> Result<u64, IoError> {
+    info!("Pruning old logs");
+    let mut dirs = vec![LAUNCHER_DIR.join("logs")];
+    for instance in list_all_instances().await? {
+        let dot_minecraft = instance.get_dot_minecraft_path();
+        dirs.push(dot_minecraft.join("logs"));
+        dirs.push(dot_minecraft.join("crash-reports"));
+    }
+
+    let mut freed = 0;
+    for dir in dirs.into_iter().filter(|n| n.is_dir()) {
+        freed += file_utils::clean_old_files(&dir, LOG_SIZE_LIMIT, Some(LOG_MAX_AGE)).await?;
+    }
+    pt!("Freed {} MB", freed / (1024 * 1024));
+    Ok(freed)
+}
+
+/// Deletes asset objects that aren't used by any
+/// asset index (ie. any downloaded version).
+///
+/// Returns the number of bytes freed.
+///
+/// # Errors
+/// If the assets folder couldn't be read
+/// or a file couldn't be deleted.
+pub async fn delete_orphan_assets() -> Result<u64, IoError> {
+    info!("Deleting unused assets");
+    let mut freed = 0;
+    for path in find_orphan_assets().await? {
+        freed += dir_size(&path);
+        tokio::fs::remove_file(&path).await.path(&path)?;
+    }
+    pt!("Freed {} MB", freed / (1024 * 1024));
+    Ok(freed)
+}
+
+/// Deletes files left behind by the Forge/NeoForge installers,
+/// and `forge` folders of instances that don't use Forge anymore
+/// (eg: after a failed install).
+///
+/// Returns the number of bytes freed.
+///
+/// # Errors
+/// If the instances folder couldn't be read
+/// or a file couldn't be deleted.
+pub async fn delete_forge_leftovers() -> Result<u64, IoError> {
+    info!("Deleting Forge installer leftovers");
+    let mut freed = 0;
+    for path in find_forge_leftovers().await? {
+        freed += dir_size(&path);
+        if path.is_dir() {
+            tokio::fs::remove_dir_all(&path).await.path(&path)?;
+        } else {
+            tokio::fs::remove_file(&path).await.path(&path)?;
+        }
+    }
+    pt!("Freed {} MB", freed / (1024 * 1024));
+    Ok(freed)
+}
+
+async fn find_orphan_assets() -> Result<Vec<PathBuf>, IoError> {
+    let assets_dir = LAUNCHER_DIR.join("assets/dir");
+    let indexes_dir = assets_dir.join("indexes");
+    let objects_dir = assets_dir.join("objects");
+    if !indexes_dir.is_dir() || !objects_dir.is_dir() {
+        return Ok(Vec::new());
+    }
+
+    let mut used = HashSet::new();
+    let mut indexes = tokio::fs::read_dir(&indexes_dir).await.dir(&indexes_dir)?;
+    while let Some(index) = indexes.next_entry().await.dir(&indexes_dir)? {
+        let path = index.path();
+        if path.extension().is_none_or(|n| n != "json") {
+            continue;
+        }
+        let index = tokio::fs::read_to_string(&path).await.path(&path)?;
+        let Ok(index) = serde_json::from_str::<AssetIndexMap>(&index) else {
+            // Don't risk deleting assets if
+            // we can't tell what's being used
+            return Ok(Vec::new());
+        };
+        used.extend(index.objects.into_values().map(|n| n.hash));
+    }
+
+    Ok(WalkDir::new(&objects_dir)
+        .min_depth(2)
+        .max_depth(2)
+        .into_iter()
+        .filter_map(Result::ok)
+        .filter(|n| n.file_type().is_file())
+        .filter(|n| !used.contains(&*n.file_name().to_string_lossy()))
+        .map(walkdir::DirEntry::into_path)
+        .collect())
+}
+
+async fn find_forge_leftovers() -> Result<Vec<PathBuf>, IoError> {
+    let mut leftovers = Vec::new();
+    for instance in list_all_instances().await? {
+        if instance.is_server() {
+            continue;
+        }
+        let instance_dir = instance.get_instance_path();
+        let forge_dir = instance_dir.join("forge");
+        if !forge_dir.is_dir() {
+            continue;
+        }
+
+        let config_path = instance_dir.join("config.json");
+        let mod_type = tokio::fs::read_to_string(&config_path)
+            .await
+            .ok()
+            .and_then(|n| serde_json::from_str::<InstanceConfigJson>(&n).ok())
+            .map(|n| n.mod_type);
+        let uses_forge = mod_type
+            .as_deref()
+            .is_none_or(|n| n == "Forge" || n == "NeoForge");
+        if !uses_forge {
+            // An incomplete install leaves a lock file behind
+            let lock_path = instance_dir.join("forge.lock");
+            if lock_path.is_file() {
+                leftovers.push(lock_path);
+            }
+            leftovers.push(forge_dir);
+            continue;
+        }
+
+        leftovers.extend(
+            FORGE_JUNK_FILES
+                .iter()
+                .map(|n| forge_dir.join(n))
+                .filter(|n| n.is_file()),
+        );
+        let versions_dir = forge_dir.join("versions");
+        if versions_dir.is_dir() {
+            leftovers.push(versions_dir);
+        }
+    }
+    Ok(leftovers)
+}
+
+async fn list_all_instances() -> Result<Vec<InstanceSelection>, IoError> {
+    let mut instances = Vec::new();
+    for (folder, is_server) in [("instances", false), ("servers", true)] {
+        let dir = LAUNCHER_DIR.join(folder);
+        if !dir.is_dir() {
+            continue;
+        }
+        let mut entries = tokio::fs::read_dir(&dir).await.dir(&dir)?;
+        while let Some(entry) = entries.next_entry().await.dir(&dir)? {
+            if entry.path().is_dir() {
+                instances.push(InstanceSelection::new(
+                    &entry.file_name().to_string_lossy(),
+                    is_server,
+                ));
+            }
+        }
+    }
+    Ok(instances)
+}
+
+async fn list_dirs(dir: &Path) -> Vec<PathBuf> {
+    let mut dirs = Vec::new();
+    let Ok(mut entries) = tokio::fs::read_dir(dir).await else {
+        return dirs;
+    };
+    while let Ok(Some(entry)) = entries.next_entry().await {
+        let path = entry.path();
+        if path.is_dir() {
+            dirs.push(path);
+        }
+    }
+    dirs
+}
+
+/// Size of a file, or all files in a folder (recursively).
+/// Missing or unreadable files count as 0.
+fn dir_size(path: &Path) -> u64 {
+    WalkDir::new(path)
+        .into_iter()
+        .filter_map(Result::ok)
+        .filter(|n| n.file_type().is_file())
+        .filter_map(|n| n.metadata().ok())
+        .map(|n| n.len())
+        .sum()
+}
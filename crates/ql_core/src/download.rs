@@ -0,0 +1,503 @@
+//! The download manager. Every `file_utils::download_file_to_*`
+//! function goes through here, which lets us:
+//!
+//! - Limit how many downloads run at once, launcher-wide
+//!   (see [`set_max_concurrent`]).
+//! - Limit the total download speed (see [`set_bandwidth_limit`]).
+//! - Resume interrupted downloads to a file using HTTP `Range`
+//!   requests, instead of starting over.
+//! - Track the total progress, in bytes, of all running
+//!   downloads (see [`stats`]).
+//!
+//! - Try configured mirrors before the official servers
+//!   (see [`crate::mirror`]).
+//! - Block all downloads in offline mode (see [`set_offline`]).
+//!
+//! Retrying (with exponential backoff) is handled
+//! by the callers through [`crate::retry_if`].
+
+use std::{
+    ffi::OsString,
+    fs::TryLockError,
+    io::SeekFrom,
+    path::{Path, PathBuf},
+    sync::{
+        atomic::{AtomicBool, AtomicU64, AtomicUsize, Ordering},
+        LazyLock, Mutex,
+    },
+    time::{Duration, Instant},
+};
+
+use futures::StreamExt;
+use ql_reqwest::{Response, StatusCode};
+use tokio::{
+    io::{AsyncSeekExt, AsyncWriteExt},
+    sync::Notify,
+};
+
+use crate::{error::DownloadFileError, mirror, network, pt, IntoIoError, RequestError, CLIENT};
+
+/// The default number of downloads that can run at once.
+pub const DEFAULT_MAX_CONCURRENT: usize = 64;
+
+static MAX_CONCURRENT: AtomicUsize = AtomicUsize::new(DEFAULT_MAX_CONCURRENT);
+/// In bytes per second, `0` means unlimited.
+static BANDWIDTH_LIMIT: AtomicU64 = AtomicU64::new(0);
+static OFFLINE: AtomicBool = AtomicBool::new(false);
+
+static ACTIVE_SLOTS: Mutex<usize> = Mutex::new(0);
+static SLOT_FREED: LazyLock<Notify> = LazyLock::new(Notify::new);
+/// When the bandwidth limit allows the next chunk to be downloaded.
+static NEXT_CHUNK_AT: Mutex<Option<Instant>> = Mutex::new(None);
+
+static STATS_JOBS: AtomicUsize = AtomicUsize::new(0);
+static STATS_DOWNLOADED: AtomicU64 = AtomicU64::new(0);
+static STATS_TOTAL: AtomicU64 = AtomicU64::new(0);
+
+/// Sets how many downloads can run at once (minimum 1).
+///
+/// This applies to all downloads in the launcher,
+/// including ones that are already waiting to start.
+pub fn set_max_concurrent(max: usize) {
+    MAX_CONCURRENT.store(max.max(1), Ordering::Relaxed);
+    SLOT_FREED.notify_waiters();
+}
+
+#[must_use]
+pub fn max_concurrent() -> usize {
+    MAX_CONCURRENT.load(Ordering::Relaxed)
+}
+
+/// Limits the total download speed of the launcher,
+/// in bytes per second. `None` (or `0`) means unlimited.
+pub fn set_bandwidth_limit(bytes_per_sec: Option<u64>) {
+    BANDWIDTH_LIMIT.store(bytes_per_sec.unwrap_or(0), Ordering::Relaxed);
+}
+
+/// Turns offline mode on or off.
+///
+/// In offline mode, every download fails immediately
+/// with [`RequestError::Offline`] instead of touching
+/// the network. Metadata is read from
+/// [`crate::meta_cache`] instead.
+pub fn set_offline(offline: bool) {
+    OFFLINE.store(offline, Ordering::Relaxed);
+}
+
+#[must_use]
+pub fn is_offline() -> bool {
+    OFFLINE.load(Ordering::Relaxed)
+}
+
+/// Progress of all currently running downloads.
+///
+/// The counters reset when all downloads finish.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct DownloadStats {
+    /// Number of files currently being downloaded
+    /// (not counting ones waiting to start).
+    pub jobs: usize,
+    /// Bytes downloaded so far.
+    pub downloaded: u64,
+    /// Total bytes to download (only counts files
+    /// whose size is known, ie. that have started).
+    pub total: u64,
+}
+
+#[must_use]
+pub fn stats() -> DownloadStats {
+    DownloadStats {
+        jobs: STATS_JOBS.load(Ordering::Relaxed),
+        downloaded: STATS_DOWNLOADED.load(Ordering::Relaxed),
+        total: STATS_TOTAL.load(Ordering::Relaxed),
+    }
+}
+
+/// Downloads a file into memory.
+pub(crate) async fn fetch(url: &str, user_agent: Option<&str>) -> Result<Vec<u8>, RequestError> {
+    let _slot = Slot::acquire().await;
+    let mut job = Job::start();
+
+    let response = send(url, user_agent, 0).await?;
+    if let Some(len) = response.content_length() {
+        job.add_total(len);
+    }
+
+    let mut out = Vec::new();
+    let mut stream = response.bytes_stream();
+    while let Some(chunk) = stream.next().await {
+        let chunk = chunk?;
+        throttle(chunk.len()).await;
+        job.add_downloaded(chunk.len() as u64);
+        out.extend_from_slice(&chunk);
+    }
+
+    job.finish();
+    Ok(out)
+}
+
+/// Downloads a file to `path`, through `part` (see [`PartFile`]).
+///
+/// If `part` isn't empty (from a previous failed attempt),
+/// the download is resumed from where it stopped, and the final size
+/// is checked against the one reported by the server.
+pub(crate) async fn fetch_to_path(
+    url: &str,
+    user_agent: Option<&str>,
+    path: &Path,
+    part: &PartFile,
+) -> Result<(), DownloadFileError> {
+    let part_path = &part.path;
+    let _slot = Slot::acquire().await;
+    let mut job = Job::start();
+
+    let existing = part.len();
+    let mut response = match send(url, user_agent, existing).await {
+        // Already fully downloaded, or the file changed on the server
+        Err(RequestError::DownloadError { code, .. })
+            if existing > 0 && code == StatusCode::RANGE_NOT_SATISFIABLE =>
+        {
+            send(url, user_agent, 0).await?
+        }
+        result => result?,
+    };
+
+    let mut resumed_size = None;
+    if existing > 0 && response.status() == StatusCode::PARTIAL_CONTENT {
+        match get_content_range(&response) {
+            Some((start, total)) if start == existing => resumed_size = Some(total),
+            // Not the part we asked for
+            _ => response = send(url, user_agent, 0).await?,
+        }
+    }
+
+    // Written through the locked handle, since
+    // locks on Windows block writes from other ones
+    let mut file = tokio::fs::File::from_std(part.file.try_clone().path(part_path)?);
+    if resumed_size.is_some() {
+        job.add_total(existing);
+        job.add_downloaded(existing);
+        file.seek(SeekFrom::End(0)).await.path(part_path)?;
+    } else {
+        // Server doesn't support resuming, start over
+        file.set_len(0).await.path(part_path)?;
+        file.seek(SeekFrom::Start(0)).await.path(part_path)?;
+    }
+    if let Some(len) = response.content_length() {
+        job.add_total(len);
+    }
+
+    let mut stream = response.bytes_stream();
+    while let Some(chunk) = stream.next().await {
+        let chunk = chunk?;
+        throttle(chunk.len()).await;
+        file.write_all(&chunk).await.path(part_path)?;
+        job.add_downloaded(chunk.len() as u64);
+    }
+    file.flush().await.path(part_path)?;
+    drop(file);
+
+    // Catches the file changing on the server between attempts
+    if let Some(Some(expected)) = resumed_size {
+        let got = part.len();
+        if got != expected {
+            // Emptied rather than removed, as it's still locked
+            part.file.set_len(0).path(part_path)?;
+            return Err(RequestError::SizeMismatch {
+                url: url.to_owned(),
+                expected,
+                got,
+            }
+            .into());
+        }
+    }
+
+    tokio::fs::rename(part_path, path).await.path(path)?;
+    job.finish();
+    Ok(())
+}
+
+/// Parses `Content-Range: bytes <start>-<end>/<total>`
+/// into the start and (if known) total size.
+fn get_content_range(response: &Response) -> Option<(u64, Option<u64>)> {
+    let range = response
+        .headers()
+        .get("Content-Range")?
+        .to_str()
+        .ok()?
+        .strip_prefix("bytes ")?;
+    let (range, total) = range.split_once('/')?;
+    let start = range.split_once('-')?.0.parse().ok()?;
+    Some((start, total.parse().ok()))
+}
+
+/// Tries each mirror of `url` in order
+/// (see [`mirror::get_urls`]), returning the first success.
+async fn send(
+    url: &str,
+    user_agent: Option<&str>,
+    range_start: u64,
+) -> Result<Response, RequestError> {
+    if is_offline() {
+        return Err(RequestError::Offline(url.to_owned()));
+    }
+    let mut urls = mirror::get_urls(url);
+    let last = urls.pop().unwrap_or_else(|| url.to_owned());
+    for url in &urls {
+        match send_to(url, user_agent, range_start).await {
+            Ok(response) => return Ok(response),
+            Err(error) => pt!("Download from {url} failed ({error}), trying next mirror"),
+        }
+    }
+    send_to(&last, user_agent, range_start).await
+}
+
+async fn send_to(
+    url: &str,
+    user_agent: Option<&str>,
+    range_start: u64,
+) -> Result<Response, RequestError> {
+    let mut get = CLIENT.get(url);
+    let user_agent_override = network::user_agent_override();
+    if let Some(user_agent) = user_agent_override.as_deref().or(user_agent) {
+        get = get.header("User-Agent", user_agent);
+    }
+    if range_start > 0 {
+        get = get.header("Range", format!("bytes={range_start}-"));
+    }
+
+    let response = get.send().await?;
+    if response.status().is_success() {
+        Ok(response)
+    } else {
+        Err(RequestError::DownloadError {
+            code: response.status(),
+            url: response.url().clone(),
+        })
+    }
+}
+
+/// The temporary file that a download to `path` is written to
+/// (`<path>.part`), kept after failed attempts (even across
+/// launcher restarts) so a later download can resume it.
+///
+/// It's locked while in use, so two downloads of the same file
+/// (in this launcher or another one) don't write to it at once.
+/// If it's already locked, a unique `.part` file is used instead,
+/// which can't be resumed later.
+pub(crate) struct PartFile {
+    pub path: PathBuf,
+    file: std::fs::File,
+    shared: bool,
+}
+
+impl PartFile {
+    pub async fn open(path: &Path) -> Result<Self, DownloadFileError> {
+        static COUNTER: AtomicUsize = AtomicUsize::new(0);
+
+        if let Some(parent) = path.parent() {
+            if !parent.is_dir() {
+                tokio::fs::create_dir_all(&parent).await.path(parent)?;
+            }
+        }
+
+        let part_path = get_part_path(path, "");
+        let file = tokio::fs::OpenOptions::new()
+            .read(true)
+            .write(true)
+            .create(true)
+            .truncate(false)
+            .open(&part_path)
+            .await
+            .path(&part_path)?
+            .into_std()
+            .await;
+        match file.try_lock() {
+            Ok(()) => {
+                return Ok(Self {
+                    path: part_path,
+                    file,
+                    shared: true,
+                })
+            }
+            Err(TryLockError::WouldBlock) => {}
+            Err(TryLockError::Error(err)) => {
+                pt!("Couldn't lock {part_path:?}, using a separate file: {err}");
+            }
+        }
+
+        let part_path = get_part_path(
+            path,
+            &format!(
+                ".{}.{}",
+                std::process::id(),
+                COUNTER.fetch_add(1, Ordering::Relaxed)
+            ),
+        );
+        let file = tokio::fs::OpenOptions::new()
+            .read(true)
+            .write(true)
+            .create_new(true)
+            .open(&part_path)
+            .await
+            .path(&part_path)?
+            .into_std()
+            .await;
+        Ok(Self {
+            path: part_path,
+            file,
+            shared: false,
+        })
+    }
+
+    fn len(&self) -> u64 {
+        self.file.metadata().map_or(0, |n| n.len())
+    }
+
+    /// Called when the download failed for good. Keeps the
+    /// file for a later download to resume, unless there's
+    /// nothing to resume (`resumable` is false for errors
+    /// like a missing file, where retrying won't help).
+    pub async fn fail(self, resumable: bool) {
+        if !(resumable && self.shared && self.len() > 0) {
+            _ = tokio::fs::remove_file(&self.path).await;
+        }
+    }
+}
+
+fn get_part_path(path: &Path, suffix: &str) -> PathBuf {
+    let mut part_path: OsString = path.as_os_str().to_owned();
+    part_path.push(suffix);
+    part_path.push(".part");
+    PathBuf::from(part_path)
+}
+
+/// Waits until the bandwidth limit allows
+/// downloading `bytes` more bytes.
+async fn throttle(bytes: usize) {
+    let limit = BANDWIDTH_LIMIT.load(Ordering::Relaxed);
+    if limit == 0 {
+        return;
+    }
+
+    let start_at = {
+        let mut next = NEXT_CHUNK_AT.lock().unwrap();
+        let now = Instant::now();
+        let start_at = next.map_or(now, |n| n.max(now));
+        *next = Some(start_at + Duration::from_secs_f64(bytes as f64 / limit as f64));
+        start_at
+    };
+    tokio::time::sleep_until(start_at.into()).await;
+}
+
+/// One of the [`max_concurrent`] download slots,
+/// freed when dropped.
+struct Slot;
+
+impl Slot {
+    async fn acquire() -> Self {
+        loop {
+            let freed = SLOT_FREED.notified();
+            {
+                let mut active = ACTIVE_SLOTS.lock().unwrap();
+                if *active < max_concurrent() {
+                    *active += 1;
+                    return Slot;
+                }
+            }
+            freed.await;
+        }
+    }
+}
+
+impl Drop for Slot {
+    fn drop(&mut self) {
+        *ACTIVE_SLOTS.lock().unwrap() -= 1;
+        SLOT_FREED.notify_one();
+    }
+}
+
+/// Tracks a download in the global [`stats`].
+///
+/// If dropped without calling [`Job::finish`] (ie. the download
+/// failed), its bytes are removed from the stats again,
+/// so retries don't count twice.
+struct Job {
+    downloaded: u64,
+    total: u64,
+    finished: bool,
+}
+
+impl Job {
+    fn start() -> Self {
+        if STATS_JOBS.fetch_add(1, Ordering::Relaxed) == 0 {
+            // New batch of downloads
+            STATS_DOWNLOADED.store(0, Ordering::Relaxed);
+            STATS_TOTAL.store(0, Ordering::Relaxed);
+        }
+        Self {
+            downloaded: 0,
+            total: 0,
+            finished: false,
+        }
+    }
+
+    fn add_downloaded(&mut self, bytes: u64) {
+        self.downloaded += bytes;
+        STATS_DOWNLOADED.fetch_add(bytes, Ordering::Relaxed);
+    }
+
+    fn add_total(&mut self, bytes: u64) {
+        self.total += bytes;
+        STATS_TOTAL.fetch_add(bytes, Ordering::Relaxed);
+    }
+
+    fn finish(mut self) {
+        self.finished = true;
+    }
+}
+
+impl Drop for Job {
+    fn drop(&mut self) {
+        if !self.finished {
+            STATS_DOWNLOADED.fetch_sub(self.downloaded, Ordering::Relaxed);
+            STATS_TOTAL.fetch_sub(self.total, Ordering::Relaxed);
+        }
+        STATS_JOBS.fetch_sub(1, Ordering::Relaxed);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Write;
+
+    use super::PartFile;
+
+    #[tokio::test]
+    async fn part_files() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let path = dir.path().join("mods/sodium.jar");
+        let shared_path = dir.path().join("mods/sodium.jar.part");
+
+        let first = PartFile::open(&path).await.unwrap();
+        assert_eq!(first.path, shared_path);
+
+        // A second download of the same file at the same time
+        let second = PartFile::open(&path).await.unwrap();
+        assert_ne!(second.path, shared_path);
+        let second_path = second.path.clone();
+        second.fail(true).await;
+        assert!(!second_path.exists());
+
+        (&first.file).write_all(b"abc").unwrap();
+        first.fail(true).await;
+        assert!(shared_path.exists());
+
+        // Picked up again later, to resume
+        let again = PartFile::open(&path).await.unwrap();
+        assert_eq!(again.path, shared_path);
+        assert_eq!(again.len(), 3);
+        again.fail(false).await;
+        assert!(!shared_path.exists());
+    }
+}
@@ -7,18 +7,17 @@ use std::{
     sync::LazyLock,
 };
 
-use futures::StreamExt;
 use ql_reqwest::header::InvalidHeaderValue;
 use serde::de::DeserializeOwned;
 use thiserror::Error;
 use tokio::fs::DirEntry;
-use tokio_util::io::StreamReader;
 use walkdir::WalkDir;
 use zip::{write::FileOptions, ZipWriter};
 
 use crate::{
+    download,
     error::{DownloadFileError, IoError},
-    info_no_log, retry, IntoIoError, IntoJsonError, JsonDownloadError, CLIENT,
+    info_no_log, retry_if, IntoIoError, IntoJsonError, JsonDownloadError,
 };
 
 /// The path to the QuantumLauncher root folder.
@@ -182,26 +181,17 @@ pub fn is_new_user() -> bool {
 /// - Redirect loop detected
 /// - Redirect limit exhausted.
 pub async fn download_file_to_string(url: &str, user_agent: bool) -> Result<String, RequestError> {
-    async fn inner(url: &str, user_agent: bool) -> Result<String, RequestError> {
-        let mut get = CLIENT.get(url);
-        if user_agent {
-            get = get.header(
-                "User-Agent",
-                "Mrmayman/quantumlauncher (mrmayman.github.io/quantumlauncher)",
-            );
-        }
-        let response = get.send().await?;
-        if response.status().is_success() {
-            Ok(response.text().await?)
-        } else {
-            Err(RequestError::DownloadError {
-                code: response.status(),
-                url: response.url().clone(),
-            })
-        }
-    }
-
-    retry(async || inner(url, user_agent).await).await
+    let user_agent =
+        user_agent.then_some("Mrmayman/quantumlauncher (mrmayman.github.io/quantumlauncher)");
+    let bytes = retry_if(
+        async || download::fetch(url, user_agent).await,
+        RequestError::is_retryable,
+    )
+    .await?;
+    Ok(match String::from_utf8(bytes) {
+        Ok(n) => n,
+        Err(err) => String::from_utf8_lossy(err.as_bytes()).into_owned(),
+    })
 }
 
 /// Downloads a file from the given URL into a JSON.
@@ -232,7 +222,14 @@ pub async fn download_file_to_json<T: DeserializeOwned>(
         Ok(serde_json::from_str(&text).json(text)?)
     }
 
-    retry(async || inner(url, user_agent).await).await
+    retry_if(
+        async || inner(url, user_agent).await,
+        |err| match err {
+            JsonDownloadError::RequestError(err) => err.is_retryable(),
+            JsonDownloadError::SerdeError(_) => true,
+        },
+    )
+    .await
 }
 
 /// Downloads a file from the given URL into a `Vec<u8>`.
@@ -249,23 +246,12 @@ pub async fn download_file_to_json<T: DeserializeOwned>(
 /// - Redirect loop detected
 /// - Redirect limit exhausted.
 pub async fn download_file_to_bytes(url: &str, user_agent: bool) -> Result<Vec<u8>, RequestError> {
-    async fn inner(url: &str, user_agent: bool) -> Result<Vec<u8>, RequestError> {
-        let mut get = CLIENT.get(url);
-        if user_agent {
-            get = get.header("User-Agent", "quantumlauncher");
-        }
-        let response = get.send().await?;
-        if response.status().is_success() {
-            Ok(response.bytes().await?.to_vec())
-        } else {
-            Err(RequestError::DownloadError {
-                code: response.status(),
-                url: response.url().clone(),
-            })
-        }
-    }
-
-    retry(async || inner(url, user_agent).await).await
+    let user_agent = user_agent.then_some("quantumlauncher");
+    retry_if(
+        async || download::fetch(url, user_agent).await,
+        RequestError::is_retryable,
+    )
+    .await
 }
 
 /// Downloads a file from the given URL and saves it to a path.
@@ -290,38 +276,21 @@ pub async fn download_file_to_path(
     user_agent: bool,
     path: &Path,
 ) -> Result<(), DownloadFileError> {
-    async fn inner(url: &str, user_agent: bool, path: &Path) -> Result<(), DownloadFileError> {
-        let mut get = CLIENT.get(url);
-        if user_agent {
-            get = get.header("User-Agent", "quantumlauncher");
-        }
-        let response = get.send().await?;
-
-        if response.status().is_success() {
-            let stream = response
-                .bytes_stream()
-                .map(|n| n.map_err(std::io::Error::other));
-            let mut stream = StreamReader::new(stream);
-
-            if let Some(parent) = path.parent() {
-                if !parent.is_dir() {
-                    tokio::fs::create_dir_all(&parent).await.path(parent)?;
-                }
-            }
-
-            let mut file = tokio::fs::File::create(&path).await.path(path)?;
-            tokio::io::copy(&mut stream, &mut file).await.path(path)?;
-            Ok(())
-        } else {
-            Err(RequestError::DownloadError {
-                code: response.status(),
-                url: response.url().clone(),
-            }
-            .into())
-        }
+    let user_agent = user_agent.then_some("quantumlauncher");
+    let is_retryable = |err: &DownloadFileError| match err {
+        DownloadFileError::Request(err) => err.is_retryable(),
+        DownloadFileError::Io(_) => false,
+    };
+    let part = download::PartFile::open(path).await?;
+    let result = retry_if(
+        async || download::fetch_to_path(url, user_agent, path, &part).await,
+        is_retryable,
+    )
+    .await;
+    if let Err(err) = &result {
+        part.fail(is_retryable(err)).await;
     }
-
-    retry(async || inner(url, user_agent, path).await).await
+    result
 }
 
 /// Downloads a file from the given URL into a `Vec<u8>`,
@@ -342,23 +311,11 @@ pub async fn download_file_to_bytes_with_agent(
     url: &str,
     user_agent: &str,
 ) -> Result<Vec<u8>, RequestError> {
-    async fn inner(url: &str, user_agent: &str) -> Result<Vec<u8>, RequestError> {
-        let response = CLIENT
-            .get(url)
-            .header("User-Agent", user_agent)
-            .send()
-            .await?;
-        if response.status().is_success() {
-            Ok(response.bytes().await?.to_vec())
-        } else {
-            Err(RequestError::DownloadError {
-                code: response.status(),
-                url: response.url().clone(),
-            })
-        }
-    }
-
-    retry(async || inner(url, user_agent).await).await
+    retry_if(
+        async || download::fetch(url, Some(user_agent)).await,
+        RequestError::is_retryable,
+    )
+    .await
 }
 
 const NETWORK_ERROR_MSG: &str = r"
@@ -379,6 +336,33 @@ pub enum RequestError {
     ReqwestError(#[from] ql_reqwest::Error),
     #[error("Download Error (invalid header value){NETWORK_ERROR_MSG}")]
     InvalidHeaderValue(#[from] InvalidHeaderValue),
+    #[error("Can't download while in offline mode\nUrl: {0}")]
+    Offline(String),
+    #[error("Download Error (got {got} bytes, expected {expected}){NETWORK_ERROR_MSG}Url: {url}")]
+    SizeMismatch {
+        url: String,
+        expected: u64,
+        got: u64,
+    },
+}
+
+impl RequestError {
+    /// Whether trying again could help.
+    ///
+    /// Errors like "404 Not Found" will just
+    /// happen again, so there's no point retrying.
+    #[must_use]
+    pub fn is_retryable(&self) -> bool {
+        match self {
+            RequestError::DownloadError { code, .. } => {
+                !code.is_client_error()
+                    || *code == ql_reqwest::StatusCode::REQUEST_TIMEOUT
+                    || *code == ql_reqwest::StatusCode::TOO_MANY_REQUESTS
+            }
+            RequestError::ReqwestError(_) | RequestError::SizeMismatch { .. } => true,
+            RequestError::InvalidHeaderValue(_) | RequestError::Offline(_) => false,
+        }
+    }
 }
 
 /// Sets the executable bit on a file.
@@ -431,6 +415,23 @@ pub fn create_symlink(src: &Path, dest: &Path) -> Result<(), IoError> {
     }
 }
 
+/// Creates a hard link at `dest` pointing to the
+/// same data as `src`, falling back to copying
+/// if hard links aren't possible (eg: `src` and `dest`
+/// are on different drives, or the filesystem
+/// doesn't support them).
+///
+/// # Errors
+/// - If `src` doesn't exist
+/// - If `dest` already exists (hard link) and couldn't be overwritten (copy)
+/// - If user doesn't have permission for `src`/`dest`
+pub async fn hard_link_or_copy(src: &Path, dest: &Path) -> Result<(), IoError> {
+    if tokio::fs::hard_link(src, dest).await.is_err() {
+        tokio::fs::copy(src, dest).await.path(src)?;
+    }
+    Ok(())
+}
+
 pub async fn clean_log_spam() -> Result<(), IoError> {
     const SIZE_LIMIT_BYTES: u64 = 100 * 1024 * 1024; // 100 MB
 
@@ -439,12 +440,38 @@ pub async fn clean_log_spam() -> Result<(), IoError> {
         tokio::fs::create_dir_all(&logs_dir).await.path(logs_dir)?;
         return Ok(());
     }
+
+    let freed = clean_old_files(&logs_dir, SIZE_LIMIT_BYTES, None).await?;
+    if freed > 0 {
+        info_no_log!(
+            "Log exceeded {} MB, cleaned up",
+            SIZE_LIMIT_BYTES / (1024 * 1024)
+        );
+    }
+    Ok(())
+}
+
+/// Deletes files (not folders) directly inside `dir`,
+/// oldest first, until their total size is under `size_limit`.
+///
+/// If `max_age` is given, files that were last modified
+/// longer ago than that are deleted too.
+///
+/// Returns the number of bytes freed.
+///
+/// # Errors
+/// If `dir` couldn't be read or a file couldn't be deleted.
+pub async fn clean_old_files(
+    dir: &Path,
+    size_limit: u64,
+    max_age: Option<std::time::Duration>,
+) -> Result<u64, IoError> {
     let mut total_size = 0;
     let mut files: Vec<(DirEntry, Metadata)> = Vec::new();
 
-    let mut read_dir = tokio::fs::read_dir(&logs_dir).await.dir(&logs_dir)?;
+    let mut read_dir = tokio::fs::read_dir(dir).await.dir(dir)?;
 
-    while let Some(entry) = read_dir.next_entry().await.dir(&logs_dir)? {
+    while let Some(entry) = read_dir.next_entry().await.dir(dir)? {
         let metadata = entry.metadata().await.path(entry.path())?;
         if metadata.is_file() {
             total_size += metadata.len();
@@ -452,29 +479,31 @@ pub async fn clean_log_spam() -> Result<(), IoError> {
         }
     }
 
-    if total_size <= SIZE_LIMIT_BYTES {
-        return Ok(());
-    }
-
-    info_no_log!(
-        "Log exceeded {} MB, cleaning up",
-        SIZE_LIMIT_BYTES / (1024 * 1024)
-    );
     files.sort_unstable_by_key(|(_, metadata)| {
         metadata.modified().unwrap_or(std::time::SystemTime::now())
     });
 
+    let mut freed = 0;
     for (file, metadata) in files {
+        let is_old = max_age.is_some_and(|max_age| {
+            metadata
+                .modified()
+                .ok()
+                .and_then(|n| n.elapsed().ok())
+                .is_some_and(|n| n > max_age)
+        });
+        if total_size <= size_limit && !is_old {
+            // Sorted oldest first, so nothing after this is old either
+            break;
+        }
+
         let path = file.path();
         tokio::fs::remove_file(&path).await.path(path)?;
         total_size -= metadata.len();
-
-        if total_size <= SIZE_LIMIT_BYTES {
-            break;
-        }
+        freed += metadata.len();
     }
 
-    Ok(())
+    Ok(freed)
 }
 
 /// Recursively copies the contents of
@@ -652,6 +681,28 @@ pub async fn find_item_in_dir<F: FnMut(&Path, &str) -> bool>(
     Ok(None)
 }
 
+/// Returns the SHA-1 hash of `data`
+/// as a lowercase hex string.
+#[must_use]
+pub fn get_sha1(data: &[u8]) -> String {
+    use sha1::{Digest, Sha1};
+    Sha1::digest(data)
+        .iter()
+        .map(|b| format!("{b:02x}"))
+        .collect()
+}
+
+/// Returns the SHA-512 hash of `data`
+/// as a lowercase hex string.
+#[must_use]
+pub fn get_sha512(data: &[u8]) -> String {
+    use sha2::{Digest, Sha512};
+    Sha512::digest(data)
+        .iter()
+        .map(|b| format!("{b:02x}"))
+        .collect()
+}
+
 pub async fn zip_directory_to_bytes<P: AsRef<Path>>(dir: P) -> std::io::Result<Vec<u8>> {
     let mut buffer = Cursor::new(Vec::new());
     let mut zip = ZipWriter::new(&mut buffer);
@@ -117,6 +117,37 @@ pub struct InstanceConfigJson {
     ///
     /// Ultimately if you want one less icon in your taskbar then go ahead.
     pub close_on_start: Option<bool>,
+    /// **Client Only**
+    ///
+    /// The account this instance was last launched with.
+    /// It's selected again when selecting this instance.
+    ///
+    /// - `"Offline:<username>"` for offline profiles
+    /// - Otherwise the name of the account in the launcher config
+    ///   (eg: `"Steve"`, `"Steve (elyby)"`)
+    ///
+    /// Added in v0.4.3
+    pub last_account: Option<String>,
+    /// The sidebar group (folder) the instance is in.
+    /// `None` for ungrouped instances.
+    ///
+    /// Added in v0.4.3
+    pub group: Option<String>,
+    /// **Default: `false`**
+    ///
+    /// Favourites are shown at the top of the sidebar.
+    ///
+    /// Added in v0.4.3
+    pub is_favorite: Option<bool>,
+    /// A colour tag shown next to the instance
+    /// in the sidebar, as a hex code (eg: `"#e05555"`).
+    ///
+    /// Added in v0.4.3
+    pub color: Option<String>,
+    /// Notes written by the user, about the instance.
+    ///
+    /// Added in v0.4.3
+    pub notes: Option<String>,
 }
 
 impl InstanceConfigJson {
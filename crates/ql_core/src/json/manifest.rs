@@ -1,4 +1,4 @@
-use crate::{file_utils, IntoJsonError, JsonDownloadError};
+use crate::{meta_cache, IntoJsonError, JsonDownloadError};
 use serde::Deserialize;
 
 /// An official Minecraft version manifest
@@ -25,6 +25,9 @@ impl Manifest {
     /// - ARM32 linux: <https://raw.githubusercontent.com/theofficialgman/piston-meta-arm32/refs/heads/main/mc/game/version_manifest_v2.json>
     /// - Other platforms: <https://launchermeta.mojang.com/mc/game/version_manifest_v2.json>
     ///
+    /// Both files are cached (see [`meta_cache`]), so this
+    /// works offline if it has succeeded once before.
+    ///
     /// # Errors
     /// Returns an error if either file cannot be downloaded or parsed into JSON.
     pub async fn download() -> Result<Manifest, JsonDownloadError> {
@@ -42,12 +45,15 @@ impl Manifest {
             "https://launchermeta.mojang.com/mc/game/version_manifest_v2.json";
 
         let (older_manifest, newer_manifest) = tokio::try_join!(
-            file_utils::download_file_to_string(OLDER_VERSIONS_JSON, false),
-            file_utils::download_file_to_string(NEWER_VERSIONS_JSON, false)
+            meta_cache::download_string(OLDER_VERSIONS_JSON, false),
+            meta_cache::download_string(NEWER_VERSIONS_JSON, false)
         )?;
         let mut older_manifest: Self =
             serde_json::from_str(&older_manifest).json(older_manifest)?;
         let newer_manifest: Self = serde_json::from_str(&newer_manifest).json(newer_manifest)?;
+        for version in &mut older_manifest.versions {
+            version.source = VersionSource::BetterJsons;
+        }
 
         // Removes newer versions from out-of-date manifest
         // if it ever gets updated, to not mess up the list.
@@ -106,6 +112,96 @@ pub struct Version {
     pub url: String,
     pub time: String,
     pub releaseTime: String,
+    /// Which manifest this version came from
+    /// (set by [`Manifest::download`]).
+    #[serde(skip)]
+    pub source: VersionSource,
+}
+
+/// The `type` of a version in the manifest.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum VersionType {
+    Release,
+    Snapshot,
+    /// `old_beta`
+    Beta,
+    /// `old_alpha` (also used for Classic, Indev, Infdev)
+    Alpha,
+    /// Unknown type, or not from a manifest
+    /// (eg: imported instances)
+    Other,
+}
+
+impl VersionType {
+    pub const ALL: &[Self] = &[Self::Release, Self::Snapshot, Self::Beta, Self::Alpha];
+
+    /// Parses the manifest's `type` field.
+    #[must_use]
+    pub fn from_manifest(r#type: &str) -> Self {
+        match r#type {
+            "release" => Self::Release,
+            "snapshot" => Self::Snapshot,
+            "old_beta" => Self::Beta,
+            "old_alpha" => Self::Alpha,
+            _ => Self::Other,
+        }
+    }
+}
+
+impl std::fmt::Display for VersionType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                Self::Release => "release",
+                Self::Snapshot => "snapshot",
+                Self::Beta => "beta",
+                Self::Alpha => "alpha",
+                Self::Other => "other",
+            }
+        )
+    }
+}
+
+impl std::str::FromStr for VersionType {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "release" => Ok(Self::Release),
+            "snapshot" => Ok(Self::Snapshot),
+            "beta" | "old_beta" => Ok(Self::Beta),
+            "alpha" | "old_alpha" => Ok(Self::Alpha),
+            _ => Err(format!(
+                "unknown version type {s} (expected release, snapshot, beta or alpha)"
+            )),
+        }
+    }
+}
+
+/// Where a version's entry in the list came from.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum VersionSource {
+    /// The official manifest (or the ARM fork of it)
+    #[default]
+    Mojang,
+    /// The curated [BetterJSONs](https://mcphackers.org/BetterJSONs/version_manifest_v2.json)
+    /// manifest, with old versions from Omniarchive
+    BetterJsons,
+}
+
+impl std::fmt::Display for VersionSource {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                Self::Mojang => "Mojang",
+                Self::BetterJsons => "BetterJSONs",
+            }
+        )
+    }
 }
 
 fn exclude_versions_after<T, F>(vec: &[T], predicate: F) -> Vec<T>
@@ -15,6 +15,10 @@
 #![allow(clippy::cast_precision_loss)]
 #![allow(clippy::missing_errors_doc)]
 
+/// Disk usage report and cleanup of the launcher folder.
+pub mod disk_usage;
+/// Central download manager (concurrency, bandwidth limit, resuming).
+pub mod download;
 mod error;
 /// Common utilities for working with files.
 pub mod file_utils;
@@ -22,6 +26,12 @@ pub mod jarmod;
 /// JSON structs for version, instance config, Fabric, Forge, Optifine, Quilt, Neoforge, etc.
 pub mod json;
 mod loader;
+/// Cache of downloaded metadata, for when there's no internet.
+pub mod meta_cache;
+/// Download mirrors (BMCLAPI, caching proxies, etc.) with a fallback chain.
+pub mod mirror;
+/// Proxy, CA certificate, timeout and user agent settings.
+pub mod network;
 /// Logging macros.
 pub mod print;
 mod progress;
@@ -34,12 +44,14 @@ use std::{
     sync::LazyLock,
 };
 
+use chrono::{DateTime, FixedOffset};
 pub use error::{
     DownloadFileError, IntoIoError, IntoJsonError, IntoStringError, IoError, JsonDownloadError,
     JsonError, JsonFileError,
 };
 pub use file_utils::{RequestError, LAUNCHER_DIR};
 use futures::StreamExt;
+pub use json::manifest::{VersionSource, VersionType};
 use json::VersionDetails;
 pub use loader::Loader;
 pub use print::{logger_finish, LogType, LoggingState, LOGGER};
@@ -63,11 +75,15 @@ macro_rules! no_window {
     };
 }
 
-pub static CLIENT: LazyLock<ql_reqwest::Client> = LazyLock::new(ql_reqwest::Client::new);
+/// The HTTP client used for all requests,
+/// configured by [`network::set_config`].
+pub static CLIENT: LazyLock<ql_reqwest::Client> = LazyLock::new(network::build_global_client);
 
 /// Perform multiple async tasks concurrently. Useful for things like
 /// downloading lots of files at the same time.
 ///
+/// At most [`download::max_concurrent`] tasks run at once.
+///
 /// # Calling
 ///
 /// This takes in an `Iterator` of the `Future` of `async fn -> Result<T, E>`
@@ -99,13 +115,13 @@ pub static CLIENT: LazyLock<ql_reqwest::Client> = LazyLock::new(ql_reqwest::Clie
 pub async fn do_jobs<T, E>(
     results: impl Iterator<Item = impl std::future::Future<Output = Result<T, E>>>,
 ) -> Result<Vec<T>, E> {
-    const JOBS: usize = 64;
+    let jobs = download::max_concurrent();
     let mut tasks = futures::stream::FuturesUnordered::new();
     let mut outputs = Vec::new();
 
     for result in results {
         tasks.push(result);
-        if tasks.len() > JOBS {
+        if tasks.len() > jobs {
             if let Some(task) = tasks.next().await {
                 outputs.push(task?);
             }
@@ -167,6 +183,38 @@ where
     result
 }
 
+/// Like [`retry`], but only retries if
+/// `should_retry` returns `true` for the error,
+/// and waits longer between each try
+/// (0.5s, 1s, 2s, 4s, 8s).
+///
+/// Useful for downloads, to not retry errors that will
+/// just happen again (like "404 Not Found") and to give
+/// a struggling server some time. Don't use this where
+/// the user is waiting on a quick answer (like logging in).
+///
+/// # Errors
+/// Returns whatever error the original function returned.
+pub async fn retry_if<T, E, Res, Func>(f: Func, should_retry: impl Fn(&E) -> bool) -> Result<T, E>
+where
+    Res: Future<Output = Result<T, E>>,
+    Func: Fn() -> Res,
+{
+    const LIMIT: u32 = 5;
+    const FIRST_DELAY: std::time::Duration = std::time::Duration::from_millis(500);
+
+    let mut result = f().await;
+    for i in 0..LIMIT {
+        match &result {
+            Err(err) if should_retry(err) => {}
+            _ => break,
+        }
+        tokio::time::sleep(FIRST_DELAY * 2u32.pow(i)).await;
+        result = f().await;
+    }
+    result
+}
+
 #[derive(Clone, Debug)]
 pub enum InstanceSelection {
     Instance(String),
@@ -201,6 +249,13 @@ impl InstanceSelection {
         }
     }
 
+    /// The custom icon of the instance, shown in the sidebar.
+    /// May not exist.
+    #[must_use]
+    pub fn get_icon_path(&self) -> PathBuf {
+        self.get_instance_path().join("icon.png")
+    }
+
     #[must_use]
     pub fn get_name(&self) -> &str {
         match self {
@@ -226,10 +281,43 @@ impl InstanceSelection {
 /// - `name`: The name of the version according to
 ///   [BetterJSONs](https://mcphackers.org/BetterJSONs/version_manifest_v2.json)
 /// - `is_classic_server`: Whether it is a Minecraft Classic entry
+/// - `kind`: Release, snapshot, etc.
+/// - `release_time`: When the version came out (if known)
+/// - `source`: Which manifest it came from
 #[derive(Debug, Clone)]
 pub struct ListEntry {
     pub name: String,
     pub is_classic_server: bool,
+    pub kind: VersionType,
+    pub release_time: Option<DateTime<FixedOffset>>,
+    pub source: VersionSource,
+}
+
+impl ListEntry {
+    /// An entry with just a name, for versions not from
+    /// the version list (eg: when importing instances).
+    #[must_use]
+    pub fn new(name: String) -> Self {
+        Self {
+            name,
+            is_classic_server: false,
+            kind: VersionType::Other,
+            release_time: None,
+            source: VersionSource::Mojang,
+        }
+    }
+}
+
+impl From<json::manifest::Version> for ListEntry {
+    fn from(version: json::manifest::Version) -> Self {
+        Self {
+            kind: VersionType::from_manifest(&version.r#type),
+            release_time: DateTime::parse_from_rfc3339(&version.releaseTime).ok(),
+            source: version.source,
+            name: version.id,
+            is_classic_server: false,
+        }
+    }
 }
 
 impl Display for ListEntry {
@@ -0,0 +1,131 @@
+//! A cache of downloaded metadata (version lists,
+//! version JSONs, loader lists, etc.), so the launcher
+//! still works without internet.
+//!
+//! Every successful download through this module is saved to
+//! `QuantumLauncher/cache/meta/`, along with when it was downloaded.
+//! If a later download fails (or [offline mode](crate::download::set_offline)
+//! is on), the cached copy is used instead.
+//!
+//! Only use this for small files that change rarely,
+//! not for mods, libraries or assets.
+
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+
+use crate::{
+    download, err, file_utils, IntoJsonError, JsonDownloadError, RequestError, LAUNCHER_DIR,
+};
+
+#[derive(Serialize, Deserialize)]
+struct CachedResponse {
+    url: String,
+    /// Unix timestamp (seconds) of when this was downloaded.
+    fetched_at: u64,
+    body: String,
+}
+
+/// Downloads a file into a `String`, falling back to
+/// the cached copy if the download fails.
+///
+/// See [`file_utils::download_file_to_string`] for the arguments.
+///
+/// # Errors
+/// If the download failed and there's no cached copy.
+pub async fn download_string(url: &str, user_agent: bool) -> Result<String, RequestError> {
+    if download::is_offline() {
+        return match read(url).await {
+            Some(cached) => Ok(cached.body),
+            None => Err(RequestError::Offline(url.to_owned())),
+        };
+    }
+
+    match file_utils::download_file_to_string(url, user_agent).await {
+        Ok(body) => {
+            write(url, &body).await;
+            Ok(body)
+        }
+        Err(error) => {
+            let Some(cached) = read(url).await else {
+                return Err(error);
+            };
+            err!(
+                "Couldn't download {url}, using cached copy from {} ago\n{error}",
+                format_age(cached.fetched_at)
+            );
+            Ok(cached.body)
+        }
+    }
+}
+
+/// Downloads a file into a JSON, falling back to
+/// the cached copy if the download fails.
+///
+/// # Errors
+/// - If the download failed and there's no cached copy.
+/// - If the file couldn't be parsed into JSON
+pub async fn download_json<T: DeserializeOwned>(
+    url: &str,
+    user_agent: bool,
+) -> Result<T, JsonDownloadError> {
+    let text = download_string(url, user_agent).await?;
+    Ok(serde_json::from_str(&text).json(text)?)
+}
+
+/// When `url` was last downloaded successfully,
+/// if it's in the cache.
+pub async fn fetched_at(url: &str) -> Option<SystemTime> {
+    read(url)
+        .await
+        .map(|n| UNIX_EPOCH + Duration::from_secs(n.fetched_at))
+}
+
+async fn read(url: &str) -> Option<CachedResponse> {
+    let cached = tokio::fs::read_to_string(cache_path(url)).await.ok()?;
+    let cached: CachedResponse = serde_json::from_str(&cached).ok()?;
+    // Just in case of a hash collision
+    (cached.url == url).then_some(cached)
+}
+
+async fn write(url: &str, body: &str) {
+    let cached = CachedResponse {
+        url: url.to_owned(),
+        fetched_at: SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |n| n.as_secs()),
+        body: body.to_owned(),
+    };
+    let path = cache_path(url);
+    let result = async {
+        if let Some(parent) = path.parent() {
+            tokio::fs::create_dir_all(parent).await?;
+        }
+        let cached = serde_json::to_string(&cached)?;
+        tokio::fs::write(&path, cached).await
+    }
+    .await;
+    if let Err(error) = result {
+        err!("Couldn't cache {url}: {error}");
+    }
+}
+
+fn cache_path(url: &str) -> std::path::PathBuf {
+    LAUNCHER_DIR
+        .join("cache/meta")
+        .join(format!("{}.json", file_utils::get_sha1(url.as_bytes())))
+}
+
+fn format_age(fetched_at: u64) -> String {
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |n| n.as_secs());
+    let secs = now.saturating_sub(fetched_at);
+    if secs < 60 * 60 {
+        format!("{} minutes", secs / 60)
+    } else if secs < 24 * 60 * 60 {
+        format!("{} hours", secs / (60 * 60))
+    } else {
+        format!("{} days", secs / (24 * 60 * 60))
+    }
+}
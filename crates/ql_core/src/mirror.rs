@@ -0,0 +1,266 @@
+//! Download mirrors, for networks where the official
+//! servers (Mojang, Fabric, Forge, etc.) are slow or blocked.
+//!
+//! Mirrors are set per [`MirrorSource`] in a [`MirrorConfig`],
+//! and are tried in order, falling back to the
+//! official server if all of them fail.
+//!
+//! All downloads through [`crate::file_utils`]
+//! use the mirrors automatically.
+//!
+//! # Mirror formats
+//! - `https://mirror.example.com` - Replaces the official
+//!   host, keeping the path.
+//!   `https://meta.fabricmc.net/v2/versions` becomes
+//!   `https://mirror.example.com/v2/versions`
+//! - `https://cache.example.com/{host}` - For caching proxies.
+//!   `https://meta.fabricmc.net/v2/versions` becomes
+//!   `https://cache.example.com/meta.fabricmc.net/v2/versions`
+//! - `bmclapi` - The [BMCLAPI](https://bmclapi2.bangbang93.com)
+//!   mirror (only supports Mojang, Forge, Fabric, Quilt and NeoForge).
+
+use std::{collections::BTreeMap, sync::RwLock};
+
+use serde::{Deserialize, Serialize};
+
+const BMCLAPI: &str = "https://bmclapi2.bangbang93.com";
+/// The keyword for using the BMCLAPI mirror.
+pub const BMCLAPI_KEYWORD: &str = "bmclapi";
+
+/// (Official URL prefix, BMCLAPI URL prefix)
+const BMCLAPI_PATHS: &[(&str, &str)] = &[
+    ("https://launchermeta.mojang.com", ""),
+    ("https://launcher.mojang.com", ""),
+    ("https://piston-meta.mojang.com", ""),
+    ("https://piston-data.mojang.com", ""),
+    ("https://resources.download.minecraft.net", "/assets"),
+    ("https://libraries.minecraft.net", "/maven"),
+    ("https://files.minecraftforge.net/maven", "/maven"),
+    ("https://maven.minecraftforge.net", "/maven"),
+    ("https://meta.fabricmc.net", "/fabric-meta"),
+    ("https://maven.fabricmc.net", "/maven"),
+    ("https://meta.quiltmc.org", "/quilt-meta"),
+    ("https://maven.quiltmc.org/repository/release", "/maven"),
+    ("https://maven.neoforged.net/releases", "/maven"),
+];
+
+static MIRRORS: RwLock<Option<MirrorConfig>> = RwLock::new(None);
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum MirrorSource {
+    Mojang,
+    Fabric,
+    Quilt,
+    Forge,
+    NeoForge,
+    Paper,
+    Corretto,
+}
+
+impl MirrorSource {
+    pub const ALL: &[Self] = &[
+        Self::Mojang,
+        Self::Fabric,
+        Self::Quilt,
+        Self::Forge,
+        Self::NeoForge,
+        Self::Paper,
+        Self::Corretto,
+    ];
+
+    /// The official hosts of this source.
+    #[must_use]
+    pub fn hosts(self) -> &'static [&'static str] {
+        match self {
+            MirrorSource::Mojang => &[
+                "launchermeta.mojang.com",
+                "launcher.mojang.com",
+                "piston-meta.mojang.com",
+                "piston-data.mojang.com",
+                "libraries.minecraft.net",
+                "resources.download.minecraft.net",
+            ],
+            MirrorSource::Fabric => &["meta.fabricmc.net", "maven.fabricmc.net"],
+            MirrorSource::Quilt => &["meta.quiltmc.org", "maven.quiltmc.org"],
+            MirrorSource::Forge => &["files.minecraftforge.net", "maven.minecraftforge.net"],
+            MirrorSource::NeoForge => &["maven.neoforged.net"],
+            MirrorSource::Paper => &["qing762.is-a.dev", "api.papermc.io", "fill-data.papermc.io"],
+            MirrorSource::Corretto => &["corretto.aws"],
+        }
+    }
+}
+
+impl std::fmt::Display for MirrorSource {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                MirrorSource::Mojang => "Minecraft",
+                MirrorSource::Fabric => "Fabric",
+                MirrorSource::Quilt => "Quilt",
+                MirrorSource::Forge => "Forge",
+                MirrorSource::NeoForge => "NeoForge",
+                MirrorSource::Paper => "Paper",
+                MirrorSource::Corretto => "Java (Corretto)",
+            }
+        )
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct MirrorConfig {
+    /// Mirrors for each source, tried in order.
+    /// See the [module docs](self) for the format.
+    #[serde(default)]
+    pub mirrors: BTreeMap<MirrorSource, Vec<String>>,
+    /// Whether to try the official server
+    /// if all the mirrors fail.
+    #[serde(default = "default_true")]
+    pub fallback_to_official: bool,
+}
+
+fn default_true() -> bool {
+    true
+}
+
+impl Default for MirrorConfig {
+    fn default() -> Self {
+        Self {
+            mirrors: BTreeMap::new(),
+            fallback_to_official: true,
+        }
+    }
+}
+
+impl MirrorConfig {
+    /// Returns the URLs to try (in order) for downloading `url`.
+    #[must_use]
+    pub fn get_urls(&self, url: &str) -> Vec<String> {
+        let Some((host, path)) = split_url(url) else {
+            return vec![url.to_owned()];
+        };
+        let Some(source) = MirrorSource::ALL.iter().find(|n| n.hosts().contains(&host)) else {
+            return vec![url.to_owned()];
+        };
+
+        let mut urls: Vec<String> = self
+            .mirrors
+            .get(source)
+            .into_iter()
+            .flatten()
+            .map(|n| n.trim())
+            .filter(|n| !n.is_empty())
+            .filter_map(|mirror| rewrite(mirror, url, host, path))
+            .collect();
+        if urls.is_empty() || self.fallback_to_official {
+            urls.push(url.to_owned());
+        }
+        urls
+    }
+}
+
+/// Sets the mirrors used by all downloads.
+pub fn set_mirrors(config: MirrorConfig) {
+    *MIRRORS.write().unwrap() = Some(config);
+}
+
+/// Returns the URLs to try (in order) for downloading `url`,
+/// using the mirrors set by [`set_mirrors`].
+#[must_use]
+pub fn get_urls(url: &str) -> Vec<String> {
+    match &*MIRRORS.read().unwrap() {
+        Some(config) => config.get_urls(url),
+        None => vec![url.to_owned()],
+    }
+}
+
+fn rewrite(mirror: &str, url: &str, host: &str, path: &str) -> Option<String> {
+    if mirror.eq_ignore_ascii_case(BMCLAPI_KEYWORD) {
+        let (official, prefix) = BMCLAPI_PATHS.iter().find(|(n, _)| {
+            url.strip_prefix(n)
+                .is_some_and(|rest| rest.is_empty() || rest.starts_with('/'))
+        })?;
+        return Some(format!("{BMCLAPI}{prefix}{}", &url[official.len()..]));
+    }
+    let base = mirror.trim_end_matches('/');
+    Some(if base.contains("{host}") {
+        format!("{}{path}", base.replace("{host}", host))
+    } else {
+        format!("{base}{path}")
+    })
+}
+
+/// `https://example.com/a/b` -> `("example.com", "/a/b")`
+fn split_url(url: &str) -> Option<(&str, &str)> {
+    let rest = url
+        .strip_prefix("https://")
+        .or_else(|| url.strip_prefix("http://"))?;
+    Some(match rest.find('/') {
+        Some(i) => (&rest[..i], &rest[i..]),
+        None => (rest, ""),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config(source: MirrorSource, mirrors: &[&str], fallback_to_official: bool) -> MirrorConfig {
+        MirrorConfig {
+            mirrors: [(source, mirrors.iter().map(|n| (*n).to_owned()).collect())].into(),
+            fallback_to_official,
+        }
+    }
+
+    #[test]
+    fn replaces_host() {
+        let config = config(MirrorSource::Fabric, &["https://mirror.example.com/"], true);
+        assert_eq!(
+            config.get_urls("https://meta.fabricmc.net/v2/versions/loader"),
+            vec![
+                "https://mirror.example.com/v2/versions/loader",
+                "https://meta.fabricmc.net/v2/versions/loader"
+            ]
+        );
+    }
+
+    #[test]
+    fn caching_proxy_and_no_fallback() {
+        let config = config(
+            MirrorSource::Mojang,
+            &["https://cache.corp/{host}", "  "],
+            false,
+        );
+        assert_eq!(
+            config.get_urls("https://libraries.minecraft.net/a/b.jar"),
+            vec!["https://cache.corp/libraries.minecraft.net/a/b.jar"]
+        );
+    }
+
+    #[test]
+    fn bmclapi() {
+        let config = config(MirrorSource::NeoForge, &[BMCLAPI_KEYWORD], true);
+        assert_eq!(
+            config.get_urls("https://maven.neoforged.net/releases/net/neoforged/x.jar"),
+            vec![
+                "https://bmclapi2.bangbang93.com/maven/net/neoforged/x.jar",
+                "https://maven.neoforged.net/releases/net/neoforged/x.jar"
+            ]
+        );
+        // Not supported by BMCLAPI, so only the official URL is left
+        assert_eq!(
+            config.get_urls("https://maven.neoforged.net/api/maven/versions"),
+            vec!["https://maven.neoforged.net/api/maven/versions"]
+        );
+    }
+
+    #[test]
+    fn other_hosts_untouched() {
+        let config = config(MirrorSource::Fabric, &["https://mirror.example.com"], false);
+        assert_eq!(
+            config.get_urls("https://api.modrinth.com/v2/search"),
+            vec!["https://api.modrinth.com/v2/search"]
+        );
+    }
+}
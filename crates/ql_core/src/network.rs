@@ -0,0 +1,202 @@
+//! Network settings for all of the launcher's traffic
+//! (and the game's, for the proxy): HTTP/HTTPS/SOCKS5 proxy,
+//! extra trusted CA certificates, timeout and user agent.
+//!
+//! Call [`set_config`] *before* the first request,
+//! as [`crate::CLIENT`] is built only once.
+
+use std::{path::PathBuf, sync::RwLock, time::Duration};
+
+use ql_reqwest::{Certificate, Client, NoProxy, Proxy, Url};
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+use crate::{err, IntoIoError, IoError};
+
+static CONFIG: RwLock<Option<NetworkConfig>> = RwLock::new(None);
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct NetworkConfig {
+    /// `None` (or an empty URL) means no proxy.
+    pub proxy: Option<ProxyConfig>,
+    /// PEM files with certificates to trust, in addition
+    /// to the system ones (eg: for corporate TLS inspection).
+    #[serde(default)]
+    pub ca_certificates: Vec<PathBuf>,
+    /// How long (in seconds) to wait for a connection,
+    /// or for more data on a stalled download.
+    pub timeout_secs: Option<u64>,
+    /// Replaces the user agent of *every* request.
+    pub user_agent: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct ProxyConfig {
+    /// `http://host:port`, `https://host:port` or `socks5://host:port`
+    pub url: String,
+    pub username: Option<String>,
+    /// Not saved in the launcher config, the launcher
+    /// keeps it in the system keyring instead.
+    /// (Only read from the config to move it there.)
+    #[serde(default, skip_serializing)]
+    pub password: Option<String>,
+    /// Hosts that are reached directly, without the proxy.
+    /// Same format as the `NO_PROXY` environment variable
+    /// (`example.com`, `.example.com`, `192.168.1.0/24`, `*`).
+    #[serde(default)]
+    pub no_proxy: Vec<String>,
+}
+
+impl ProxyConfig {
+    fn credentials(&self) -> Option<(&str, &str)> {
+        let username = self.username.as_deref().filter(|n| !n.is_empty())?;
+        Some((username, self.password.as_deref().unwrap_or_default()))
+    }
+
+    fn no_proxy_hosts(&self) -> impl Iterator<Item = &str> {
+        self.no_proxy
+            .iter()
+            .map(|n| n.trim())
+            .filter(|n| !n.is_empty())
+    }
+}
+
+const NETWORK_ERR_PREFIX: &str = "while setting up network (check proxy settings):\n";
+
+#[derive(Debug, Error)]
+pub enum NetworkConfigError {
+    #[error("{NETWORK_ERR_PREFIX}invalid proxy: {0}")]
+    Proxy(ql_reqwest::Error),
+    #[error("{NETWORK_ERR_PREFIX}invalid certificate {path:?}: {error}")]
+    Certificate {
+        path: PathBuf,
+        error: ql_reqwest::Error,
+    },
+    #[error("{NETWORK_ERR_PREFIX}{0}")]
+    Io(#[from] IoError),
+    #[error("{NETWORK_ERR_PREFIX}{0}")]
+    Client(ql_reqwest::Error),
+}
+
+/// Sets the network settings used by [`crate::CLIENT`]
+/// and [`java_args`].
+pub fn set_config(config: NetworkConfig) {
+    *CONFIG.write().unwrap() = Some(config);
+}
+
+/// Builds a client from `config`.
+///
+/// # Errors
+/// - If the proxy URL is invalid
+/// - If a CA certificate couldn't be read or parsed
+pub fn build_client(config: &NetworkConfig) -> Result<Client, NetworkConfigError> {
+    let mut builder = Client::builder();
+
+    if let Some(proxy_config) = config.proxy.as_ref().filter(|n| !n.url.trim().is_empty()) {
+        let mut proxy = Proxy::all(proxy_config.url.trim()).map_err(NetworkConfigError::Proxy)?;
+        if let Some((username, password)) = proxy_config.credentials() {
+            proxy = proxy.basic_auth(username, password);
+        }
+        let no_proxy: Vec<&str> = proxy_config.no_proxy_hosts().collect();
+        if !no_proxy.is_empty() {
+            proxy = proxy.no_proxy(NoProxy::from_string(&no_proxy.join(",")));
+        }
+        builder = builder.proxy(proxy);
+    }
+
+    for path in &config.ca_certificates {
+        let pem = std::fs::read(path).path(path)?;
+        let certificates = Certificate::from_pem_bundle(&pem).map_err(|error| {
+            NetworkConfigError::Certificate {
+                path: path.clone(),
+                error,
+            }
+        })?;
+        for certificate in certificates {
+            builder = builder.add_root_certificate(certificate);
+        }
+    }
+
+    if let Some(timeout) = config.timeout_secs.filter(|n| *n > 0) {
+        // Not `.timeout()`, as that would
+        // cut off big downloads halfway through
+        let timeout = Duration::from_secs(timeout);
+        builder = builder.connect_timeout(timeout).read_timeout(timeout);
+    }
+    if let Some(user_agent) = config.user_agent.as_deref().filter(|n| !n.is_empty()) {
+        builder = builder.user_agent(user_agent);
+    }
+
+    builder.build().map_err(NetworkConfigError::Client)
+}
+
+/// Builds [`crate::CLIENT`] from the settings
+/// given to [`set_config`].
+pub(crate) fn build_global_client() -> Client {
+    let Some(config) = CONFIG.read().unwrap().clone() else {
+        return Client::new();
+    };
+    build_client(&config).unwrap_or_else(|error| {
+        err!("{error}\nFalling back to default network settings");
+        Client::new()
+    })
+}
+
+/// The user agent set in the network settings, which
+/// replaces the one requested by individual downloads.
+pub(crate) fn user_agent_override() -> Option<String> {
+    CONFIG
+        .read()
+        .unwrap()
+        .as_ref()?
+        .user_agent
+        .clone()
+        .filter(|n| !n.is_empty())
+}
+
+/// JVM arguments (`-Dhttp.proxyHost=...` and so on) to make
+/// the game use the configured proxy. Empty if there isn't one.
+///
+/// The credentials aren't passed on: Java ignores them
+/// unless the game sets up its own `Authenticator`, and
+/// they would be visible to anyone who can list processes.
+#[must_use]
+pub fn java_args() -> Vec<String> {
+    let Some(proxy) = CONFIG
+        .read()
+        .unwrap()
+        .as_ref()
+        .and_then(|n| n.proxy.clone())
+    else {
+        return Vec::new();
+    };
+    let Ok(url) = Url::parse(proxy.url.trim()) else {
+        return Vec::new();
+    };
+    let (Some(host), Some(port)) = (url.host_str(), url.port_or_known_default()) else {
+        return Vec::new();
+    };
+
+    let mut args = Vec::new();
+    if url.scheme().starts_with("socks") {
+        args.push(format!("-DsocksProxyHost={host}"));
+        args.push(format!("-DsocksProxyPort={port}"));
+    } else {
+        for protocol in ["http", "https"] {
+            args.push(format!("-D{protocol}.proxyHost={host}"));
+            args.push(format!("-D{protocol}.proxyPort={port}"));
+        }
+    }
+    // Java uses `|` and `*` wildcards instead of `,` and leading dots
+    let hosts: Vec<String> = proxy
+        .no_proxy_hosts()
+        .map(|n| match n.strip_prefix('.') {
+            Some(domain) => format!("*.{domain}"),
+            None => n.to_owned(),
+        })
+        .collect();
+    if !hosts.is_empty() {
+        args.push(format!("-Dhttp.nonProxyHosts={}", hosts.join("|")));
+    }
+    args
+}
@@ -3,13 +3,17 @@ use ql_core::{file_utils, pt, DownloadFileError, IntoIoError, LAUNCHER_DIR};
 /// Gets the java argument to start the authlib injector.
 ///
 /// Authlib Injector allows the game to use alternative
-/// authentication methods such as <https://ely.by>.
+/// authentication methods such as <https://ely.by>
+/// or any other Yggdrasil API server.
 ///
 /// This function automatically downloads it from
 /// [GitHub](https://github.com/yushijinhun/authlib-injector)
 /// and sets it up if not present, and then returns
-/// `-javaagent:YOUR_LAUNCHER_DIR/downloads/authlib_injector.jar=ely.by`
-pub async fn get_authlib_injector() -> Result<String, DownloadFileError> {
+/// `-javaagent:YOUR_LAUNCHER_DIR/downloads/authlib_injector.jar={server}`
+///
+/// `server` is the API root of the auth server
+/// (or `ely.by`, which authlib-injector understands).
+pub async fn get_authlib_injector(server: &str) -> Result<String, DownloadFileError> {
     const URL: &str = "https://github.com/yushijinhun/authlib-injector/releases/download/v1.2.5/authlib-injector-1.2.5.jar";
 
     let dir = LAUNCHER_DIR.join("downloads");
@@ -21,5 +25,5 @@ pub async fn get_authlib_injector() -> Result<String, DownloadFileError> {
         file_utils::download_file_to_path(URL, false, &path).await?;
     }
 
-    Ok(format!("-javaagent:{}=ely.by", path.to_string_lossy()))
+    Ok(format!("-javaagent:{}={server}", path.to_string_lossy()))
 }
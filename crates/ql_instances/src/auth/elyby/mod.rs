@@ -2,9 +2,7 @@ use super::AccountData;
 use ql_core::{err, info, pt, IntoJsonError, IntoStringError, RequestError, CLIENT};
 use serde::Deserialize;
 
-mod authlib;
 mod error;
-pub(crate) use authlib::get_authlib_injector;
 pub use error::{AccountResponseError, Error};
 
 // Well, no one's gonna be stealing this one :)
@@ -61,6 +59,8 @@ pub async fn login_new(email: String, password: String) -> Result<Account, Error
         refresh_token: account_response.accessToken,
         needs_refresh: false,
         account_type: super::AccountType::ElyBy,
+        auth_server: None,
+        expires_at: None,
     }))
 }
 
@@ -107,6 +107,8 @@ pub async fn login_refresh(email: String, refresh_token: String) -> Result<Accou
         refresh_token: account_response.accessToken,
         needs_refresh: false,
         account_type: super::AccountType::ElyBy,
+        auth_server: None,
+        expires_at: None,
     })
 }
 
@@ -0,0 +1,147 @@
+//! Keeping account logins alive.
+//!
+//! Access tokens (only Microsoft ones tell us when)
+//! expire after a while, so [`should_refresh`] them
+//! a bit before that and [`refresh`] in the background.
+//!
+//! If the refresh token itself is no longer valid
+//! (expired, or revoked by a password change etc.)
+//! then [`RefreshError::revoked`] is set, and the user
+//! has to log in again.
+
+use std::time::{Duration, SystemTime};
+
+use ql_core::{GenericProgress, RequestError};
+use ql_reqwest::StatusCode;
+
+use super::{elyby, ms, yggdrasil, AccountData, AccountType};
+
+/// How long before expiry an access token gets refreshed.
+pub const REFRESH_MARGIN: Duration = Duration::from_secs(5 * 60);
+
+/// Whether `account` should be refreshed at `now`:
+/// - Its access token expires within [`REFRESH_MARGIN`], or
+/// - It was just loaded from the config (no access token,
+///   unknown expiry) and has a refresh token, so it gets refreshed
+///   on startup. This also finds out early if the login was revoked.
+///
+/// Accounts with a token but an unknown expiry time (ely.by,
+/// authlib-injector) are left alone, they get refreshed on launch.
+#[must_use]
+pub fn should_refresh(account: &AccountData, now: SystemTime) -> bool {
+    if account.refresh_token.is_empty() {
+        return false;
+    }
+    match (&account.access_token, account.expires_at) {
+        (Some(_), Some(expires_at)) => now + REFRESH_MARGIN >= expires_at,
+        (None, None) => account.needs_refresh,
+        _ => false,
+    }
+}
+
+#[derive(Debug, Clone, thiserror::Error)]
+#[error("{message}")]
+pub struct RefreshError {
+    pub message: String,
+    /// The refresh token is no longer valid,
+    /// so the user has to log in again.
+    pub revoked: bool,
+}
+
+impl RefreshError {
+    fn new(err: impl std::fmt::Display, revoked: bool) -> Self {
+        Self {
+            message: err.to_string(),
+            revoked,
+        }
+    }
+}
+
+impl From<ms::Error> for RefreshError {
+    fn from(err: ms::Error) -> Self {
+        let revoked = matches!(err, ms::Error::RefreshTokenRevoked);
+        Self::new(err, revoked)
+    }
+}
+
+impl From<elyby::Error> for RefreshError {
+    fn from(err: elyby::Error) -> Self {
+        let revoked = matches!(
+            &err,
+            elyby::Error::Request(RequestError::DownloadError { code, .. })
+                if *code == StatusCode::UNAUTHORIZED || *code == StatusCode::FORBIDDEN
+        );
+        Self::new(err, revoked)
+    }
+}
+
+impl From<yggdrasil::Error> for RefreshError {
+    fn from(err: yggdrasil::Error) -> Self {
+        let revoked = matches!(
+            &err,
+            yggdrasil::Error::Response(response)
+                if response.error == "ForbiddenOperationException"
+        );
+        Self::new(err, revoked)
+    }
+}
+
+/// Gets a new access token for `account`,
+/// whatever type it is.
+///
+/// # Errors
+/// - If the request failed
+/// - If the login is no longer valid
+///   ([`RefreshError::revoked`])
+pub async fn refresh(
+    account: &AccountData,
+    sender: Option<std::sync::mpsc::Sender<GenericProgress>>,
+) -> Result<AccountData, RefreshError> {
+    let username = account.username.clone();
+    let refresh_token = account.refresh_token.clone();
+    Ok(match account.account_type {
+        AccountType::Microsoft => ms::login_refresh(username, refresh_token, sender).await?,
+        AccountType::ElyBy => elyby::login_refresh(username, refresh_token).await?,
+        AccountType::AuthlibInjector => {
+            let api_root = account.auth_server.clone().unwrap_or_default();
+            yggdrasil::login_refresh(api_root, username, refresh_token).await?
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn refresh_margin() {
+        let now = SystemTime::now();
+        let mut account = AccountData {
+            access_token: Some("token".to_owned()),
+            uuid: String::new(),
+            refresh_token: "refresh".to_owned(),
+            needs_refresh: false,
+            username: "Steve".to_owned(),
+            nice_username: "Steve".to_owned(),
+            account_type: AccountType::Microsoft,
+            auth_server: None,
+            expires_at: None,
+        };
+        assert!(!should_refresh(&account, now));
+
+        account.expires_at = Some(now + Duration::from_secs(60 * 60));
+        assert!(!should_refresh(&account, now));
+
+        account.expires_at = Some(now + Duration::from_secs(60));
+        assert!(should_refresh(&account, now));
+
+        // Just loaded from the config
+        account.access_token = None;
+        account.expires_at = None;
+        account.needs_refresh = true;
+        assert!(should_refresh(&account, now));
+
+        account.refresh_token = String::new();
+        assert!(!should_refresh(&account, now));
+    }
+}
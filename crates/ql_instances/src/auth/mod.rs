@@ -2,8 +2,14 @@ use std::fmt::Display;
 
 use crate::auth;
 
+mod authlib;
 pub mod elyby;
+pub mod lifecycle;
 pub mod ms;
+pub mod skin;
+pub mod yggdrasil;
+
+pub(crate) use authlib::get_authlib_injector;
 
 #[derive(Debug, Clone)]
 pub struct AccountData {
@@ -16,14 +22,26 @@ pub struct AccountData {
     pub nice_username: String,
 
     pub account_type: AccountType,
+    /// The Yggdrasil API root, for
+    /// [`AccountType::AuthlibInjector`] accounts.
+    pub auth_server: Option<String>,
+    /// When `access_token` expires, if known
+    /// (only for [`AccountType::Microsoft`]).
+    ///
+    /// See [`lifecycle::should_refresh`].
+    pub expires_at: Option<std::time::SystemTime>,
 }
 
 impl AccountData {
     #[must_use]
     pub fn get_username_modified(&self) -> String {
         let suffix = match self.account_type {
-            auth::AccountType::Microsoft => "",
-            auth::AccountType::ElyBy => " (elyby)",
+            auth::AccountType::Microsoft => String::new(),
+            auth::AccountType::ElyBy => " (elyby)".to_owned(),
+            auth::AccountType::AuthlibInjector => format!(
+                " ({})",
+                yggdrasil::host(self.auth_server.as_deref().unwrap_or_default())
+            ),
         };
         format!("{}{suffix}", self.username)
     }
@@ -33,6 +51,9 @@ impl AccountData {
 pub enum AccountType {
     Microsoft,
     ElyBy,
+    /// A custom authlib-injector (Yggdrasil API) server,
+    /// see [`AccountData::auth_server`]
+    AuthlibInjector,
 }
 
 impl std::fmt::Display for AccountType {
@@ -43,6 +64,7 @@ impl std::fmt::Display for AccountType {
             match self {
                 AccountType::Microsoft => "Microsoft",
                 AccountType::ElyBy => "ElyBy",
+                AccountType::AuthlibInjector => "AuthlibInjector",
             }
         )
     }
@@ -54,6 +76,70 @@ impl AccountData {
         let account_type = self.account_type;
         matches!(account_type, AccountType::ElyBy)
     }
+
+    /// The server to pass to authlib-injector, or `None`
+    /// if the account doesn't need it (Microsoft).
+    #[must_use]
+    pub fn authlib_injector_server(&self) -> Option<&str> {
+        match self.account_type {
+            AccountType::Microsoft => None,
+            AccountType::ElyBy => Some("ely.by"),
+            AccountType::AuthlibInjector => self.auth_server.as_deref(),
+        }
+    }
+}
+
+/// The UUID of an offline (cracked) player, the same
+/// one vanilla servers in offline mode would use.
+///
+/// This is a version 3 UUID of `OfflinePlayer:<username>`,
+/// like Java's `UUID.nameUUIDFromBytes`.
+#[must_use]
+pub fn offline_uuid(username: &str) -> String {
+    let mut hash = md5::compute(format!("OfflinePlayer:{username}")).0;
+    hash[6] = (hash[6] & 0x0f) | 0x30;
+    hash[8] = (hash[8] & 0x3f) | 0x80;
+
+    let hex: String = hash.iter().map(|n| format!("{n:02x}")).collect();
+    format!(
+        "{}-{}-{}-{}-{}",
+        &hex[0..8],
+        &hex[8..12],
+        &hex[12..16],
+        &hex[16..20],
+        &hex[20..32]
+    )
+}
+
+/// Reads the network proxy password (see
+/// [`ql_core::network::ProxyConfig::password`]),
+/// which is kept in the keyring instead of the launcher config.
+///
+/// Returns `None` if there isn't one saved.
+///
+/// # Errors
+/// If the keyring couldn't be accessed.
+pub fn read_proxy_password(username: &str) -> Result<Option<String>, KeyringError> {
+    match get_proxy_keyring_entry(username)?.get_password() {
+        Ok(password) => Ok(Some(password)),
+        Err(keyring::Error::NoEntry) => Ok(None),
+        Err(err) => Err(KeyringError(err)),
+    }
+}
+
+/// Saves the network proxy password to the keyring
+/// (see [`read_proxy_password`]).
+///
+/// # Errors
+/// If the keyring couldn't be accessed.
+pub fn save_proxy_password(username: &str, password: &str) -> Result<(), KeyringError> {
+    get_proxy_keyring_entry(username)?
+        .set_password(password)
+        .map_err(KeyringError)
+}
+
+fn get_proxy_keyring_entry(username: &str) -> Result<keyring::Entry, KeyringError> {
+    keyring::Entry::new("QuantumLauncher", &format!("{username}#proxy")).map_err(KeyringError)
 }
 
 #[derive(Debug, thiserror::Error)]
@@ -92,3 +178,14 @@ Now after this, in the sidebar, right click it and click "Set as Default""#
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn offline_uuid() {
+        assert_eq!(
+            super::offline_uuid("Notch"),
+            "b50ad385-829d-3141-a216-7e7d7539ba7f"
+        );
+    }
+}
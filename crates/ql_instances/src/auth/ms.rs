@@ -17,7 +17,7 @@
 //!
 //! ```no_run
 //! # async fn do1() -> Result<(), Box<dyn std::error::Error>> {
-//! use ql_instances::login_1_link;
+//! use ql_instances::auth::ms::login_1_link;
 //! let auth_code_response = login_1_link().await?;
 //! // AuthCodeResponse { verification_uri, user_code, .. }
 //! # Ok(()) }
@@ -30,7 +30,7 @@
 //! ```no_run
 //! # async fn do2() -> Result<(), Box<dyn std::error::Error>> {
 //! # // Default construction
-//! # let auth_code_response = ql_instances::AuthCodeResponse {
+//! # let auth_code_response = ql_instances::auth::ms::AuthCodeResponse {
 //! #     user_code: String::new(),
 //! #     device_code: String::new(),
 //! #     verification_uri: String::new(),
@@ -38,8 +38,8 @@
 //! #     interval: 0,
 //! #     message: String::new(),
 //! # };
-//! use ql_instances::login_3_xbox;
-//! use ql_instances::login_2_wait;
+//! use ql_instances::auth::ms::login_3_xbox;
+//! use ql_instances::auth::ms::login_2_wait;
 //!
 //! let auth_token_response = login_2_wait(auth_code_response).await?;
 //! // AuthTokenResponse { access_token, refresh_token }
@@ -60,10 +60,22 @@
 //! # async fn do3() -> Result<(), Box<dyn std::error::Error>> {
 //! # let username = String::new();
 //! # let refresh_token = String::new();
-//! use ql_instances::login_refresh;
+//! use ql_instances::auth::ms::login_refresh;
 //! let account_data = login_refresh(username, refresh_token, None).await?;
 //! # Ok(()) }
 //! ```
+//!
+//! See [`super::lifecycle`] for refreshing tokens
+//! before they expire, and detecting revoked logins.
+//!
+//! # Cancelling
+//! [`login_2_wait`] gives up once the code expires
+//! ([`Error::LoginTimedOut`]). To cancel it earlier,
+//! just drop the future (eg: abort the task).
+//!
+//! # Testing
+//! [`set_endpoints`] redirects all requests
+//! to a local mock server.
 
 use ql_core::{
     err, info, pt, retry, GenericProgress, IntoJsonError, IntoStringError, JsonError, RequestError,
@@ -72,7 +84,11 @@ use ql_core::{
 use ql_reqwest::{Client, StatusCode};
 use serde::Deserialize;
 use serde_json::json;
-use std::collections::HashMap;
+use std::{
+    collections::HashMap,
+    sync::RwLock,
+    time::{Duration, Instant, SystemTime},
+};
 
 use crate::auth::AccountType;
 
@@ -91,6 +107,48 @@ use super::{AccountData, KeyringError};
 /// outside of this launcher**.
 pub const CLIENT_ID: &str = "43431a16-38f5-4b42-91f9-4bf70c3bee1e";
 
+/// The servers used for logging in.
+///
+/// Only meant to be changed (through [`set_endpoints`])
+/// for testing against a local mock server.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Endpoints {
+    /// `https://login.live.com` (refreshing tokens)
+    pub live: String,
+    /// `https://login.microsoftonline.com` (device code login)
+    pub microsoft_online: String,
+    /// `https://user.auth.xboxlive.com`
+    pub xbox_user: String,
+    /// `https://xsts.auth.xboxlive.com`
+    pub xbox_xsts: String,
+    /// `https://api.minecraftservices.com`
+    pub minecraft: String,
+}
+
+impl Default for Endpoints {
+    fn default() -> Self {
+        Self {
+            live: "https://login.live.com".to_owned(),
+            microsoft_online: "https://login.microsoftonline.com".to_owned(),
+            xbox_user: "https://user.auth.xboxlive.com".to_owned(),
+            xbox_xsts: "https://xsts.auth.xboxlive.com".to_owned(),
+            minecraft: "https://api.minecraftservices.com".to_owned(),
+        }
+    }
+}
+
+static ENDPOINTS: RwLock<Option<Endpoints>> = RwLock::new(None);
+
+/// Sends all Microsoft login requests to `endpoints`
+/// instead of the official servers (for testing).
+pub fn set_endpoints(endpoints: Endpoints) {
+    *ENDPOINTS.write().unwrap() = Some(endpoints);
+}
+
+fn endpoints() -> Endpoints {
+    ENDPOINTS.read().unwrap().clone().unwrap_or_default()
+}
+
 #[derive(Debug, Clone, PartialEq, Deserialize)]
 pub struct AuthCodeResponse {
     pub user_code: String,
@@ -125,7 +183,8 @@ struct MinecraftAuthResponse {
     access_token: String,
     // username: String,
     // roles: Vec<String>,
-    // expires_in: u32,
+    /// Seconds until `access_token` expires
+    expires_in: Option<u64>,
     // token_type: String,
 }
 
@@ -188,6 +247,10 @@ pub enum Error {
 
     #[error("Your Microsoft account doesn't own Minecraft!\nJust enter the username in the text box instead of logging in.")]
     DoesntOwnGame,
+    #[error("{AUTH_ERR_PREFIX}your login has expired or was revoked\n(for example, by changing your password)\n\nPlease log in again.")]
+    RefreshTokenRevoked,
+    #[error("{AUTH_ERR_PREFIX}login timed out (the code expired)\nPlease try again.")]
+    LoginTimedOut,
 }
 
 impl From<ql_reqwest::Error> for Error {
@@ -227,12 +290,30 @@ pub async fn login_refresh(
 ) -> Result<AccountData, Error> {
     send_progress(sender.as_ref(), 0, 4, "Refreshing account token...");
 
+    let data = exchange_refresh_token(&refresh_token).await?;
+
+    let entry = keyring::Entry::new("QuantumLauncher", &username)?;
+    entry.set_password(&data.refresh_token)?;
+
+    let data = login_3_xbox(data, sender, false).await?;
+
+    Ok(data)
+}
+
+/// Gets a new Microsoft access token (and refresh token)
+/// from a refresh token. Doesn't save anything to the keyring.
+///
+/// # Errors
+/// - [`Error::RefreshTokenRevoked`] if the user needs to log in again
+/// - If the request failed
+pub async fn exchange_refresh_token(refresh_token: &str) -> Result<AuthTokenResponse, Error> {
+    let url = format!("{}/oauth20_token.srf", endpoints().live);
     let response = retry(async || {
         CLIENT
-            .post("https://login.live.com/oauth20_token.srf")
+            .post(&url)
             .form(&[
                 ("client_id", CLIENT_ID),
-                ("refresh_token", &refresh_token),
+                ("refresh_token", refresh_token),
                 ("grant_type", "refresh_token"),
                 ("redirect_uri", "https://login.live.com/oauth20_desktop.srf"),
                 ("scope", "XboxLive.signin offline_access"),
@@ -244,22 +325,21 @@ pub async fn login_refresh(
     })
     .await?;
 
-    let data: RefreshResponse = serde_json::from_str(&response).json(response)?;
-
-    let entry = keyring::Entry::new("QuantumLauncher", &username)?;
-    entry.set_password(&data.refresh_token)?;
-
-    let data = login_3_xbox(
-        AuthTokenResponse {
-            access_token: data.access_token,
-            refresh_token: data.refresh_token,
-        },
-        sender,
-        false,
-    )
-    .await?;
+    if let Ok(error) = serde_json::from_str::<AuthServiceErrorMessage>(&response) {
+        // `invalid_grant`: The refresh token expired (unused for 90 days)
+        // or was revoked (password change, "sign out everywhere", etc.)
+        return Err(if error.error == "invalid_grant" {
+            Error::RefreshTokenRevoked
+        } else {
+            Error::MissingField(format!("access_token (error: {})", error.error))
+        });
+    }
 
-    Ok(data)
+    let data: RefreshResponse = serde_json::from_str(&response).json(response)?;
+    Ok(AuthTokenResponse {
+        access_token: data.access_token,
+        refresh_token: data.refresh_token,
+    })
 }
 
 pub async fn login_1_link() -> Result<AuthCodeResponse, Error> {
@@ -267,7 +347,10 @@ pub async fn login_1_link() -> Result<AuthCodeResponse, Error> {
 
     pt!("Sending device code request");
     let response = CLIENT
-        .get("https://login.microsoftonline.com/consumers/oauth2/v2.0/devicecode")
+        .get(format!(
+            "{}/consumers/oauth2/v2.0/devicecode",
+            endpoints().microsoft_online
+        ))
         .query(&[
             ("client_id", CLIENT_ID),
             ("scope", "XboxLive.signin offline_access"),
@@ -298,6 +381,29 @@ pub async fn login_3_xbox(
     data: AuthTokenResponse,
     sender: Option<std::sync::mpsc::Sender<GenericProgress>>,
     check_ownership: bool,
+) -> Result<AccountData, Error> {
+    let account = authenticate(data, sender, check_ownership).await?;
+
+    let entry = keyring::Entry::new("QuantumLauncher", &account.username)?;
+    entry.set_password(&account.refresh_token)?;
+
+    info!("Finished Microsoft Account login!");
+
+    Ok(account)
+}
+
+/// Logs into Xbox Live and Minecraft with a Microsoft
+/// token, like [`login_3_xbox`] but without saving
+/// anything to the keyring.
+///
+/// # Errors
+/// - If any of the requests failed
+/// - If the account doesn't own the game
+///   (only if `check_ownership` is true)
+pub async fn authenticate(
+    data: AuthTokenResponse,
+    sender: Option<std::sync::mpsc::Sender<GenericProgress>>,
+    check_ownership: bool,
 ) -> Result<AccountData, Error> {
     let steps = if check_ownership { 5 } else { 4 };
 
@@ -317,23 +423,20 @@ pub async fn login_3_xbox(
         }
     }
 
-    let entry = keyring::Entry::new("QuantumLauncher", &final_details.name)?;
-    entry.set_password(&data.refresh_token)?;
-
-    let data = AccountData {
+    Ok(AccountData {
         access_token: Some(minecraft.access_token),
         uuid: final_details.id.ok_or(Error::NoUuid)?,
         refresh_token: data.refresh_token,
         needs_refresh: false,
         account_type: AccountType::Microsoft,
+        auth_server: None,
+        expires_at: minecraft
+            .expires_in
+            .map(|n| SystemTime::now() + Duration::from_secs(n)),
 
         username: final_details.name.clone(),
         nice_username: final_details.name,
-    };
-
-    info!("Finished Microsoft Account login!");
-
-    Ok(data)
+    })
 }
 
 fn send_progress(
@@ -353,12 +456,35 @@ fn send_progress(
     }
 }
 
+/// Waits for the user to log in with the code
+/// from [`login_1_link`].
+///
+/// # Errors
+/// - [`Error::LoginTimedOut`] if the code expired
+///   before the user logged in
+/// - If the user declined the login
+/// - If the request failed
 pub async fn login_2_wait(response: AuthCodeResponse) -> Result<AuthTokenResponse, Error> {
+    // Microsoft codes last 15 minutes
+    let expires_in = u64::try_from(response.expires_in)
+        .ok()
+        .filter(|n| *n > 0)
+        .unwrap_or(15 * 60);
+    let deadline = Instant::now() + Duration::from_secs(expires_in);
+    let mut interval = response.interval;
+    let url = format!(
+        "{}/consumers/oauth2/v2.0/token",
+        endpoints().microsoft_online
+    );
+
     loop {
-        tokio::time::sleep(std::time::Duration::from_secs(response.interval + 1)).await;
+        tokio::time::sleep(Duration::from_secs(interval + 1)).await;
+        if Instant::now() >= deadline {
+            return Err(Error::LoginTimedOut);
+        }
 
         let code_resp = CLIENT
-            .post("https://login.microsoftonline.com/consumers/oauth2/v2.0/token")
+            .post(&url)
             .form(&[
                 ("client_id", CLIENT_ID),
                 ("scope", "XboxLive.signin offline_access"),
@@ -373,9 +499,12 @@ pub async fn login_2_wait(response: AuthCodeResponse) -> Result<AuthTokenRespons
                 let txt = code_resp.text().await?;
                 let error: AuthServiceErrorMessage = serde_json::from_str(&txt).json(txt)?;
                 match &error.error as &str {
-                    "authorization_declined" | "expired_token" | "invalid_grant" => {
+                    "expired_token" => return Err(Error::LoginTimedOut),
+                    "authorization_declined" | "invalid_grant" => {
                         return Err(Error::InvalidAccessToken);
                     }
+                    "slow_down" => interval += 5,
+                    // "authorization_pending"
                     _ => {}
                 }
             }
@@ -407,7 +536,7 @@ async fn login_in_xbox_live(
     });
 
     let xbox_res = client
-        .post("https://user.auth.xboxlive.com/user/authenticate")
+        .post(format!("{}/user/authenticate", endpoints().xbox_user))
         .json(&xbox_authenticate_json)
         .send()
         .await?
@@ -438,8 +567,9 @@ async fn login_in_minecraft(
             "xbox_res.display_claims.xui[0].uhs".to_owned(),
         ))?;
 
+    let endpoints = endpoints();
     let xbox_security_token_res = client
-        .post("https://xsts.auth.xboxlive.com/xsts/authorize")
+        .post(format!("{}/xsts/authorize", endpoints.xbox_xsts))
         .json(&json!({
             "Properties": {
                 "SandboxId": "RETAIL",
@@ -459,7 +589,10 @@ async fn login_in_minecraft(
     let xbox_security_token = &xbox_security_token_res.token;
 
     let minecraft_resp = client
-        .post("https://api.minecraftservices.com/authentication/login_with_xbox")
+        .post(format!(
+            "{}/authentication/login_with_xbox",
+            endpoints.minecraft
+        ))
         .json(&json!({
             "identityToken":
                 format!(
@@ -481,7 +614,7 @@ async fn get_final_details(
     minecraft_res: &MinecraftAuthResponse,
 ) -> Result<MinecraftFinalDetails, Error> {
     let text = client
-        .get("https://api.minecraftservices.com/minecraft/profile")
+        .get(format!("{}/minecraft/profile", endpoints().minecraft))
         .header("Accept", "application/json")
         .bearer_auth(&minecraft_res.access_token)
         .send()
@@ -511,10 +644,8 @@ async fn check_minecraft_ownership(access_token: &str) -> Result<bool, Error> {
         items: Vec<serde_json::Value>,
     }
 
-    let client = Client::new();
-
-    let response = client
-        .get("https://api.minecraftservices.com/entitlements/mcstore")
+    let response = CLIENT
+        .get(format!("{}/entitlements/mcstore", endpoints().minecraft))
         .bearer_auth(access_token)
         .send()
         .await?
@@ -524,3 +655,152 @@ async fn check_minecraft_ownership(access_token: &str) -> Result<bool, Error> {
 
     Ok(!response.items.is_empty())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::auth::lifecycle;
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+    /// Responds to each request with a canned
+    /// response, based on the path and body.
+    fn mock_response(path: &str, body: &str) -> (u16, String) {
+        match path {
+            "/oauth20_token.srf" if body.contains("refresh_token=revoked") => (
+                400,
+                r#"{"error":"invalid_grant","error_description":"revoked"}"#.to_owned(),
+            ),
+            "/oauth20_token.srf" => (
+                200,
+                r#"{"access_token":"ms_token","refresh_token":"new_refresh"}"#.to_owned(),
+            ),
+            "/consumers/oauth2/v2.0/token" => (400, r#"{"error":"authorization_pending"}"#.to_owned()),
+            "/user/authenticate" | "/xsts/authorize" => (
+                200,
+                r#"{"IssueInstant":"","NotAfter":"","Token":"xbox_token","DisplayClaims":{"xui":[{"uhs":"1234"}]}}"#
+                    .to_owned(),
+            ),
+            "/authentication/login_with_xbox" => (
+                200,
+                r#"{"access_token":"mc_token","expires_in":86400}"#.to_owned(),
+            ),
+            "/minecraft/profile" => (200, r#"{"id":"uuid","name":"Steve"}"#.to_owned()),
+            "/entitlements/mcstore" => (200, r#"{"items":[{"name":"game_minecraft"}]}"#.to_owned()),
+            _ => (404, String::new()),
+        }
+    }
+
+    async fn start_mock_server() -> String {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+
+        tokio::spawn(async move {
+            loop {
+                let Ok((mut stream, _)) = listener.accept().await else {
+                    return;
+                };
+                tokio::spawn(async move {
+                    let mut buf = Vec::new();
+                    let mut chunk = [0; 4096];
+                    // Read until the headers and the whole body arrived
+                    let (head, body) = loop {
+                        let n = stream.read(&mut chunk).await.unwrap();
+                        if n == 0 {
+                            return;
+                        }
+                        buf.extend_from_slice(&chunk[..n]);
+                        let text = String::from_utf8_lossy(&buf).to_string();
+                        let Some((head, body)) = text.split_once("\r\n\r\n") else {
+                            continue;
+                        };
+                        let length = head
+                            .lines()
+                            .find_map(|n| {
+                                let (k, v) = n.split_once(':')?;
+                                k.eq_ignore_ascii_case("content-length")
+                                    .then(|| v.trim().parse::<usize>().ok())?
+                            })
+                            .unwrap_or(0);
+                        if body.len() >= length {
+                            break (head.to_owned(), body.to_owned());
+                        }
+                    };
+                    let path = head.split_whitespace().nth(1).unwrap_or("/");
+                    let (code, response) = mock_response(path, &body);
+                    let response = format!(
+                        "HTTP/1.1 {code} Mock\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{response}",
+                        response.len()
+                    );
+                    _ = stream.write_all(response.as_bytes()).await;
+                });
+            }
+        });
+
+        url
+    }
+
+    #[tokio::test]
+    async fn mock_login_lifecycle() {
+        let url = start_mock_server().await;
+        set_endpoints(Endpoints {
+            live: url.clone(),
+            microsoft_online: url.clone(),
+            xbox_user: url.clone(),
+            xbox_xsts: url.clone(),
+            minecraft: url,
+        });
+
+        // Refreshing, then logging in
+        let token = exchange_refresh_token("valid").await.unwrap();
+        assert_eq!(token.refresh_token, "new_refresh");
+        let account = authenticate(token, None, true).await.unwrap();
+        assert_eq!(account.access_token.as_deref(), Some("mc_token"));
+        assert_eq!(account.nice_username, "Steve");
+        let expires_at = account.expires_at.unwrap();
+        assert!(expires_at > SystemTime::now() + Duration::from_secs(86000));
+
+        // Revoked refresh token
+        assert!(matches!(
+            exchange_refresh_token("revoked").await,
+            Err(Error::RefreshTokenRevoked)
+        ));
+
+        // The user never enters the code
+        let code = AuthCodeResponse {
+            user_code: String::new(),
+            device_code: "code".to_owned(),
+            verification_uri: String::new(),
+            expires_in: 1,
+            interval: 0,
+            message: String::new(),
+        };
+        assert!(matches!(
+            login_2_wait(code).await,
+            Err(Error::LoginTimedOut)
+        ));
+
+        // Background refresh, saving the new refresh token to the keyring
+        keyring::set_default_credential_builder(keyring::mock::default_credential_builder());
+        let mut account = AccountData {
+            access_token: None,
+            uuid: "uuid".to_owned(),
+            refresh_token: "valid".to_owned(),
+            needs_refresh: true,
+            username: "Steve".to_owned(),
+            nice_username: "Steve".to_owned(),
+            account_type: AccountType::Microsoft,
+            auth_server: None,
+            expires_at: None,
+        };
+        let now = SystemTime::now();
+        assert!(lifecycle::should_refresh(&account, now));
+        let refreshed = lifecycle::refresh(&account, None).await.unwrap();
+        assert_eq!(refreshed.refresh_token, "new_refresh");
+        assert_eq!(refreshed.access_token.as_deref(), Some("mc_token"));
+        assert!(!lifecycle::should_refresh(&refreshed, now));
+
+        account.refresh_token = "revoked".to_owned();
+        let err = lifecycle::refresh(&account, None).await.unwrap_err();
+        assert!(err.revoked);
+    }
+}
@@ -0,0 +1,662 @@
+//! Skins and capes for logged-in accounts, and a local
+//! library of skins to switch between.
+//!
+//! # Microsoft accounts
+//! Uses the Minecraft services profile API to get the
+//! current skin/capes, upload skins and switch capes.
+//!
+//! # ely.by and authlib-injector accounts
+//! Skins are read from ely.by's skin system, or the
+//! session server of the authlib-injector server. Changing
+//! them is only possible on the website
+//! (see [`ELYBY_SKINS_PAGE`]).
+//!
+//! # Preview
+//! [`render_preview`] draws a flat front view of
+//! a skin on the CPU (no GPU needed).
+
+use std::path::PathBuf;
+
+use base64::Engine;
+use image::{ImageFormat, RgbaImage};
+use ql_core::{
+    file_utils, info, IntoIoError, IntoJsonError, IoError, JsonError, RequestError, CLIENT,
+    LAUNCHER_DIR,
+};
+use ql_reqwest::RequestBuilder;
+use serde::{Deserialize, Serialize};
+
+use super::{AccountData, AccountType};
+
+const PROFILE_URL: &str = "https://api.minecraftservices.com/minecraft/profile";
+/// Where ely.by users can change their skin.
+pub const ELYBY_SKINS_PAGE: &str = "https://ely.by/skins";
+
+const SKIN_ERR_PREFIX: &str = "while managing skin:\n";
+
+#[derive(Debug, thiserror::Error)]
+pub enum SkinError {
+    #[error("{SKIN_ERR_PREFIX}{0}")]
+    Request(#[from] RequestError),
+    #[error("{SKIN_ERR_PREFIX}{0}")]
+    Json(#[from] JsonError),
+    #[error("{SKIN_ERR_PREFIX}{0}")]
+    Io(#[from] IoError),
+    #[error("{SKIN_ERR_PREFIX}invalid image: {0}")]
+    Image(#[from] image::ImageError),
+    #[error("{SKIN_ERR_PREFIX}skin must be 64x64 or 64x32 pixels (this one is {0}x{1})")]
+    InvalidSize(u32, u32),
+    #[error("{SKIN_ERR_PREFIX}account isn't logged in (no access token)")]
+    NotLoggedIn,
+    #[error("{SKIN_ERR_PREFIX}ely.by skins and capes can only be changed on the website:\n{ELYBY_SKINS_PAGE}")]
+    ElyByUnsupported,
+    #[error("{SKIN_ERR_PREFIX}skins and capes of authlib-injector accounts can only be changed on the server's website")]
+    AuthlibInjectorUnsupported,
+    #[error("{SKIN_ERR_PREFIX}server sent invalid texture info")]
+    InvalidTextures,
+}
+
+impl From<ql_reqwest::Error> for SkinError {
+    fn from(value: ql_reqwest::Error) -> Self {
+        Self::Request(RequestError::ReqwestError(value))
+    }
+}
+
+/// The arm width of a skin.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum SkinModel {
+    /// 4 pixel wide arms ("Steve")
+    #[default]
+    Classic,
+    /// 3 pixel wide arms ("Alex")
+    Slim,
+}
+
+impl SkinModel {
+    pub const ALL: &[Self] = &[Self::Classic, Self::Slim];
+}
+
+impl std::fmt::Display for SkinModel {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                SkinModel::Classic => "Classic",
+                SkinModel::Slim => "Slim",
+            }
+        )
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Cape {
+    pub id: String,
+    pub alias: String,
+    pub url: String,
+    pub active: bool,
+}
+
+/// The skin and capes of an account.
+#[derive(Debug, Clone)]
+pub struct SkinProfile {
+    /// `None` for the default skin.
+    pub skin_url: Option<String>,
+    pub model: SkinModel,
+    pub capes: Vec<Cape>,
+}
+
+impl SkinProfile {
+    #[must_use]
+    pub fn active_cape(&self) -> Option<&Cape> {
+        self.capes.iter().find(|n| n.active)
+    }
+}
+
+#[derive(Deserialize)]
+struct MsProfile {
+    #[serde(default)]
+    skins: Vec<MsSkin>,
+    #[serde(default)]
+    capes: Vec<MsCape>,
+}
+
+#[derive(Deserialize)]
+struct MsSkin {
+    state: String,
+    url: String,
+    variant: String,
+}
+
+#[derive(Deserialize)]
+struct MsCape {
+    id: String,
+    state: String,
+    url: String,
+    alias: String,
+}
+
+impl From<MsProfile> for SkinProfile {
+    fn from(profile: MsProfile) -> Self {
+        let skin = profile.skins.into_iter().find(|n| n.state == "ACTIVE");
+        Self {
+            model: match &skin {
+                Some(skin) if skin.variant.eq_ignore_ascii_case("slim") => SkinModel::Slim,
+                _ => SkinModel::Classic,
+            },
+            skin_url: skin.map(|n| n.url),
+            capes: profile
+                .capes
+                .into_iter()
+                .map(|n| Cape {
+                    active: n.state == "ACTIVE",
+                    id: n.id,
+                    alias: n.alias,
+                    url: n.url,
+                })
+                .collect(),
+        }
+    }
+}
+
+/// Yggdrasil texture info (also used by ely.by)
+#[derive(Deserialize, Default)]
+#[allow(non_snake_case)]
+struct Textures {
+    SKIN: Option<Texture>,
+    CAPE: Option<Texture>,
+}
+
+#[derive(Deserialize)]
+struct Texture {
+    url: String,
+    metadata: Option<TextureMetadata>,
+}
+
+#[derive(Deserialize)]
+struct TextureMetadata {
+    model: Option<String>,
+}
+
+impl Textures {
+    fn into_profile(self, cape_name: &str) -> SkinProfile {
+        SkinProfile {
+            model: match self
+                .SKIN
+                .as_ref()
+                .and_then(|n| n.metadata.as_ref())
+                .and_then(|n| n.model.as_deref())
+            {
+                Some("slim") => SkinModel::Slim,
+                _ => SkinModel::Classic,
+            },
+            skin_url: self.SKIN.map(|n| n.url),
+            capes: self
+                .CAPE
+                .map(|n| Cape {
+                    id: cape_name.to_owned(),
+                    alias: cape_name.to_owned(),
+                    url: n.url,
+                    active: true,
+                })
+                .into_iter()
+                .collect(),
+        }
+    }
+}
+
+fn to_https(url: &str) -> String {
+    match url.strip_prefix("http://") {
+        Some(rest) => format!("https://{rest}"),
+        None => url.to_owned(),
+    }
+}
+
+#[derive(Deserialize)]
+struct SessionProfile {
+    #[serde(default)]
+    properties: Vec<SessionProfileProperty>,
+}
+
+#[derive(Deserialize)]
+struct SessionProfileProperty {
+    name: String,
+    /// Base64 encoded JSON
+    value: String,
+}
+
+#[derive(Deserialize)]
+struct SessionTextures {
+    #[serde(default)]
+    textures: Textures,
+}
+
+/// Gets the current skin and capes of an account.
+///
+/// # Errors
+/// - If the account has no access token (Microsoft only)
+/// - If the request failed
+pub async fn get_profile(account: &AccountData) -> Result<SkinProfile, SkinError> {
+    match account.account_type {
+        AccountType::Microsoft => {
+            let token = access_token(account)?;
+            let text = send(CLIENT.get(PROFILE_URL).bearer_auth(token)).await?;
+            let profile: MsProfile = serde_json::from_str(&text).json(text)?;
+            Ok(profile.into())
+        }
+        AccountType::ElyBy => {
+            let url = format!(
+                "https://skinsystem.ely.by/textures/{}",
+                account.nice_username
+            );
+            let text = file_utils::download_file_to_string(&url, false).await?;
+            // Empty response if the account has no skin
+            let textures: Textures = if text.trim().is_empty() {
+                Textures::default()
+            } else {
+                serde_json::from_str(&text).json(text)?
+            };
+            let mut profile = textures.into_profile("ely.by");
+            // ely.by hands out plain http texture URLs
+            profile.skin_url = profile.skin_url.map(|n| to_https(&n));
+            for cape in &mut profile.capes {
+                cape.url = to_https(&cape.url);
+            }
+            Ok(profile)
+        }
+        AccountType::AuthlibInjector => {
+            let api_root = account.auth_server.as_deref().unwrap_or_default();
+            let url = format!(
+                "{api_root}/sessionserver/session/minecraft/profile/{}?unsigned=true",
+                account.uuid
+            );
+            let text = file_utils::download_file_to_string(&url, false).await?;
+            let profile: SessionProfile = serde_json::from_str(&text).json(text)?;
+
+            let Some(textures) = profile
+                .properties
+                .into_iter()
+                .find(|n| n.name == "textures")
+            else {
+                return Ok(Textures::default().into_profile(""));
+            };
+            let textures = base64::engine::general_purpose::STANDARD
+                .decode(&textures.value)
+                .map_err(|_| SkinError::InvalidTextures)?;
+            let textures = String::from_utf8_lossy(&textures).to_string();
+            let textures: SessionTextures = serde_json::from_str(&textures).json(textures)?;
+            Ok(textures
+                .textures
+                .into_profile(&super::yggdrasil::host(api_root)))
+        }
+    }
+}
+
+/// Downloads a skin or cape texture (PNG).
+///
+/// # Errors
+/// If the download failed.
+pub async fn download_texture(url: &str) -> Result<Vec<u8>, SkinError> {
+    Ok(file_utils::download_file_to_bytes(url, false).await?)
+}
+
+/// Sets the skin of a Microsoft account from a PNG image.
+///
+/// # Errors
+/// - If the image isn't a valid skin (see [`check_skin`])
+/// - If the account isn't a Microsoft one
+/// - If the request failed
+pub async fn upload_skin(
+    account: &AccountData,
+    png: Vec<u8>,
+    model: SkinModel,
+) -> Result<SkinProfile, SkinError> {
+    check_skin(&png)?;
+    let token = ms_access_token(account)?;
+    info!("Uploading skin ({model})");
+
+    // `reqwest`'s multipart feature isn't enabled,
+    // and this is the only place that needs it
+    const BOUNDARY: &str = "----QuantumLauncherSkinUpload";
+    let variant = match model {
+        SkinModel::Classic => "classic",
+        SkinModel::Slim => "slim",
+    };
+    let mut body = format!(
+        "--{BOUNDARY}\r\nContent-Disposition: form-data; name=\"variant\"\r\n\r\n{variant}\r\n\
+        --{BOUNDARY}\r\nContent-Disposition: form-data; name=\"file\"; filename=\"skin.png\"\r\n\
+        Content-Type: image/png\r\n\r\n"
+    )
+    .into_bytes();
+    body.extend_from_slice(&png);
+    body.extend_from_slice(format!("\r\n--{BOUNDARY}--\r\n").as_bytes());
+
+    let text = send(
+        CLIENT
+            .post(format!("{PROFILE_URL}/skins"))
+            .bearer_auth(token)
+            .header(
+                "Content-Type",
+                format!("multipart/form-data; boundary={BOUNDARY}"),
+            )
+            .body(body),
+    )
+    .await?;
+    let profile: MsProfile = serde_json::from_str(&text).json(text)?;
+    Ok(profile.into())
+}
+
+/// Resets a Microsoft account to the default skin.
+///
+/// # Errors
+/// - If the account isn't a Microsoft one
+/// - If the request failed
+pub async fn reset_skin(account: &AccountData) -> Result<SkinProfile, SkinError> {
+    let token = ms_access_token(account)?;
+    let text = send(
+        CLIENT
+            .delete(format!("{PROFILE_URL}/skins/active"))
+            .bearer_auth(token),
+    )
+    .await?;
+    let profile: MsProfile = serde_json::from_str(&text).json(text)?;
+    Ok(profile.into())
+}
+
+/// Shows one of the account's capes (by [`Cape::id`]),
+/// or hides the cape if `None`.
+///
+/// # Errors
+/// - If the account isn't a Microsoft one
+/// - If the request failed
+pub async fn set_cape(account: &AccountData, id: Option<String>) -> Result<SkinProfile, SkinError> {
+    let token = ms_access_token(account)?;
+    let url = format!("{PROFILE_URL}/capes/active");
+    let request = match &id {
+        Some(id) => CLIENT.put(url).json(&serde_json::json!({ "capeId": id })),
+        None => CLIENT.delete(url),
+    };
+    let text = send(request.bearer_auth(token)).await?;
+    let profile: MsProfile = serde_json::from_str(&text).json(text)?;
+    Ok(profile.into())
+}
+
+fn access_token(account: &AccountData) -> Result<&str, SkinError> {
+    account
+        .access_token
+        .as_deref()
+        .ok_or(SkinError::NotLoggedIn)
+}
+
+fn ms_access_token(account: &AccountData) -> Result<&str, SkinError> {
+    match account.account_type {
+        AccountType::Microsoft => access_token(account),
+        AccountType::ElyBy => Err(SkinError::ElyByUnsupported),
+        AccountType::AuthlibInjector => Err(SkinError::AuthlibInjectorUnsupported),
+    }
+}
+
+async fn send(request: RequestBuilder) -> Result<String, SkinError> {
+    let response = request.send().await?;
+    if !response.status().is_success() {
+        return Err(RequestError::DownloadError {
+            code: response.status(),
+            url: response.url().clone(),
+        }
+        .into());
+    }
+    Ok(response.text().await?)
+}
+
+/// Checks that `png` is a valid skin image
+/// (a 64x64 or legacy 64x32 PNG).
+///
+/// # Errors
+/// If it isn't.
+pub fn check_skin(png: &[u8]) -> Result<(), SkinError> {
+    let image = image::load_from_memory_with_format(png, ImageFormat::Png)?;
+    match (image.width(), image.height()) {
+        (64, 64 | 32) => Ok(()),
+        (w, h) => Err(SkinError::InvalidSize(w, h)),
+    }
+}
+
+/// An RGBA image, ready for displaying.
+#[derive(Debug, Clone)]
+pub struct SkinPreview {
+    pub width: u32,
+    pub height: u32,
+    pub rgba: Vec<u8>,
+}
+
+/// Renders a front view of a skin, `scale` times
+/// bigger than the skin's pixels (16x32 at scale 1).
+///
+/// # Errors
+/// If the image isn't a valid skin (see [`check_skin`]).
+pub fn render_preview(png: &[u8], model: SkinModel, scale: u32) -> Result<SkinPreview, SkinError> {
+    check_skin(png)?;
+    let skin = image::load_from_memory_with_format(png, ImageFormat::Png)?.to_rgba8();
+    let is_legacy = skin.height() == 32;
+    let arm = match model {
+        SkinModel::Classic => 4,
+        SkinModel::Slim => 3,
+    };
+
+    let mut out = RgbaImage::new(16, 32);
+    // (texture x, y, width, height, output x, y, mirrored)
+    let mut parts: Vec<(u32, u32, u32, u32, u32, u32, bool)> = vec![
+        (8, 8, 8, 8, 4, 0, false),            // head
+        (20, 20, 8, 12, 4, 8, false),         // body
+        (44, 20, arm, 12, 4 - arm, 8, false), // right arm
+        (4, 20, 4, 12, 4, 20, false),         // right leg
+    ];
+    if is_legacy {
+        // Old skins have no separate left arm/leg, so they're mirrored
+        parts.push((44, 20, arm, 12, 12, 8, true));
+        parts.push((4, 20, 4, 12, 8, 20, true));
+    } else {
+        parts.push((36, 52, arm, 12, 12, 8, false)); // left arm
+        parts.push((20, 52, 4, 12, 8, 20, false)); // left leg
+    }
+    // Second layer (hat, jacket, sleeves, pants)
+    parts.push((40, 8, 8, 8, 4, 0, false));
+    if !is_legacy {
+        parts.extend([
+            (20, 36, 8, 12, 4, 8, false),
+            (44, 36, arm, 12, 4 - arm, 8, false),
+            (52, 52, arm, 12, 12, 8, false),
+            (4, 36, 4, 12, 4, 20, false),
+            (4, 52, 4, 12, 8, 20, false),
+        ]);
+    }
+
+    for (tx, ty, w, h, ox, oy, mirrored) in parts {
+        for y in 0..h {
+            for x in 0..w {
+                let sx = if mirrored { tx + w - 1 - x } else { tx + x };
+                let src = *skin.get_pixel(sx, ty + y);
+                let dst = out.get_pixel_mut(ox + x, oy + y);
+                *dst = blend(*dst, src);
+            }
+        }
+    }
+
+    let scale = scale.max(1);
+    let out = image::imageops::resize(
+        &out,
+        16 * scale,
+        32 * scale,
+        image::imageops::FilterType::Nearest,
+    );
+    Ok(SkinPreview {
+        width: out.width(),
+        height: out.height(),
+        rgba: out.into_raw(),
+    })
+}
+
+fn blend(dst: image::Rgba<u8>, src: image::Rgba<u8>) -> image::Rgba<u8> {
+    let a = u32::from(src[3]);
+    if a == 255 {
+        return src;
+    }
+    if a == 0 {
+        return dst;
+    }
+    let mix = |s: u8, d: u8| ((u32::from(s) * a + u32::from(d) * (255 - a)) / 255) as u8;
+    image::Rgba([
+        mix(src[0], dst[0]),
+        mix(src[1], dst[1]),
+        mix(src[2], dst[2]),
+        dst[3].max(src[3]),
+    ])
+}
+
+/// A skin saved in the local library
+/// (`QuantumLauncher/skins/`).
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct LibrarySkin {
+    pub name: String,
+    pub model: SkinModel,
+}
+
+impl LibrarySkin {
+    #[must_use]
+    pub fn path(&self) -> PathBuf {
+        library_dir().join(format!("{}.png", self.name))
+    }
+
+    /// # Errors
+    /// If the skin file couldn't be read.
+    pub async fn read(&self) -> Result<Vec<u8>, SkinError> {
+        let path = self.path();
+        Ok(tokio::fs::read(&path).await.path(path)?)
+    }
+}
+
+fn library_dir() -> PathBuf {
+    LAUNCHER_DIR.join("skins")
+}
+
+/// Lists the skins in the local library.
+///
+/// # Errors
+/// If the library index couldn't be read.
+pub async fn library_list() -> Result<Vec<LibrarySkin>, SkinError> {
+    let path = library_dir().join("index.json");
+    if !path.is_file() {
+        return Ok(Vec::new());
+    }
+    let text = tokio::fs::read_to_string(&path).await.path(path)?;
+    Ok(serde_json::from_str(&text).json(text)?)
+}
+
+/// Saves a skin to the local library, replacing
+/// any skin with the same name.
+///
+/// # Errors
+/// - If the image isn't a valid skin (see [`check_skin`])
+/// - If the library couldn't be written to
+pub async fn library_add(name: &str, png: &[u8], model: SkinModel) -> Result<(), SkinError> {
+    check_skin(png)?;
+    // Don't allow escaping the skins folder
+    let name: String = name
+        .chars()
+        .map(|c| {
+            if c.is_alphanumeric() || c == '-' || c == ' ' {
+                c
+            } else {
+                '_'
+            }
+        })
+        .collect();
+
+    let dir = library_dir();
+    tokio::fs::create_dir_all(&dir).await.path(&dir)?;
+
+    let skin = LibrarySkin {
+        name: name.clone(),
+        model,
+    };
+    tokio::fs::write(skin.path(), png).await.path(skin.path())?;
+
+    let mut skins = library_list().await?;
+    skins.retain(|n| n.name != name);
+    skins.push(skin);
+    library_save(&skins).await
+}
+
+/// Removes a skin from the local library.
+///
+/// # Errors
+/// If the library couldn't be written to.
+pub async fn library_remove(name: &str) -> Result<(), SkinError> {
+    let mut skins = library_list().await?;
+    if let Some(skin) = skins.iter().find(|n| n.name == name) {
+        let path = skin.path();
+        if path.is_file() {
+            tokio::fs::remove_file(&path).await.path(path)?;
+        }
+    }
+    skins.retain(|n| n.name != name);
+    library_save(&skins).await
+}
+
+async fn library_save(skins: &[LibrarySkin]) -> Result<(), SkinError> {
+    let path = library_dir().join("index.json");
+    let text = serde_json::to_string_pretty(skins).json_to()?;
+    tokio::fs::write(&path, text).await.path(path)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn skin_png(height: u32) -> Vec<u8> {
+        let mut skin = RgbaImage::new(64, height);
+        // Head front, top-left pixel
+        skin.put_pixel(8, 8, image::Rgba([255, 0, 0, 255]));
+        // Right arm front, top-left pixel
+        skin.put_pixel(44, 20, image::Rgba([0, 255, 0, 255]));
+        let mut png = Vec::new();
+        image::DynamicImage::ImageRgba8(skin)
+            .write_to(&mut std::io::Cursor::new(&mut png), ImageFormat::Png)
+            .unwrap();
+        png
+    }
+
+    #[test]
+    fn preview_layout() {
+        let preview = render_preview(&skin_png(64), SkinModel::Classic, 2).unwrap();
+        assert_eq!((preview.width, preview.height), (32, 64));
+        let pixel = |x: u32, y: u32| {
+            let i = ((y * preview.width + x) * 4) as usize;
+            preview.rgba[i..i + 4].to_vec()
+        };
+        assert_eq!(pixel(8, 0), vec![255, 0, 0, 255]);
+        assert_eq!(pixel(0, 16), vec![0, 255, 0, 255]);
+    }
+
+    #[test]
+    fn legacy_skin_mirrors_arm() {
+        let preview = render_preview(&skin_png(32), SkinModel::Classic, 1).unwrap();
+        // Mirrored: the arm's left edge ends up on the right
+        let i = ((8 * preview.width + 15) * 4) as usize;
+        assert_eq!(&preview.rgba[i..i + 4], &[0, 255, 0, 255]);
+    }
+
+    #[test]
+    fn rejects_wrong_size() {
+        let mut png = Vec::new();
+        image::DynamicImage::ImageRgba8(RgbaImage::new(32, 32))
+            .write_to(&mut std::io::Cursor::new(&mut png), ImageFormat::Png)
+            .unwrap();
+        assert!(matches!(
+            check_skin(&png),
+            Err(SkinError::InvalidSize(32, 32))
+        ));
+    }
+}
@@ -0,0 +1,285 @@
+//! Login for custom [authlib-injector](https://github.com/yushijinhun/authlib-injector)
+//! (Yggdrasil API) servers, such as LittleSkin, Blessing Skin
+//! or self-hosted ones.
+//!
+//! ely.by has its own module ([`super::elyby`])
+//! as its API differs slightly.
+//!
+//! # Usage
+//! 1. [`get_server_info`] on the URL the user entered, to
+//!    find the API root (through the `X-Authlib-Injector-API-Location`
+//!    header) and check it's actually an authlib-injector server.
+//! 2. [`login_new`] with the API root, username and password.
+//! 3. Later, [`login_refresh`] on every play session.
+
+use ql_core::{err, info, pt, IntoJsonError, IntoStringError, JsonError, RequestError, CLIENT};
+use ql_reqwest::Url;
+use serde::Deserialize;
+
+use super::{elyby::AccountResponseError, AccountData, AccountType, KeyringError};
+
+const AUTH_ERR_PREFIX: &str = "while logging into authlib-injector account:\n";
+
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    #[error("{AUTH_ERR_PREFIX}{0}")]
+    Request(#[from] RequestError),
+    #[error("{AUTH_ERR_PREFIX}{0}")]
+    Json(#[from] JsonError),
+    #[error("{AUTH_ERR_PREFIX}\n{0}")]
+    Response(#[from] AccountResponseError),
+    #[error("{AUTH_ERR_PREFIX}{0}")]
+    KeyringError(#[from] KeyringError),
+    #[error("{AUTH_ERR_PREFIX}invalid server URL: {0}")]
+    InvalidUrl(String),
+    #[error("{AUTH_ERR_PREFIX}{0} is not an authlib-injector server\n(missing server metadata)")]
+    NotAServer(String),
+    #[error("{AUTH_ERR_PREFIX}this account has no Minecraft profile (character)\nCreate one on the server's website first")]
+    NoProfile,
+}
+
+impl From<ql_reqwest::Error> for Error {
+    fn from(value: ql_reqwest::Error) -> Self {
+        Self::Request(RequestError::ReqwestError(value))
+    }
+}
+
+impl From<keyring::Error> for Error {
+    fn from(err: keyring::Error) -> Self {
+        Self::KeyringError(KeyringError(err))
+    }
+}
+
+/// An authlib-injector server, found by [`get_server_info`].
+#[derive(Debug, Clone)]
+pub struct ServerInfo {
+    /// The Yggdrasil API root, passed to authlib-injector.
+    pub api_root: String,
+    pub server_name: String,
+}
+
+#[derive(Deserialize)]
+struct ServerMetadata {
+    meta: ServerMetadataMeta,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ServerMetadataMeta {
+    server_name: Option<String>,
+}
+
+/// Finds the API root of an authlib-injector server from
+/// a URL entered by the user (`https://` is optional).
+///
+/// Follows the `X-Authlib-Injector-API-Location` ("ALI")
+/// header, so the website's URL works too
+/// (eg: `littleskin.cn` instead of `https://littleskin.cn/api/yggdrasil`).
+///
+/// # Errors
+/// - If the URL is invalid
+/// - If the server couldn't be reached
+/// - If it's not an authlib-injector server
+pub async fn get_server_info(url: &str) -> Result<ServerInfo, Error> {
+    let url = url.trim();
+    let url = if url.contains("://") {
+        url.to_owned()
+    } else {
+        format!("https://{url}")
+    };
+    let url = Url::parse(&url).map_err(|_| Error::InvalidUrl(url.clone()))?;
+
+    info!("Checking authlib-injector server: {url}");
+    let response = CLIENT.get(url.clone()).send().await?;
+    let api_root = match response
+        .headers()
+        .get("X-Authlib-Injector-API-Location")
+        .and_then(|n| n.to_str().ok())
+    {
+        // May be relative to the URL
+        Some(location) => url
+            .join(location)
+            .map_err(|_| Error::InvalidUrl(location.to_owned()))?,
+        None => url,
+    };
+    let api_root = api_root.as_str().trim_end_matches('/').to_owned();
+    pt!("API root: {api_root}");
+
+    let text = ql_core::file_utils::download_file_to_string(&api_root, false).await?;
+    let metadata: ServerMetadata =
+        serde_json::from_str(&text).map_err(|_| Error::NotAServer(api_root.clone()))?;
+
+    Ok(ServerInfo {
+        server_name: metadata.meta.server_name.unwrap_or_else(|| host(&api_root)),
+        api_root,
+    })
+}
+
+/// Logs into an account on an authlib-injector server.
+///
+/// If the account has multiple profiles (characters),
+/// the first one is used.
+///
+/// # Errors
+/// - If the username or password is wrong
+/// - If the account has no profiles
+/// - If the server couldn't be reached
+/// - If the token couldn't be saved to the keyring
+pub async fn login_new(
+    api_root: String,
+    username: String,
+    password: String,
+) -> Result<AccountData, Error> {
+    info!("Logging into {}... ({username})", host(&api_root));
+    let response: AccountResponse = post(
+        &api_root,
+        "authenticate",
+        &serde_json::json!({
+            "agent": { "name": "Minecraft", "version": 1 },
+            "username": &username,
+            "password": &password,
+            "clientToken": super::elyby::CLIENT_ID,
+        }),
+    )
+    .await?;
+
+    let response = if response.selectedProfile.is_some() {
+        response
+    } else {
+        let profile = response
+            .availableProfiles
+            .into_iter()
+            .next()
+            .ok_or(Error::NoProfile)?;
+        pt!("Selecting profile {}", profile.name);
+        post(
+            &api_root,
+            "refresh",
+            &serde_json::json!({
+                "accessToken": response.accessToken,
+                "clientToken": super::elyby::CLIENT_ID,
+                "selectedProfile": { "id": profile.id, "name": profile.name },
+            }),
+        )
+        .await?
+    };
+
+    account_data(api_root, username, response)
+}
+
+/// Gets a new access token for an account,
+/// to be done on every play session.
+///
+/// # Errors
+/// - If the account was logged out (or the token revoked)
+/// - If the server couldn't be reached
+/// - If the token couldn't be saved to the keyring
+pub async fn login_refresh(
+    api_root: String,
+    username: String,
+    refresh_token: String,
+) -> Result<AccountData, Error> {
+    pt!("Refreshing {} account...", host(&api_root));
+    let response: AccountResponse = post(
+        &api_root,
+        "refresh",
+        &serde_json::json!({
+            "accessToken": refresh_token,
+            "clientToken": super::elyby::CLIENT_ID,
+        }),
+    )
+    .await?;
+    account_data(api_root, username, response)
+}
+
+fn account_data(
+    api_root: String,
+    username: String,
+    response: AccountResponse,
+) -> Result<AccountData, Error> {
+    let profile = response.selectedProfile.ok_or(Error::NoProfile)?;
+
+    let entry = get_keyring_entry(&username, &api_root)?;
+    entry.set_password(&response.accessToken)?;
+
+    Ok(AccountData {
+        access_token: Some(response.accessToken.clone()),
+        uuid: profile.id,
+
+        username,
+        nice_username: profile.name,
+
+        refresh_token: response.accessToken,
+        needs_refresh: false,
+        account_type: AccountType::AuthlibInjector,
+        auth_server: Some(api_root),
+        expires_at: None,
+    })
+}
+
+async fn post<T: serde::de::DeserializeOwned>(
+    api_root: &str,
+    endpoint: &str,
+    body: &serde_json::Value,
+) -> Result<T, Error> {
+    let response = CLIENT
+        .post(format!("{api_root}/authserver/{endpoint}"))
+        .json(body)
+        .send()
+        .await?;
+    let status = response.status();
+    let url = response.url().clone();
+    let text = response.text().await?;
+
+    if !status.is_success() {
+        if let Ok(error) = serde_json::from_str::<AccountResponseError>(&text) {
+            return Err(error.into());
+        }
+        return Err(RequestError::DownloadError { code: status, url }.into());
+    }
+    Ok(serde_json::from_str(&text).json(text)?)
+}
+
+pub fn read_refresh_token(username: &str, api_root: &str) -> Result<String, Error> {
+    let entry = get_keyring_entry(username, api_root)?;
+    Ok(entry.get_password()?)
+}
+
+fn get_keyring_entry(username: &str, api_root: &str) -> Result<keyring::Entry, Error> {
+    Ok(keyring::Entry::new(
+        "QuantumLauncher",
+        &format!("{username}#yggdrasil#{api_root}"),
+    )?)
+}
+
+pub fn logout(username: &str, api_root: &str) -> Result<(), String> {
+    let entry = get_keyring_entry(username, api_root).strerr()?;
+    if let Err(err) = entry.delete_credential() {
+        err!("Couldn't remove authlib-injector account credential (Username: {username}, Server: {api_root}):\n{err}");
+    }
+    Ok(())
+}
+
+/// `https://littleskin.cn/api/yggdrasil` -> `littleskin.cn`
+#[must_use]
+pub fn host(api_root: &str) -> String {
+    Url::parse(api_root)
+        .ok()
+        .and_then(|n| n.host_str().map(ToOwned::to_owned))
+        .unwrap_or_else(|| api_root.to_owned())
+}
+
+#[derive(Deserialize, Clone, Debug)]
+#[allow(non_snake_case)]
+struct AccountResponse {
+    accessToken: String,
+    #[serde(default)]
+    availableProfiles: Vec<AccountResponseProfile>,
+    selectedProfile: Option<AccountResponseProfile>,
+}
+
+#[derive(Deserialize, Clone, Debug)]
+struct AccountResponseProfile {
+    id: String,
+    name: String,
+}
@@ -15,7 +15,7 @@ use ql_core::{
 use zip_extract::ZipExtractError;
 
 #[allow(clippy::wildcard_imports)]
-use crate::download::constants::*;
+use crate::{download::constants::*, library_store};
 
 use super::{DownloadError, GameDownloader};
 
@@ -177,24 +177,7 @@ impl GameDownloader {
         libraries_dir: &Path,
     ) -> Result<Vec<u8>, DownloadError> {
         let lib_file_path = libraries_dir.join(PathBuf::from(artifact.get_path()));
-
-        let lib_dir_path = lib_file_path
-            .parent()
-            .expect(
-                "Downloaded java library does not have parent module like the sun in com.sun.java",
-            )
-            .to_path_buf();
-
-        tokio::fs::create_dir_all(&lib_dir_path)
-            .await
-            .path(lib_dir_path)?;
-        let library_downloaded = file_utils::download_file_to_bytes(&artifact.url, false).await?;
-
-        tokio::fs::write(&lib_file_path, &library_downloaded)
-            .await
-            .path(lib_file_path)?;
-
-        Ok(library_downloaded)
+        library_store::get_library(artifact, &lib_file_path).await
     }
 
     async fn download_library_native(
@@ -7,16 +7,16 @@ use std::{path::PathBuf, sync::mpsc::Sender};
 use ql_core::json::AssetIndexMap;
 use ql_core::{
     do_jobs,
-    file_utils::{self, LAUNCHER_DIR},
+    file_utils::LAUNCHER_DIR,
     impl_3_errs_jri, info,
-    json::{InstanceConfigJson, Manifest, VersionDetails},
-    pt, DownloadFileError, DownloadProgress, IntoIoError, IntoJsonError, IoError, JsonError,
-    ListEntry, RequestError,
+    json::{version::LibraryDownloadArtifact, InstanceConfigJson, Manifest, VersionDetails},
+    meta_cache, pt, DownloadFileError, DownloadProgress, IntoIoError, IntoJsonError, IoError,
+    JsonError, ListEntry, RequestError,
 };
 use thiserror::Error;
 use tokio::sync::Mutex;
 
-use crate::json_profiles::ProfileJson;
+use crate::{json_profiles::ProfileJson, library_store};
 
 use self::constants::DEFAULT_RAM_MB_FOR_INSTANCE;
 
@@ -82,7 +82,22 @@ impl GameDownloader {
         })
     }
 
-    #[allow(unused)]
+    /// Like [`GameDownloader::new`], but for an instance
+    /// that already exists (eg: to change its version).
+    pub async fn for_existing_instance(
+        instance_dir: PathBuf,
+        version: &ListEntry,
+        sender: Option<Sender<DownloadProgress>>,
+    ) -> Result<GameDownloader, DownloadError> {
+        let version_json =
+            GameDownloader::new_download_version_json(version, sender.as_ref()).await?;
+        Ok(Self {
+            instance_dir,
+            version_json,
+            sender,
+        })
+    }
+
     pub fn with_existing_instance(
         version_json: VersionDetails,
         instance_dir: PathBuf,
@@ -110,12 +125,16 @@ impl GameDownloader {
 
         let jar_path = version_dir.join(format!("{}.jar", self.version_json.id));
 
-        file_utils::download_file_to_path(
-            &self.version_json.downloads.client.url,
-            false,
-            &jar_path,
-        )
-        .await?;
+        // Shared through the library store, so instances of the same
+        // version can be created offline from already downloaded files
+        let client = &self.version_json.downloads.client;
+        let artifact = LibraryDownloadArtifact {
+            path: Some(library_store::client_jar_path(&self.version_json.id)),
+            sha1: client.sha1.clone(),
+            size: client.size,
+            url: client.url.clone(),
+        };
+        library_store::get_library(&artifact, &jar_path).await?;
 
         Ok(())
     }
@@ -127,8 +146,7 @@ impl GameDownloader {
 
             let log_config_name = format!("logging-{}", logging.client.file.id);
 
-            let log_config =
-                file_utils::download_file_to_string(&logging.client.file.url, false).await?;
+            let log_config = meta_cache::download_string(&logging.client.file.url, false).await?;
 
             let config_path = self.instance_dir.join(log_config_name);
             tokio::fs::write(&config_path, log_config.as_bytes())
@@ -141,7 +159,7 @@ impl GameDownloader {
     pub async fn download_assets(&self) -> Result<(), DownloadError> {
         info!("Downloading assets");
         let asset_index: AssetIndexMap =
-            file_utils::download_file_to_json(&self.version_json.assetIndex.url, false).await?;
+            meta_cache::download_json(&self.version_json.assetIndex.url, false).await?;
 
         let assets_dir = LAUNCHER_DIR.join("assets");
         tokio::fs::create_dir_all(&assets_dir)
@@ -322,6 +340,11 @@ impl GameDownloader {
             is_classic_server: None,
             do_gc_tuning: None,
             close_on_start: None,
+            last_account: None,
+            group: None,
+            is_favorite: None,
+            color: None,
+            notes: None,
             is_server: Some(false),
             omniarchive: None,
         };
@@ -356,7 +379,7 @@ impl GameDownloader {
         if let Some(sender) = sender {
             _ = sender.send(DownloadProgress::DownloadingVersionJson);
         }
-        let json = file_utils::download_file_to_string(&version.url, false).await?;
+        let json = meta_cache::download_string(&version.url, false).await?;
         let json = serde_json::from_str(&json).json(json)?;
         Ok(json)
     }
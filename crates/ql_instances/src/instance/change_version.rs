@@ -0,0 +1,107 @@
+use std::sync::mpsc::Sender;
+
+use ql_core::{
+    info, json::VersionDetails, pt, DownloadProgress, InstanceSelection, IntoIoError, ListEntry,
+};
+
+use crate::download::{DownloadError, GameDownloader};
+
+/// Changes the Minecraft version of an existing instance
+/// (upgrading or downgrading it) in place.
+///
+/// `.minecraft` (worlds, configs, screenshots, mods)
+/// and the instance config are kept. Only the game jar,
+/// libraries and version JSON are replaced.
+///
+/// **Note:** Mod loaders need to be uninstalled before this,
+/// and reinstalled after (their libraries are removed).
+/// Mods aren't touched either: see
+/// `ql_mod_manager::store::update_for_game_version`.
+///
+/// If downloading the new version fails, the
+/// old libraries are restored.
+///
+/// # Errors
+/// - If the version isn't in the version list
+/// - If the instance's files couldn't be read/written
+/// - If anything couldn't be downloaded
+pub async fn change_instance_version(
+    instance_name: String,
+    version: ListEntry,
+    progress_sender: Option<Sender<DownloadProgress>>,
+    download_assets: bool,
+) -> Result<(), DownloadError> {
+    let instance = InstanceSelection::Instance(instance_name);
+    let old_version = VersionDetails::load(&instance).await?;
+    let instance_dir = instance.get_instance_path();
+    info!(
+        "Changing version of instance {} ({} -> {})",
+        instance.get_name(),
+        old_version.id,
+        version.name
+    );
+
+    let mut game_downloader =
+        GameDownloader::for_existing_instance(instance_dir.clone(), &version, progress_sender)
+            .await?;
+
+    // Kept until the new version is fully downloaded
+    let libraries_dir = instance_dir.join("libraries");
+    let old_libraries_dir = instance_dir.join("libraries_old");
+    if old_libraries_dir.exists() {
+        tokio::fs::remove_dir_all(&old_libraries_dir)
+            .await
+            .path(&old_libraries_dir)?;
+    }
+    if libraries_dir.exists() {
+        tokio::fs::rename(&libraries_dir, &old_libraries_dir)
+            .await
+            .path(&libraries_dir)?;
+    }
+
+    let result = async {
+        game_downloader.download_logging_config().await?;
+        game_downloader.download_jar().await?;
+        game_downloader.download_libraries().await?;
+        if download_assets {
+            game_downloader.download_assets().await?;
+        }
+        game_downloader.create_version_json().await?;
+        Ok::<(), DownloadError>(())
+    }
+    .await;
+
+    if let Err(err) = result {
+        pt!("Failed, restoring old libraries");
+        if libraries_dir.exists() {
+            tokio::fs::remove_dir_all(&libraries_dir)
+                .await
+                .path(&libraries_dir)?;
+        }
+        if old_libraries_dir.exists() {
+            tokio::fs::rename(&old_libraries_dir, &libraries_dir)
+                .await
+                .path(&old_libraries_dir)?;
+        }
+        return Err(err);
+    }
+
+    if old_libraries_dir.exists() {
+        tokio::fs::remove_dir_all(&old_libraries_dir)
+            .await
+            .path(&old_libraries_dir)?;
+    }
+    if old_version.id != version.name {
+        let old_version_dir = instance_dir
+            .join(".minecraft/versions")
+            .join(&old_version.id);
+        if old_version_dir.is_dir() {
+            tokio::fs::remove_dir_all(&old_version_dir)
+                .await
+                .path(&old_version_dir)?;
+        }
+    }
+
+    info!("Finished changing version to {}", version.name);
+    Ok(())
+}
@@ -0,0 +1,281 @@
+use std::{
+    path::{Path, PathBuf},
+    sync::mpsc::Sender,
+};
+
+use ql_core::{file_utils, info, pt, GenericProgress, InstanceSelection, IntoIoError, IoError};
+use thiserror::Error;
+use walkdir::WalkDir;
+
+use crate::library_store;
+
+const CLONE_ERR_PREFIX: &str = "while cloning instance:\n";
+
+#[derive(Debug, Error)]
+pub enum CloneError {
+    #[error("{CLONE_ERR_PREFIX}{0}")]
+    Io(#[from] IoError),
+    #[error("an instance with that name already exists!")]
+    InstanceAlreadyExists,
+}
+
+/// Which optional data is copied
+/// over when cloning an instance.
+#[derive(Debug, Clone, Copy)]
+pub struct CloneOptions {
+    /// Singleplayer worlds (client) or
+    /// the server world (server)
+    pub worlds: bool,
+    /// Game logs and crash reports
+    pub logs: bool,
+    pub screenshots: bool,
+}
+
+impl Default for CloneOptions {
+    fn default() -> Self {
+        Self {
+            worlds: true,
+            logs: false,
+            screenshots: false,
+        }
+    }
+}
+
+/// Text files bigger than this aren't
+/// checked for paths to the old instance.
+const FIXUP_MAX_SIZE: u64 = 1024 * 1024;
+/// Files that may contain paths to the old instance
+/// (mostly mod configs, which sometimes store absolute paths).
+const FIXUP_EXTENSIONS: &[&str] = &[
+    "json",
+    "json5",
+    "txt",
+    "toml",
+    "properties",
+    "cfg",
+    "conf",
+    "ini",
+    "yml",
+    "yaml",
+];
+
+/// Creates a copy of an instance (or server) named `new_name`,
+/// returning the new instance.
+///
+/// Mods, configs, loaders and settings are always copied.
+/// Worlds, logs and screenshots are copied depending
+/// on `options`. Mod snapshots (rollback history)
+/// aren't copied, the clone starts fresh.
+///
+/// Libraries are hard linked from the shared
+/// library store instead of being copied, and any
+/// absolute paths to the old instance in config files
+/// are changed to point to the new one.
+///
+/// # Errors
+/// - If an instance named `new_name` already exists
+/// - If the instance couldn't be read or the clone written
+pub async fn clone_instance(
+    instance: &InstanceSelection,
+    new_name: &str,
+    options: CloneOptions,
+    sender: Option<&Sender<GenericProgress>>,
+) -> Result<InstanceSelection, CloneError> {
+    let mut new_instance = instance.clone();
+    new_instance.set_name(new_name);
+
+    let src = instance.get_instance_path();
+    let dst = new_instance.get_instance_path();
+    if dst.exists() {
+        return Err(CloneError::InstanceAlreadyExists);
+    }
+    info!("Cloning {} to {new_name}", instance.get_name());
+
+    send_progress(sender, 0, "Copying files");
+    let exceptions = get_exceptions(instance, options).await;
+    if let Err(err) = copy_instance(&src, &dst, &exceptions).await {
+        // Don't leave a half-copied instance behind
+        _ = tokio::fs::remove_dir_all(&dst).await;
+        return Err(err.into());
+    }
+
+    send_progress(sender, 1, "Updating paths");
+    let fixed = fix_paths(&src, &dst).await?;
+    if fixed > 0 {
+        pt!("Updated paths in {fixed} files");
+    }
+
+    send_progress(sender, 2, "Done");
+    pt!("Finished cloning");
+    Ok(new_instance)
+}
+
+async fn get_exceptions(instance: &InstanceSelection, options: CloneOptions) -> Vec<PathBuf> {
+    let instance_dir = instance.get_instance_path();
+    let dot_minecraft = instance.get_dot_minecraft_path();
+
+    let mut exceptions = vec![
+        instance_dir.join("mod_snapshots"),
+        // Leftover from a failed version change
+        instance_dir.join("libraries_old"),
+    ];
+
+    if !options.worlds {
+        match instance {
+            InstanceSelection::Instance(_) => exceptions.push(dot_minecraft.join("saves")),
+            InstanceSelection::Server(_) => {
+                let level_name = get_server_level_name(&instance_dir).await;
+                exceptions.extend(
+                    ["", "_nether", "_the_end"]
+                        .iter()
+                        .map(|suffix| instance_dir.join(format!("{level_name}{suffix}"))),
+                );
+            }
+        }
+    }
+    if !options.logs {
+        exceptions.push(dot_minecraft.join("logs"));
+        exceptions.push(dot_minecraft.join("crash-reports"));
+    }
+    if !options.screenshots {
+        exceptions.push(dot_minecraft.join("screenshots"));
+    }
+    exceptions
+}
+
+async fn get_server_level_name(server_dir: &Path) -> String {
+    tokio::fs::read_to_string(server_dir.join("server.properties"))
+        .await
+        .ok()
+        .and_then(|n| {
+            n.lines()
+                .filter_map(|n| n.split_once('='))
+                .find(|(key, _)| key.trim() == "level-name")
+                .map(|(_, value)| value.trim().to_owned())
+        })
+        .filter(|n| !n.is_empty())
+        .unwrap_or_else(|| "world".to_owned())
+}
+
+async fn copy_instance(src: &Path, dst: &Path, exceptions: &[PathBuf]) -> Result<(), IoError> {
+    let libraries_dir = src.join("libraries");
+
+    let mut exceptions = exceptions.to_vec();
+    exceptions.push(libraries_dir.clone());
+    file_utils::copy_dir_recursive_ext(src, dst, &exceptions).await?;
+
+    if libraries_dir.is_dir() {
+        library_store::link_libraries(&libraries_dir, &dst.join("libraries")).await?;
+    }
+    Ok(())
+}
+
+/// Replaces absolute paths to the `src` instance
+/// with `dst` in the clone's text files,
+/// returning the number of files changed.
+async fn fix_paths(src: &Path, dst: &Path) -> Result<usize, IoError> {
+    let src_str = src.to_string_lossy();
+    let dst_str = dst.to_string_lossy();
+    let mut replacements = vec![(src_str.to_string(), dst_str.to_string())];
+    if src_str.contains('\\') {
+        // Windows paths can also appear escaped (JSON)
+        // or with forward slashes
+        replacements.push((src_str.replace('\\', "\\\\"), dst_str.replace('\\', "\\\\")));
+        replacements.push((src_str.replace('\\', "/"), dst_str.replace('\\', "/")));
+    }
+
+    let files: Vec<PathBuf> = WalkDir::new(dst)
+        .into_iter()
+        // Worlds and libraries are big, and don't contain paths
+        .filter_entry(|n| {
+            !(n.file_type().is_dir()
+                && (n.file_name() == "libraries" || n.path().join("level.dat").exists()))
+        })
+        .filter_map(Result::ok)
+        .filter(|n| {
+            n.file_type().is_file()
+                && n.metadata().is_ok_and(|n| n.len() <= FIXUP_MAX_SIZE)
+                && n.path()
+                    .extension()
+                    .and_then(|n| n.to_str())
+                    .is_some_and(|ext| FIXUP_EXTENSIONS.contains(&ext))
+        })
+        .map(walkdir::DirEntry::into_path)
+        .collect();
+
+    let mut fixed = 0;
+    for file in files {
+        let Ok(contents) = tokio::fs::read_to_string(&file).await else {
+            // Not UTF-8, leave it alone
+            continue;
+        };
+        let mut new_contents: Option<String> = None;
+        for (from, to) in &replacements {
+            if let Some(n) = replace_path(new_contents.as_deref().unwrap_or(&contents), from, to) {
+                new_contents = Some(n);
+            }
+        }
+        if let Some(new_contents) = new_contents {
+            tokio::fs::write(&file, new_contents).await.path(&file)?;
+            fixed += 1;
+        }
+    }
+    Ok(fixed)
+}
+
+/// Replaces occurrences of the path `from` in `text`,
+/// returning `None` if there weren't any.
+///
+/// Only whole paths (or their subpaths) are replaced,
+/// so `instances/Test` doesn't touch `instances/Test 2`.
+fn replace_path(text: &str, from: &str, to: &str) -> Option<String> {
+    let mut out = String::with_capacity(text.len());
+    let mut last = 0;
+    for (i, _) in text.match_indices(from) {
+        let end = i + from.len();
+        let is_whole = text[end..].chars().next().is_none_or(|c| {
+            matches!(
+                c,
+                '/' | '\\' | '"' | '\'' | '\n' | '\r' | '\t' | ';' | ':' | ','
+            )
+        });
+        if is_whole {
+            out.push_str(&text[last..i]);
+            out.push_str(to);
+            last = end;
+        }
+    }
+    if last == 0 {
+        return None;
+    }
+    out.push_str(&text[last..]);
+    Some(out)
+}
+
+fn send_progress(sender: Option<&Sender<GenericProgress>>, done: usize, message: &str) {
+    if let Some(sender) = sender {
+        _ = sender.send(GenericProgress {
+            done,
+            total: 2,
+            message: Some(message.to_owned()),
+            has_finished: false,
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::replace_path;
+
+    #[test]
+    fn replace_whole_paths() {
+        let text = r#"{"a": "/ql/instances/Test/mods", "b": "/ql/instances/Test 2", "c": "/ql/instances/Test"}"#;
+        assert_eq!(
+            replace_path(text, "/ql/instances/Test", "/ql/instances/Copy").as_deref(),
+            Some(
+                r#"{"a": "/ql/instances/Copy/mods", "b": "/ql/instances/Test 2", "c": "/ql/instances/Copy"}"#
+            )
+        );
+        assert_eq!(replace_path(text, "/ql/instances/Other", "/x"), None);
+    }
+}
@@ -4,7 +4,10 @@ use ql_core::{
     info, DownloadProgress, IntoIoError, ListEntry, LAUNCHER_DIR, LAUNCHER_VERSION_NAME,
 };
 
-use crate::download::{DownloadError, GameDownloader};
+use crate::{
+    download::{DownloadError, GameDownloader},
+    library_store,
+};
 
 /// Creates a Minecraft instance.
 ///
@@ -46,6 +49,7 @@ pub async fn create_instance(
     game_downloader.download_logging_config().await?;
     game_downloader.download_jar().await?;
     game_downloader.download_libraries().await?;
+    library_store::mark_migrated(&game_downloader.instance_dir).await?;
 
     if download_assets {
         game_downloader.download_assets().await?;
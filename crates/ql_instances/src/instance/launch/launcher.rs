@@ -97,7 +97,9 @@ impl GameLauncher {
                 )));
             };
 
-        if let Some(AccountType::ElyBy) = account_details.map(|n| n.account_type) {
+        if let Some(AccountType::ElyBy | AccountType::AuthlibInjector) =
+            account_details.map(|n| n.account_type)
+        {
             if !self.version_json.is_legacy_version()
                 && !game_arguments.iter().any(|n| n.contains("uuid"))
             {
@@ -140,12 +142,12 @@ impl GameLauncher {
             replace_var(argument, "auth_xuid", "0");
 
             let uuid = if let Some(account_details) = account_details {
-                &account_details.uuid
+                account_details.uuid.clone()
             } else {
-                "00000000-0000-0000-0000-000000000000"
+                crate::auth::offline_uuid(&self.username)
             };
-            replace_var(argument, "auth_uuid", uuid);
-            replace_var(argument, "uuid", uuid);
+            replace_var(argument, "auth_uuid", &uuid);
+            replace_var(argument, "uuid", &uuid);
 
             let access_token = if let Some(account_details) = account_details {
                 account_details
@@ -240,6 +242,7 @@ impl GameLauncher {
             format!("-Dio.netty.native.workdir={natives_path}"),
             self.config_json.get_ram_argument(),
         ];
+        args.extend(ql_core::network::java_args());
 
         // I've disabled these for now because they make the
         // FPS slightly worse (!) from my testing?
@@ -269,8 +272,8 @@ impl GameLauncher {
             args.push("-Dminecraft.api.account.host=https://nope.invalid".to_owned());
             args.push("-Dminecraft.api.session.host=https://nope.invalid".to_owned());
             args.push("-Dminecraft.api.services.host=https://nope.invalid".to_owned());
-        } else if auth.is_some_and(AccountData::is_elyby) {
-            args.push(crate::auth::elyby::get_authlib_injector().await?);
+        } else if let Some(server) = auth.and_then(AccountData::authlib_injector_server) {
+            args.push(crate::auth::get_authlib_injector(server).await?);
         }
 
         if cfg!(target_pointer_width = "32") {
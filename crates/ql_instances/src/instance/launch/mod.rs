@@ -65,7 +65,7 @@ pub async fn launch(
         )
         .await?;
 
-    info!("Java args: {java_arguments:?}\n");
+    info!("Java args: {:?}\n", censor_java_args(&java_arguments));
 
     print_censored_args(auth.as_ref(), &mut game_arguments);
 
@@ -135,6 +135,19 @@ fn censor_string<F: FnOnce(&mut Vec<String>)>(vec: &[String], argument: &str, co
     code(&mut new);
 }
 
+/// Hides proxy passwords (see [`ql_core::network::java_args`]).
+fn censor_java_args(java_arguments: &[String]) -> Vec<String> {
+    java_arguments
+        .iter()
+        .map(|n| match n.split_once('=') {
+            Some((key, _)) if key.ends_with("proxyPassword") || key.ends_with("socks.password") => {
+                format!("{key}=[REDACTED]")
+            }
+            _ => n.clone(),
+        })
+        .collect()
+}
+
 fn replace_var(string: &mut String, var: &str, value: &str) {
     *string = string.replace(&format!("${{{var}}}"), value);
 }
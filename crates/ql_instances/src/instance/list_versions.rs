@@ -1,4 +1,4 @@
-use ql_core::{json::Manifest, JsonDownloadError, ListEntry};
+use ql_core::{json::Manifest, JsonDownloadError, ListEntry, VersionType};
 
 /// Returns a list of every downloadable version of Minecraft.
 /// Sources the list from Mojang and Omniarchive (combined).
@@ -17,9 +17,104 @@ pub async fn list_versions() -> Result<Vec<ListEntry>, JsonDownloadError> {
         .await?
         .versions
         .into_iter()
-        .map(|n| ListEntry {
-            name: n.id,
-            is_classic_server: false,
-        })
+        .map(ListEntry::from)
         .collect())
 }
+
+/// Narrows down a version list (from [`list_versions`])
+/// by type, release date and name.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct VersionFilter {
+    /// Only show these types (all types if empty)
+    pub kinds: Vec<VersionType>,
+    /// Only show versions released at (or after) this one.
+    ///
+    /// Ignored if there's no version with this name
+    /// (check with [`VersionFilter::is_since_valid`]).
+    pub since: Option<String>,
+    /// Only show versions containing this (case-insensitive)
+    pub search: String,
+}
+
+impl VersionFilter {
+    #[must_use]
+    pub fn apply(&self, versions: &[ListEntry]) -> Vec<ListEntry> {
+        let since = self.since.as_ref().and_then(|since| {
+            versions
+                .iter()
+                .find(|n| n.name == *since)
+                .and_then(|n| n.release_time)
+        });
+        let search = self.search.trim().to_lowercase();
+
+        versions
+            .iter()
+            .filter(|n| self.kinds.is_empty() || self.kinds.contains(&n.kind))
+            .filter(|n| since.is_none_or(|since| n.release_time.is_some_and(|t| t >= since)))
+            .filter(|n| search.is_empty() || n.name.to_lowercase().contains(&search))
+            .cloned()
+            .collect()
+    }
+
+    /// Whether [`VersionFilter::since`] is empty
+    /// or refers to a version in `versions`.
+    #[must_use]
+    pub fn is_since_valid(&self, versions: &[ListEntry]) -> bool {
+        self.since
+            .as_ref()
+            .is_none_or(|since| versions.iter().any(|n| n.name == *since))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::DateTime;
+
+    fn entry(name: &str, kind: VersionType, time: &str) -> ListEntry {
+        ListEntry {
+            kind,
+            release_time: DateTime::parse_from_rfc3339(time).ok(),
+            ..ListEntry::new(name.to_owned())
+        }
+    }
+
+    #[test]
+    fn filter_versions() {
+        let versions = [
+            entry("1.21", VersionType::Release, "2024-06-13T08:24:03+00:00"),
+            entry("24w14a", VersionType::Snapshot, "2024-04-03T11:48:00+00:00"),
+            entry("1.20.1", VersionType::Release, "2023-06-12T13:25:51+00:00"),
+            entry("1.16", VersionType::Release, "2020-06-23T16:20:52+00:00"),
+            entry("1.12.2", VersionType::Release, "2017-09-18T08:39:46+00:00"),
+            entry("b1.7.3", VersionType::Beta, "2011-07-08T00:00:00+00:00"),
+        ];
+        let names = |filter: &VersionFilter| -> Vec<String> {
+            filter
+                .apply(&versions)
+                .into_iter()
+                .map(|n| n.name)
+                .collect()
+        };
+
+        let filter = VersionFilter {
+            kinds: vec![VersionType::Release],
+            since: Some("1.16".to_owned()),
+            search: String::new(),
+        };
+        assert_eq!(names(&filter), ["1.21", "1.20.1", "1.16"]);
+
+        let filter = VersionFilter {
+            search: "1.20".to_owned(),
+            ..Default::default()
+        };
+        assert_eq!(names(&filter), ["1.20.1"]);
+
+        let filter = VersionFilter {
+            since: Some("1.99".to_owned()),
+            ..Default::default()
+        };
+        assert!(!filter.is_since_valid(&versions));
+        assert_eq!(filter.apply(&versions).len(), versions.len());
+    }
+}
@@ -0,0 +1,85 @@
+use std::sync::mpsc::Sender;
+
+use ql_core::{info, json::VersionDetails, pt, DownloadProgress, InstanceSelection, IntoIoError};
+
+use crate::download::{DownloadError, GameDownloader};
+
+/// Mojang only hosts the LWJGL versions used by the game,
+/// so overridden versions are downloaded from Maven Central.
+const MAVEN_CENTRAL: &str = "https://repo1.maven.org/maven2/";
+const MOJANG_LIBRARIES: &str = "https://libraries.minecraft.net/";
+
+/// Switches the LWJGL version used by a client instance,
+/// redownloading the libraries (and natives).
+///
+/// This is used when importing instances from other launchers
+/// (like Prism/MultiMC) that let you override LWJGL.
+/// Does nothing if the instance already uses `version`.
+///
+/// # Errors
+/// - `details.json` couldn't be read or written to
+/// - The libraries couldn't be downloaded
+///   (eg: if the version doesn't exist)
+pub async fn override_lwjgl_version(
+    instance: &InstanceSelection,
+    version: &str,
+    sender: Option<Sender<DownloadProgress>>,
+) -> Result<(), DownloadError> {
+    let instance_dir = instance.get_instance_path();
+    let mut json = VersionDetails::load(instance).await?;
+
+    let mut changed = false;
+    for library in &mut json.libraries {
+        let Some(name) = &library.name else {
+            continue;
+        };
+        let parts: Vec<&str> = name.split(':').collect();
+        if parts.len() < 3 || !parts[0].starts_with("org.lwjgl") || parts[2] == version {
+            continue;
+        }
+        let old = parts[2].to_owned();
+        let replace = |n: &str| {
+            n.replace(&format!("/{old}/"), &format!("/{version}/"))
+                .replace(&format!("-{old}"), &format!("-{version}"))
+                .replace(MOJANG_LIBRARIES, MAVEN_CENTRAL)
+        };
+
+        library.name = Some(name.replacen(&format!(":{old}"), &format!(":{version}"), 1));
+        if let Some(downloads) = &mut library.downloads {
+            if let Some(artifact) = &mut downloads.artifact {
+                artifact.path = artifact.path.as_deref().map(replace);
+                artifact.url = replace(&artifact.url);
+                // The hash is of the old version
+                artifact.sha1.clear();
+            }
+            for classifier in downloads
+                .classifiers
+                .iter_mut()
+                .flat_map(|n| n.values_mut())
+            {
+                classifier.url = replace(&classifier.url);
+            }
+        }
+        changed = true;
+    }
+
+    if !changed {
+        return Ok(());
+    }
+    info!("Overriding LWJGL version to {version}");
+
+    // Old natives would conflict with the new ones
+    let natives_dir = instance_dir.join("libraries/natives");
+    if natives_dir.is_dir() {
+        tokio::fs::remove_dir_all(&natives_dir)
+            .await
+            .path(&natives_dir)?;
+    }
+
+    let mut downloader = GameDownloader::with_existing_instance(json, instance_dir, sender);
+    downloader.download_libraries().await?;
+    downloader.create_version_json().await?;
+    pt!("Done overriding LWJGL");
+
+    Ok(())
+}
@@ -7,7 +7,7 @@ use ql_core::{
     info, json::version::LibraryDownloads, IntoIoError, CLASSPATH_SEPARATOR, LAUNCHER_VERSION_NAME,
 };
 
-use crate::{download::GameDownloader, LAUNCHER_VERSION};
+use crate::{download::GameDownloader, library_store, LAUNCHER_VERSION};
 
 use super::launch::{error::GameLaunchError, GameLauncher};
 
@@ -19,6 +19,7 @@ impl GameLauncher {
 
         self.migrate_natives(&version).await?;
         self.migrate_classpath_to_relative(&version).await?;
+        library_store::migrate_instance(&self.instance_dir, &self.version_json).await?;
 
         Ok(())
     }
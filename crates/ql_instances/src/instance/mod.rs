@@ -1,5 +1,8 @@
+pub mod change_version;
+pub mod clone;
 pub mod create;
 pub mod launch;
 pub mod list_versions;
+pub mod lwjgl;
 mod migrate;
 pub mod read_log;
@@ -1,12 +1,16 @@
 use std::collections::BTreeMap;
 
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 
 /// Represents the `launcher_profiles.json` file.
 ///
 /// It's not needed for the game to run, but some
 /// loader installers depend on it so it's included.
-#[derive(Serialize)]
+///
+/// This is also read when importing instances
+/// from the official launcher.
+#[derive(Serialize, Deserialize)]
+#[serde(default)]
 #[allow(non_snake_case)]
 pub struct ProfileJson {
     pub profiles: BTreeMap<String, Profiles>,
@@ -21,14 +25,16 @@ pub struct ProfileJson {
     pub version: Option<i32>,
 }
 
-#[derive(Serialize)]
+#[derive(Serialize, Deserialize)]
 #[allow(non_snake_case)]
 pub struct Profiles {
+    #[serde(default)]
     pub name: String,
     pub r#type: Option<String>,
     pub created: Option<String>,
     pub lastUsed: Option<String>,
     pub icon: Option<String>,
+    #[serde(default)]
     pub lastVersionId: String,
     pub gameDir: Option<String>,
     pub javaDir: Option<String>,
@@ -38,13 +44,13 @@ pub struct Profiles {
     pub resolution: Option<Resolution>,
 }
 
-#[derive(Serialize)]
+#[derive(Serialize, Deserialize)]
 pub struct Resolution {
     pub height: i32,
     pub width: i32,
 }
 
-#[derive(Serialize)]
+#[derive(Serialize, Deserialize)]
 #[allow(non_snake_case)]
 pub struct AuthenticationDatabase {
     pub accessToken: String,
@@ -53,7 +59,7 @@ pub struct AuthenticationDatabase {
     pub profiles: BTreeMap<String, String>,
 }
 
-#[derive(Serialize)]
+#[derive(Serialize, Deserialize)]
 #[allow(non_snake_case)]
 pub struct LauncherVersion {
     pub name: String,
@@ -61,7 +67,8 @@ pub struct LauncherVersion {
     pub profilesFormat: i32,
 }
 
-#[derive(Serialize)]
+#[derive(Serialize, Deserialize)]
+#[serde(default)]
 #[allow(non_snake_case)]
 #[allow(clippy::struct_excessive_bools)]
 pub struct Settings {
@@ -78,7 +85,7 @@ pub struct Settings {
     pub soundOn: Option<bool>,
 }
 
-#[derive(Serialize)]
+#[derive(Serialize, Deserialize)]
 pub struct SelectedUser {
     pub account: String,
     pub profile: String,
@@ -91,19 +98,7 @@ impl Default for ProfileJson {
             clientToken: None,
             authenticationDatabase: None,
             launcherVersion: None,
-            settings: Settings {
-                enableSnapshots: true,
-                enableAdvanced: true,
-                keepLauncherOpen: true,
-                showGameLog: true,
-                locale: None,
-                showMenu: true,
-                enableHistorical: true,
-                profileSorting: "ByLastPlayed".to_owned(),
-                crashAssistance: false,
-                enableAnalytics: false,
-                soundOn: Some(false),
-            },
+            settings: Settings::default(),
             analyticsToken: None,
             analyticsFailcount: None,
             selectedUser: None,
@@ -111,3 +106,21 @@ impl Default for ProfileJson {
         }
     }
 }
+
+impl Default for Settings {
+    fn default() -> Self {
+        Self {
+            enableSnapshots: true,
+            enableAdvanced: true,
+            keepLauncherOpen: true,
+            showGameLog: true,
+            locale: None,
+            showMenu: true,
+            enableHistorical: true,
+            profileSorting: "ByLastPlayed".to_owned(),
+            crashAssistance: false,
+            enableAnalytics: false,
+            soundOn: Some(false),
+        }
+    }
+}
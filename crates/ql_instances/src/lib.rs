@@ -63,13 +63,17 @@
 pub mod auth;
 mod download;
 mod instance;
-mod json_profiles;
+pub mod json_profiles;
 mod launcher_update_detector;
+pub mod library_store;
 
 pub use download::{constants::OS_NAME, DownloadError};
+pub use instance::change_version::change_instance_version;
+pub use instance::clone::{clone_instance, CloneError, CloneOptions};
 pub use instance::create::create_instance;
 pub use instance::launch::launch;
-pub use instance::list_versions::list_versions;
+pub use instance::list_versions::{list_versions, VersionFilter};
+pub use instance::lwjgl::override_lwjgl_version;
 pub use instance::read_log::{read_logs, LogEvent, LogLine, ReadError};
 pub use launcher_update_detector::{
     check_for_launcher_updates, install_launcher_update, UpdateCheckInfo, UpdateError,
@@ -0,0 +1,400 @@
+//! A shared store of Java libraries, so that instances
+//! of the same (or similar) versions don't each keep
+//! their own copy of LWJGL, Netty, Guava and so on.
+//!
+//! # Layout
+//! ```txt
+//! QuantumLauncher/libraries/
+//!     org/lwjgl/lwjgl/3.3.3/lwjgl-3.3.3.jar   (maven layout)
+//! QuantumLauncher/instances/<name>/libraries/
+//!     org/lwjgl/lwjgl/3.3.3/lwjgl-3.3.3.jar   (hard link to the above)
+//!     natives/                                (per-instance, not shared)
+//! ```
+//!
+//! Instances keep their `libraries` folder (hard linked
+//! to the store), so the classpath doesn't change.
+//! The game jar is shared the same way (see [`client_jar_path`]),
+//! linked to `.minecraft/versions/<id>/<id>.jar`.
+//! Files only enter the store after their sha1 is checked
+//! against the version JSON. If hard links aren't
+//! supported, the files are copied instead.
+//!
+//! Store files that aren't used by any instance anymore
+//! can be removed with [`clean_library_store`].
+//! [`STORE_LOCK`] keeps that from deleting a file that's
+//! been written to the store but not linked to an instance yet.
+
+use std::{
+    collections::HashSet,
+    path::{Path, PathBuf},
+    sync::atomic::{AtomicUsize, Ordering},
+};
+
+use ql_core::{
+    err, file_utils, info,
+    json::{version::LibraryDownloadArtifact, VersionDetails},
+    pt, IntoIoError, IoError, LAUNCHER_DIR,
+};
+use tokio::sync::RwLock;
+use walkdir::WalkDir;
+
+use crate::download::DownloadError;
+
+/// Created in an instance dir once its libraries use the store
+/// (when creating it, or after moving its old libraries over).
+const MIGRATED_MARKER: &str = ".library_store";
+
+/// Held for reading while adding files to the store and linking
+/// them to instances, and for writing while cleaning up the store.
+static STORE_LOCK: RwLock<()> = RwLock::const_new(());
+
+#[must_use]
+pub fn store_dir() -> PathBuf {
+    LAUNCHER_DIR.join("libraries")
+}
+
+/// Where the game jar of a version is kept
+/// in the store (relative to it).
+pub(crate) fn client_jar_path(version_id: &str) -> String {
+    format!("com/mojang/minecraft/{version_id}/minecraft-{version_id}-client.jar")
+}
+
+/// Gets a library from the store (downloading it if missing
+/// or corrupted), and links it to `dest`.
+///
+/// Returns the contents of the library
+/// (used for extracting natives).
+pub(crate) async fn get_library(
+    artifact: &LibraryDownloadArtifact,
+    dest: &Path,
+) -> Result<Vec<u8>, DownloadError> {
+    let relative_path = artifact.get_path();
+    let store_path = store_dir().join(&relative_path);
+    let _guard = STORE_LOCK.read().await;
+
+    if let Ok(bytes) = tokio::fs::read(&store_path).await {
+        if is_valid(artifact, &bytes) {
+            link_to(&store_path, dest).await?;
+            return Ok(bytes);
+        }
+        err!("Library in store is corrupted, redownloading: {relative_path}");
+    }
+
+    let bytes = file_utils::download_file_to_bytes(&artifact.url, false).await?;
+    if is_valid(artifact, &bytes) {
+        write_atomic(&store_path, &bytes).await?;
+        link_to(&store_path, dest).await?;
+    } else {
+        // Don't let unverified files into the store,
+        // they would be shared with other instances.
+        err!("Library has wrong sha1, not sharing it: {relative_path}");
+        remove_if_exists(dest).await?;
+        tokio::fs::write(dest, &bytes).await.path(dest)?;
+    }
+    Ok(bytes)
+}
+
+/// Moves the libraries of an instance created before
+/// the store existed into the store. Runs only once per instance.
+///
+/// Libraries that don't match the sha1 in the version JSON
+/// are left alone.
+pub(crate) async fn migrate_instance(
+    instance_dir: &Path,
+    version_json: &VersionDetails,
+) -> Result<(), IoError> {
+    let marker = instance_dir.join(MIGRATED_MARKER);
+    if marker.exists() {
+        return Ok(());
+    }
+    info!("Moving instance libraries to shared store");
+    let _guard = STORE_LOCK.read().await;
+
+    let libraries_dir = instance_dir.join("libraries");
+    let mut moved = 0;
+    for artifact in version_json
+        .libraries
+        .iter()
+        .filter_map(|n| n.downloads.as_ref()?.artifact.as_ref())
+    {
+        let relative_path = artifact.get_path();
+        let path = libraries_dir.join(&relative_path);
+        let Ok(bytes) = tokio::fs::read(&path).await else {
+            continue;
+        };
+        if !is_valid(artifact, &bytes) {
+            continue;
+        }
+
+        let store_path = store_dir().join(&relative_path);
+        let store_is_valid = tokio::fs::read(&store_path)
+            .await
+            .is_ok_and(|n| is_valid(artifact, &n));
+        if !store_is_valid {
+            write_atomic(&store_path, &bytes).await?;
+        }
+        link_to(&store_path, &path).await?;
+        moved += 1;
+    }
+
+    mark_migrated(instance_dir).await?;
+    pt!("Moved {moved} libraries");
+    Ok(())
+}
+
+/// Marks a new instance (whose libraries were
+/// downloaded through the store) as already migrated,
+/// so [`migrate_instance`] doesn't have to check them all.
+pub(crate) async fn mark_migrated(instance_dir: &Path) -> Result<(), IoError> {
+    let marker = instance_dir.join(MIGRATED_MARKER);
+    tokio::fs::write(&marker, "").await.path(marker)
+}
+
+/// Deletes libraries from the shared store that
+/// aren't used by any instance, returning
+/// the number of bytes freed.
+///
+/// # Errors
+/// If the instances or store dirs couldn't be read,
+/// or a file couldn't be deleted.
+pub async fn clean_library_store() -> Result<u64, IoError> {
+    clean_store(&LAUNCHER_DIR).await
+}
+
+async fn clean_store(launcher_dir: &Path) -> Result<u64, IoError> {
+    let store = launcher_dir.join("libraries");
+    if !store.is_dir() {
+        return Ok(0);
+    }
+    info!("Cleaning up shared library store");
+    // Waits for downloads in progress to be linked to their instances
+    let _guard = STORE_LOCK.write().await;
+
+    let instances_dir = launcher_dir.join("instances");
+    let mut used: HashSet<PathBuf> = HashSet::new();
+    if instances_dir.is_dir() {
+        let mut instances = tokio::fs::read_dir(&instances_dir)
+            .await
+            .dir(&instances_dir)?;
+        while let Some(instance) = instances.next_entry().await.dir(&instances_dir)? {
+            let libraries_dir = instance.path().join("libraries");
+            used.extend(
+                list_files(&libraries_dir)?
+                    .into_iter()
+                    .filter(|n| !is_native(n)),
+            );
+            used.extend(get_client_jars(&instance.path()).await?);
+        }
+    }
+
+    let mut freed = 0;
+    for file in list_files(&store)? {
+        if used.contains(&file) {
+            continue;
+        }
+        let path = store.join(&file);
+        freed += tokio::fs::metadata(&path).await.path(&path)?.len();
+        tokio::fs::remove_file(&path).await.path(&path)?;
+    }
+    remove_empty_dirs(&store);
+
+    pt!("Freed {} MB", freed / (1024 * 1024));
+    Ok(freed)
+}
+
+/// The store paths of the game jars in
+/// an instance's `.minecraft/versions` folder.
+async fn get_client_jars(instance_dir: &Path) -> Result<Vec<PathBuf>, IoError> {
+    let versions_dir = instance_dir.join(".minecraft").join("versions");
+    if !versions_dir.is_dir() {
+        return Ok(Vec::new());
+    }
+    let mut jars = Vec::new();
+    let mut versions = tokio::fs::read_dir(&versions_dir)
+        .await
+        .dir(&versions_dir)?;
+    while let Some(version) = versions.next_entry().await.dir(&versions_dir)? {
+        let id = version.file_name().to_string_lossy().into_owned();
+        if version.path().join(format!("{id}.jar")).is_file() {
+            jars.push(PathBuf::from(client_jar_path(&id)));
+        }
+    }
+    Ok(jars)
+}
+
+/// Gives the instance at `dst` the libraries of the one
+/// at `src` (used when cloning). Shared libraries are
+/// hard linked, so the clone uses the store too instead
+/// of keeping its own copies.
+///
+/// Natives are extracted in place for every
+/// launch, so they're copied instead.
+pub(crate) async fn link_libraries(src: &Path, dst: &Path) -> Result<(), IoError> {
+    for file in list_files(src)? {
+        let from = src.join(&file);
+        let to = dst.join(&file);
+        if let Some(parent) = to.parent() {
+            tokio::fs::create_dir_all(parent).await.path(parent)?;
+        }
+        if is_native(&file) {
+            tokio::fs::copy(&from, &to).await.path(&from)?;
+        } else {
+            file_utils::hard_link_or_copy(&from, &to).await?;
+        }
+    }
+    Ok(())
+}
+
+/// Whether a path (relative to the `libraries` dir)
+/// is a per-instance native library.
+fn is_native(path: &Path) -> bool {
+    path.components()
+        .next()
+        .is_some_and(|n| n.as_os_str() == "natives")
+}
+
+fn is_valid(artifact: &LibraryDownloadArtifact, bytes: &[u8]) -> bool {
+    // Some (modified) version JSONs don't have hashes,
+    // in that case the path is all we have.
+    artifact.sha1.is_empty() || file_utils::get_sha1(bytes) == artifact.sha1
+}
+
+/// Replaces `dest` with a hard link (or copy) of `store_path`.
+async fn link_to(store_path: &Path, dest: &Path) -> Result<(), IoError> {
+    if let Some(parent) = dest.parent() {
+        tokio::fs::create_dir_all(parent).await.path(parent)?;
+    }
+    // Writing to an existing hard link would modify
+    // the file for every instance, so always replace it.
+    remove_if_exists(dest).await?;
+    file_utils::hard_link_or_copy(store_path, dest).await
+}
+
+/// Writes to a temporary file and renames it, so that
+/// instances linked to an old (corrupted) version
+/// of the file don't see a half-written one.
+async fn write_atomic(path: &Path, bytes: &[u8]) -> Result<(), IoError> {
+    static COUNTER: AtomicUsize = AtomicUsize::new(0);
+
+    if let Some(parent) = path.parent() {
+        tokio::fs::create_dir_all(parent).await.path(parent)?;
+    }
+    let mut temp_path = path.as_os_str().to_owned();
+    temp_path.push(format!(
+        ".{}.{}.part",
+        std::process::id(),
+        COUNTER.fetch_add(1, Ordering::Relaxed)
+    ));
+    let temp_path = PathBuf::from(temp_path);
+
+    tokio::fs::write(&temp_path, bytes).await.path(&temp_path)?;
+    tokio::fs::rename(&temp_path, path).await.path(path)
+}
+
+async fn remove_if_exists(path: &Path) -> Result<(), IoError> {
+    if tokio::fs::symlink_metadata(path).await.is_ok() {
+        tokio::fs::remove_file(path).await.path(path)?;
+    }
+    Ok(())
+}
+
+/// Lists all files in `dir`, relative to it.
+fn list_files(dir: &Path) -> Result<Vec<PathBuf>, IoError> {
+    if !dir.is_dir() {
+        return Ok(Vec::new());
+    }
+    let mut files = Vec::new();
+    for entry in WalkDir::new(dir) {
+        let entry = entry.map_err(|n| IoError::ReadDir {
+            error: n.to_string(),
+            parent: dir.to_owned(),
+        })?;
+        if entry.file_type().is_file() {
+            if let Ok(path) = entry.path().strip_prefix(dir) {
+                files.push(path.to_owned());
+            }
+        }
+    }
+    Ok(files)
+}
+
+fn remove_empty_dirs(dir: &Path) {
+    // Deepest dirs first, so parents are empty by the time we reach them
+    for entry in WalkDir::new(dir).min_depth(1).contents_first(true) {
+        let Ok(entry) = entry else {
+            continue;
+        };
+        if entry.file_type().is_dir() {
+            // Fails if not empty, which is fine
+            _ = std::fs::remove_dir(entry.path());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use super::{clean_store, client_jar_path, link_to};
+
+    const LIBRARY: &str = "org/lwjgl/lwjgl/3.3.3/lwjgl-3.3.3.jar";
+
+    /// What creating an instance of 1.20.1 leaves behind,
+    /// with the files already in the store.
+    async fn create(launcher_dir: &Path, name: &str) {
+        let store = launcher_dir.join("libraries");
+        let instance = launcher_dir.join("instances").join(name);
+        link_to(
+            &store.join(client_jar_path("1.20.1")),
+            &instance.join(".minecraft/versions/1.20.1/1.20.1.jar"),
+        )
+        .await
+        .unwrap();
+        link_to(
+            &store.join(LIBRARY),
+            &instance.join("libraries").join(LIBRARY),
+        )
+        .await
+        .unwrap();
+    }
+
+    #[tokio::test]
+    async fn create_delete_create() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let launcher_dir = dir.path();
+        let store = launcher_dir.join("libraries");
+        for file in [client_jar_path("1.20.1").as_str(), LIBRARY] {
+            let path = store.join(file);
+            std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+            std::fs::write(&path, file).unwrap();
+        }
+
+        create(launcher_dir, "First").await;
+        std::fs::remove_dir_all(launcher_dir.join("instances/First")).unwrap();
+        // Nothing uses the store now
+        assert!(clean_store(launcher_dir).await.unwrap() > 0);
+        assert!(!store.join(client_jar_path("1.20.1")).exists());
+
+        // Back in the store after downloading again
+        for file in [client_jar_path("1.20.1").as_str(), LIBRARY] {
+            let path = store.join(file);
+            std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+            std::fs::write(&path, file).unwrap();
+        }
+        create(launcher_dir, "Second").await;
+        create(launcher_dir, "Third").await;
+        std::fs::remove_dir_all(launcher_dir.join("instances/Second")).unwrap();
+
+        // Still used by "Third", including the game jar
+        assert_eq!(clean_store(launcher_dir).await.unwrap(), 0);
+        assert!(store.join(client_jar_path("1.20.1")).is_file());
+        assert!(store.join(LIBRARY).is_file());
+
+        create(launcher_dir, "Fourth").await;
+        let jar = launcher_dir.join("instances/Fourth/.minecraft/versions/1.20.1/1.20.1.jar");
+        assert_eq!(
+            std::fs::read_to_string(jar).unwrap(),
+            client_jar_path("1.20.1")
+        );
+    }
+}
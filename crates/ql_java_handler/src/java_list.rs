@@ -1,7 +1,7 @@
 use std::fmt::Display;
 
-use crate::file_utils;
 use ql_core::json::version::JavaVersionJson;
+use ql_core::meta_cache;
 use serde::Deserialize;
 
 use crate::JsonDownloadError;
@@ -128,7 +128,7 @@ pub struct JavaListJson {
 impl JavaListJson {
     pub async fn download() -> Result<Self, JsonDownloadError> {
         pub const JAVA_LIST_URL: &str = "https://launchermeta.mojang.com/v1/products/java-runtime/2ec0cc96c44e5a76b9c8b7c39df7210883d12871/all.json";
-        file_utils::download_file_to_json(JAVA_LIST_URL, false).await
+        meta_cache::download_json(JAVA_LIST_URL, false).await
     }
 
     pub fn get_url(&self, version: JavaVersion) -> Option<String> {
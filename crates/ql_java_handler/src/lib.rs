@@ -9,8 +9,8 @@ use thiserror::Error;
 use java_files::{JavaFile, JavaFilesJson};
 use java_list::JavaListJson;
 use ql_core::{
-    do_jobs, err, file_utils, info, pt, GenericProgress, IntoIoError, IoError, JsonDownloadError,
-    JsonError, RequestError, LAUNCHER_DIR,
+    do_jobs, download, err, file_utils, info, pt, DownloadFileError, GenericProgress, IntoIoError,
+    IoError, JsonDownloadError, JsonError, RequestError, LAUNCHER_DIR,
 };
 
 mod compression;
@@ -97,6 +97,9 @@ pub async fn get_java_binary(
     }
 
     if !java_dir.exists() || is_incomplete_install {
+        if download::is_offline() {
+            return Err(JavaInstallError::Offline(version));
+        }
         info!("Installing Java: {version}");
         install_java(version, java_install_progress_sender).await?;
     }
@@ -351,6 +354,8 @@ pub enum JavaInstallError {
 
     #[error("on your platform, only Java 8 (Minecraft 1.16.5 and below) is supported!\n")]
     UnsupportedOnlyJava8,
+    #[error("{JAVA_INSTALL_ERR_PREFIX}{0} isn't installed, and can't be downloaded in offline mode.\nTurn off offline mode in launcher settings, or set a custom Java path in instance settings")]
+    Offline(JavaVersion),
 
     #[error("{JAVA_INSTALL_ERR_PREFIX}zip extract error:\n{0}")]
     ZipExtract(#[from] ZipExtractError),
@@ -360,6 +365,15 @@ pub enum JavaInstallError {
     UnknownExtension(String),
 }
 
+impl From<DownloadFileError> for JavaInstallError {
+    fn from(value: DownloadFileError) -> Self {
+        match value {
+            DownloadFileError::Request(err) => Self::Request(err),
+            DownloadFileError::Io(err) => Self::Io(err),
+        }
+    }
+}
+
 pub async fn delete_java_installs() {
     info!("Clearing Java installs");
     let java_installs = LAUNCHER_DIR.join("java_installs");
@@ -1,6 +1,6 @@
 use std::{io::Cursor, path::Path, sync::mpsc::Sender};
 
-use ql_core::{file_utils, GenericProgress};
+use ql_core::{file_utils, GenericProgress, IntoIoError};
 
 use crate::{extract_tar_gz, send_progress, JavaInstallError, JavaVersion};
 
@@ -55,7 +55,11 @@ pub async fn install_third_party_java(
             has_finished: false,
         },
     );
-    let file_bytes = file_utils::download_file_to_bytes(url, false).await?;
+    // Downloaded to a file (not memory) so that
+    // it can be resumed if the connection drops.
+    let archive_path = install_dir.with_extension("archive");
+    file_utils::download_file_to_path(url, false, &archive_path).await?;
+    let file_bytes = tokio::fs::read(&archive_path).await.path(&archive_path)?;
     send_progress(
         java_install_progress_sender,
         GenericProgress {
@@ -72,5 +76,8 @@ pub async fn install_third_party_java(
     } else {
         return Err(JavaInstallError::UnknownExtension(url.to_owned()));
     }
+    tokio::fs::remove_file(&archive_path)
+        .await
+        .path(&archive_path)?;
     Ok(())
 }
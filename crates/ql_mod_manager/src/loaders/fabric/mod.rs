@@ -3,7 +3,8 @@ use std::{path::Path, sync::mpsc::Sender};
 use ql_core::{
     file_utils, info,
     json::{FabricJSON, VersionDetails},
-    GenericProgress, InstanceSelection, IntoIoError, IntoJsonError, RequestError, LAUNCHER_DIR,
+    meta_cache, GenericProgress, InstanceSelection, IntoIoError, IntoJsonError, RequestError,
+    LAUNCHER_DIR,
 };
 use serde::Deserialize;
 use version_compare::compare_versions;
@@ -37,9 +38,15 @@ pub async fn get_list_of_versions(
         is_quilt: bool,
     ) -> Result<Vec<FabricVersionListItem>, FabricInstallError> {
         let version_json = VersionDetails::load(instance_name).await?;
-        let version_list =
-            download_file_to_string(&format!("/versions/loader/{}", version_json.id), is_quilt)
-                .await?;
+        let version_list = meta_cache::download_string(
+            &format!(
+                "{}/versions/loader/{}",
+                if is_quilt { QUILT_URL } else { FABRIC_URL },
+                version_json.id
+            ),
+            false,
+        )
+        .await?;
         let versions = serde_json::from_str(&version_list).json(version_list)?;
         Ok(versions)
     }
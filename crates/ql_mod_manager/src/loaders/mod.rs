@@ -80,8 +80,8 @@ pub async fn install_specified_loader(
                 });
             }
 
-            // TODO: Specified version
-            neoforge::install(instance, Some(send), None)
+            // TODO: Java install progress
+            neoforge::install(specified_version, instance, Some(send), None)
                 .await
                 .strerr()?;
         }
@@ -24,12 +24,17 @@ struct NeoforgeVersions {
 }
 
 pub async fn install(
+    neoforge_version: Option<String>,
     instance: InstanceSelection,
     f_progress: Option<Sender<ForgeInstallProgress>>,
     j_progress: Option<Sender<GenericProgress>>,
 ) -> Result<(), ForgeInstallError> {
     info!("Installing NeoForge");
-    let (neoforge_version, json) = get_neoforge_version(f_progress.as_ref(), &instance).await?;
+    let (neoforge_version, json) = if let Some(version) = neoforge_version {
+        (version, VersionDetails::load(&instance).await?)
+    } else {
+        get_neoforge_version(f_progress.as_ref(), &instance).await?
+    };
 
     send_progress(
         f_progress.as_ref(),
@@ -217,8 +222,7 @@ async fn get_neoforge_version(
     let neoforge_version = versions
         .versions
         .iter()
-        .filter(|n| n.starts_with(&start_pattern))
-        .next_back()
+        .rfind(|n| n.starts_with(&start_pattern))
         .ok_or(ForgeInstallError::NoForgeVersionFound)?
         .clone();
 
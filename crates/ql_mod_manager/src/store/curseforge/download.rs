@@ -10,8 +10,9 @@ use ql_core::{
 
 use crate::store::{
     curseforge::{get_query_type, ModQuery},
-    get_loader, get_mods_resourcepacks_shaderpacks_dir, install_modpack, CurseforgeNotAllowed,
-    ModConfig, ModError, ModFile, ModIndex, QueryType, SOURCE_ID_CURSEFORGE,
+    get_loader, get_mods_resourcepacks_shaderpacks_dir, install_modpack,
+    modpack::set_icon_from_url,
+    CurseforgeNotAllowed, ModConfig, ModError, ModFile, ModIndex, QueryType, SOURCE_ID_CURSEFORGE,
 };
 
 use super::Mod;
@@ -134,6 +135,11 @@ impl<'a> ModDownloader<'a> {
                 } else {
                     err!("Invalid modpack downloaded from curseforge! Corrupted?");
                 }
+                set_icon_from_url(
+                    &self.instance,
+                    response.logo.as_ref().map(|n| n.url.as_str()),
+                )
+                .await;
                 return Ok(());
             }
         };
@@ -190,6 +196,7 @@ impl<'a> ModDownloader<'a> {
                     icon_url: response.logo.clone().map(|n| n.url),
                     project_source: SOURCE_ID_CURSEFORGE.to_owned(),
                     project_id: id_index_str.clone(),
+                    curseforge_file_id: Some(file_query.data.id),
                     files: vec![ModFile {
                         url,
                         filename: file_query.data.fileName,
@@ -12,7 +12,7 @@ use serde::Deserialize;
 
 use crate::{rate_limiter::RATE_LIMITER, store::SearchMod};
 
-use super::{Backend, CurseforgeNotAllowed, ModError, QueryType, SearchResult};
+use super::{Backend, CurseforgeNotAllowed, ModError, ModVersionInfo, QueryType, SearchResult};
 use categories::get_categories;
 
 mod categories;
@@ -94,9 +94,29 @@ impl CurseforgeFileQuery {
     }
 }
 
+#[derive(Deserialize, Clone, Debug)]
+struct CurseforgeFileList {
+    data: Vec<CurseforgeFile>,
+}
+
+impl CurseforgeFileList {
+    async fn load(mod_id: &str, version: &str, loader: Option<&str>) -> Result<Self, ModError> {
+        let mut params = HashMap::from([
+            ("gameVersion", version.to_owned()),
+            ("pageSize", "50".to_owned()),
+        ]);
+        if let Some(loader) = loader {
+            params.insert("modLoaderType", loader.to_owned());
+        }
+        let response = send_request(&format!("mods/{mod_id}/files"), &params).await?;
+        Ok(serde_json::from_str(&response).json(response)?)
+    }
+}
+
 #[derive(Deserialize, Clone, Debug)]
 #[allow(non_snake_case)]
 pub struct CurseforgeFile {
+    pub id: i32,
     pub fileName: String,
     pub downloadUrl: Option<String>,
     pub gameVersions: Vec<String>,
@@ -255,6 +275,62 @@ impl Backend for CurseforgeBackend {
         Ok((download_version_time, response.data.name))
     }
 
+    async fn get_versions_since(
+        id: &str,
+        version: &str,
+        loader: Option<ql_core::Loader>,
+        since: DateTime<chrono::FixedOffset>,
+    ) -> Result<Vec<ModVersionInfo>, ModError> {
+        // Each changelog is a separate request,
+        // so don't go overboard for very outdated mods.
+        const MAX_CHANGELOGS: usize = 10;
+
+        #[derive(Deserialize)]
+        struct Changelog {
+            data: String,
+        }
+
+        let _lock = RATE_LIMITER.lock().await;
+        let files =
+            CurseforgeFileList::load(id, version, loader.map(|n| n.to_curseforge())).await?;
+
+        let mut files = files
+            .data
+            .into_iter()
+            .map(|file| Ok((DateTime::parse_from_rfc3339(&file.fileDate)?, file)))
+            .collect::<Result<Vec<_>, ModError>>()?;
+        files.retain(|(date, _)| *date > since);
+        files.sort_by(|(a, _), (b, _)| b.cmp(a));
+
+        let mut versions = Vec::new();
+        for (i, (release_date, file)) in files.into_iter().enumerate() {
+            let changelog = if i < MAX_CHANGELOGS {
+                RATE_LIMITER.lock().await;
+                let changelog = send_request(
+                    &format!("mods/{id}/files/{}/changelog", file.id),
+                    &HashMap::new(),
+                )
+                .await?;
+                let changelog: Changelog = serde_json::from_str(&changelog).json(changelog)?;
+                changelog.data
+            } else {
+                String::new()
+            };
+
+            versions.push(ModVersionInfo {
+                name: file.displayName,
+                release_date,
+                changelog,
+                dependencies: file
+                    .dependencies
+                    .into_iter()
+                    .map(|n| format!("CF:{}", n.modId))
+                    .collect(),
+            });
+        }
+        Ok(versions)
+    }
+
     async fn download(
         id: &str,
         instance: &ql_core::InstanceSelection,
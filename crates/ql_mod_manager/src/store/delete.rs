@@ -1,4 +1,4 @@
-use crate::store::{ModError, ModIndex};
+use crate::store::{create_snapshot, ModError, ModIndex};
 use ql_core::{err, info, pt, InstanceSelection, IoError, ModId};
 use std::{
     collections::{HashMap, HashSet},
@@ -12,7 +12,16 @@ pub async fn delete_mods(
     if ids.is_empty() {
         return Ok(ids);
     }
+    create_snapshot(&instance, "Delete mods").await?;
+    delete_mods_no_snapshot(ids, instance).await
+}
 
+/// [`delete_mods`] but without taking a snapshot,
+/// for use by operations that take their own.
+pub(crate) async fn delete_mods_no_snapshot(
+    ids: Vec<ModId>,
+    instance: InstanceSelection,
+) -> Result<Vec<ModId>, ModError> {
     info!("Deleting mods:");
     let mut index = ModIndex::get(&instance).await?;
 
@@ -1,4 +1,4 @@
-use ql_core::file_utils;
+use ql_core::{file_utils, InstanceSelection, IntoIoError, IntoStringError};
 
 #[derive(Clone)]
 pub struct ImageResult {
@@ -85,3 +85,33 @@ pub async fn download_image(url: String, icon: bool) -> Result<ImageResult, Stri
         is_svg: false,
     })
 }
+
+/// Size (in pixels) that instance icons are scaled down to.
+const INSTANCE_ICON_SIZE: u32 = 64;
+
+/// Sets the custom icon of an instance
+/// (shown in the sidebar) from an image file.
+///
+/// The image is converted to PNG and scaled down.
+///
+/// # Errors
+/// - If the image couldn't be decoded
+/// - If the icon couldn't be written
+pub async fn set_instance_icon(instance: &InstanceSelection, image: &[u8]) -> Result<(), String> {
+    let img = image::io::Reader::new(std::io::Cursor::new(image))
+        .with_guessed_format()
+        .strerr()?
+        .decode()
+        .strerr()?;
+    let img = img.thumbnail(INSTANCE_ICON_SIZE, INSTANCE_ICON_SIZE);
+
+    let mut buffer = Vec::new();
+    img.write_to(
+        &mut std::io::Cursor::new(&mut buffer),
+        image::ImageFormat::Png,
+    )
+    .strerr()?;
+
+    let path = instance.get_icon_path();
+    tokio::fs::write(&path, buffer).await.path(&path).strerr()
+}
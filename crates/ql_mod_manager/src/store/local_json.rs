@@ -18,6 +18,11 @@ pub struct ModConfig {
     /// Eg: "modrinth"
     pub project_source: String,
     pub project_id: String,
+    /// ID of the installed file, for mods from Curseforge
+    /// (used when exporting Curseforge modpacks).
+    /// Missing for mods installed by older versions.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub curseforge_file_id: Option<i32>,
     pub files: Vec<ModFile>,
     pub supported_versions: Vec<String>,
     pub dependencies: HashSet<String>,
@@ -16,6 +16,7 @@ mod local_json;
 mod modpack;
 mod modrinth;
 mod recommended;
+mod snapshot;
 mod toggle;
 mod update;
 
@@ -23,13 +24,18 @@ pub use add_file::add_files;
 pub use curseforge::CurseforgeBackend;
 pub use delete::delete_mods;
 pub use error::ModError;
-pub use image::{download_image, ImageResult};
+pub use image::{download_image, set_instance_icon, ImageResult};
 pub use local_json::{ModConfig, ModFile, ModIndex};
 pub use modpack::{install_modpack, PackError};
 pub use modrinth::ModrinthBackend;
 pub use recommended::{RecommendedMod, RECOMMENDED_MODS};
+pub use snapshot::{
+    create_snapshot, list_snapshots, restore_snapshot, undo_last_change, ModSnapshot, SnapshotFile,
+};
 pub use toggle::{flip_filename, toggle_mods, toggle_mods_local};
-pub use update::{apply_updates, check_for_updates};
+pub use update::{
+    apply_updates, check_for_updates, update_for_game_version, GameVersionModReport, ModUpdate,
+};
 
 pub const SOURCE_ID_MODRINTH: &str = "modrinth";
 pub const SOURCE_ID_CURSEFORGE: &str = "curseforge";
@@ -51,6 +57,17 @@ pub trait Backend {
         version: &str,
         loader: Option<Loader>,
     ) -> Result<(DateTime<chrono::FixedOffset>, String), ModError>;
+    /// Gets all versions of a mod (compatible with the given
+    /// game version and loader) released after `since`,
+    /// sorted newest first.
+    ///
+    /// Used for showing changelogs before updating mods.
+    async fn get_versions_since(
+        id: &str,
+        version: &str,
+        loader: Option<Loader>,
+        since: DateTime<chrono::FixedOffset>,
+    ) -> Result<Vec<ModVersionInfo>, ModError>;
 
     async fn download(
         id: &str,
@@ -91,6 +108,7 @@ pub async fn download_mod(
     instance: &InstanceSelection,
     sender: Option<Sender<GenericProgress>>,
 ) -> Result<HashSet<CurseforgeNotAllowed>, ModError> {
+    create_snapshot(instance, "Install mod").await?;
     match id {
         ModId::Modrinth(n) => ModrinthBackend::download(n, instance, sender).await,
         ModId::Curseforge(n) => CurseforgeBackend::download(n, instance, sender).await,
@@ -101,6 +119,17 @@ pub async fn download_mods_bulk(
     ids: Vec<ModId>,
     instance: InstanceSelection,
     sender: Option<Sender<GenericProgress>>,
+) -> Result<HashSet<CurseforgeNotAllowed>, ModError> {
+    create_snapshot(&instance, "Install mods").await?;
+    download_mods_bulk_no_snapshot(ids, instance, sender).await
+}
+
+/// [`download_mods_bulk`] but without taking a [`ModSnapshot`],
+/// for use by operations that take their own.
+pub(crate) async fn download_mods_bulk_no_snapshot(
+    ids: Vec<ModId>,
+    instance: InstanceSelection,
+    sender: Option<Sender<GenericProgress>>,
 ) -> Result<HashSet<CurseforgeNotAllowed>, ModError> {
     let (modrinth, other): (Vec<ModId>, Vec<ModId>) = ids.into_iter().partition(|n| match n {
         ModId::Modrinth(_) => true,
@@ -145,6 +174,33 @@ pub async fn get_latest_version_date(
     })
 }
 
+pub async fn get_versions_since(
+    loader: Option<Loader>,
+    mod_id: &ModId,
+    version: &str,
+    since: DateTime<chrono::FixedOffset>,
+) -> Result<Vec<ModVersionInfo>, ModError> {
+    match mod_id {
+        ModId::Modrinth(n) => ModrinthBackend::get_versions_since(n, version, loader, since).await,
+        ModId::Curseforge(n) => {
+            CurseforgeBackend::get_versions_since(n, version, loader, since).await
+        }
+    }
+}
+
+/// A single released version of a mod,
+/// as seen while checking for updates.
+#[derive(Debug, Clone)]
+pub struct ModVersionInfo {
+    pub name: String,
+    pub release_date: DateTime<chrono::FixedOffset>,
+    /// Markdown (Modrinth) or HTML (Curseforge)
+    pub changelog: String,
+    /// Dependencies in the same format as
+    /// [`ModConfig::dependencies`] (ie. `CF:` prefix for curseforge).
+    pub dependencies: HashSet<String>,
+}
+
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum QueryType {
     Mods,
@@ -16,7 +16,7 @@ mod modrinth;
 
 pub use error::PackError;
 
-use super::CurseforgeNotAllowed;
+use super::{create_snapshot, download_image, set_instance_icon, CurseforgeNotAllowed};
 
 /// Installs a modpack file.
 ///
@@ -40,6 +40,9 @@ use super::CurseforgeNotAllowed;
 ///   if none present, or if it's a modrinth pack.
 /// - `Ok(None)` - This isn't a modpack.
 /// - `Err` - Any error that occured.
+///
+/// If the pack has an `icon.png` and the instance
+/// doesn't have an icon yet, it's used as the instance icon.
 pub async fn install_modpack(
     file: Vec<u8>,
     instance: InstanceSelection,
@@ -48,6 +51,7 @@ pub async fn install_modpack(
     let mut zip = zip::ZipArchive::new(Cursor::new(file))?;
 
     info!("Installing modpack");
+    create_snapshot(&instance, "Install modpack").await?;
 
     let index_json_modrinth: Option<modrinth::PackIndex> =
         read_json_from_zip(&mut zip, "modrinth.index.json")?;
@@ -87,6 +91,17 @@ pub async fn install_modpack(
         if name == "modrinth.index.json" || name == "manifest.json" || name == "modlist.html" {
             continue;
         }
+        if name == "icon.png" {
+            let mut buf = Vec::new();
+            file.read_to_end(&mut buf)
+                .map_err(|n| PackError::ZipIoError(n, name.clone()))?;
+            if !instance.get_icon_path().exists() {
+                if let Err(err) = set_instance_icon(&instance, &buf).await {
+                    err!("Couldn't set modpack icon: {err}");
+                }
+            }
+            continue;
+        }
 
         if let Some(sender) = sender {
             _ = sender.send(GenericProgress {
@@ -130,6 +145,28 @@ pub async fn install_modpack(
     Ok(Some(not_allowed))
 }
 
+/// Sets the instance icon to the icon of a modpack
+/// from the store, unless the instance already has one.
+///
+/// Errors are only logged, it's not worth
+/// failing the install over.
+pub(crate) async fn set_icon_from_url(instance: &InstanceSelection, url: Option<&str>) {
+    let Some(url) = url.filter(|n| !n.is_empty()) else {
+        return;
+    };
+    if instance.get_icon_path().exists() {
+        return;
+    }
+    let result = match download_image(url.to_owned(), false).await {
+        Ok(image) if !image.is_svg => set_instance_icon(instance, &image.image).await,
+        Ok(_) => Ok(()),
+        Err(err) => Err(err),
+    };
+    if let Err(err) = result {
+        err!("Couldn't set modpack icon: {err}");
+    }
+}
+
 fn read_json_from_zip<T: serde::de::DeserializeOwned>(
     zip: &mut zip::ZipArchive<Cursor<Vec<u8>>>,
     name: &str,
@@ -15,6 +15,7 @@ use ql_core::{
 use crate::store::{
     get_mods_resourcepacks_shaderpacks_dir, install_modpack,
     local_json::{ModConfig, ModIndex},
+    modpack::set_icon_from_url,
     modrinth::versions::ModVersion,
     ModError, QueryType, SOURCE_ID_MODRINTH,
 };
@@ -118,6 +119,9 @@ impl ModDownloader {
                     self.download_file(query_type, file).await?;
                 }
             }
+            if query_type == QueryType::ModPacks {
+                set_icon_from_url(&self.instance, project_info.icon_url.as_deref()).await;
+            }
 
             self.add_mod_to_index(
                 &project_info,
@@ -275,6 +279,7 @@ impl ModDownloader {
             description: project_info.description.clone(),
             icon_url: project_info.icon_url.clone(),
             project_id: project_info.id.clone(),
+            curseforge_file_id: None,
             files: download_version.files.clone(),
             supported_versions: download_version.game_versions.clone(),
             dependencies: dependency_list,
@@ -11,7 +11,9 @@ use crate::{
     store::{SearchMod, StoreBackendType},
 };
 
-use super::{Backend, CurseforgeNotAllowed, ModError, Query, QueryType, SearchResult};
+use super::{
+    Backend, CurseforgeNotAllowed, ModError, ModVersionInfo, Query, QueryType, SearchResult,
+};
 
 mod download;
 mod info;
@@ -20,6 +22,37 @@ mod versions;
 
 pub struct ModrinthBackend;
 
+/// Filters out the versions compatible with the
+/// given game version and loader, sorted oldest first.
+fn compatible_versions(
+    download_info: &[ModVersion],
+    version: &str,
+    loader: Option<Loader>,
+) -> Vec<ModVersion> {
+    let version = version.to_owned();
+
+    let mut download_versions: Vec<ModVersion> = download_info
+        .iter()
+        .filter(|v| v.game_versions.contains(&version))
+        .filter(|v| {
+            if let Some(loader) = &loader {
+                if v.loaders.first().is_none_or(|n| n == "minecraft") {
+                    true
+                } else {
+                    v.loaders.contains(&loader.to_modrinth_str().to_owned())
+                }
+            } else {
+                true
+            }
+        })
+        .cloned()
+        .collect();
+
+    // Sort by date published
+    download_versions.sort_by(version_sort);
+    download_versions
+}
+
 impl Backend for ModrinthBackend {
     async fn search(
         query: Query,
@@ -64,27 +97,7 @@ impl Backend for ModrinthBackend {
         loader: Option<Loader>,
     ) -> Result<(DateTime<chrono::FixedOffset>, String), ModError> {
         let download_info = ModVersion::download(id).await?;
-        let version = version.to_owned();
-
-        let mut download_versions: Vec<ModVersion> = download_info
-            .iter()
-            .filter(|v| v.game_versions.contains(&version))
-            .filter(|v| {
-                if let Some(loader) = &loader {
-                    if v.loaders.first().is_none_or(|n| n == "minecraft") {
-                        true
-                    } else {
-                        v.loaders.contains(&loader.to_modrinth_str().to_owned())
-                    }
-                } else {
-                    true
-                }
-            })
-            .cloned()
-            .collect();
-
-        // Sort by date published
-        download_versions.sort_by(version_sort);
+        let download_versions = compatible_versions(&download_info, version, loader);
 
         let download_version =
             download_versions
@@ -102,6 +115,36 @@ impl Backend for ModrinthBackend {
         Ok((download_version_time, download_version.name))
     }
 
+    async fn get_versions_since(
+        id: &str,
+        version: &str,
+        loader: Option<Loader>,
+        since: DateTime<chrono::FixedOffset>,
+    ) -> Result<Vec<ModVersionInfo>, ModError> {
+        let download_info = ModVersion::download(id).await?;
+        let download_versions = compatible_versions(&download_info, version, loader);
+
+        let mut versions = Vec::new();
+        for v in download_versions.into_iter().rev() {
+            let release_date = DateTime::parse_from_rfc3339(&v.date_published)?;
+            if release_date <= since {
+                break;
+            }
+            versions.push(ModVersionInfo {
+                name: v.name,
+                release_date,
+                changelog: v.changelog.unwrap_or_default(),
+                dependencies: v
+                    .dependencies
+                    .into_iter()
+                    .filter(|n| n.dependency_type == "required")
+                    .filter_map(|n| n.project_id)
+                    .collect(),
+            });
+        }
+        Ok(versions)
+    }
+
     async fn download(
         id: &str,
         instance: &InstanceSelection,
@@ -15,7 +15,7 @@ pub struct ModVersion {
     // pub featured: bool,
     pub name: String,
     pub version_number: String,
-    // pub changelog: String,
+    pub changelog: Option<String>,
     // pub changelog_url: Option<String>,
     pub date_published: String,
     // pub downloads: usize,
@@ -0,0 +1,356 @@
+//! Automatic, lightweight snapshots of the `mods`,
+//! `resourcepacks` and `shaderpacks` folders (everything
+//! the mod index tracks) and `mod_index.json`, taken before every mutating
+//! store operation (installing, deleting, updating mods
+//! and installing modpacks).
+//!
+//! # Layout
+//! ```txt
+//! <instance>/mod_snapshots/
+//!     objects/<sha1>          (deduplicated mod files)
+//!     <snapshot id>.json      (see `ModSnapshot`)
+//! ```
+//!
+//! Each mod file is only stored once no matter how many
+//! snapshots refer to it, and hard links are used where possible,
+//! so taking a snapshot is cheap.
+
+use std::{
+    collections::{BTreeMap, HashSet},
+    path::PathBuf,
+    time::UNIX_EPOCH,
+};
+
+use ql_core::{file_utils, info, pt, InstanceSelection, IntoIoError, IntoJsonError};
+use serde::{Deserialize, Serialize};
+
+use super::{ModError, ModIndex};
+
+/// Older snapshots beyond this are deleted automatically.
+const MAX_SNAPSHOTS: usize = 20;
+
+/// Folders (in `.minecraft`) that store content can be installed to.
+const SNAPSHOT_DIRS: &[&str] = &["mods", "resourcepacks", "texturepacks", "shaderpacks"];
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ModSnapshot {
+    /// Unique, sortable id (also the name of the snapshot file).
+    pub id: String,
+    /// RFC 3339 time of when the snapshot was taken.
+    pub created: String,
+    /// The operation this snapshot was taken before.
+    /// Eg: `"Update mods"`
+    pub reason: String,
+    /// Path (relative to `.minecraft`, like `mods/sodium.jar`)
+    /// to snapshotted file.
+    ///
+    /// Snapshots from before [`ModSnapshot::dirs`] existed
+    /// only have file names in `mods/`.
+    pub files: BTreeMap<String, SnapshotFile>,
+    pub index: ModIndex,
+    /// The folders that were snapshotted.
+    #[serde(default)]
+    pub dirs: Vec<String>,
+}
+
+impl ModSnapshot {
+    fn dirs(&self) -> Vec<&str> {
+        if self.dirs.is_empty() {
+            vec!["mods"]
+        } else {
+            self.dirs.iter().map(String::as_str).collect()
+        }
+    }
+
+    fn file_path(&self, name: &str) -> String {
+        if self.dirs.is_empty() {
+            format!("mods/{name}")
+        } else {
+            name.to_owned()
+        }
+    }
+
+    /// Number of files in the `mods` folder
+    #[must_use]
+    pub fn mod_count(&self) -> usize {
+        self.files
+            .keys()
+            .filter(|n| self.file_path(n).starts_with("mods/"))
+            .count()
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct SnapshotFile {
+    /// SHA-1 hash, the file is stored in `objects/<hash>`.
+    pub hash: String,
+    pub size: u64,
+    /// Modification time (seconds since unix epoch).
+    /// Used along with `size` to skip re-hashing unchanged files.
+    pub modified: u64,
+}
+
+fn snapshots_dir(instance: &InstanceSelection) -> PathBuf {
+    instance.get_instance_path().join("mod_snapshots")
+}
+
+/// Takes a snapshot of the current `mods`, `resourcepacks`
+/// and `shaderpacks` folders and mod index.
+///
+/// Returns `None` if nothing has changed since the
+/// last snapshot (so no new snapshot was needed).
+pub async fn create_snapshot(
+    instance: &InstanceSelection,
+    reason: &str,
+) -> Result<Option<ModSnapshot>, ModError> {
+    let dir = snapshots_dir(instance);
+    let objects_dir = dir.join("objects");
+    tokio::fs::create_dir_all(&objects_dir)
+        .await
+        .path(&objects_dir)?;
+
+    let index = ModIndex::get(instance).await?;
+    let dot_minecraft = instance.get_dot_minecraft_path();
+
+    let last = list_snapshots(instance).await?.into_iter().next();
+    // Keyed the same way as the new snapshot
+    let last_files: BTreeMap<String, &SnapshotFile> = last
+        .as_ref()
+        .map(|last| {
+            last.files
+                .iter()
+                .map(|(name, file)| (last.file_path(name), file))
+                .collect()
+        })
+        .unwrap_or_default();
+
+    let mut files = BTreeMap::new();
+    let mut dirs = Vec::new();
+    for dir_name in SNAPSHOT_DIRS {
+        let dir = dot_minecraft.join(dir_name);
+        if !dir.is_dir() {
+            continue;
+        }
+        dirs.push((*dir_name).to_owned());
+
+        let mut entries = tokio::fs::read_dir(&dir).await.dir(&dir)?;
+        while let Some(entry) = entries.next_entry().await.dir(&dir)? {
+            let path = entry.path();
+            let metadata = entry.metadata().await.path(&path)?;
+            if !metadata.is_file() {
+                continue;
+            }
+            let name = format!("{dir_name}/{}", entry.file_name().to_string_lossy());
+            let modified = metadata
+                .modified()
+                .ok()
+                .and_then(|n| n.duration_since(UNIX_EPOCH).ok())
+                .map_or(0, |n| n.as_secs());
+
+            let file = if let Some(old) = last_files
+                .get(&name)
+                .filter(|n| n.size == metadata.len() && n.modified == modified)
+            {
+                (*old).clone()
+            } else {
+                let bytes = tokio::fs::read(&path).await.path(&path)?;
+                SnapshotFile {
+                    hash: file_utils::get_sha1(&bytes),
+                    size: metadata.len(),
+                    modified,
+                }
+            };
+
+            let object = objects_dir.join(&file.hash);
+            if !object.exists() {
+                file_utils::hard_link_or_copy(&path, &object).await?;
+            }
+            files.insert(name, file);
+        }
+    }
+
+    if let Some(last) = &last {
+        if last.dirs == dirs && last.files == files && is_same_index(&last.index, &index)? {
+            return Ok(None);
+        }
+    }
+
+    let now = chrono::Local::now();
+    let snapshot = ModSnapshot {
+        id: now.format("%Y%m%d-%H%M%S-%3f").to_string(),
+        created: now.to_rfc3339(),
+        reason: reason.to_owned(),
+        files,
+        index,
+        dirs,
+    };
+
+    let path = dir.join(format!("{}.json", snapshot.id));
+    let json = serde_json::to_string(&snapshot).json_to()?;
+    tokio::fs::write(&path, json).await.path(&path)?;
+    pt!("Created mod snapshot: {} ({reason})", snapshot.id);
+
+    prune_snapshots(instance).await?;
+    Ok(Some(snapshot))
+}
+
+fn is_same_index(a: &ModIndex, b: &ModIndex) -> Result<bool, ModError> {
+    Ok(serde_json::to_value(a).json_to()? == serde_json::to_value(b).json_to()?)
+}
+
+/// Lists all the mod snapshots of an instance,
+/// newest first.
+pub async fn list_snapshots(instance: &InstanceSelection) -> Result<Vec<ModSnapshot>, ModError> {
+    let dir = snapshots_dir(instance);
+    if !dir.is_dir() {
+        return Ok(Vec::new());
+    }
+
+    let mut snapshots = Vec::new();
+    let mut entries = tokio::fs::read_dir(&dir).await.dir(&dir)?;
+    while let Some(entry) = entries.next_entry().await.dir(&dir)? {
+        let path = entry.path();
+        if path.extension().is_none_or(|n| n != "json") {
+            continue;
+        }
+        let json = tokio::fs::read_to_string(&path).await.path(&path)?;
+        let snapshot: ModSnapshot = serde_json::from_str(&json).json(json)?;
+        snapshots.push(snapshot);
+    }
+
+    snapshots.sort_by(|a, b| b.id.cmp(&a.id));
+    Ok(snapshots)
+}
+
+/// Restores the snapshotted folders and mod index to how
+/// they were in the snapshot with the given `id`.
+///
+/// The current state is snapshotted first,
+/// so this can be undone too.
+pub async fn restore_snapshot(instance: &InstanceSelection, id: &str) -> Result<(), ModError> {
+    let snapshot = read_snapshot(instance, id).await?;
+    create_snapshot(instance, &format!("Restore snapshot {id}")).await?;
+    apply_snapshot(instance, snapshot).await
+}
+
+/// Undoes the last mod change, ie. restores the
+/// newest snapshot and then deletes it
+/// (so calling this repeatedly goes further back in time).
+///
+/// Returns the restored snapshot, or `None` if there
+/// are no snapshots.
+pub async fn undo_last_change(
+    instance: &InstanceSelection,
+) -> Result<Option<ModSnapshot>, ModError> {
+    let Some(snapshot) = list_snapshots(instance).await?.into_iter().next() else {
+        return Ok(None);
+    };
+    apply_snapshot(instance, snapshot.clone()).await?;
+    delete_snapshot(instance, &snapshot).await?;
+    Ok(Some(snapshot))
+}
+
+/// Rolls back a failed operation, given the
+/// snapshot [`create_snapshot`] took before it.
+///
+/// A snapshot taken for the failed operation is deleted
+/// afterwards (it's the same as the restored state), so
+/// [`undo_last_change`] still undoes the last real change.
+pub(crate) async fn roll_back(
+    instance: &InstanceSelection,
+    taken: Option<ModSnapshot>,
+) -> Result<(), ModError> {
+    if let Some(snapshot) = taken {
+        apply_snapshot(instance, snapshot.clone()).await?;
+        delete_snapshot(instance, &snapshot).await?;
+    } else if let Some(snapshot) = list_snapshots(instance).await?.into_iter().next() {
+        // Nothing had changed since this one was taken
+        apply_snapshot(instance, snapshot).await?;
+    }
+    Ok(())
+}
+
+async fn delete_snapshot(
+    instance: &InstanceSelection,
+    snapshot: &ModSnapshot,
+) -> Result<(), ModError> {
+    let path = snapshots_dir(instance).join(format!("{}.json", snapshot.id));
+    tokio::fs::remove_file(&path).await.path(&path)?;
+    clean_objects(instance).await
+}
+
+async fn read_snapshot(instance: &InstanceSelection, id: &str) -> Result<ModSnapshot, ModError> {
+    let path = snapshots_dir(instance).join(format!("{id}.json"));
+    let json = tokio::fs::read_to_string(&path).await.path(&path)?;
+    Ok(serde_json::from_str(&json).json(json)?)
+}
+
+async fn apply_snapshot(
+    instance: &InstanceSelection,
+    mut snapshot: ModSnapshot,
+) -> Result<(), ModError> {
+    info!(
+        "Restoring mod snapshot {} ({})",
+        snapshot.id, snapshot.reason
+    );
+    let objects_dir = snapshots_dir(instance).join("objects");
+    let dot_minecraft = instance.get_dot_minecraft_path();
+
+    for dir_name in snapshot.dirs() {
+        let dir = dot_minecraft.join(dir_name);
+        if !dir.is_dir() {
+            tokio::fs::create_dir_all(&dir).await.path(&dir)?;
+            continue;
+        }
+        let mut entries = tokio::fs::read_dir(&dir).await.dir(&dir)?;
+        while let Some(entry) = entries.next_entry().await.dir(&dir)? {
+            let path = entry.path();
+            if path.is_file() {
+                tokio::fs::remove_file(&path).await.path(&path)?;
+            }
+        }
+    }
+
+    for (name, file) in &snapshot.files {
+        let path = dot_minecraft.join(snapshot.file_path(name));
+        file_utils::hard_link_or_copy(&objects_dir.join(&file.hash), &path).await?;
+    }
+
+    snapshot.index.save(instance).await?;
+    pt!("Restored {} files", snapshot.files.len());
+    Ok(())
+}
+
+/// Deletes snapshots beyond [`MAX_SNAPSHOTS`]
+/// along with any objects no longer used.
+async fn prune_snapshots(instance: &InstanceSelection) -> Result<(), ModError> {
+    let snapshots = list_snapshots(instance).await?;
+    if snapshots.len() <= MAX_SNAPSHOTS {
+        return Ok(());
+    }
+
+    let dir = snapshots_dir(instance);
+    for snapshot in snapshots.into_iter().skip(MAX_SNAPSHOTS) {
+        let path = dir.join(format!("{}.json", snapshot.id));
+        tokio::fs::remove_file(&path).await.path(&path)?;
+    }
+    clean_objects(instance).await
+}
+
+async fn clean_objects(instance: &InstanceSelection) -> Result<(), ModError> {
+    let used: HashSet<String> = list_snapshots(instance)
+        .await?
+        .into_iter()
+        .flat_map(|n| n.files.into_values().map(|n| n.hash))
+        .collect();
+
+    let objects_dir = snapshots_dir(instance).join("objects");
+    let mut entries = tokio::fs::read_dir(&objects_dir).await.dir(&objects_dir)?;
+    while let Some(entry) = entries.next_entry().await.dir(&objects_dir)? {
+        if !used.contains(&*entry.file_name().to_string_lossy()) {
+            let path = entry.path();
+            tokio::fs::remove_file(&path).await.path(&path)?;
+        }
+    }
+    Ok(())
+}
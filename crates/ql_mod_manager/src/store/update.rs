@@ -1,35 +1,131 @@
 use std::sync::mpsc::Sender;
 
-use chrono::DateTime;
+use chrono::{DateTime, FixedOffset};
 use ql_core::{
-    do_jobs, info_no_log, json::VersionDetails, GenericProgress, InstanceSelection, Loader,
+    do_jobs, err, info_no_log, json::VersionDetails, pt, GenericProgress, InstanceSelection, Loader,
 };
 
-use crate::store::{get_latest_version_date, get_loader};
+use crate::store::{
+    create_snapshot, delete::delete_mods_no_snapshot, download_mods_bulk_no_snapshot,
+    get_latest_version_date, get_loader, get_versions_since, snapshot::roll_back, toggle_mods,
+};
+
+use super::{ModError, ModId, ModIndex};
 
-use super::{delete_mods, download_mods_bulk, ModError, ModId, ModIndex};
+/// An available update for a store-installed mod.
+///
+/// Returned by [`check_for_updates`] so the user can
+/// review what's changed before applying it.
+#[derive(Debug, Clone)]
+pub struct ModUpdate {
+    pub id: ModId,
+    pub name: String,
+    pub old_version: String,
+    pub new_version: String,
+    pub release_date: DateTime<FixedOffset>,
+    /// Changelogs of all versions between the installed
+    /// and the new one (newest first): `(version, body)`
+    ///
+    /// Changelogs are Markdown (Modrinth) or HTML (Curseforge).
+    pub changelogs: Vec<(String, String)>,
+    /// Whether the new version has a different
+    /// set of dependencies than the installed one.
+    pub dependencies_changed: bool,
+}
 
+/// Updates the given mods to their latest compatible versions.
+///
+/// A [`ModSnapshot`](super::ModSnapshot) is taken first, so the
+/// old mod files are kept around until the new ones have been downloaded.
+/// If anything goes wrong, the mods folder and index are restored
+/// to how they were before the update.
 pub async fn apply_updates(
     selected_instance: InstanceSelection,
     updates: Vec<ModId>,
     progress: Option<Sender<GenericProgress>>,
 ) -> Result<(), ModError> {
-    // It's as simple as that!
-    delete_mods(updates.clone(), selected_instance.clone()).await?;
-    download_mods_bulk(updates, selected_instance, progress).await?;
+    let snapshot = create_snapshot(&selected_instance, "Update mods").await?;
+
+    let result = async {
+        delete_mods_no_snapshot(updates.clone(), selected_instance.clone()).await?;
+        download_mods_bulk_no_snapshot(updates, selected_instance.clone(), progress).await?;
+        Ok::<(), ModError>(())
+    }
+    .await;
+
+    if let Err(error) = result {
+        err!("Could not update mods, restoring old versions: {error}");
+        roll_back(&selected_instance, snapshot).await?;
+        return Err(error);
+    }
     Ok(())
 }
 
 pub async fn check_for_updates(
     selected_instance: InstanceSelection,
-) -> Result<Vec<(ModId, String)>, ModError> {
+) -> Result<Vec<ModUpdate>, ModError> {
+    Ok(check_for_updates_inner(selected_instance).await?.0)
+}
+
+/// Mods changed by [`update_for_game_version`] (names).
+#[derive(Debug, Clone, Default)]
+pub struct GameVersionModReport {
+    pub updated: Vec<String>,
+    /// No version compatible with the new
+    /// game version, so these were disabled
+    pub disabled: Vec<String>,
+}
+
+/// After changing an instance's game version, swaps its store mods
+/// to versions compatible with the new game version
+/// (see [`check_for_updates`] and [`apply_updates`]),
+/// and disables the ones that aren't available for it.
+pub async fn update_for_game_version(
+    selected_instance: InstanceSelection,
+    progress: Option<Sender<GenericProgress>>,
+) -> Result<GameVersionModReport, ModError> {
+    let (updates, incompatible) = check_for_updates_inner(selected_instance.clone()).await?;
+
+    let index = ModIndex::get(&selected_instance).await?;
+    let to_disable: Vec<String> = incompatible
+        .into_iter()
+        .filter(|id| index.mods.get(id).is_some_and(|n| n.enabled))
+        .collect();
+    let report = GameVersionModReport {
+        updated: updates.iter().map(|n| n.name.clone()).collect(),
+        disabled: to_disable
+            .iter()
+            .filter_map(|id| index.mods.get(id))
+            .map(|n| n.name.clone())
+            .collect(),
+    };
+
+    if !updates.is_empty() {
+        apply_updates(
+            selected_instance.clone(),
+            updates.into_iter().map(|n| n.id).collect(),
+            progress,
+        )
+        .await?;
+    }
+    if !to_disable.is_empty() {
+        toggle_mods(to_disable, selected_instance).await?;
+    }
+    Ok(report)
+}
+
+/// Returns the available updates, and the (index) IDs of
+/// mods that have no version compatible with the game version.
+async fn check_for_updates_inner(
+    selected_instance: InstanceSelection,
+) -> Result<(Vec<ModUpdate>, Vec<String>), ModError> {
     let index = ModIndex::get(&selected_instance).await?;
 
     let version_json = VersionDetails::load(&selected_instance).await?;
 
     let loader = get_loader(&selected_instance).await?;
     if let Some(Loader::OptiFine) = loader {
-        return Ok(Vec::new());
+        return Ok((Vec::new(), Vec::new()));
     }
     info_no_log!(
         "Checking for mod updates (loader: {})",
@@ -38,25 +134,76 @@ pub async fn check_for_updates(
 
     let version = &version_json.id;
 
-    let updated_mods: Result<Vec<Option<(ModId, String)>>, ModError> = do_jobs(
+    let results: Result<Vec<Result<Option<ModUpdate>, String>>, ModError> = do_jobs(
         index
             .mods
             .into_iter()
             .map(|(id, installed_mod)| async move {
                 let mod_id = ModId::from_index_str(&id);
 
-                let (download_version_time, download_version) =
-                    get_latest_version_date(loader, &mod_id, version).await?;
+                let download_version_time =
+                    match get_latest_version_date(loader, &mod_id, version).await {
+                        Ok((n, _)) => n,
+                        Err(ModError::NoCompatibleVersionFound(_)) => {
+                            pt!("{}: no version for {version}", installed_mod.name);
+                            return Ok(Err(id));
+                        }
+                        Err(err) => return Err(err),
+                    };
 
                 let installed_version_time =
                     DateTime::parse_from_rfc3339(&installed_mod.version_release_time)?;
 
-                Ok((download_version_time > installed_version_time)
-                    .then_some((mod_id, download_version)))
+                // Installed for a different game version
+                // (eg: after changing the instance's version),
+                // so any compatible version is an "update",
+                // even if it's older.
+                let is_other_game_version = !installed_mod.supported_versions.contains(version);
+
+                let since = if is_other_game_version {
+                    DateTime::<FixedOffset>::MIN_UTC.fixed_offset()
+                } else if download_version_time <= installed_version_time {
+                    return Ok(Ok(None));
+                } else {
+                    installed_version_time
+                };
+
+                let versions = get_versions_since(loader, &mod_id, version, since).await?;
+                let Some(latest) = versions.first() else {
+                    return Ok(Ok(None));
+                };
+                pt!(
+                    "{}: {} -> {}",
+                    installed_mod.name,
+                    installed_mod.installed_version,
+                    latest.name
+                );
+
+                Ok(Ok(Some(ModUpdate {
+                    id: mod_id,
+                    new_version: latest.name.clone(),
+                    release_date: latest.release_date,
+                    dependencies_changed: latest.dependencies != installed_mod.dependencies,
+                    changelogs: versions
+                        .into_iter()
+                        .map(|n| (n.name, n.changelog))
+                        .collect(),
+                    name: installed_mod.name,
+                    old_version: installed_mod.installed_version,
+                })))
             }),
     )
     .await;
-    let updated_mods: Vec<(ModId, String)> = updated_mods?.into_iter().flatten().collect();
+
+    let mut updated_mods = Vec::new();
+    let mut incompatible = Vec::new();
+    for result in results? {
+        match result {
+            Ok(Some(update)) => updated_mods.push(update),
+            Ok(None) => {}
+            Err(id) => incompatible.push(id),
+        }
+    }
 
     if updated_mods.is_empty() {
         info_no_log!("No mod updates found");
@@ -64,5 +211,5 @@ pub async fn check_for_updates(
         info_no_log!("Found mod updates");
     }
 
-    Ok(updated_mods)
+    Ok((updated_mods, incompatible))
 }
@@ -0,0 +1,657 @@
+//! Exporting instances as modpacks for other platforms
+//! (Modrinth `.mrpack` and Curseforge `.zip`), so they
+//! can be shared with people using other launchers.
+//!
+//! Mods installed from the store (tracked in `mod_index.json`)
+//! are written to the pack index as download entries,
+//! and everything else goes into `overrides/`.
+
+use std::{
+    collections::{HashMap, HashSet},
+    path::{Path, PathBuf},
+    sync::mpsc::Sender,
+};
+
+use ql_core::{
+    file_utils, info,
+    json::{FabricJSON, InstanceConfigJson, VersionDetails},
+    pt, GenericProgress, InstanceSelection, IntoIoError, IntoJsonError,
+};
+use ql_mod_manager::store::{ModIndex, SOURCE_ID_CURSEFORGE, SOURCE_ID_MODRINTH};
+use serde::Serialize;
+
+use crate::{export::EXCEPTIONS, InstancePackageError};
+
+/// Files that belong to the launcher
+/// and shouldn't end up in `overrides/`.
+/// Relative to the instance dir.
+const PACK_EXCEPTIONS: &[&str] = &[
+    ".minecraft/mod_index.json",
+    ".minecraft/launcher_profiles.json",
+    "mod_snapshots",
+    // Servers (`.minecraft` is the instance dir)
+    "mod_index.json",
+    "config.json",
+    "details.json",
+    "server.jar",
+];
+
+#[derive(Serialize)]
+#[allow(non_snake_case)]
+struct MrpackIndex {
+    formatVersion: usize,
+    game: &'static str,
+    versionId: String,
+    name: String,
+    files: Vec<MrpackFile>,
+    dependencies: HashMap<String, String>,
+}
+
+#[derive(Serialize)]
+#[allow(non_snake_case)]
+struct MrpackFile {
+    path: String,
+    hashes: MrpackHashes,
+    env: MrpackEnv,
+    downloads: Vec<String>,
+    fileSize: usize,
+}
+
+#[derive(Serialize)]
+struct MrpackHashes {
+    sha1: String,
+    sha512: String,
+}
+
+#[derive(Serialize, Clone, Copy)]
+struct MrpackEnv {
+    client: &'static str,
+    server: &'static str,
+}
+
+#[derive(Serialize)]
+#[allow(non_snake_case)]
+struct CfManifest {
+    minecraft: CfMinecraft,
+    manifestType: &'static str,
+    manifestVersion: usize,
+    name: String,
+    version: String,
+    author: String,
+    files: Vec<CfFile>,
+    overrides: &'static str,
+}
+
+#[derive(Serialize)]
+#[allow(non_snake_case)]
+struct CfMinecraft {
+    version: String,
+    modLoaders: Vec<CfLoader>,
+}
+
+#[derive(Serialize)]
+struct CfLoader {
+    id: String,
+    primary: bool,
+}
+
+#[derive(Serialize)]
+#[allow(non_snake_case)]
+struct CfFile {
+    projectID: u32,
+    fileID: u32,
+    required: bool,
+}
+
+/// A mod from the store that will be
+/// downloaded by the pack instead of
+/// being bundled in `overrides/`.
+struct StoreFile {
+    project_id: String,
+    curseforge_file_id: Option<i32>,
+    url: String,
+    /// Path relative to `.minecraft`, eg: `mods/sodium.jar`
+    path: String,
+}
+
+/// Exports an instance as a Modrinth modpack (`.mrpack`).
+///
+/// Enabled mods downloaded from Modrinth are listed in
+/// `modrinth.index.json` (with their hashes), while every other
+/// file (configs, Curseforge/local mods, etc.) is put in `overrides/`.
+///
+/// # Arguments
+/// - `exceptions`: Paths to leave out of the pack, in
+///   the same format as [`crate::export_instance`]
+///   (relative to the instance dir).
+///
+/// # Errors
+/// - Instance files couldn't be read
+/// - The loader (or its version) isn't supported by Modrinth packs
+/// - Zipping the pack failed
+pub async fn export_modrinth_pack(
+    instance: InstanceSelection,
+    exceptions: HashSet<String>,
+    progress: Option<Sender<GenericProgress>>,
+) -> Result<Vec<u8>, InstancePackageError> {
+    info!("Exporting instance as Modrinth pack...");
+    let mc_version = VersionDetails::load(&instance).await?.id;
+    let loader = get_loader(&instance, &mc_version).await?;
+
+    let store_files = get_store_files(&instance, SOURCE_ID_MODRINTH, &exceptions).await?;
+    let mc_dir = instance.get_dot_minecraft_path();
+    // The mod index doesn't know which side a mod is for,
+    // so only the side this instance is for needs it
+    let env = if instance.is_server() {
+        MrpackEnv {
+            client: "optional",
+            server: "required",
+        }
+    } else {
+        MrpackEnv {
+            client: "required",
+            server: "optional",
+        }
+    };
+
+    send_progress(progress.as_ref(), 0, "Hashing mods...");
+    let mut files = Vec::new();
+    for file in &store_files {
+        let path = mc_dir.join(&file.path);
+        let bytes = tokio::fs::read(&path).await.path(&path)?;
+        files.push(MrpackFile {
+            path: file.path.clone(),
+            hashes: MrpackHashes {
+                sha1: file_utils::get_sha1(&bytes),
+                sha512: file_utils::get_sha512(&bytes),
+            },
+            env,
+            downloads: vec![file.url.clone()],
+            fileSize: bytes.len(),
+        });
+    }
+
+    let mut dependencies = HashMap::from([("minecraft".to_owned(), mc_version)]);
+    if let Some((loader, version)) = loader {
+        let key = match loader {
+            PackLoader::Fabric => "fabric-loader",
+            PackLoader::Quilt => "quilt-loader",
+            PackLoader::Forge => "forge",
+            PackLoader::NeoForge => "neoforge",
+        };
+        dependencies.insert(key.to_owned(), version);
+    }
+
+    let index = MrpackIndex {
+        formatVersion: 1,
+        game: "minecraft",
+        versionId: "1.0.0".to_owned(),
+        name: instance.get_name().to_owned(),
+        files,
+        dependencies,
+    };
+    let index = serde_json::to_string_pretty(&index).json_to()?;
+
+    package(
+        &instance,
+        exceptions,
+        &store_files,
+        ("modrinth.index.json", index),
+        progress.as_ref(),
+    )
+    .await
+}
+
+/// Exports an instance as a Curseforge modpack (`.zip`).
+///
+/// Enabled mods downloaded from Curseforge are listed in
+/// `manifest.json`, while every other file (configs,
+/// Modrinth/local mods, etc.) is put in `overrides/`.
+///
+/// # Arguments
+/// - `exceptions`: Paths to leave out of the pack, in
+///   the same format as [`crate::export_instance`]
+///   (relative to the instance dir).
+///
+/// # Errors
+/// - Instance files couldn't be read
+/// - The loader (or its version) isn't supported by Curseforge packs
+/// - Zipping the pack failed
+pub async fn export_curseforge_pack(
+    instance: InstanceSelection,
+    exceptions: HashSet<String>,
+    progress: Option<Sender<GenericProgress>>,
+) -> Result<Vec<u8>, InstancePackageError> {
+    info!("Exporting instance as Curseforge pack...");
+    let mc_version = VersionDetails::load(&instance).await?.id;
+    let loader = get_loader(&instance, &mc_version).await?;
+
+    let store_files =
+        curseforge_files(get_store_files(&instance, SOURCE_ID_CURSEFORGE, &exceptions).await?);
+    let (store_files, files): (Vec<StoreFile>, Vec<CfFile>) = store_files.into_iter().unzip();
+
+    let mod_loaders = loader
+        .map(|(loader, version)| {
+            let name = match loader {
+                PackLoader::Fabric => "fabric",
+                PackLoader::Quilt => "quilt",
+                PackLoader::Forge => "forge",
+                PackLoader::NeoForge => "neoforge",
+            };
+            CfLoader {
+                id: format!("{name}-{version}"),
+                primary: true,
+            }
+        })
+        .into_iter()
+        .collect();
+
+    let manifest = CfManifest {
+        minecraft: CfMinecraft {
+            version: mc_version,
+            modLoaders: mod_loaders,
+        },
+        manifestType: "minecraftModpack",
+        manifestVersion: 1,
+        name: instance.get_name().to_owned(),
+        version: "1.0.0".to_owned(),
+        author: String::new(),
+        files,
+        overrides: "overrides",
+    };
+    let manifest = serde_json::to_string_pretty(&manifest).json_to()?;
+
+    package(
+        &instance,
+        exceptions,
+        &store_files,
+        ("manifest.json", manifest),
+        progress.as_ref(),
+    )
+    .await
+}
+
+/// Copies the instance's `.minecraft` folder into `overrides/`
+/// (minus the exceptions and the store mods that are downloaded
+/// by the pack), writes the index file (and instance icon)
+/// and zips it all up.
+async fn package(
+    instance: &InstanceSelection,
+    mut exceptions: HashSet<String>,
+    store_files: &[StoreFile],
+    (index_name, index): (&str, String),
+    progress: Option<&Sender<GenericProgress>>,
+) -> Result<Vec<u8>, InstancePackageError> {
+    exceptions.extend(EXCEPTIONS.iter().map(|n| (*n).to_owned()));
+    exceptions.extend(PACK_EXCEPTIONS.iter().map(|n| (*n).to_owned()));
+    pt!("Exceptions (not included in export): {exceptions:?}");
+
+    let instance_path = instance.get_instance_path();
+    let mc_dir = instance.get_dot_minecraft_path();
+    let exceptions: Vec<PathBuf> = exceptions
+        .iter()
+        .map(|n| instance_path.join(n))
+        .chain(store_files.iter().map(|n| mc_dir.join(&n.path)))
+        .collect();
+
+    send_progress(progress, 1, "Copying data...");
+    let dir = tempfile::TempDir::new().map_err(InstancePackageError::TempDir)?;
+    let overrides = dir.path().join("overrides");
+    file_utils::copy_dir_recursive_ext(&mc_dir, &overrides, &exceptions).await?;
+
+    let index_path = dir.path().join(index_name);
+    tokio::fs::write(&index_path, index)
+        .await
+        .path(&index_path)?;
+
+    // Picked up as the instance icon when importing
+    let icon_path = instance.get_icon_path();
+    if icon_path.is_file() {
+        tokio::fs::copy(&icon_path, dir.path().join("icon.png"))
+            .await
+            .path(&icon_path)?;
+    }
+
+    pt!("Packaging the instance into zip");
+    send_progress(progress, 2, "Zipping files...");
+    let bytes = file_utils::zip_directory_to_bytes(dir.path())
+        .await
+        .map_err(InstancePackageError::ZipIo)?;
+    pt!("Done!");
+
+    Ok(bytes)
+}
+
+/// Gets the enabled mods from the mod index that
+/// were downloaded from `source` and are still present,
+/// minus the ones the user left out (`exceptions`).
+async fn get_store_files(
+    instance: &InstanceSelection,
+    source: &str,
+    exceptions: &HashSet<String>,
+) -> Result<Vec<StoreFile>, InstancePackageError> {
+    let index = ModIndex::get(instance).await?;
+    let mods_dir = instance.get_dot_minecraft_path().join("mods");
+    Ok(store_files_from_index(index, source, &mods_dir, exceptions))
+}
+
+fn store_files_from_index(
+    index: ModIndex,
+    source: &str,
+    mods_dir: &Path,
+    exceptions: &HashSet<String>,
+) -> Vec<StoreFile> {
+    index
+        .mods
+        .into_values()
+        .filter(|n| n.enabled && n.project_source == source)
+        .flat_map(|config| {
+            let project_id = config.project_id;
+            let curseforge_file_id = config.curseforge_file_id;
+            config
+                .files
+                .into_iter()
+                .filter(|file| !file.url.is_empty() && mods_dir.join(&file.filename).is_file())
+                .map(move |file| StoreFile {
+                    project_id: project_id.clone(),
+                    curseforge_file_id,
+                    url: file.url,
+                    path: format!("mods/{}", file.filename),
+                })
+        })
+        .filter(|file| !is_excluded(&file.path, exceptions))
+        .collect()
+}
+
+/// Whether `path` (relative to `.minecraft`) or any folder
+/// it's in is one of the `exceptions` (like `.minecraft/mods`).
+fn is_excluded(path: &str, exceptions: &HashSet<String>) -> bool {
+    Path::new(path).ancestors().any(|n| {
+        !n.as_os_str().is_empty()
+            && exceptions.contains(&format!(".minecraft/{}", n.to_string_lossy()))
+    })
+}
+
+/// Pairs Curseforge store files with their manifest entries.
+///
+/// Mods whose file ID can't be figured out are
+/// left out, so they get bundled in `overrides/` instead.
+fn curseforge_files(store_files: Vec<StoreFile>) -> Vec<(StoreFile, CfFile)> {
+    store_files
+        .into_iter()
+        .filter_map(|file| {
+            let project_id = file.project_id.strip_prefix("CF:")?.parse().ok()?;
+            let file_id = match file.curseforge_file_id {
+                Some(n) => u32::try_from(n).ok()?,
+                // Installed before file IDs were saved in the index
+                None => get_curseforge_file_id(&file.url)?,
+            };
+            Some((
+                file,
+                CfFile {
+                    projectID: project_id,
+                    fileID: file_id,
+                    required: true,
+                },
+            ))
+        })
+        .collect()
+}
+
+/// Curseforge download URLs look like
+/// `https://edge.forgecdn.net/files/4567/89/name.jar`
+/// for a file ID of `4567089`.
+fn get_curseforge_file_id(url: &str) -> Option<u32> {
+    let mut parts = url.split("/files/").nth(1)?.split('/');
+    let high: u32 = parts.next()?.parse().ok()?;
+    let low: u32 = parts.next()?.parse().ok()?;
+    Some(high * 1000 + low)
+}
+
+#[derive(Clone, Copy)]
+enum PackLoader {
+    Fabric,
+    Quilt,
+    Forge,
+    NeoForge,
+}
+
+/// Gets the installed loader and its version,
+/// or `None` if the instance is vanilla.
+async fn get_loader(
+    instance: &InstanceSelection,
+    mc_version: &str,
+) -> Result<Option<(PackLoader, String)>, InstancePackageError> {
+    let config = InstanceConfigJson::read(instance).await?;
+    let instance_dir = instance.get_instance_path();
+
+    let loader = match config.mod_type.as_str() {
+        "Vanilla" => return Ok(None),
+        "Fabric" => PackLoader::Fabric,
+        "Quilt" => PackLoader::Quilt,
+        "Forge" => PackLoader::Forge,
+        "NeoForge" => PackLoader::NeoForge,
+        _ => return Err(InstancePackageError::PackUnsupportedLoader(config.mod_type)),
+    };
+
+    let version = match loader {
+        PackLoader::Fabric | PackLoader::Quilt => {
+            let prefix = if let PackLoader::Fabric = loader {
+                "net.fabricmc:fabric-loader:"
+            } else {
+                "org.quiltmc:quilt-loader:"
+            };
+            let path = instance_dir.join("fabric.json");
+            if path.is_file() {
+                let json = tokio::fs::read_to_string(&path).await.path(&path)?;
+                let json: FabricJSON = serde_json::from_str(&json).json(json)?;
+                json.libraries
+                    .iter()
+                    .find_map(|n| n.name.strip_prefix(prefix).map(str::to_owned))
+            } else {
+                None
+            }
+        }
+        PackLoader::Forge | PackLoader::NeoForge => {
+            read_forge_id(&instance_dir.join("forge/details.json"))
+                .await?
+                .and_then(|id| get_forge_version(&id, mc_version))
+        }
+    };
+
+    version.map(|version| Some((loader, version))).ok_or(
+        InstancePackageError::PackUnknownLoaderVersion(config.mod_type),
+    )
+}
+
+/// Reads the `id` field of `forge/details.json`.
+async fn read_forge_id(path: &Path) -> Result<Option<String>, InstancePackageError> {
+    #[derive(serde::Deserialize)]
+    struct Details {
+        id: String,
+    }
+
+    if !path.is_file() {
+        return Ok(None);
+    }
+    let json = tokio::fs::read_to_string(path).await.path(path)?;
+    let mut value: serde_json::Value = serde_json::from_str(&json).json(json)?;
+    // Sometimes the JSON is stored as a string
+    // containing JSON, so unwrap that here.
+    if let serde_json::Value::String(inner) = value {
+        value = serde_json::from_str(&inner).json(inner)?;
+    }
+    Ok(serde_json::from_value::<Details>(value).ok().map(|n| n.id))
+}
+
+/// Extracts the Forge/NeoForge version from its version id.
+///
+/// Eg: `1.20.1-forge-47.2.0`, `neoforge-20.4.80`,
+/// `1.7.10-Forge10.13.4.1614-1.7.10`
+pub(crate) fn get_forge_version(id: &str, mc_version: &str) -> Option<String> {
+    let start = id.to_lowercase().rfind("forge")? + "forge".len();
+    let version = id[start..].trim_start_matches('-');
+    let version = version
+        .strip_prefix(&format!("{mc_version}-"))
+        .unwrap_or(version);
+    let version = version
+        .strip_suffix(&format!("-{mc_version}"))
+        .unwrap_or(version);
+    (!version.is_empty()).then(|| version.to_owned())
+}
+
+fn send_progress(progress: Option<&Sender<GenericProgress>>, done: usize, message: &str) {
+    if let Some(progress) = progress {
+        _ = progress.send(GenericProgress {
+            done,
+            total: 3,
+            message: Some(message.to_owned()),
+            has_finished: false,
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use ql_mod_manager::store::{ModIndex, SOURCE_ID_CURSEFORGE, SOURCE_ID_MODRINTH};
+
+    use super::{
+        curseforge_files, get_curseforge_file_id, get_forge_version, store_files_from_index,
+    };
+
+    #[test]
+    fn forge_versions() {
+        let cases = [
+            ("1.20.1-forge-47.2.0", "1.20.1", "47.2.0"),
+            ("neoforge-20.4.80", "1.20.4", "20.4.80"),
+            ("1.7.10-Forge10.13.4.1614-1.7.10", "1.7.10", "10.13.4.1614"),
+            ("1.10.2-forge1.10.2-12.18.3.2511", "1.10.2", "12.18.3.2511"),
+        ];
+        for (id, mc, expected) in cases {
+            assert_eq!(get_forge_version(id, mc).as_deref(), Some(expected));
+        }
+    }
+
+    #[test]
+    fn curseforge_file_ids() {
+        assert_eq!(
+            get_curseforge_file_id("https://edge.forgecdn.net/files/4567/89/mod.jar"),
+            Some(4_567_089)
+        );
+        assert_eq!(get_curseforge_file_id("https://example.com/mod.jar"), None);
+    }
+
+    /// As written to `mod_index.json` when downloading
+    fn mod_entry(
+        name: &str,
+        source: &str,
+        project_id: &str,
+        file_id: Option<i32>,
+        url: &str,
+    ) -> serde_json::Value {
+        serde_json::json!({
+            "name": name,
+            "manually_installed": true,
+            "installed_version": "1.0",
+            "version_release_time": "",
+            "enabled": name != "disabled",
+            "description": "",
+            "icon_url": null,
+            "project_source": source,
+            "project_id": project_id,
+            "curseforge_file_id": file_id,
+            "files": [{ "url": url, "filename": format!("{name}.jar"), "primary": true }],
+            "supported_versions": [],
+            "dependencies": [],
+            "dependents": [],
+        })
+    }
+
+    #[test]
+    fn excluded_store_files() {
+        let dir = tempfile::TempDir::new().unwrap();
+        for name in ["sodium.jar", "iris.jar"] {
+            std::fs::write(dir.path().join(name), []).unwrap();
+        }
+        let index = serde_json::json!({
+            "mods": {
+                "AANobbMI": mod_entry("sodium", SOURCE_ID_MODRINTH, "AANobbMI", None, "https://cdn.modrinth.com/sodium.jar"),
+                "YL57xq9U": mod_entry("iris", SOURCE_ID_MODRINTH, "YL57xq9U", None, "https://cdn.modrinth.com/iris.jar"),
+            },
+            "is_server": false,
+        });
+        let paths = |exceptions: &[&str]| {
+            let index: ModIndex = serde_json::from_value(index.clone()).unwrap();
+            let exceptions: HashSet<String> = exceptions.iter().map(|n| (*n).to_owned()).collect();
+            let mut paths: Vec<_> =
+                store_files_from_index(index, SOURCE_ID_MODRINTH, dir.path(), &exceptions)
+                    .into_iter()
+                    .map(|n| n.path)
+                    .collect();
+            paths.sort_unstable();
+            paths
+        };
+
+        assert_eq!(paths(&[]), ["mods/iris.jar", "mods/sodium.jar"]);
+        assert_eq!(paths(&[".minecraft/mods/iris.jar"]), ["mods/sodium.jar"]);
+        assert!(paths(&[".minecraft/mods"]).is_empty());
+        assert_eq!(paths(&[".minecraft/config"]).len(), 2);
+    }
+
+    #[test]
+    fn curseforge_export_round_trip() {
+        let dir = tempfile::TempDir::new().unwrap();
+        for name in ["jei.jar", "old.jar", "disabled.jar"] {
+            std::fs::write(dir.path().join(name), []).unwrap();
+        }
+
+        let mod_entry = |name: &str, project_id: u32, file_id: Option<i32>, url: &str| {
+            mod_entry(
+                name,
+                SOURCE_ID_CURSEFORGE,
+                &format!("CF:{project_id}"),
+                file_id,
+                url,
+            )
+        };
+        let index = serde_json::json!({
+            "mods": {
+                // URL doesn't follow the CDN pattern, so only the saved ID works
+                "CF:238222": mod_entry("jei", 238_222, Some(5_101_366), "https://mediafilez.forgecdn.net/jei.jar"),
+                // Installed before file IDs were saved
+                "CF:1234": mod_entry("old", 1234, None, "https://edge.forgecdn.net/files/4567/89/old.jar"),
+                "CF:5678": mod_entry("disabled", 5678, Some(1), "https://example.com/disabled.jar"),
+            },
+            "is_server": false,
+        });
+        let index: ModIndex = serde_json::from_value(index).unwrap();
+
+        let files = curseforge_files(store_files_from_index(
+            index,
+            SOURCE_ID_CURSEFORGE,
+            dir.path(),
+            &HashSet::new(),
+        ));
+        let (store_files, files): (Vec<_>, Vec<_>) = files.into_iter().unzip();
+        let mut paths: Vec<_> = store_files.into_iter().map(|n| n.path).collect();
+        paths.sort_unstable();
+        assert_eq!(paths, ["mods/jei.jar", "mods/old.jar"]);
+
+        // Read back the way the modpack installer reads `manifest.json`
+        let manifest: Vec<serde_json::Value> =
+            serde_json::from_str(&serde_json::to_string(&files).unwrap()).unwrap();
+        let mut entries: Vec<(u64, u64)> = manifest
+            .iter()
+            .map(|n| {
+                (
+                    n["projectID"].as_u64().unwrap(),
+                    n["fileID"].as_u64().unwrap(),
+                )
+            })
+            .collect();
+        entries.sort_unstable();
+        assert_eq!(entries, [(1234, 4_567_089), (238_222, 5_101_366)]);
+    }
+}
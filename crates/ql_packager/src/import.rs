@@ -1,5 +1,5 @@
 use ql_core::{
-    file_utils, info,
+    err, file_utils, info,
     json::{InstanceConfigJson, VersionDetails},
     pt, GenericProgress, InstanceSelection, IntoIoError, IntoJsonError, ListEntry, Loader,
     Progress,
@@ -14,12 +14,32 @@ use std::{
 use tokio::fs;
 use zip_extract::extract;
 
-use crate::InstanceInfo;
+use crate::{other_launchers, InstanceInfo};
 
 use super::InstancePackageError;
 
 pub const OUT_OF: usize = 4;
 
+/// An instance that was imported, along with
+/// anything that had to be left out of it.
+#[derive(Debug, Clone)]
+pub struct ImportedInstance {
+    pub instance: InstanceSelection,
+    /// Components of the original instance that aren't
+    /// supported (yet), eg: `LiteLoader 1.12.2`.
+    /// The rest of the instance is imported without them.
+    pub skipped: Vec<String>,
+}
+
+impl ImportedInstance {
+    pub(crate) fn new(instance: InstanceSelection) -> Self {
+        Self {
+            instance,
+            skipped: Vec::new(),
+        }
+    }
+}
+
 /// Imports a Minecraft instance from a `.zip` file exported by the launcher.
 ///
 /// This function performs the following:
@@ -28,6 +48,14 @@ pub const OUT_OF: usize = 4;
 /// 3. Creates a new instance using the extracted configuration.
 /// 4. Copies the extracted files to the main instances directory.
 ///
+/// `zip_path` may also be an instance folder of another launcher
+/// (MultiMC/Prism, ATLauncher, GDLauncher, Curseforge app),
+/// or the official launcher's `launcher_profiles.json`
+/// (which imports all of its profiles, returning the first one).
+///
+/// Parts of the instance that can't be imported (like
+/// unsupported loaders) are listed in [`ImportedInstance::skipped`].
+///
 /// Finally, it returns a bool indicating whether the file
 /// was an actual packaged instance or not. You can use this
 /// for fuzzy file detection, running this function and running
@@ -48,12 +76,40 @@ pub async fn import_instance(
     zip_path: PathBuf,
     download_assets: bool,
     sender: Option<Sender<GenericProgress>>,
-) -> Result<Option<InstanceSelection>, InstancePackageError> {
+) -> Result<Option<ImportedInstance>, InstancePackageError> {
+    let sender = sender.map(Arc::new);
+
+    if zip_path.is_dir() {
+        return Ok(import_dir(&zip_path, download_assets, sender)
+            .await?
+            .into_iter()
+            .next());
+    }
+    if zip_path
+        .file_name()
+        .is_some_and(|n| n == "launcher_profiles.json")
+    {
+        if let Some(dir) = zip_path.parent() {
+            return Ok(import_dir(dir, download_assets, sender)
+                .await?
+                .into_iter()
+                .next());
+        }
+    }
+
+    import_zip(&zip_path, download_assets, sender).await
+}
+
+async fn import_zip(
+    zip_path: &Path,
+    download_assets: bool,
+    sender: Option<Arc<Sender<GenericProgress>>>,
+) -> Result<Option<ImportedInstance>, InstancePackageError> {
     let temp_dir_obj = tempfile::TempDir::new().map_err(InstancePackageError::TempDir)?;
     let temp_dir = temp_dir_obj.path();
 
     pt!("Extracting zip to {temp_dir:?}");
-    let zip_file = std::fs::File::open(&zip_path).path(&zip_path)?;
+    let zip_file = std::fs::File::open(zip_path).path(zip_path)?;
     if let Some(sender) = &sender {
         _ = sender.send(GenericProgress {
             done: 0,
@@ -65,25 +121,16 @@ pub async fn import_instance(
     extract(zip_file, temp_dir, true)?;
 
     let try_ql = temp_dir.join("quantum-config.json");
-    let try_mmc = temp_dir.join("mmc-pack.json");
 
     let instance = if let Ok(instance_info) = fs::read_to_string(&try_ql).await {
-        Some(
-            import_quantumlauncher(
-                download_assets,
-                temp_dir,
-                instance_info,
-                sender.map(Arc::new),
-            )
-            .await?,
-        )
-    } else if let Ok(mmc_pack) = fs::read_to_string(&try_mmc).await {
-        Some(
-            crate::multimc::import(download_assets, temp_dir, mmc_pack, sender.map(Arc::new))
-                .await?,
-        )
+        Some(ImportedInstance::new(
+            import_quantumlauncher(download_assets, temp_dir, instance_info, sender).await?,
+        ))
     } else {
-        None
+        import_dir(temp_dir, download_assets, sender)
+            .await?
+            .into_iter()
+            .next()
     };
 
     fs::remove_dir_all(&temp_dir).await.path(temp_dir)?;
@@ -91,6 +138,117 @@ pub async fn import_instance(
     Ok(instance)
 }
 
+/// Imports the instance(s) in an (unpacked) instance
+/// folder of another launcher.
+///
+/// Returns an empty `Vec` if it isn't one.
+async fn import_dir(
+    dir: &Path,
+    download_assets: bool,
+    sender: Option<Arc<Sender<GenericProgress>>>,
+) -> Result<Vec<ImportedInstance>, InstancePackageError> {
+    let try_mmc = dir.join("mmc-pack.json");
+    if let Ok(mmc_pack) = fs::read_to_string(&try_mmc).await {
+        return Ok(vec![
+            crate::multimc::import(download_assets, dir, mmc_pack, sender).await?,
+        ]);
+    }
+
+    let mut instances = Vec::new();
+    for instance in other_launchers::detect(dir).await? {
+        instances.push(other_launchers::import(instance, download_assets, sender.clone()).await?);
+    }
+    Ok(instances)
+}
+
+/// The result of [`import_instances_bulk`].
+#[derive(Debug, Clone)]
+pub struct BulkImportResult {
+    pub imported: Vec<ImportedInstance>,
+    /// Name of file/folder, and error message
+    pub failed: Vec<(String, String)>,
+}
+
+/// Imports every instance found in a folder,
+/// for migrating from another launcher in one go.
+///
+/// `dir` can be:
+/// - An instances folder of another launcher
+///   (eg: `PrismLauncher/instances`), or a folder
+///   containing exported `.zip` instances
+/// - The official launcher's `.minecraft` folder
+///   (imports all profiles)
+/// - A single instance folder
+///
+/// Instances that fail to import are skipped
+/// and reported in [`BulkImportResult::failed`].
+///
+/// # Errors
+/// If `dir` couldn't be read.
+pub async fn import_instances_bulk(
+    dir: PathBuf,
+    download_assets: bool,
+    sender: Option<Sender<GenericProgress>>,
+) -> Result<BulkImportResult, InstancePackageError> {
+    info!("Importing all instances in {dir:?}");
+    let sender = sender.map(Arc::new);
+    let mut result = BulkImportResult {
+        imported: Vec::new(),
+        failed: Vec::new(),
+    };
+
+    let dir_name = dir
+        .file_name()
+        .map_or(String::new(), |n| n.to_string_lossy().to_string());
+    match import_dir(&dir, download_assets, sender.clone()).await {
+        Ok(instances) if !instances.is_empty() => {
+            result.imported = instances;
+            return Ok(result);
+        }
+        Ok(_) => {}
+        Err(error) => {
+            result.failed.push((dir_name, error.to_string()));
+            return Ok(result);
+        }
+    }
+
+    let mut entries = fs::read_dir(&dir).await.dir(&dir)?;
+    let mut paths = Vec::new();
+    while let Some(entry) = entries.next_entry().await.dir(&dir)? {
+        paths.push(entry.path());
+    }
+    paths.sort();
+
+    for path in paths {
+        let name = path
+            .file_name()
+            .map_or(String::new(), |n| n.to_string_lossy().to_string());
+        let imported = if path.is_dir() {
+            import_dir(&path, download_assets, sender.clone()).await
+        } else if path.extension().is_some_and(|n| n == "zip") {
+            import_zip(&path, download_assets, sender.clone())
+                .await
+                .map(|n| n.into_iter().collect())
+        } else {
+            continue;
+        };
+        match imported {
+            Ok(instances) => result.imported.extend(instances),
+            Err(error) => {
+                err!("Couldn't import {name}: {error}");
+                result.failed.push((name, error.to_string()));
+            }
+        }
+    }
+
+    info!(
+        "Imported {} instances ({} failed)",
+        result.imported.len(),
+        result.failed.len()
+    );
+    Ok(result)
+}
+
 async fn import_quantumlauncher(
     download_assets: bool,
     temp_dir: &Path,
@@ -113,8 +271,8 @@ async fn import_quantumlauncher(
     pt!("Version : {}", version_json.id);
     pt!("Exceptions : {:?} ", instance_info.exceptions);
     let version = ListEntry {
-        name: version_json.id.clone(),
         is_classic_server: instance_info.is_server && version_json.id.starts_with("c0."),
+        ..ListEntry::new(version_json.id.clone())
     };
 
     if instance_info.is_server {
@@ -1,7 +1,10 @@
 use std::{collections::HashSet, path::PathBuf};
 
-use ql_core::{IoError, JsonError, JsonFileError};
-use ql_mod_manager::loaders::{fabric::FabricInstallError, forge::ForgeInstallError};
+use ql_core::{impl_3_errs_jri, IoError, JsonError, RequestError};
+use ql_mod_manager::{
+    loaders::{fabric::FabricInstallError, forge::ForgeInstallError},
+    store::ModError,
+};
 use ql_servers::ServerError;
 use serde::{Deserialize, Serialize};
 use thiserror::Error;
@@ -10,11 +13,14 @@ use zip_extract::ZipExtractError;
 use ql_instances::DownloadError;
 
 mod export;
+mod export_pack;
 mod import;
 mod multimc;
+mod other_launchers;
 
-pub use export::{EXCEPTIONS, export_instance};
-pub use import::import_instance;
+pub use export::{export_instance, EXCEPTIONS};
+pub use export_pack::{export_curseforge_pack, export_modrinth_pack};
+pub use import::{import_instance, import_instances_bulk, BulkImportResult, ImportedInstance};
 
 const PKG_ERR_PREFIX: &str = "while importing/exporting instance:\n";
 #[derive(Debug, Error)]
@@ -28,6 +34,8 @@ pub enum InstancePackageError {
     Io(#[from] IoError),
     #[error("{PKG_ERR_PREFIX}{0}")]
     Json(#[from] JsonError),
+    #[error("{PKG_ERR_PREFIX}{0}")]
+    Request(#[from] RequestError),
 
     #[error("{PKG_ERR_PREFIX}while creating base instance for import:\n{0}")]
     Download(#[from] DownloadError),
@@ -40,6 +48,8 @@ pub enum InstancePackageError {
     Forge(#[from] ForgeInstallError),
     #[error("{PKG_ERR_PREFIX}{0}")]
     Fabric(#[from] FabricInstallError),
+    #[error("{PKG_ERR_PREFIX}{0}")]
+    Mod(#[from] ModError),
 
     #[error("{PKG_ERR_PREFIX}while extracting zip:\n{0}")]
     ZipExtract(#[from] ZipExtractError),
@@ -54,17 +64,15 @@ pub enum InstancePackageError {
     Ini(#[from] ini::ParseError),
     #[error("{PKG_ERR_PREFIX}in ini file:\nentry {1:?} of section {0:?} is missing!")]
     IniFieldMissing(String, String),
-}
 
-impl From<JsonFileError> for InstancePackageError {
-    fn from(value: JsonFileError) -> Self {
-        match value {
-            JsonFileError::SerdeError(err) => Self::Json(err),
-            JsonFileError::Io(err) => Self::Io(err),
-        }
-    }
+    #[error("{PKG_ERR_PREFIX}modpacks don't support the {0} loader\n(only Fabric, Quilt, Forge and NeoForge)")]
+    PackUnsupportedLoader(String),
+    #[error("{PKG_ERR_PREFIX}couldn't find the installed {0} version\n(try reinstalling it)")]
+    PackUnknownLoaderVersion(String),
 }
 
+impl_3_errs_jri!(InstancePackageError, Json, Request, Io);
+
 #[derive(Debug, Deserialize, Serialize)]
 pub struct InstanceInfo {
     pub instance_name: String,
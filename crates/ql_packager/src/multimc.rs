@@ -3,10 +3,15 @@ use std::{
     sync::{mpsc::Sender, Arc},
 };
 
-use crate::{import::pipe_progress, import::OUT_OF, InstancePackageError};
+use crate::{
+    import::{pipe_progress, ImportedInstance, OUT_OF},
+    InstancePackageError,
+};
 use ql_core::{
-    err, file_utils, info, json::InstanceConfigJson, GenericProgress, InstanceSelection,
-    IntoIoError, IntoJsonError, ListEntry,
+    err, file_utils, info,
+    jarmod::{JarMod, JarMods},
+    json::InstanceConfigJson,
+    GenericProgress, InstanceSelection, IntoIoError, IntoJsonError, ListEntry,
 };
 use serde::{Deserialize, Serialize};
 use tokio::fs;
@@ -19,8 +24,39 @@ pub struct MmcPack {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[allow(non_snake_case)]
 pub struct MmcPackComponent {
+    /// Eg: `net.minecraft`, `net.fabricmc.fabric-loader`,
+    /// `org.multimc.jarmod.<uuid>`
+    pub uid: String,
+    pub version: Option<String>,
+    #[serde(default)]
     pub cachedName: String,
+    #[serde(default)]
     pub cachedVersion: String,
+    #[serde(default)]
+    pub disabled: bool,
+}
+
+impl MmcPackComponent {
+    fn get_version(&self) -> String {
+        self.version
+            .clone()
+            .unwrap_or_else(|| self.cachedVersion.clone())
+    }
+}
+
+/// `patches/<uid>.json`, only the fields
+/// needed for jar mods.
+#[derive(Debug, Clone, Deserialize)]
+struct MmcPatch {
+    #[serde(rename = "jarMods")]
+    jar_mods: Option<Vec<MmcJarMod>>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+struct MmcJarMod {
+    #[serde(rename = "MMC-filename")]
+    filename: Option<String>,
+    name: String,
 }
 
 pub async fn import(
@@ -28,7 +64,7 @@ pub async fn import(
     temp_dir: &Path,
     mmc_pack: String,
     sender: Option<Arc<Sender<GenericProgress>>>,
-) -> Result<InstanceSelection, InstancePackageError> {
+) -> Result<ImportedInstance, InstancePackageError> {
     info!("Importing MultiMC instance...");
     let mmc_pack: MmcPack = serde_json::from_str(&mmc_pack).json(mmc_pack)?;
 
@@ -45,47 +81,88 @@ pub async fn import(
         .to_owned();
     let instance_selection = InstanceSelection::new(&instance_name, false);
 
+    let mut lwjgl_version = None;
+    let mut jar_mods = Vec::new();
+    let mut skipped = Vec::new();
+
     for component in &mmc_pack.components {
-        match component.cachedName.as_str() {
-            "Minecraft" => {
+        match component.uid.as_str() {
+            "net.minecraft" => {
                 mmc_minecraft(download_assets, sender.clone(), &instance_name, component).await?;
             }
 
-            name @ ("Forge" | "NeoForge") => {
+            uid @ ("net.minecraftforge" | "net.neoforged") => {
                 mmc_forge(
                     sender.clone(),
                     &instance_selection,
                     component,
-                    name == "NeoForge",
+                    uid == "net.neoforged",
                 )
                 .await?;
             }
-            name @ ("Fabric Loader" | "Quilt Loader") => {
+            uid @ ("net.fabricmc.fabric-loader" | "org.quiltmc.quilt-loader") => {
                 ql_mod_manager::loaders::fabric::install(
-                    Some(component.cachedVersion.clone()),
+                    Some(component.get_version()),
                     instance_selection.clone(),
                     sender.as_deref(),
-                    name == "Quilt",
+                    uid == "org.quiltmc.quilt-loader",
                 )
                 .await?;
             }
 
-            "Intermediary Mappings" | "LWJGL 2" | "LWJGL 3" => {}
-            name => err!("Unknown component (in MultiMC instance): {name}"),
+            "org.lwjgl" | "org.lwjgl3" => lwjgl_version = Some(component.get_version()),
+
+            uid if uid.starts_with("org.multimc.jarmod.") => {
+                jar_mods.extend(
+                    read_jar_mods(temp_dir, uid)
+                        .await?
+                        .into_iter()
+                        .map(|filename| JarMod {
+                            filename,
+                            enabled: !component.disabled,
+                        }),
+                );
+            }
+
+            // Implied by the loaders above
+            "net.fabricmc.intermediary" | "org.quiltmc.hashed" => {}
+            "com.mumfrey.liteloader" => {
+                err!("LiteLoader isn't supported yet, skipping (in MultiMC instance)");
+                skipped.push(format!("LiteLoader {}", component.get_version()));
+            }
+            uid => {
+                err!(
+                    "Unknown component (in MultiMC instance): {} ({uid})",
+                    component.cachedName
+                );
+                skipped.push(if component.cachedName.is_empty() {
+                    uid.to_owned()
+                } else {
+                    format!("{} {}", component.cachedName, component.get_version())
+                });
+            }
         }
     }
 
+    if let Some(version) = lwjgl_version {
+        mmc_lwjgl(sender.clone(), &instance_selection, &version).await?;
+    }
+
     copy_files(temp_dir, sender, &instance_selection).await?;
 
-    let mut config = InstanceConfigJson::read(&instance_selection).await?;
-    if let Some(jvmargs) = ini.get_from(Some("General"), "JvmArgs") {
-        let mut java_args = config.java_args.clone().unwrap_or_default();
-        java_args.extend(jvmargs.split_whitespace().map(str::to_owned));
-        config.java_args = Some(java_args);
+    if !jar_mods.is_empty() {
+        let mut jarmods_json = JarMods { mods: jar_mods };
+        jarmods_json.save(&instance_selection).await?;
     }
+
+    let mut config = InstanceConfigJson::read(&instance_selection).await?;
+    apply_instance_cfg(&ini, &mut config);
     config.save(&instance_selection).await?;
     info!("Finished importing MultiMC instance");
-    Ok(instance_selection)
+    Ok(ImportedInstance {
+        instance: instance_selection,
+        skipped,
+    })
 }
 
 async fn copy_files(
@@ -93,8 +170,13 @@ async fn copy_files(
     sender: Option<Arc<Sender<GenericProgress>>>,
     instance_selection: &InstanceSelection,
 ) -> Result<(), InstancePackageError> {
-    let src = temp_dir.join("minecraft");
-    if src.is_dir() {
+    // Older versions of MultiMC use `minecraft`,
+    // PrismLauncher uses `.minecraft`
+    let src = [".minecraft", "minecraft"]
+        .into_iter()
+        .map(|n| temp_dir.join(n))
+        .find(|n| n.is_dir());
+    if let Some(src) = src {
         let dst = instance_selection.get_dot_minecraft_path();
         if let Some(sender) = sender.as_deref() {
             _ = sender.send(GenericProgress {
@@ -132,10 +214,7 @@ async fn mmc_minecraft(
     instance_name: &str,
     component: &MmcPackComponent,
 ) -> Result<(), InstancePackageError> {
-    let version = ListEntry {
-        name: component.cachedVersion.clone(),
-        is_classic_server: false,
-    };
+    let version = ListEntry::new(component.get_version());
     let (d_send, d_recv) = std::sync::mpsc::channel();
     if let Some(sender) = sender.clone() {
         std::thread::spawn(move || {
@@ -166,6 +245,7 @@ async fn mmc_forge(
     }
     if is_neoforge {
         ql_mod_manager::loaders::neoforge::install(
+            Some(component.get_version()),
             instance_selection.clone(),
             Some(f_send),
             None, // TODO: Java install progress
@@ -173,7 +253,7 @@ async fn mmc_forge(
         .await?;
     } else {
         ql_mod_manager::loaders::forge::install(
-            Some(component.cachedVersion.clone()),
+            Some(component.get_version()),
             instance_selection.clone(),
             Some(f_send),
             None, // TODO: Java install progress
@@ -183,6 +263,100 @@ async fn mmc_forge(
     Ok(())
 }
 
+async fn mmc_lwjgl(
+    sender: Option<Arc<Sender<GenericProgress>>>,
+    instance_selection: &InstanceSelection,
+    version: &str,
+) -> Result<(), InstancePackageError> {
+    let (d_send, d_recv) = std::sync::mpsc::channel();
+    if let Some(sender) = sender {
+        std::thread::spawn(move || {
+            pipe_progress(d_recv, &sender);
+        });
+    }
+    ql_instances::override_lwjgl_version(instance_selection, version, Some(d_send)).await?;
+    Ok(())
+}
+
+/// Reads the jar mod filenames (in `jarmods/`)
+/// of a jar mod component from its patch file.
+async fn read_jar_mods(temp_dir: &Path, uid: &str) -> Result<Vec<String>, InstancePackageError> {
+    let patch_path = temp_dir.join("patches").join(format!("{uid}.json"));
+    if !patch_path.is_file() {
+        err!("Missing patch file for jar mod (in MultiMC instance): {uid}");
+        return Ok(Vec::new());
+    }
+    let patch = fs::read_to_string(&patch_path).await.path(patch_path)?;
+    let patch: MmcPatch = serde_json::from_str(&patch).json(patch)?;
+
+    Ok(patch
+        .jar_mods
+        .unwrap_or_default()
+        .into_iter()
+        .map(|n| {
+            // Name is like `org.multimc.jarmods:<uuid>:1`
+            n.filename.unwrap_or_else(|| {
+                let id = n.name.split(':').nth(1).unwrap_or(&n.name);
+                format!("{id}.jar")
+            })
+        })
+        .collect())
+}
+
+/// Carries over the per-instance settings from `instance.cfg`:
+/// memory, JVM arguments, Java path and window size.
+///
+/// Settings that aren't overridden for the instance
+/// (eg: `OverrideMemory=false`) are left as the defaults.
+fn apply_instance_cfg(ini: &ini::Ini, config: &mut InstanceConfigJson) {
+    let get = |key: &str| {
+        ini.get_from(Some("General"), key)
+            .or(ini.get_from(None::<String>, key))
+            .map(str::trim)
+            .filter(|n| !n.is_empty())
+    };
+    let overridden = |key: &str| get(key) != Some("false");
+
+    if overridden("OverrideMemory") {
+        if let Some(ram) = get("MaxMemAlloc").and_then(|n| n.parse().ok()) {
+            config.ram_in_mb = ram;
+        }
+        if let Some(min) = get("MinMemAlloc").and_then(|n| n.parse::<usize>().ok()) {
+            add_args(&mut config.java_args, [format!("-Xms{min}M")]);
+        }
+    }
+    if overridden("OverrideJavaArgs") {
+        if let Some(jvmargs) = get("JvmArgs") {
+            add_args(
+                &mut config.java_args,
+                jvmargs.split_whitespace().map(str::to_owned),
+            );
+        }
+    }
+    if overridden("OverrideJavaLocation") {
+        if let Some(java) = get("JavaPath") {
+            config.java_override = Some(java.to_owned());
+        }
+    }
+    if overridden("OverrideWindow") {
+        if let (Some(width), Some(height)) = (get("MinecraftWinWidth"), get("MinecraftWinHeight")) {
+            add_args(
+                &mut config.game_args,
+                [
+                    "--width".to_owned(),
+                    width.to_owned(),
+                    "--height".to_owned(),
+                    height.to_owned(),
+                ],
+            );
+        }
+    }
+}
+
+fn add_args(args: &mut Option<Vec<String>>, new: impl IntoIterator<Item = String>) {
+    args.get_or_insert_with(Vec::new).extend(new);
+}
+
 fn filter_bytearray(input: &str) -> String {
     // PrismLauncher puts some weird ByteArray
     // field in the INI config file, that our pookie little ini parser
@@ -0,0 +1,772 @@
+//! Importing instances from other launchers' on-disk formats:
+//!
+//! - Official launcher (`launcher_profiles.json`)
+//! - ATLauncher (`instance.json`)
+//! - Curseforge app (`minecraftinstance.json`)
+//! - GDLauncher (`config.json`)
+//!
+//! Each of these is read into a [`ForeignInstance`],
+//! which is then created as a regular instance.
+
+use std::{
+    collections::{HashMap, HashSet},
+    path::{Path, PathBuf},
+    sync::{mpsc::Sender, Arc},
+};
+
+use ql_core::{
+    err, file_utils, info,
+    json::{InstanceConfigJson, Manifest},
+    pt, GenericProgress, InstanceSelection, IntoIoError, IntoJsonError, ListEntry, Loader,
+    LAUNCHER_DIR,
+};
+use ql_instances::json_profiles::ProfileJson;
+use ql_mod_manager::{
+    loaders::LoaderInstallResult,
+    store::{ModConfig, ModFile, ModIndex, SOURCE_ID_CURSEFORGE},
+};
+use serde::Deserialize;
+use tokio::fs;
+
+use crate::{
+    export_pack::get_forge_version,
+    import::{pipe_progress, ImportedInstance, OUT_OF},
+    InstancePackageError,
+};
+
+/// Files and folders (relative to the game dir) that belong
+/// to the other launcher or are redownloaded anyway,
+/// so they aren't copied over.
+const FOREIGN_EXCEPTIONS: &[&str] = &[
+    "versions",
+    "libraries",
+    "assets",
+    "bin",
+    "natives",
+    "logs",
+    "crash-reports",
+    "webcache2",
+    "usercache.json",
+    // Official launcher (some of these contain account tokens!)
+    "launcher_profiles.json",
+    "launcher_profiles_microsoft_store.json",
+    "launcher_accounts.json",
+    "launcher_accounts_microsoft_store.json",
+    "launcher_msa_credentials.bin",
+    "launcher_msa_credentials_microsoft_store.bin",
+    "launcher_settings.json",
+    "launcher_ui_state.json",
+    "launcher_product_state.json",
+    "launcher_entitlements.json",
+    "launcher_log.txt",
+    "launcher_cef_log.txt",
+    "treatment_tags.json",
+    // ATLauncher
+    "instance.json",
+    "instance.json.bak",
+    // Curseforge app
+    "minecraftinstance.json",
+    "profileImage",
+    // GDLauncher
+    "config.json",
+];
+
+/// Files copied from the official launcher's main `.minecraft`
+/// for profiles that don't have their own game dir.
+///
+/// That folder is shared by all such profiles (and the worlds,
+/// mods and packs in it belong to none of them in particular),
+/// so only the game settings are carried over.
+const SHARED_GAME_DIR_FILES: &[&str] = &[
+    "options.txt",
+    "servers.dat",
+    "optionsof.txt",
+    "optionsshaders.txt",
+];
+
+/// An instance from another launcher,
+/// ready to be imported.
+pub struct ForeignInstance {
+    pub name: String,
+    /// Minecraft version, eg: `1.20.1`
+    pub version: String,
+    /// The loader and (if known) its version
+    pub loader: Option<(Loader, Option<String>)>,
+    /// Folder whose contents become the new `.minecraft`.
+    pub game_dir: PathBuf,
+    /// Whether `game_dir` is shared with other instances
+    /// (see [`SHARED_GAME_DIR_FILES`]).
+    pub shared_game_dir: bool,
+    /// Mods installed through the other launcher's
+    /// mod browser, for `mod_index.json`.
+    pub mods: HashMap<String, ModConfig>,
+    pub ram_in_mb: Option<usize>,
+    pub java_args: Vec<String>,
+    pub java_path: Option<String>,
+    /// Window (width, height)
+    pub resolution: Option<(u32, u32)>,
+}
+
+/// Detects instances of other launchers in `dir`.
+///
+/// Returns an empty `Vec` if `dir` isn't
+/// an instance of any supported launcher.
+/// The official launcher can have many profiles
+/// in one folder, so this may return more than one.
+///
+/// # Errors
+/// If the launcher-specific files couldn't be read or parsed.
+pub async fn detect(dir: &Path) -> Result<Vec<ForeignInstance>, InstancePackageError> {
+    Ok(if dir.join("minecraftinstance.json").is_file() {
+        vec![read_curseforge(dir).await?]
+    } else if let Some(instance) = read_atlauncher(dir).await? {
+        vec![instance]
+    } else if let Some(instance) = read_gdlauncher(dir).await? {
+        vec![instance]
+    } else if dir.join("launcher_profiles.json").is_file() {
+        // Checked last, because loader installers sometimes leave
+        // a `launcher_profiles.json` in other launchers' instances.
+        read_official(dir).await?
+    } else {
+        Vec::new()
+    })
+}
+
+/// Creates a new instance from a [`ForeignInstance`],
+/// installing its loader and copying its files and settings over.
+///
+/// # Errors
+/// - Creating the instance or installing the loader failed
+/// - Files couldn't be copied
+pub async fn import(
+    instance: ForeignInstance,
+    download_assets: bool,
+    sender: Option<Arc<Sender<GenericProgress>>>,
+) -> Result<ImportedInstance, InstancePackageError> {
+    let name = get_unique_name(&instance.name);
+    info!("Importing instance {name} (from {:?})", instance.game_dir);
+    pt!("Version: {}", instance.version);
+
+    let (d_send, d_recv) = std::sync::mpsc::channel();
+    if let Some(sender) = sender.clone() {
+        std::thread::spawn(move || {
+            pipe_progress(d_recv, &sender);
+        });
+    }
+    ql_instances::create_instance(
+        name.clone(),
+        ListEntry::new(instance.version.clone()),
+        Some(d_send),
+        download_assets,
+    )
+    .await?;
+    let selection = InstanceSelection::new(&name, false);
+    let mut skipped = Vec::new();
+
+    if let Some((loader, version)) = instance.loader {
+        pt!("Loader: {loader:?} {version:?}");
+        let result = ql_mod_manager::loaders::install_specified_loader(
+            selection.clone(),
+            loader,
+            sender.clone(),
+            version.clone(),
+        )
+        .await
+        .map_err(InstancePackageError::Loader)?;
+        if !matches!(result, LoaderInstallResult::Ok) {
+            err!("{loader:?} can't be installed automatically, skipping");
+            skipped.push(match version {
+                Some(version) => format!("{loader:?} {version}"),
+                None => format!("{loader:?}"),
+            });
+        }
+    }
+
+    if let Some(sender) = &sender {
+        _ = sender.send(GenericProgress {
+            done: 2,
+            total: OUT_OF,
+            message: Some("Copying files...".to_owned()),
+            has_finished: false,
+        });
+    }
+    let dot_minecraft = selection.get_dot_minecraft_path();
+    if instance.shared_game_dir {
+        info!("Game folder is shared with other profiles, only copying settings");
+        for file in SHARED_GAME_DIR_FILES {
+            let src = instance.game_dir.join(file);
+            if src.is_file() {
+                fs::copy(&src, dot_minecraft.join(file)).await.path(&src)?;
+            }
+        }
+    } else {
+        let exceptions: Vec<PathBuf> = FOREIGN_EXCEPTIONS
+            .iter()
+            .map(|n| instance.game_dir.join(n))
+            .collect();
+        file_utils::copy_dir_recursive_ext(&instance.game_dir, &dot_minecraft, &exceptions).await?;
+    }
+
+    if !instance.mods.is_empty() {
+        pt!("Adding {} mods to index", instance.mods.len());
+        let mut index = ModIndex::get(&selection).await?;
+        index.mods.extend(instance.mods);
+        index.save(&selection).await?;
+    }
+
+    let mut config = InstanceConfigJson::read(&selection).await?;
+    if let Some(ram) = instance.ram_in_mb {
+        config.ram_in_mb = ram;
+    }
+    if !instance.java_args.is_empty() {
+        config.java_args = Some(instance.java_args);
+    }
+    if let Some(java) = instance.java_path {
+        config.java_override = Some(java);
+    }
+    if let Some((width, height)) = instance.resolution {
+        config.game_args = Some(vec![
+            "--width".to_owned(),
+            width.to_string(),
+            "--height".to_owned(),
+            height.to_string(),
+        ]);
+    }
+    config.save(&selection).await?;
+
+    info!("Finished importing instance {name}");
+    Ok(ImportedInstance {
+        instance: selection,
+        skipped,
+    })
+}
+
+#[derive(Deserialize)]
+#[allow(non_snake_case)]
+struct JsonVersionInherits {
+    inheritsFrom: Option<String>,
+    #[serde(default)]
+    libraries: Vec<JsonVersionLibrary>,
+}
+
+#[derive(Deserialize)]
+struct JsonVersionLibrary {
+    name: String,
+}
+
+async fn read_official(dir: &Path) -> Result<Vec<ForeignInstance>, InstancePackageError> {
+    let path = dir.join("launcher_profiles.json");
+    let json = fs::read_to_string(&path).await.path(&path)?;
+    let profiles: ProfileJson = serde_json::from_str(&json).json(json)?;
+
+    let mut manifest = None;
+    let mut instances = Vec::new();
+
+    for profile in profiles.profiles.into_values() {
+        let version_id = match profile.r#type.as_deref() {
+            Some(kind @ ("latest-release" | "latest-snapshot")) => {
+                if manifest.is_none() {
+                    manifest = Some(Manifest::download().await?);
+                }
+                let manifest = manifest.as_ref().unwrap();
+                let latest = if kind == "latest-release" {
+                    manifest.get_latest_release()
+                } else {
+                    manifest.get_latest_snapshot()
+                };
+                let Some(latest) = latest else {
+                    continue;
+                };
+                latest.id.clone()
+            }
+            _ if profile.lastVersionId.is_empty() => continue,
+            _ => profile.lastVersionId.clone(),
+        };
+
+        let (version, loader) = resolve_official_version(dir, &version_id).await?;
+        let (ram_in_mb, java_args) = split_java_args(profile.javaArgs.as_deref());
+
+        let name = if profile.name.is_empty() {
+            version_id
+        } else {
+            profile.name
+        };
+
+        let game_dir = profile
+            .gameDir
+            .map_or_else(|| dir.to_owned(), PathBuf::from);
+        instances.push(ForeignInstance {
+            name,
+            version,
+            loader,
+            shared_game_dir: game_dir == dir,
+            game_dir,
+            mods: HashMap::new(),
+            ram_in_mb,
+            java_args,
+            java_path: profile.javaDir,
+            resolution: profile
+                .resolution
+                .and_then(|n| Some((n.width.try_into().ok()?, n.height.try_into().ok()?))),
+        });
+    }
+
+    Ok(instances)
+}
+
+/// Figures out the Minecraft version and loader
+/// from an official launcher version id, like
+/// `fabric-loader-0.15.0-1.20.1` or `1.20.1-forge-47.2.0`.
+async fn resolve_official_version(
+    dir: &Path,
+    id: &str,
+) -> Result<(String, Option<(Loader, Option<String>)>), InstancePackageError> {
+    let path = dir.join("versions").join(id).join(format!("{id}.json"));
+    let json: Option<JsonVersionInherits> = if path.is_file() {
+        let json = fs::read_to_string(&path).await.path(&path)?;
+        Some(serde_json::from_str(&json).json(json)?)
+    } else {
+        None
+    };
+
+    let Some(mc_version) = json.as_ref().and_then(|n| n.inheritsFrom.clone()) else {
+        // Vanilla version (or one we can't read)
+        return Ok((id.to_owned(), None));
+    };
+
+    let library_version = |prefix: &str| {
+        json.iter()
+            .flat_map(|n| &n.libraries)
+            .find_map(|n| n.name.strip_prefix(prefix).map(str::to_owned))
+    };
+
+    let loader = if let Some(version) = library_version("net.fabricmc:fabric-loader:") {
+        Some((Loader::Fabric, Some(version)))
+    } else if let Some(version) = library_version("org.quiltmc:quilt-loader:") {
+        Some((Loader::Quilt, Some(version)))
+    } else if id.contains("neoforge") {
+        Some((Loader::Neoforge, get_forge_version(id, &mc_version)))
+    } else if id.to_lowercase().contains("forge") {
+        Some((Loader::Forge, get_forge_version(id, &mc_version)))
+    } else {
+        err!("Unknown loader for version {id}, importing as vanilla");
+        None
+    };
+
+    Ok((mc_version, loader))
+}
+
+#[derive(Deserialize)]
+#[allow(non_snake_case)]
+struct AtlInstance {
+    /// Minecraft version
+    id: String,
+    launcher: AtlLauncher,
+}
+
+#[derive(Deserialize)]
+#[allow(non_snake_case)]
+struct AtlLauncher {
+    name: String,
+    loaderVersion: Option<AtlLoaderVersion>,
+    maximumMemory: Option<usize>,
+    javaArguments: Option<String>,
+    javaPath: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct AtlLoaderVersion {
+    r#type: String,
+    version: String,
+}
+
+async fn read_atlauncher(dir: &Path) -> Result<Option<ForeignInstance>, InstancePackageError> {
+    let path = dir.join("instance.json");
+    if !path.is_file() {
+        return Ok(None);
+    }
+    let json = fs::read_to_string(&path).await.path(&path)?;
+    let Ok(instance) = serde_json::from_str::<AtlInstance>(&json) else {
+        return Ok(None);
+    };
+
+    let (ram_in_mb, java_args) = split_java_args(instance.launcher.javaArguments.as_deref());
+    Ok(Some(ForeignInstance {
+        loader: instance
+            .launcher
+            .loaderVersion
+            .and_then(|n| parse_loader(&n.r#type, Some(&n.version), &instance.id)),
+        name: instance.launcher.name,
+        version: instance.id,
+        game_dir: dir.to_owned(),
+        shared_game_dir: false,
+        mods: HashMap::new(),
+        ram_in_mb: instance.launcher.maximumMemory.or(ram_in_mb),
+        java_args,
+        java_path: instance.launcher.javaPath,
+        resolution: None,
+    }))
+}
+
+#[derive(Deserialize)]
+#[allow(non_snake_case)]
+struct CfInstance {
+    name: String,
+    gameVersion: String,
+    baseModLoader: Option<CfModLoader>,
+    allocatedMemory: Option<usize>,
+    javaArgsOverride: Option<String>,
+    #[serde(default)]
+    installedAddons: Vec<CfAddon>,
+}
+
+#[derive(Deserialize)]
+#[allow(non_snake_case)]
+struct CfAddon {
+    addonID: u32,
+    name: String,
+    installedFile: Option<CfAddonFile>,
+}
+
+#[derive(Deserialize)]
+#[allow(non_snake_case)]
+struct CfAddonFile {
+    id: i32,
+    displayName: String,
+    fileName: String,
+    /// Ends with `.disabled` if the mod is disabled
+    FileNameOnDisk: Option<String>,
+    #[serde(default)]
+    fileDate: String,
+    downloadUrl: Option<String>,
+    #[serde(default)]
+    gameVersion: Vec<String>,
+    #[serde(default)]
+    dependencies: Vec<CfAddonDependency>,
+}
+
+#[derive(Deserialize)]
+#[allow(non_snake_case)]
+struct CfAddonDependency {
+    addonId: u32,
+    /// `3` for required dependencies
+    r#type: u32,
+}
+
+#[derive(Deserialize)]
+struct CfModLoader {
+    /// Eg: `forge-47.2.0`, `fabric-0.15.3-1.20.1`
+    name: String,
+}
+
+async fn read_curseforge(dir: &Path) -> Result<ForeignInstance, InstancePackageError> {
+    let path = dir.join("minecraftinstance.json");
+    let json = fs::read_to_string(&path).await.path(&path)?;
+    let instance: CfInstance = serde_json::from_str(&json).json(json)?;
+
+    let loader = instance.baseModLoader.and_then(|n| {
+        let (kind, version) = n.name.split_once('-').unwrap_or((&n.name, ""));
+        parse_loader(kind, Some(version), &instance.gameVersion)
+    });
+    let (ram_in_mb, java_args) = split_java_args(instance.javaArgsOverride.as_deref());
+    let mods = read_curseforge_addons(&dir.join("mods"), instance.installedAddons);
+
+    Ok(ForeignInstance {
+        name: instance.name,
+        version: instance.gameVersion,
+        loader,
+        game_dir: dir.to_owned(),
+        shared_game_dir: false,
+        mods,
+        ram_in_mb: instance.allocatedMemory.or(ram_in_mb),
+        java_args,
+        java_path: None,
+        resolution: None,
+    })
+}
+
+/// Converts the Curseforge app's installed addons into
+/// mod index entries, for the mods that are actually
+/// present in `mods_dir` (skipping resource packs, etc).
+fn read_curseforge_addons(mods_dir: &Path, addons: Vec<CfAddon>) -> HashMap<String, ModConfig> {
+    let mut mods: HashMap<String, ModConfig> = addons
+        .into_iter()
+        .filter_map(|addon| {
+            let file = addon.installedFile?;
+            let on_disk = file.FileNameOnDisk.unwrap_or(file.fileName);
+            if !mods_dir.join(&on_disk).is_file() {
+                return None;
+            }
+            let (filename, enabled) = match on_disk.strip_suffix(".disabled") {
+                Some(n) => (n.to_owned(), false),
+                None => (on_disk, true),
+            };
+
+            let id = format!("CF:{}", addon.addonID);
+            let config = ModConfig {
+                name: addon.name,
+                manually_installed: true,
+                installed_version: file.displayName,
+                version_release_time: file.fileDate,
+                enabled,
+                description: String::new(),
+                icon_url: None,
+                project_source: SOURCE_ID_CURSEFORGE.to_owned(),
+                project_id: id.clone(),
+                curseforge_file_id: Some(file.id),
+                files: vec![ModFile {
+                    url: file.downloadUrl.unwrap_or_default(),
+                    filename,
+                    primary: true,
+                }],
+                supported_versions: file
+                    .gameVersion
+                    .into_iter()
+                    .filter(|n| n.contains('.'))
+                    .collect(),
+                dependencies: file
+                    .dependencies
+                    .iter()
+                    .filter(|n| n.r#type == 3)
+                    .map(|n| format!("CF:{}", n.addonId))
+                    .collect(),
+                dependents: HashSet::new(),
+            };
+            Some((id, config))
+        })
+        .collect();
+
+    // Only link up dependencies that were imported too
+    let links: Vec<(String, String)> = mods
+        .iter()
+        .flat_map(|(id, config)| {
+            config
+                .dependencies
+                .iter()
+                .map(move |dep| (id.clone(), dep.clone()))
+        })
+        .collect();
+    for (id, dep) in links {
+        if let Some(dependency) = mods.get_mut(&dep) {
+            dependency.dependents.insert(id);
+        } else if let Some(config) = mods.get_mut(&id) {
+            config.dependencies.remove(&dep);
+        }
+    }
+
+    mods
+}
+
+#[derive(Deserialize)]
+#[allow(non_snake_case)]
+struct GdConfig {
+    loader: GdLoader,
+    javaArgs: Option<String>,
+    javaMemory: Option<usize>,
+    resolution: Option<GdResolution>,
+}
+
+#[derive(Deserialize)]
+#[allow(non_snake_case)]
+struct GdLoader {
+    /// `vanilla`, `fabric`, `forge`, etc.
+    loaderType: String,
+    loaderVersion: Option<String>,
+    mcVersion: String,
+}
+
+#[derive(Deserialize)]
+struct GdResolution {
+    width: u32,
+    height: u32,
+}
+
+async fn read_gdlauncher(dir: &Path) -> Result<Option<ForeignInstance>, InstancePackageError> {
+    let path = dir.join("config.json");
+    if !path.is_file() {
+        return Ok(None);
+    }
+    let json = fs::read_to_string(&path).await.path(&path)?;
+    // QuantumLauncher instances have a `config.json` too,
+    // but without the `loader` field.
+    let Ok(config) = serde_json::from_str::<GdConfig>(&json) else {
+        return Ok(None);
+    };
+
+    let (ram_in_mb, java_args) = split_java_args(config.javaArgs.as_deref());
+    Ok(Some(ForeignInstance {
+        // GDLauncher uses the folder name as the instance name
+        name: dir
+            .file_name()
+            .map_or("Imported".to_owned(), |n| n.to_string_lossy().to_string()),
+        loader: parse_loader(
+            &config.loader.loaderType,
+            config.loader.loaderVersion.as_deref(),
+            &config.loader.mcVersion,
+        ),
+        version: config.loader.mcVersion,
+        game_dir: dir.to_owned(),
+        shared_game_dir: false,
+        mods: HashMap::new(),
+        ram_in_mb: config.javaMemory.or(ram_in_mb),
+        java_args,
+        java_path: None,
+        resolution: config.resolution.map(|n| (n.width, n.height)),
+    }))
+}
+
+/// Parses a loader name (eg: `Fabric`, `neoforge`) and version.
+/// The version may be prefixed/suffixed with the Minecraft
+/// version (eg: `1.20.1-47.2.0`, `0.15.3-1.20.1`), which is removed.
+fn parse_loader(
+    kind: &str,
+    version: Option<&str>,
+    mc_version: &str,
+) -> Option<(Loader, Option<String>)> {
+    let loader = match kind.to_lowercase().as_str() {
+        "fabric" => Loader::Fabric,
+        "quilt" => Loader::Quilt,
+        "forge" => Loader::Forge,
+        "neoforge" => Loader::Neoforge,
+        "vanilla" | "" => return None,
+        _ => {
+            err!("Unsupported loader {kind}, importing as vanilla");
+            return None;
+        }
+    };
+    let version = version.filter(|n| !n.is_empty()).map(|version| {
+        let version = version
+            .strip_prefix(&format!("{mc_version}-"))
+            .unwrap_or(version);
+        version
+            .strip_suffix(&format!("-{mc_version}"))
+            .unwrap_or(version)
+            .to_owned()
+    });
+    Some((loader, version))
+}
+
+/// Splits Java arguments, pulling out the max memory (`-Xmx`)
+/// since that's stored separately (as `ram_in_mb`).
+fn split_java_args(args: Option<&str>) -> (Option<usize>, Vec<String>) {
+    let mut ram = None;
+    let args = args
+        .unwrap_or_default()
+        .split_whitespace()
+        .filter(|arg| {
+            let Some(amount) = arg.strip_prefix("-Xmx") else {
+                return true;
+            };
+            let (number, multiplier) = match amount.chars().last() {
+                Some('G' | 'g') => (&amount[..amount.len() - 1], 1024),
+                Some('M' | 'm') => (&amount[..amount.len() - 1], 1),
+                _ => return true,
+            };
+            let Ok(number) = number.parse::<usize>() else {
+                return true;
+            };
+            ram = Some(number * multiplier);
+            false
+        })
+        .map(str::to_owned)
+        .collect();
+    (ram, args)
+}
+
+/// Avoids clashing with existing instances by adding
+/// a number to the end, eg: `Modpack (2)`.
+fn get_unique_name(name: &str) -> String {
+    let name: String = name
+        .chars()
+        .map(|c| if matches!(c, '/' | '\\') { '-' } else { c })
+        .collect();
+    let name = name.trim();
+    let name = if name.is_empty() { "Imported" } else { name };
+
+    let instances_dir = LAUNCHER_DIR.join("instances");
+    let mut candidate = name.to_owned();
+    let mut i = 2;
+    while instances_dir.join(&candidate).exists() {
+        candidate = format!("{name} ({i})");
+        i += 1;
+    }
+    candidate
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse_loader, read_curseforge_addons, split_java_args, CfInstance};
+
+    #[test]
+    fn loader_versions() {
+        let cases = [
+            ("forge", "1.20.1-47.2.0", "47.2.0"),
+            ("fabric", "0.15.3-1.20.1", "0.15.3"),
+            ("NeoForge", "20.4.80", "20.4.80"),
+        ];
+        for (kind, version, expected) in cases {
+            let (_, version) = parse_loader(kind, Some(version), "1.20.1").unwrap();
+            assert_eq!(version.as_deref(), Some(expected));
+        }
+        assert!(parse_loader("vanilla", None, "1.20.1").is_none());
+    }
+
+    #[test]
+    fn java_args_memory() {
+        let (ram, args) = split_java_args(Some("-Xmx4G -XX:+UseG1GC -Xms512M"));
+        assert_eq!(ram, Some(4096));
+        assert_eq!(args, ["-XX:+UseG1GC", "-Xms512M"]);
+    }
+
+    #[test]
+    fn curseforge_addons() {
+        let dir = tempfile::TempDir::new().unwrap();
+        std::fs::write(dir.path().join("jei.jar"), []).unwrap();
+        std::fs::write(dir.path().join("lib.jar.disabled"), []).unwrap();
+
+        let addon = |id: u32, file: &str, deps: &[u32]| {
+            serde_json::json!({
+                "addonID": id,
+                "name": file,
+                "installedFile": {
+                    "id": id * 10,
+                    "displayName": file,
+                    "fileName": file,
+                    "FileNameOnDisk": file,
+                    "fileDate": "2024-01-01T00:00:00Z",
+                    "downloadUrl": format!("https://edge.forgecdn.net/files/1/2/{file}"),
+                    "gameVersion": ["1.20.1", "Forge"],
+                    "dependencies": deps
+                        .iter()
+                        .map(|n| serde_json::json!({ "addonId": n, "type": 3 }))
+                        .collect::<Vec<_>>(),
+                },
+            })
+        };
+        let instance: CfInstance = serde_json::from_value(serde_json::json!({
+            "name": "Pack",
+            "gameVersion": "1.20.1",
+            "installedAddons": [
+                addon(1, "jei.jar", &[2, 99]),
+                addon(2, "lib.jar.disabled", &[]),
+                // A resource pack, not in `mods/`
+                addon(3, "pack.zip", &[]),
+            ],
+        }))
+        .unwrap();
+
+        let mods = read_curseforge_addons(dir.path(), instance.installedAddons);
+        assert_eq!(mods.len(), 2);
+
+        let jei = &mods["CF:1"];
+        assert!(jei.enabled);
+        assert_eq!(jei.curseforge_file_id, Some(10));
+        assert_eq!(jei.supported_versions, ["1.20.1"]);
+        assert_eq!(jei.dependencies.iter().collect::<Vec<_>>(), ["CF:2"]);
+
+        let lib = &mods["CF:2"];
+        assert!(!lib.enabled);
+        assert_eq!(lib.files[0].filename, "lib.jar");
+        assert_eq!(lib.dependents.iter().collect::<Vec<_>>(), ["CF:1"]);
+    }
+}
@@ -120,6 +120,11 @@ async fn write_config(
         // This won't do anything on servers. Who wants to lose their *only way*
         // to control the server instantly after starting it?
         close_on_start: None,
+        last_account: None,
+        group: None,
+        is_favorite: None,
+        color: None,
+        notes: None,
     };
     let server_config_path = server_dir.join("config.json");
     tokio::fs::write(
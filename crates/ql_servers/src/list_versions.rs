@@ -45,8 +45,8 @@ pub async fn list() -> Result<Vec<ListEntry>, JsonDownloadError> {
                 }
 
                 return Some(ListEntry {
-                    name: n.id,
                     is_classic_server: true,
+                    ..ListEntry::from(n)
                 });
             }
             if n.id.starts_with("a1.") {
@@ -64,10 +64,7 @@ pub async fn list() -> Result<Vec<ListEntry>, JsonDownloadError> {
                 }
             }
 
-            Some(ListEntry {
-                name: n.id,
-                is_classic_server: false,
-            })
+            Some(ListEntry::from(n))
         })
         .collect())
 }
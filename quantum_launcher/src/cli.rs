@@ -1,15 +1,28 @@
-use clap::Command;
+use clap::{Arg, ArgAction, Command};
 use colored::Colorize;
 use ql_core::{
-    err_no_log,
+    download, err_no_log,
     json::{instance_config::InstanceConfigJson, version::VersionDetails},
-    IntoStringError, LAUNCHER_DIR, LAUNCHER_VERSION_NAME,
+    InstanceSelection, IntoStringError, ModId, VersionType, LAUNCHER_DIR, LAUNCHER_VERSION_NAME,
 };
-use std::io::Write;
+use ql_instances::{
+    auth::{self, AccountData},
+    LogLine, VersionFilter,
+};
+use std::{
+    collections::HashMap,
+    io::Write,
+    path::PathBuf,
+    process::ExitStatus,
+    sync::{Arc, Mutex},
+};
+use tokio::process::Child;
 
 use crate::{
+    config::{ConfigAccount, LauncherConfig},
     menu_renderer::{DISCORD, GITHUB},
-    state::get_entries,
+    single_instance,
+    state::{get_entries, load_account, Res},
 };
 
 fn command() -> Command {
@@ -34,7 +47,87 @@ fn command() -> Command {
             .about("Lists all installed Minecraft servers")
             .long_about("Lists all installed Minecraft servers. Can be paired with hyphen-separated-flags like name-loader, name-version, loader-name-version"),
     )
-    .subcommand(Command::new("list-available-versions").short_flag('a').about("Lists all downloadable versions, downloading a list from Mojang/Omniarchive"))
+    .subcommand(
+        Command::new("list-available-versions")
+            .short_flag('a')
+            .about("Lists all downloadable versions, downloading a list from Mojang/Omniarchive")
+            .arg(
+                Arg::new("type")
+                    .long("type")
+                    .short('t')
+                    .action(ArgAction::Append)
+                    .value_parser(clap::value_parser!(VersionType))
+                    .help("Only list versions of this type (release, snapshot, beta, alpha). Can be repeated"),
+            )
+            .arg(
+                Arg::new("since")
+                    .long("since")
+                    .help("Only list versions released at or after this version (eg: 1.16)"),
+            )
+            .arg(
+                Arg::new("search")
+                    .long("search")
+                    .help("Only list versions whose name contains this"),
+            )
+            .arg(
+                Arg::new("details")
+                    .long("details")
+                    .short('d')
+                    .action(ArgAction::SetTrue)
+                    .help("Also print the type, release date and source (tab-separated)"),
+            ),
+    )
+    .subcommand(
+        Command::new("account")
+            .about("Manages logged-in accounts")
+            .subcommand_required(true)
+            .subcommand(Command::new("list").about("Lists all accounts, and whether they need to log in again"))
+            .subcommand(
+                Command::new("login")
+                    .about("Logs into a Microsoft account (with a code entered in the browser)")
+                    .arg(
+                        Arg::new("timeout")
+                            .long("timeout")
+                            .value_parser(clap::value_parser!(u64))
+                            .help("Give up after this many seconds (default: until the code expires)"),
+                    ),
+            )
+            .subcommand(
+                Command::new("refresh")
+                    .about("Refreshes an account's login, to check if it's still valid")
+                    .arg(Arg::new("name").required(true).help("The account, as shown by `account list`")),
+            ),
+    )
+    .arg(
+        Arg::new("offline")
+            .long("offline")
+            .action(ArgAction::SetTrue)
+            .global(true)
+            .help("Don't use the internet (uses cached version lists, skips Java installs and update checks)"),
+    )
+    .arg(
+        Arg::new("launch")
+            .long("launch")
+            .value_name("INSTANCE")
+            .help("Launches an instance without opening the launcher, using the account it was last played with (or the offline username)"),
+    )
+    .arg(
+        Arg::new("open")
+            .value_name("LINK_OR_FILE")
+            // So mistyped subcommands aren't taken as links
+            .value_parser(|n: &str| {
+                if n.starts_with(&format!("{URL_SCHEME}:")) {
+                    return Ok(n.to_owned());
+                }
+                // Absolute, since it may be sent to an already open launcher
+                std::fs::canonicalize(n)
+                    .ok()
+                    .filter(|n| n.is_file())
+                    .map(|n| n.to_string_lossy().into_owned())
+                    .ok_or_else(|| format!("not a {URL_SCHEME}:// link or a file"))
+            })
+            .help("Opens a quantumlauncher:// link (like quantumlauncher://install-mod?modrinth=<id>&instance=<name>), or a modpack/preset file to import"),
+    )
     .subcommand(Command::new("--no-sandbox").hide(true)) // This one doesn't do anything, but on Windows i686 it's automatically passed?
 }
 
@@ -58,8 +151,12 @@ fn get_list_instance_subcommands(name: &'static str) -> Command {
         .subcommand(Command::new("loader-version-name"))
 }
 
-fn cmd_list_available_versions() {
+fn cmd_list_available_versions(matches: &clap::ArgMatches) {
     eprintln!("Listing downloadable versions...");
+    // For the network and mirror settings
+    if let Err(err) = LauncherConfig::load_s() {
+        err_no_log!("Couldn't load launcher config: {err}");
+    }
     let versions = match tokio::runtime::Runtime::new()
         .unwrap()
         .block_on(ql_instances::list_versions())
@@ -71,12 +168,438 @@ fn cmd_list_available_versions() {
         }
     };
 
+    let filter = VersionFilter {
+        kinds: matches
+            .get_many::<VersionType>("type")
+            .map(|n| n.copied().collect())
+            .unwrap_or_default(),
+        since: matches.get_one::<String>("since").cloned(),
+        search: matches
+            .get_one::<String>("search")
+            .cloned()
+            .unwrap_or_default(),
+    };
+    if !filter.is_since_valid(&versions) {
+        let since = filter.since.as_deref().unwrap_or_default();
+        err_no_log!("Unknown version for --since: {since}");
+        std::process::exit(1);
+    }
+    let show_details = matches.get_flag("details");
+
     let mut stdout = std::io::stdout().lock();
-    for version in versions {
-        writeln!(stdout, "{version}").unwrap();
+    for version in filter.apply(&versions) {
+        if show_details {
+            let date = version
+                .release_time
+                .map(|n| n.format("%Y-%m-%d").to_string())
+                .unwrap_or_default();
+            writeln!(
+                stdout,
+                "{version}\t{}\t{date}\t{}",
+                version.kind, version.source
+            )
+            .unwrap();
+        } else {
+            writeln!(stdout, "{version}").unwrap();
+        }
+    }
+}
+
+fn cmd_account(matches: &clap::ArgMatches) {
+    // `list` only reads the config, so it's fine with the launcher open
+    let _lock = if matches.subcommand_name() == Some("list") {
+        None
+    } else {
+        match single_instance::lock_headless(None) {
+            Ok(n) => n,
+            Err(err) => {
+                err_no_log!("{err}");
+                std::process::exit(1);
+            }
+        }
+    };
+    let mut config = match LauncherConfig::load_s() {
+        Ok(n) => n,
+        Err(err) => {
+            err_no_log!("Couldn't load launcher config: {err}");
+            std::process::exit(1);
+        }
+    };
+    let runtime = tokio::runtime::Runtime::new().unwrap();
+
+    let result = match matches.subcommand() {
+        Some(("list", _)) => {
+            let mut stdout = std::io::stdout().lock();
+            for (name, account) in config.accounts.iter().flatten() {
+                let account_type = account.account_type.as_deref().unwrap_or("Microsoft");
+                let status = if account.needs_relogin.unwrap_or_default() {
+                    "\tneeds login".to_owned()
+                } else {
+                    String::new()
+                };
+                writeln!(stdout, "{name}\t{account_type}{status}").unwrap();
+            }
+            return;
+        }
+        Some(("login", args)) => {
+            let timeout = args.get_one::<u64>("timeout").copied();
+            runtime.block_on(cmd_account_login(&mut config, timeout))
+        }
+        Some(("refresh", args)) => {
+            let name = args.get_one::<String>("name").unwrap();
+            runtime.block_on(cmd_account_refresh(&mut config, name))
+        }
+        _ => unreachable!(),
+    };
+
+    let saved = runtime.block_on(config.save()).strerr();
+    if let Err(err) = result.and(saved) {
+        err_no_log!("{err}");
+        std::process::exit(1);
+    }
+}
+
+async fn cmd_account_login(config: &mut LauncherConfig, timeout: Option<u64>) -> Res {
+    let code = auth::ms::login_1_link().await.strerr()?;
+    println!("{}", code.message);
+
+    let wait = auth::ms::login_2_wait(code);
+    let token = if let Some(timeout) = timeout {
+        tokio::time::timeout(std::time::Duration::from_secs(timeout), wait)
+            .await
+            .map_err(|_| auth::ms::Error::LoginTimedOut.to_string())?
+    } else {
+        wait.await
+    }
+    .strerr()?;
+    let account = auth::ms::login_3_xbox(token, None, true).await.strerr()?;
+
+    let name = account.get_username_modified();
+    config.accounts.get_or_insert_default().insert(
+        name.clone(),
+        ConfigAccount {
+            uuid: account.uuid,
+            skin: None,
+            account_type: Some(account.account_type.to_string()),
+            auth_server: None,
+            username_nice: Some(account.nice_username),
+            needs_relogin: None,
+        },
+    );
+    println!("Logged in as {name}");
+    Ok(())
+}
+
+async fn cmd_account_refresh(config: &mut LauncherConfig, name: &str) -> Res {
+    let Some(config_account) = config.accounts.as_mut().and_then(|n| n.get_mut(name)) else {
+        return Err(format!("No account named {name} (see `account list`)"));
+    };
+
+    let mut accounts = HashMap::new();
+    let mut to_remove = Vec::new();
+    load_account(
+        &mut accounts,
+        &mut Vec::new(),
+        &mut to_remove,
+        name,
+        config_account,
+    );
+    let Some(account) = accounts.remove(name) else {
+        return Err(format!(
+            "Couldn't read the login of {name} from the keyring"
+        ));
+    };
+
+    match auth::lifecycle::refresh(&account, None).await {
+        Ok(_) => {
+            config_account.needs_relogin = None;
+            println!("{name}: login is valid");
+            Ok(())
+        }
+        Err(err) => {
+            if err.revoked {
+                config_account.needs_relogin = Some(true);
+            }
+            Err(err.to_string())
+        }
+    }
+}
+
+fn cmd_launch(instance: &str) {
+    // Let the open launcher play it, if there is one
+    let lock = match single_instance::lock_headless(Some(&single_instance::Request::Launch(
+        instance.to_owned(),
+    ))) {
+        Ok(n) => n,
+        Err(err) => {
+            err_no_log!("{err}");
+            std::process::exit(1);
+        }
+    };
+    let mut config = match LauncherConfig::load_s() {
+        Ok(n) => n,
+        Err(err) => {
+            err_no_log!("Couldn't load launcher config: {err}");
+            std::process::exit(1);
+        }
+    };
+    let runtime = tokio::runtime::Runtime::new().unwrap();
+
+    let child = runtime.block_on(cmd_launch_w(&mut config, instance));
+    // For any account that needs to log in again
+    if let Err(err) = runtime.block_on(config.save()) {
+        err_no_log!("Couldn't save launcher config: {err}");
+    }
+    // Done with the launcher's files, so it can
+    // be opened while the game is running
+    drop(lock);
+
+    let result = child.and_then(|child| runtime.block_on(wait_for_game(child, instance)));
+    match result {
+        Ok(status) => std::process::exit(status.code().unwrap_or_default()),
+        Err(err) => {
+            err_no_log!("{err}");
+            std::process::exit(1);
+        }
     }
 }
 
+async fn cmd_launch_w(config: &mut LauncherConfig, instance: &str) -> Res<Arc<Mutex<Child>>> {
+    let selection = InstanceSelection::Instance(instance.to_owned());
+    if !selection.get_instance_path().is_dir() {
+        return Err(format!(
+            "No instance named {instance} (see `list-instances`)"
+        ));
+    }
+    let instance_config = InstanceConfigJson::read(&selection).await.strerr()?;
+    let (username, account) = get_launch_account(config, instance_config.last_account).await?;
+
+    ql_instances::launch(instance.to_owned(), username, None, account)
+        .await
+        .strerr()
+}
+
+async fn wait_for_game(child: Arc<Mutex<Child>>, instance: &str) -> Res<ExitStatus> {
+    let (stdout, stderr) = {
+        let mut child = child.lock().unwrap();
+        (child.stdout.take(), child.stderr.take())
+    };
+    let (Some(stdout), Some(stderr)) = (stdout, stderr) else {
+        // The log system is disabled, so the game prints to our terminal
+        loop {
+            if let Some(status) = child.lock().unwrap().try_wait().strerr()? {
+                return Ok(status);
+            }
+            tokio::time::sleep(std::time::Duration::from_millis(500)).await;
+        }
+    };
+
+    let (sender, receiver) = std::sync::mpsc::channel();
+    std::thread::spawn(move || {
+        for line in receiver {
+            match line {
+                LogLine::Info(event) => print!("{event}"),
+                line => println!("{line}"),
+            }
+        }
+    });
+    let (status, _) = ql_instances::read_logs(stdout, stderr, child, sender, instance.to_owned())
+        .await
+        .strerr()?;
+    Ok(status)
+}
+
+/// Picks the account to launch with: the one the instance
+/// was last played with, otherwise the offline username
+/// (like the launcher, which starts with offline selected).
+async fn get_launch_account(
+    config: &mut LauncherConfig,
+    last_account: Option<String>,
+) -> Res<(String, Option<AccountData>)> {
+    let Some(account_name) = last_account else {
+        if config.username.is_empty() {
+            return Err(
+                "No username to launch with, open the launcher and set one first".to_owned(),
+            );
+        }
+        return Ok((config.username.clone(), None));
+    };
+    if let Some(username) = account_name.strip_prefix("Offline:") {
+        return Ok((username.to_owned(), None));
+    }
+
+    let Some(config_account) = config
+        .accounts
+        .as_mut()
+        .and_then(|n| n.get_mut(&account_name))
+    else {
+        return Err(format!(
+            "The account {account_name} was removed, open the launcher to pick another one"
+        ));
+    };
+    let mut accounts = HashMap::new();
+    load_account(
+        &mut accounts,
+        &mut Vec::new(),
+        &mut Vec::new(),
+        &account_name,
+        config_account,
+    );
+    let Some(account) = accounts.remove(&account_name) else {
+        return Err(format!(
+            "Couldn't read the login of {account_name} from the keyring"
+        ));
+    };
+
+    if download::is_offline() {
+        // Can't refresh, so play with just the username
+        return Ok((account.nice_username, None));
+    }
+    match auth::lifecycle::refresh(&account, None).await {
+        Ok(account) => {
+            config_account.needs_relogin = None;
+            Ok((account.nice_username.clone(), Some(account)))
+        }
+        Err(err) => {
+            if err.revoked {
+                config_account.needs_relogin = Some(true);
+            }
+            Err(format!(
+                "Couldn't refresh the login of {account_name} (try `account refresh`):\n{err}"
+            ))
+        }
+    }
+}
+
+/// The scheme of links that the launcher opens
+/// (registered by `assets/freedesktop/quantum-launcher.desktop`
+/// on Linux, and on startup on Windows).
+///
+/// Not supported on macOS for now: links arrive there as an
+/// Apple event (`application:openURLs:`) rather than as an
+/// argument, and winit doesn't pass those on. So
+/// `assets/freedesktop/Info.plist` doesn't declare the scheme
+/// (`CFBundleURLTypes`), to not have links silently do nothing.
+pub const URL_SCHEME: &str = "quantumlauncher";
+
+/// Something to install, requested by a `quantumlauncher://` link
+/// (from a mod page, the Discord bot, the wiki, etc.)
+/// or by opening a file with the launcher.
+///
+/// This is always confirmed by the user before doing anything.
+#[derive(Debug, Clone)]
+pub enum UrlAction {
+    /// `install-mod?modrinth=<id>` or `install-mod?curseforge=<id>`,
+    /// optionally with `&instance=<name>`
+    Mod { id: ModId, instance: Option<String> },
+    /// `install-pack?url=<link to .mrpack or curseforge .zip>`,
+    /// optionally with `&instance=<name>`
+    Pack {
+        url: String,
+        instance: Option<String>,
+    },
+    /// A mod, modpack or preset file
+    /// (`.jar`, `.zip`, `.mrpack` or `.qmp`)
+    File { path: PathBuf },
+}
+
+impl UrlAction {
+    /// Parses a `quantumlauncher://` link or a file path.
+    pub fn parse(link: &str) -> Result<Self, String> {
+        if !link.starts_with(&format!("{URL_SCHEME}:")) {
+            let path = PathBuf::from(link);
+            let is_supported = path
+                .extension()
+                .and_then(|n| n.to_str())
+                .is_some_and(|n| ["jar", "zip", "mrpack", "qmp"].contains(&n));
+            return if !path.is_file() {
+                Err(format!("Not a {URL_SCHEME}:// link or a file: {link}"))
+            } else if is_supported {
+                Ok(Self::File { path })
+            } else {
+                Err(format!(
+                    "Can't open {link}\n(only .jar, .zip, .mrpack and .qmp files are supported)"
+                ))
+            };
+        }
+        let link = url::Url::parse(link).map_err(|err| format!("Invalid link {link:?}: {err}"))?;
+        if link.scheme() != URL_SCHEME {
+            return Err(format!(
+                "Not a {URL_SCHEME}:// link: {link} (scheme {})",
+                link.scheme()
+            ));
+        }
+        let params: HashMap<String, String> = link.query_pairs().into_owned().collect();
+        let instance = params
+            .get("instance")
+            .map(|n| check_instance_name(n))
+            .transpose()?;
+
+        // `quantumlauncher://install-mod?...` has `install-mod` as the host,
+        // while `quantumlauncher:install-mod?...` has it as the path.
+        let action = link
+            .host_str()
+            .unwrap_or_else(|| link.path())
+            .trim_matches('/');
+        match action {
+            "install-mod" => {
+                let id = match (params.get("modrinth"), params.get("curseforge")) {
+                    (Some(id), None) if !id.is_empty() => ModId::Modrinth(id.clone()),
+                    (None, Some(id)) if id.parse::<u64>().is_ok() => ModId::Curseforge(id.clone()),
+                    (None, Some(id)) => return Err(format!("Invalid Curseforge mod id {id:?}")),
+                    _ => {
+                        return Err(
+                            "install-mod links need either a modrinth or curseforge mod id"
+                                .to_owned(),
+                        )
+                    }
+                };
+                Ok(Self::Mod { id, instance })
+            }
+            "install-pack" => {
+                let url = params
+                    .get("url")
+                    .ok_or("install-pack links need the url of the pack")?;
+                let scheme = url::Url::parse(url)
+                    .map_err(|err| format!("Invalid modpack url {url:?}: {err}"))?
+                    .scheme()
+                    .to_owned();
+                if scheme != "https" {
+                    return Err(format!("Modpack url must be https, got {url:?}"));
+                }
+                Ok(Self::Pack {
+                    url: url.clone(),
+                    instance,
+                })
+            }
+            action => Err(format!("Unknown {URL_SCHEME}:// action {action:?}")),
+        }
+    }
+
+    pub fn instance(&self) -> Option<&str> {
+        match self {
+            UrlAction::Mod { instance, .. } | UrlAction::Pack { instance, .. } => {
+                instance.as_deref()
+            }
+            UrlAction::File { .. } => None,
+        }
+    }
+}
+
+/// Links come from anywhere on the web, so the instance
+/// must be a plain folder name inside `instances/`.
+fn check_instance_name(name: &str) -> Result<String, String> {
+    let mut components = std::path::Path::new(name).components();
+    let is_plain = matches!(
+        (components.next(), components.next()),
+        (Some(std::path::Component::Normal(_)), None)
+    );
+    if !is_plain || name.contains(['/', '\\']) || name.contains("..") {
+        return Err(format!("Invalid instance name {name:?}"));
+    }
+    Ok(name.to_owned())
+}
+
 fn long_about() -> String {
     format!(
         r"
@@ -255,9 +778,25 @@ fn get_side_text() -> (String, usize) {
     (text, text_len_old)
 }
 
-pub fn start_cli(is_dir_err: bool) {
+/// Handles the command-line arguments, exiting
+/// if a command was run.
+///
+/// Returns the `quantumlauncher://` link or the (absolute)
+/// path of the file the launcher was opened with, if any.
+/// See [`UrlAction::parse`].
+pub fn start_cli(is_dir_err: bool) -> Option<String> {
     let command = command();
     let matches = command.clone().get_matches();
+    if matches.get_flag("offline") {
+        download::set_offline(true);
+    }
+    if let Some(instance) = matches.get_one::<String>("launch") {
+        if is_dir_err {
+            std::process::exit(1);
+        }
+        cmd_launch(instance);
+    }
+    let open = matches.get_one::<String>("open").cloned();
 
     if let Some(subcommand) = matches.subcommand() {
         if is_dir_err {
@@ -275,7 +814,11 @@ pub fn start_cli(is_dir_err: bool) {
                 std::process::exit(0);
             }
             "list-available-versions" => {
-                cmd_list_available_versions();
+                cmd_list_available_versions(subcommand.1);
+                std::process::exit(0);
+            }
+            "account" => {
+                cmd_account(subcommand.1);
                 std::process::exit(0);
             }
             "--no-sandbox" => {
@@ -286,6 +829,7 @@ pub fn start_cli(is_dir_err: bool) {
     } else {
         print_intro();
     }
+    open
 }
 
 fn get_list_instance_subcommand(subcommand: (&str, &clap::ArgMatches)) -> Vec<PrintCmd> {
@@ -306,3 +850,49 @@ fn get_list_instance_subcommand(subcommand: (&str, &clap::ArgMatches)) -> Vec<Pr
         vec![PrintCmd::Name]
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use ql_core::ModId;
+
+    use super::UrlAction;
+
+    #[test]
+    fn parse_links() {
+        let UrlAction::Mod { id, instance } =
+            UrlAction::parse("quantumlauncher://install-mod?modrinth=AANobbMI&instance=My%20Pack")
+                .unwrap()
+        else {
+            panic!("expected install-mod");
+        };
+        assert_eq!(id, ModId::Modrinth("AANobbMI".to_owned()));
+        assert_eq!(instance.as_deref(), Some("My Pack"));
+
+        assert!(matches!(
+            UrlAction::parse("quantumlauncher:install-pack?url=https://example.com/a.mrpack"),
+            Ok(UrlAction::Pack { instance: None, .. })
+        ));
+
+        assert!(UrlAction::parse("quantumlauncher://install-mod?curseforge=abc").is_err());
+        assert!(UrlAction::parse("quantumlauncher://install-pack?url=file:///etc/passwd").is_err());
+        assert!(
+            UrlAction::parse("quantumlauncher://install-pack?url=http://example.com/a.mrpack")
+                .is_err()
+        );
+        for instance in ["..", "../..", "a/b", "a%5Cb", "%2Fetc", "", "C:%5Cx"] {
+            assert!(
+                UrlAction::parse(&format!(
+                    "quantumlauncher://install-mod?modrinth=AANobbMI&instance={instance}"
+                ))
+                .is_err(),
+                "{instance}"
+            );
+        }
+        assert!(UrlAction::parse("quantumlauncher://delete-everything").is_err());
+        assert!(UrlAction::parse("https://install-mod?modrinth=AANobbMI").is_err());
+
+        // Files (tests run from the crate folder)
+        assert!(UrlAction::parse("Cargo.toml").is_err());
+        assert!(UrlAction::parse("missing.mrpack").is_err());
+    }
+}
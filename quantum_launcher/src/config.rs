@@ -1,5 +1,8 @@
 use ql_core::{
-    err, IntoIoError, IntoJsonError, JsonFileError, LAUNCHER_DIR, LAUNCHER_VERSION_NAME,
+    download, err,
+    mirror::{self, MirrorConfig},
+    network::{self, NetworkConfig, ProxyConfig},
+    IntoIoError, IntoJsonError, JsonFileError, LAUNCHER_DIR, LAUNCHER_VERSION_NAME,
 };
 use serde::{Deserialize, Serialize};
 use std::{collections::HashMap, path::Path};
@@ -47,6 +50,9 @@ pub struct LauncherConfig {
     /// - Brown
     /// - Sky Blue
     /// - Catppuccin
+    /// - Teal
+    /// - The name of a custom theme in `QuantumLauncher/themes/`
+    ///   (see [`crate::stylesheet::custom`])
     pub style: Option<String>,
 
     /// The version that the launcher was last time
@@ -66,7 +72,7 @@ pub struct LauncherConfig {
     ///
     /// Implemented in v0.4
     ///
-    /// `String (username) : ConfigAccount { uuid: String, skin: None (unimplemented) }`
+    /// `String (username) : ConfigAccount { uuid: String, skin: Option<String> }`
     ///
     /// Upon opening the launcher,
     /// [`ql_instances::read_refresh_token`]`(username)`
@@ -83,6 +89,69 @@ pub struct LauncherConfig {
     /// - `1.0` is the default value.
     /// - `(0.x-1.0)` A lower number means zoomed out UI elements.
     pub ui_scale: Option<f64>,
+
+    /// How many files can be downloaded at the same time.
+    ///
+    /// Implemented in v0.4.3
+    ///
+    /// `None` means the default
+    /// ([`ql_core::download::DEFAULT_MAX_CONCURRENT`]).
+    /// Lowering this helps on slow or shared networks.
+    pub download_concurrency: Option<usize>,
+    /// The maximum total download speed, in KB/s.
+    ///
+    /// Implemented in v0.4.3
+    ///
+    /// `None` or `0` means unlimited.
+    pub download_speed_limit: Option<u64>,
+    /// Mirrors to download from instead of the official
+    /// Mojang/Fabric/Forge/etc. servers.
+    ///
+    /// Implemented in v0.4.3
+    ///
+    /// See [`ql_core::mirror`] for the format.
+    /// `None` means only the official servers are used.
+    pub mirrors: Option<MirrorConfig>,
+    /// Don't use the internet at all. Version lists and
+    /// other metadata are loaded from the cache instead,
+    /// and Java installs, account refreshes and update
+    /// checks are skipped.
+    ///
+    /// Implemented in v0.4.3
+    ///
+    /// Can also be turned on for one session
+    /// with the `--offline` command-line flag.
+    pub offline_mode: Option<bool>,
+    /// Proxy, extra CA certificates, timeout and user agent
+    /// for all network traffic. The proxy is also
+    /// passed to the game.
+    ///
+    /// Implemented in v0.4.3
+    ///
+    /// Only applied on startup (see [`ql_core::network`]).
+    pub network: Option<NetworkConfig>,
+    /// Saved offline usernames, to switch between
+    /// them quickly. The current one is [`Self::username`].
+    ///
+    /// Implemented in v0.4.3
+    pub offline_profiles: Option<Vec<String>>,
+    /// Instance groups that are collapsed in the sidebar
+    /// (see [`crate::state::SidebarSection::config_key`]).
+    ///
+    /// Implemented in v0.4.3
+    pub sidebar_collapsed: Option<Vec<String>>,
+    /// Keyboard shortcut overrides, from action to binding
+    /// (eg. `"launch": "Ctrl+Enter"`). An empty binding
+    /// disables the shortcut. See [`crate::keybinds::KeyAction`]
+    /// for the actions, and press F1 in the launcher to see them all.
+    ///
+    /// Implemented in v0.4.3
+    pub keybinds: Option<HashMap<String, String>>,
+    /// The UI language (eg. `en-US`).
+    /// `None` uses the system language.
+    ///
+    /// Implemented in v0.4.3
+    pub language: Option<String>,
 }
 
 impl Default for LauncherConfig {
@@ -97,6 +166,15 @@ impl Default for LauncherConfig {
             accounts: None,
             ui_scale: None,
             java_installs: Some(Vec::new()),
+            download_concurrency: None,
+            download_speed_limit: None,
+            mirrors: None,
+            offline_mode: None,
+            network: None,
+            offline_profiles: None,
+            sidebar_collapsed: None,
+            keybinds: None,
+            language: None,
         }
     }
 }
@@ -132,10 +210,41 @@ impl LauncherConfig {
             }
         }
 
+        if let Some(proxy) = config.network.as_mut().and_then(|n| n.proxy.as_mut()) {
+            if load_proxy_password(proxy) {
+                // Don't leave the plain text password in the config
+                std::fs::write(&config_path, serde_json::to_string(&config).json_to()?)
+                    .path(&config_path)?;
+            }
+        }
+        network::set_config(config.network.clone().unwrap_or_default());
+        config.apply_download_settings();
+        if config.offline_mode.unwrap_or(false) {
+            // Not turned off otherwise, to keep the `--offline` flag working
+            download::set_offline(true);
+        }
         Ok(config)
     }
 
+    /// Applies the download concurrency, speed
+    /// limit and mirrors to the download manager.
+    pub fn apply_download_settings(&self) {
+        download::set_max_concurrent(
+            self.download_concurrency
+                .unwrap_or(download::DEFAULT_MAX_CONCURRENT),
+        );
+        download::set_bandwidth_limit(
+            self.download_speed_limit
+                .filter(|n| *n > 0)
+                .map(|n| n * 1024),
+        );
+        mirror::set_mirrors(self.mirrors.clone().unwrap_or_default());
+    }
+
     pub async fn save(&self) -> Result<(), JsonFileError> {
+        if let Some(proxy) = self.network.as_ref().and_then(|n| n.proxy.as_ref()) {
+            save_proxy_password(proxy);
+        }
         let config_path = LAUNCHER_DIR.join("config.json");
         let config = serde_json::to_string(&self).json_to()?;
 
@@ -152,6 +261,38 @@ impl LauncherConfig {
     }
 }
 
+/// Reads the proxy password from the keyring.
+///
+/// Configs from older versions have it in plain text instead,
+/// in which case it's moved to the keyring and this returns `true`
+/// if that worked (the config should be saved again, without it).
+fn load_proxy_password(proxy: &mut ProxyConfig) -> bool {
+    let Some(username) = proxy.username.as_deref().filter(|n| !n.is_empty()) else {
+        return false;
+    };
+    if proxy.password.is_some() {
+        return save_proxy_password(proxy);
+    }
+    match ql_instances::auth::read_proxy_password(username) {
+        Ok(password) => proxy.password = password,
+        Err(err) => err!("Couldn't read proxy password: {err}"),
+    }
+    false
+}
+
+/// Returns whether the password was saved.
+fn save_proxy_password(proxy: &ProxyConfig) -> bool {
+    let (Some(username), Some(password)) = (
+        proxy.username.as_deref().filter(|n| !n.is_empty()),
+        proxy.password.as_deref(),
+    ) else {
+        return false;
+    };
+    ql_instances::auth::save_proxy_password(username, password)
+        .inspect_err(|err| err!("Couldn't save proxy password: {err}"))
+        .is_ok()
+}
+
 #[derive(Serialize, Deserialize, Clone, Debug)]
 pub struct ConfigAccount {
     /// UUID of the Minecraft account. Stored as a string without dashes.
@@ -165,14 +306,23 @@ pub struct ConfigAccount {
     /// You can find someone's UUID through many online services where you
     /// input their username.
     pub uuid: String,
-    /// Currently unimplemented, does nothing.
-    pub skin: Option<String>, // TODO: Add skin visualization?
+    /// URL of the account's current skin texture,
+    /// updated whenever the skin menu is opened.
+    ///
+    /// `None` for the default skin (or if never checked).
+    pub skin: Option<String>,
 
     /// Type of account:
     ///
     /// - `"Microsoft"`
     /// - `"ElyBy"`
+    /// - `"AuthlibInjector"`
     pub account_type: Option<String>,
+    /// The Yggdrasil API root of the auth server,
+    /// for `"AuthlibInjector"` accounts.
+    ///
+    /// Example: `https://littleskin.cn/api/yggdrasil`
+    pub auth_server: Option<String>,
 
     /// A game-readable "nice" username.
     ///
@@ -184,4 +334,10 @@ pub struct ConfigAccount {
     /// username while the regular "username"
     /// would be an email.
     pub username_nice: Option<String>,
+
+    /// The login expired or was revoked (eg: password change),
+    /// so the user has to log in again.
+    ///
+    /// Implemented in v0.4.3
+    pub needs_relogin: Option<bool>,
 }
@@ -0,0 +1,309 @@
+//! Translations of the launcher UI.
+//!
+//! Catalogs are [Fluent](https://projectfluent.org) files.
+//! The bundled ones live in `assets/locales/`, and more
+//! can be added as `QuantumLauncher/locales/<language>.ftl`
+//! (eg. `de-DE.ftl`), picked up on startup.
+//!
+//! Use the [`t`] macro to get a string:
+//!
+//! ```ignore
+//! widget::text(t!("launch-play"));
+//! widget::text(t!("settings-ui-scale", scale = 1.5));
+//! ```
+//!
+//! Anything missing from the selected language
+//! falls back to English (`en-US`).
+
+use std::{
+    path::Path,
+    sync::{LazyLock, RwLock},
+};
+
+use fluent_bundle::{concurrent::FluentBundle, FluentArgs, FluentResource, FluentValue};
+use ql_core::{err, info, LAUNCHER_DIR};
+use unic_langid::LanguageIdentifier;
+
+pub const FALLBACK_LANGUAGE: &str = "en-US";
+
+/// Catalogs compiled into the launcher, as `(language, source)`.
+const BUNDLED: &[(&str, &str)] = &[(
+    FALLBACK_LANGUAGE,
+    include_str!("../../assets/locales/en-US.ftl"),
+)];
+
+type Bundle = FluentBundle<FluentResource>;
+
+struct Catalogs {
+    selected: Option<Bundle>,
+    fallback: Bundle,
+}
+
+static CATALOGS: LazyLock<RwLock<Catalogs>> = LazyLock::new(|| {
+    RwLock::new(Catalogs {
+        selected: None,
+        fallback: load_bundle(FALLBACK_LANGUAGE, BUNDLED[0].1.to_owned()),
+    })
+});
+
+/// A language that can be picked in the settings.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Language {
+    /// eg. `en-US`
+    pub id: String,
+    /// The name of the language in itself (eg. `Deutsch`),
+    /// from the `language-name` entry of the catalog
+    pub name: String,
+    source: String,
+}
+
+impl std::fmt::Display for Language {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name)
+    }
+}
+
+static LANGUAGES: LazyLock<Vec<Language>> = LazyLock::new(find_languages);
+
+/// All languages with a catalog, bundled or user-provided.
+pub fn languages() -> &'static [Language] {
+    &LANGUAGES
+}
+
+/// Switches the UI language. `None` (or an unknown language)
+/// picks one matching the system locale, if there is any.
+pub fn set_language(language: Option<&str>) {
+    let language = language
+        .and_then(|id| {
+            let found = languages().iter().find(|n| n.id == id);
+            if found.is_none() {
+                err!("Unknown language {id:?}, using the system language");
+            }
+            found
+        })
+        .or_else(system_language);
+
+    let selected = language
+        .filter(|n| n.id != FALLBACK_LANGUAGE)
+        .map(|n| load_bundle(&n.id, n.source.clone()));
+    if let Some(language) = language {
+        info!("Using language: {} ({})", language.name, language.id);
+    }
+    CATALOGS.write().unwrap().selected = selected;
+}
+
+/// The catalog best matching the system locale
+/// (same region first, then just the same language).
+pub fn system_language() -> Option<&'static Language> {
+    let locale: LanguageIdentifier = sys_locale::get_locale()?.parse().ok()?;
+    let parsed: Vec<(&Language, LanguageIdentifier)> = languages()
+        .iter()
+        .filter_map(|n| Some((n, n.id.parse().ok()?)))
+        .collect();
+
+    parsed
+        .iter()
+        .find(|(_, id)| *id == locale)
+        .or_else(|| parsed.iter().find(|(_, id)| id.language == locale.language))
+        .map(|(n, _)| *n)
+}
+
+/// Gets the string for `key` in the current language.
+/// Prefer the [`t`] macro.
+pub fn tr(key: &str) -> String {
+    tr_args(key, None)
+}
+
+/// Gets the string for `key` in the current language,
+/// filling in `args`. Prefer the [`t`] macro.
+pub fn tr_args(key: &str, args: Option<&FluentArgs>) -> String {
+    let catalogs = CATALOGS.read().unwrap();
+    catalogs
+        .selected
+        .iter()
+        .chain(std::iter::once(&catalogs.fallback))
+        .find_map(|bundle| {
+            let pattern = bundle.get_message(key)?.value()?;
+            let mut errors = Vec::new();
+            let value = bundle.format_pattern(pattern, args, &mut errors);
+            for error in errors {
+                err!("Translation error in {key}: {error}");
+            }
+            Some(value.into_owned())
+        })
+        .unwrap_or_else(|| {
+            err!("Missing translation: {key}");
+            key.to_owned()
+        })
+}
+
+/// Translates a UI string (see the [module docs](self)).
+macro_rules! t {
+    ($key:literal) => {
+        $crate::i18n::tr($key)
+    };
+    ($key:literal, $($name:ident = $value:expr),+ $(,)?) => {{
+        let mut args = ::fluent_bundle::FluentArgs::new();
+        $(args.set(stringify!($name), $crate::i18n::arg($value));)+
+        $crate::i18n::tr_args($key, Some(&args))
+    }};
+}
+pub(crate) use t;
+
+/// Turns a value into an argument for [`t`].
+pub fn arg<'a>(value: impl Into<FluentValue<'a>>) -> FluentValue<'a> {
+    value.into()
+}
+
+fn load_bundle(id: &str, source: String) -> Bundle {
+    let langid: LanguageIdentifier = id.parse().unwrap_or_else(|_| {
+        err!("Invalid language id {id:?}");
+        LanguageIdentifier::default()
+    });
+    let mut bundle = FluentBundle::new_concurrent(vec![langid]);
+    // The Unicode isolation marks show up as boxes
+    bundle.set_use_isolating(false);
+
+    let resource = match FluentResource::try_new(source) {
+        Ok(n) => n,
+        Err((n, errors)) => {
+            for error in errors {
+                err!("Syntax error in {id} translations: {error}");
+            }
+            n
+        }
+    };
+    if let Err(errors) = bundle.add_resource(resource) {
+        for error in errors {
+            err!("Error in {id} translations: {error}");
+        }
+    }
+    bundle
+}
+
+fn find_languages() -> Vec<Language> {
+    let mut languages: Vec<Language> = BUNDLED
+        .iter()
+        .map(|(id, source)| language_from_source(id, (*source).to_owned()))
+        .collect();
+
+    let dir = LAUNCHER_DIR.join("locales");
+    if let Ok(entries) = std::fs::read_dir(&dir) {
+        for path in entries.filter_map(Result::ok).map(|n| n.path()) {
+            if let Some(language) = load_language_file(&path) {
+                // User files can replace bundled catalogs
+                languages.retain(|n| n.id != language.id);
+                languages.push(language);
+            }
+        }
+    }
+    languages.sort_by(|a, b| a.name.cmp(&b.name));
+    languages
+}
+
+fn load_language_file(path: &Path) -> Option<Language> {
+    if path.extension()?.to_str()? != "ftl" {
+        return None;
+    }
+    let id = path.file_stem()?.to_str()?;
+    if id.parse::<LanguageIdentifier>().is_err() {
+        err!("Translation file {path:?} isn't named after a language (like de-DE.ftl)");
+        return None;
+    }
+    match std::fs::read_to_string(path) {
+        Ok(source) => Some(language_from_source(id, source)),
+        Err(error) => {
+            err!("Could not read translation file {path:?}: {error}");
+            None
+        }
+    }
+}
+
+fn language_from_source(id: &str, source: String) -> Language {
+    let bundle = load_bundle(id, source.clone());
+    let name = bundle
+        .get_message("language-name")
+        .and_then(|n| n.value())
+        .map(|n| bundle.format_pattern(n, None, &mut Vec::new()).into_owned())
+        .unwrap_or_else(|| id.to_owned());
+    Language {
+        id: id.to_owned(),
+        name,
+        source,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use fluent_bundle::FluentResource;
+
+    use super::{load_bundle, BUNDLED, FALLBACK_LANGUAGE};
+
+    /// Finds the keys passed to the [`t`](super::t) macro
+    /// in the source files under `dir`.
+    fn used_keys(dir: &Path, keys: &mut Vec<(String, String)>) {
+        for entry in std::fs::read_dir(dir).unwrap() {
+            let path = entry.unwrap().path();
+            if path.is_dir() {
+                used_keys(&path, keys);
+                continue;
+            }
+            if path.extension().is_none_or(|n| n != "rs") {
+                continue;
+            }
+            let source = std::fs::read_to_string(&path).unwrap();
+            for (i, _) in source.match_indices("t!(\"") {
+                // Not `text!(`, `format!(`, etc.
+                if source[..i]
+                    .chars()
+                    .next_back()
+                    .is_some_and(|c| c.is_alphanumeric() || c == '_')
+                {
+                    continue;
+                }
+                let start = i + 4;
+                let len = source[start..].find('"').unwrap();
+                keys.push((
+                    source[start..start + len].to_owned(),
+                    path.display().to_string(),
+                ));
+            }
+        }
+    }
+
+    #[test]
+    fn bundled_catalogs_parse() {
+        for (id, source) in BUNDLED {
+            if let Err((_, errors)) = FluentResource::try_new((*source).to_owned()) {
+                panic!("Syntax errors in the {id} catalog: {errors:#?}");
+            }
+        }
+    }
+
+    #[test]
+    fn english_has_all_keys() {
+        let (_, source) = BUNDLED
+            .iter()
+            .find(|(id, _)| *id == FALLBACK_LANGUAGE)
+            .unwrap();
+        let bundle = load_bundle(FALLBACK_LANGUAGE, (*source).to_owned());
+
+        let mut keys = Vec::new();
+        used_keys(
+            &Path::new(env!("CARGO_MANIFEST_DIR")).join("src"),
+            &mut keys,
+        );
+        assert!(!keys.is_empty());
+
+        let missing: Vec<_> = keys
+            .iter()
+            .filter(|(key, _)| !bundle.has_message(key))
+            .collect();
+        assert!(
+            missing.is_empty(),
+            "Keys missing from the {FALLBACK_LANGUAGE} catalog: {missing:#?}"
+        );
+    }
+}
@@ -0,0 +1,310 @@
+use std::{collections::HashMap, fmt::Display};
+
+use iced::keyboard::{key::Named, Key, Modifiers};
+use ql_core::err;
+
+use crate::i18n::t;
+
+/// Something that can be done with a keyboard shortcut.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum KeyAction {
+    Switcher,
+    Launch,
+    Kill,
+    OpenLog,
+    OpenMods,
+    FocusSearch,
+    SelectUp,
+    SelectDown,
+    Back,
+    Quit,
+    Cheatsheet,
+}
+
+impl KeyAction {
+    pub const ALL: &[Self] = &[
+        Self::Switcher,
+        Self::Launch,
+        Self::Kill,
+        Self::OpenLog,
+        Self::OpenMods,
+        Self::FocusSearch,
+        Self::SelectUp,
+        Self::SelectDown,
+        Self::Back,
+        Self::Quit,
+        Self::Cheatsheet,
+    ];
+
+    /// The name used in the `keybinds` field
+    /// of [`crate::config::LauncherConfig`]
+    pub fn config_key(self) -> &'static str {
+        match self {
+            KeyAction::Switcher => "switcher",
+            KeyAction::Launch => "launch",
+            KeyAction::Kill => "kill",
+            KeyAction::OpenLog => "open_log",
+            KeyAction::OpenMods => "open_mods",
+            KeyAction::FocusSearch => "focus_search",
+            KeyAction::SelectUp => "select_up",
+            KeyAction::SelectDown => "select_down",
+            KeyAction::Back => "back",
+            KeyAction::Quit => "quit",
+            KeyAction::Cheatsheet => "cheatsheet",
+        }
+    }
+
+    fn default_binding(self) -> &'static str {
+        match self {
+            KeyAction::Switcher => "Ctrl+K",
+            KeyAction::Launch => "Enter",
+            KeyAction::Kill => "Ctrl+Backspace",
+            KeyAction::OpenLog => "Ctrl+L",
+            KeyAction::OpenMods => "Ctrl+M",
+            KeyAction::FocusSearch => "Ctrl+F",
+            KeyAction::SelectUp => "Up",
+            KeyAction::SelectDown => "Down",
+            KeyAction::Back => "Escape",
+            KeyAction::Quit => "Ctrl+Q",
+            KeyAction::Cheatsheet => "F1",
+        }
+    }
+}
+
+impl Display for KeyAction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                KeyAction::Switcher => t!("keys-action-switcher"),
+                KeyAction::Launch => t!("keys-action-launch"),
+                KeyAction::Kill => t!("keys-action-kill"),
+                KeyAction::OpenLog => t!("keys-action-open-log"),
+                KeyAction::OpenMods => t!("keys-action-open-mods"),
+                KeyAction::FocusSearch => t!("keys-action-focus-search"),
+                KeyAction::SelectUp => t!("keys-action-select-up"),
+                KeyAction::SelectDown => t!("keys-action-select-down"),
+                KeyAction::Back => t!("keys-action-back"),
+                KeyAction::Quit => t!("keys-action-quit"),
+                KeyAction::Cheatsheet => t!("keys-action-cheatsheet"),
+            }
+        )
+    }
+}
+
+/// A key combination like `Ctrl+Shift+K`.
+///
+/// `Ctrl` is the Command key on macOS.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KeyBinding {
+    key: Key,
+    ctrl: bool,
+    shift: bool,
+    alt: bool,
+}
+
+const NAMED_KEYS: &[(&str, Named)] = &[
+    ("Enter", Named::Enter),
+    ("Escape", Named::Escape),
+    ("Backspace", Named::Backspace),
+    ("Delete", Named::Delete),
+    ("Tab", Named::Tab),
+    ("Space", Named::Space),
+    ("Up", Named::ArrowUp),
+    ("Down", Named::ArrowDown),
+    ("Left", Named::ArrowLeft),
+    ("Right", Named::ArrowRight),
+    ("Home", Named::Home),
+    ("End", Named::End),
+    ("PageUp", Named::PageUp),
+    ("PageDown", Named::PageDown),
+    ("F1", Named::F1),
+    ("F2", Named::F2),
+    ("F3", Named::F3),
+    ("F4", Named::F4),
+    ("F5", Named::F5),
+    ("F6", Named::F6),
+    ("F7", Named::F7),
+    ("F8", Named::F8),
+    ("F9", Named::F9),
+    ("F10", Named::F10),
+    ("F11", Named::F11),
+    ("F12", Named::F12),
+];
+
+impl KeyBinding {
+    /// Parses a binding like `"Ctrl+Enter"` or `"Alt+L"`.
+    pub fn parse(s: &str) -> Result<Self, String> {
+        let mut binding = Self {
+            key: Key::Unidentified,
+            ctrl: false,
+            shift: false,
+            alt: false,
+        };
+        let mut parts: Vec<&str> = s.split('+').map(str::trim).collect();
+        // `Ctrl++` binds the plus key
+        if s.trim_end().ends_with("++") {
+            parts.pop();
+            *parts.last_mut().unwrap() = "+";
+        }
+        let Some((key, modifiers)) = parts.split_last() else {
+            return Err("binding is empty".to_owned());
+        };
+
+        for modifier in modifiers {
+            match modifier.to_lowercase().as_str() {
+                "ctrl" | "control" | "cmd" | "command" => binding.ctrl = true,
+                "shift" => binding.shift = true,
+                "alt" | "option" => binding.alt = true,
+                _ => return Err(format!("unknown modifier {modifier:?} in {s:?}")),
+            }
+        }
+
+        binding.key = if let Some((_, named)) = NAMED_KEYS
+            .iter()
+            .find(|(name, _)| name.eq_ignore_ascii_case(key))
+        {
+            Key::Named(*named)
+        } else if key.eq_ignore_ascii_case("esc") {
+            Key::Named(Named::Escape)
+        } else if key.eq_ignore_ascii_case("return") {
+            Key::Named(Named::Enter)
+        } else if key.chars().count() == 1 {
+            Key::Character(key.to_lowercase().into())
+        } else {
+            return Err(format!("unknown key {key:?} in {s:?}"));
+        };
+        Ok(binding)
+    }
+
+    pub fn matches(&self, key: &Key, modifiers: Modifiers) -> bool {
+        let key_matches = match (&self.key, key) {
+            (Key::Character(a), Key::Character(b)) => *a == b.to_lowercase(),
+            (a, b) => a == b,
+        };
+        // Symbols like `?` may need shift to be typed,
+        // so it's only checked for letters and named keys
+        let is_symbol =
+            matches!(&self.key, Key::Character(c) if !c.chars().all(char::is_alphanumeric));
+
+        key_matches
+            && self.ctrl == modifiers.command()
+            && self.alt == modifiers.alt()
+            && (self.shift == modifiers.shift() || (is_symbol && !self.shift))
+    }
+}
+
+impl Display for KeyBinding {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.ctrl {
+            #[cfg(target_os = "macos")]
+            write!(f, "Cmd+")?;
+            #[cfg(not(target_os = "macos"))]
+            write!(f, "Ctrl+")?;
+        }
+        if self.alt {
+            write!(f, "Alt+")?;
+        }
+        if self.shift {
+            write!(f, "Shift+")?;
+        }
+        match &self.key {
+            Key::Named(named) => {
+                let name = NAMED_KEYS
+                    .iter()
+                    .find(|(_, n)| n == named)
+                    .map_or("?", |(name, _)| name);
+                write!(f, "{name}")
+            }
+            Key::Character(c) => write!(f, "{}", c.to_uppercase()),
+            Key::Unidentified => write!(f, "?"),
+        }
+    }
+}
+
+/// The keyboard shortcuts in use, with
+/// the user's overrides applied on top of the defaults.
+#[derive(Debug, Clone)]
+pub struct Keybinds {
+    bindings: Vec<(KeyAction, Option<KeyBinding>)>,
+}
+
+impl Default for Keybinds {
+    fn default() -> Self {
+        Self::load(None)
+    }
+}
+
+impl Keybinds {
+    /// Loads the shortcuts from the `keybinds` field of
+    /// [`crate::config::LauncherConfig`], logging any invalid ones.
+    ///
+    /// Invalid bindings fall back to the default,
+    /// and an empty binding disables the shortcut.
+    pub fn load(overrides: Option<&HashMap<String, String>>) -> Self {
+        if let Some(overrides) = overrides {
+            for action in overrides.keys() {
+                if !KeyAction::ALL.iter().any(|n| n.config_key() == action) {
+                    err!("Unknown keybind action {action:?}");
+                }
+            }
+        }
+
+        let mut bindings: Vec<(KeyAction, Option<KeyBinding>)> = Vec::new();
+        for action in KeyAction::ALL {
+            let binding = match overrides.and_then(|n| n.get(action.config_key())) {
+                Some(binding) if binding.trim().is_empty() => None,
+                Some(binding) => match KeyBinding::parse(binding) {
+                    Ok(n) => Some(n),
+                    Err(error) => {
+                        err!("Invalid keybind for {}: {error}", action.config_key());
+                        Self::default_for(*action)
+                    }
+                },
+                None => Self::default_for(*action),
+            };
+
+            let binding = binding.filter(|binding| {
+                let other = bindings.iter().find(|(_, n)| n.as_ref() == Some(binding));
+                if let Some((other, _)) = other {
+                    err!(
+                        "{binding} is bound to both {} and {}, ignoring the second one",
+                        other.config_key(),
+                        action.config_key()
+                    );
+                }
+                other.is_none()
+            });
+            bindings.push((*action, binding));
+        }
+        Self { bindings }
+    }
+
+    fn default_for(action: KeyAction) -> Option<KeyBinding> {
+        KeyBinding::parse(action.default_binding()).ok()
+    }
+
+    pub fn get(&self, action: KeyAction) -> Option<&KeyBinding> {
+        self.bindings
+            .iter()
+            .find(|(n, _)| *n == action)
+            .and_then(|(_, binding)| binding.as_ref())
+    }
+
+    /// Which action (if any) a key press triggers.
+    pub fn action_for(&self, key: &Key, modifiers: Modifiers) -> Option<KeyAction> {
+        self.bindings.iter().find_map(|(action, binding)| {
+            binding
+                .as_ref()
+                .is_some_and(|n| n.matches(key, modifiers))
+                .then_some(*action)
+        })
+    }
+
+    /// How a shortcut is shown in tooltips.
+    pub fn label(&self, action: KeyAction) -> String {
+        self.get(action)
+            .map_or_else(|| t!("keys-no-shortcut"), ToString::to_string)
+    }
+}
@@ -28,9 +28,10 @@ use std::{borrow::Cow, time::Duration};
 
 use config::LauncherConfig;
 use iced::{futures::executor::block_on, Settings, Task};
-use state::{get_entries, Launcher, Message, ServerProcess};
+use single_instance::Request;
+use state::{get_entries, Launcher, Message, ServerProcess, UrlInstallMessage};
 
-use ql_core::{err, err_no_log, file_utils, info_no_log, IntoStringError, JsonFileError};
+use ql_core::{download, err, err_no_log, file_utils, info_no_log, IntoStringError, JsonFileError};
 use ql_instances::OS_NAME;
 use tokio::io::AsyncWriteExt;
 
@@ -38,9 +39,18 @@ use tokio::io::AsyncWriteExt;
 mod cli;
 /// Launcher configuration (global).
 mod config;
+/// Translations of the UI.
+mod i18n;
 /// Definitions of certain icons (like Download,
 /// Play, Settings and so on) as `iced::widget`.
 mod icon_manager;
+/// Keyboard shortcuts (configurable).
+mod keybinds;
+/// Desktop shortcuts that launch an instance directly.
+mod shortcut;
+/// Only one launcher open at a time, with
+/// later launches forwarded to it.
+mod single_instance;
 /// All the main structs and enums used in the launcher.
 mod state;
 
@@ -85,11 +95,17 @@ impl Launcher {
     fn new(
         is_new_user: bool,
         config: Result<LauncherConfig, JsonFileError>,
+        open: Option<String>,
+        listener: Option<single_instance::Listener>,
     ) -> (Self, iced::Task<Message>) {
-        let check_for_updates_command = Task::perform(
-            async move { ql_instances::check_for_launcher_updates().await.strerr() },
-            Message::UpdateCheckResult,
-        );
+        let check_for_updates_command = if download::is_offline() {
+            Task::none()
+        } else {
+            Task::perform(
+                async move { ql_instances::check_for_launcher_updates().await.strerr() },
+                Message::UpdateCheckResult,
+            )
+        };
 
         let get_entries_command = Task::perform(
             get_entries("instances".to_owned(), false),
@@ -100,9 +116,22 @@ impl Launcher {
             Message::CoreLogCleanComplete(n.strerr())
         });
 
+        let open_command = open.map_or_else(Task::none, |n| {
+            Task::done(Message::UrlInstall(UrlInstallMessage::Open(n)))
+        });
+        let listen_command = listener.map_or_else(Task::none, |n| {
+            Task::run(single_instance::requests(n), Message::CoreForwarded)
+        });
+
         (
             Launcher::load_new(None, is_new_user, config).unwrap_or_else(Launcher::with_error),
-            Task::batch([check_for_updates_command, get_entries_command, log_cmd]),
+            Task::batch([
+                check_for_updates_command,
+                get_entries_command,
+                log_cmd,
+                open_command,
+                listen_command,
+            ]),
         )
     }
 
@@ -163,12 +192,25 @@ fn main() {
     // let is_new_user = true; // Uncomment to test the intro screen.
 
     let (launcher_dir, is_dir_err) = load_launcher_dir();
-    cli::start_cli(is_dir_err);
+    let open = cli::start_cli(is_dir_err);
+    let listener = if is_dir_err {
+        None
+    } else {
+        single_instance::acquire(&open.clone().map_or(Request::Focus, Request::Open))
+    };
+    #[cfg(windows)]
+    register_url_scheme();
 
     info_no_log!("Starting up the launcher... (OS: {OS_NAME})");
 
     let icon = load_icon();
     let (scale, config) = load_ui_scale(launcher_dir.is_some());
+    i18n::set_language(config.as_ref().ok().and_then(|n| n.language.as_deref()));
+    let font = config
+        .as_ref()
+        .ok()
+        .and_then(|n| stylesheet::custom::get_startup_font(n.style.as_deref()))
+        .unwrap_or("Inter");
 
     iced::application("QuantumLauncher", Launcher::update, Launcher::view)
         .subscription(Launcher::subscription)
@@ -176,7 +218,7 @@ fn main() {
         .theme(Launcher::theme)
         .settings(Settings {
             fonts: load_fonts(),
-            default_font: iced::Font::with_name("Inter"),
+            default_font: iced::Font::with_name(font),
             ..Default::default()
         })
         .window(iced::window::Settings {
@@ -192,7 +234,7 @@ fn main() {
             }),
             ..Default::default()
         })
-        .run_with(move || Launcher::new(is_new_user, config))
+        .run_with(move || Launcher::new(is_new_user, config, open, listener))
         .unwrap();
 }
 
@@ -254,6 +296,56 @@ fn load_fonts() -> Vec<Cow<'static, [u8]>> {
     ]
 }
 
+/// Makes `quantumlauncher://` links open this launcher
+/// (for the current user, so no admin rights needed).
+#[cfg(windows)]
+fn register_url_scheme() {
+    use std::os::windows::process::CommandExt;
+    const CREATE_NO_WINDOW: u32 = 0x0800_0000;
+
+    let Ok(exe) = std::env::current_exe() else {
+        return;
+    };
+    let key = format!(r"HKCU\Software\Classes\{}", cli::URL_SCHEME);
+    let command = format!("\"{}\" \"%1\"", exe.display());
+    let entries: [Vec<String>; 3] = [
+        vec![
+            key.clone(),
+            "/ve".to_owned(),
+            "/d".to_owned(),
+            "URL:QuantumLauncher".to_owned(),
+        ],
+        vec![
+            key.clone(),
+            "/v".to_owned(),
+            "URL Protocol".to_owned(),
+            "/d".to_owned(),
+            String::new(),
+        ],
+        vec![
+            format!(r"{key}\shell\open\command"),
+            "/ve".to_owned(),
+            "/d".to_owned(),
+            command,
+        ],
+    ];
+
+    std::thread::spawn(move || {
+        for args in entries {
+            let result = std::process::Command::new("reg")
+                .arg("add")
+                .args(args)
+                .arg("/f")
+                .creation_flags(CREATE_NO_WINDOW)
+                .output();
+            if let Err(err) = result {
+                err!("Couldn't register {}:// links: {err}", cli::URL_SCHEME);
+                return;
+            }
+        }
+    });
+}
+
 #[cfg(windows)]
 fn attach_to_console() {
     use windows::Win32::System::Console::AttachConsole;
@@ -7,6 +7,7 @@ use iced::{
 
 use crate::{
     config::LauncherConfig,
+    i18n::t,
     icon_manager,
     state::{AccountMessage, MenuWelcome, Message},
 };
@@ -40,94 +41,58 @@ pub static IMG_LOGO: LazyLock<Handle> = LazyLock::new(|| {
 
 pub fn changelog_0_4_1<'a>() -> Element<'a> {
     widget::column![
-        widget::text("QuantumLauncher v0.4.1 is here!").size(40),
-
+        widget::text(t!("changelog-title")).size(40),
         widget::container(
             widget::column![
-                widget::text("TL;DR").size(32),
-                "- Shaders, Resource Pack, Modpack stores added",
-                "- Modpack support is here!",
-                "- Quality-of-life tweaks, keyboard navigation",
-                "- Portable mode, custom location support via qldir.txt",
-                "- Major bugfixes across modding, authentication, and platform compatibility",
-            ].padding(10).spacing(5)
+                widget::text(t!("changelog-tldr")).size(32),
+                lines(t!("changelog-tldr-items")),
+            ]
+            .padding(10)
+            .spacing(5)
         ),
-
         widget::Space::with_height(5),
         widget::horizontal_rule(4.0),
-        widget::text("Modding").size(32),
-        "- added Modpacks, Shaders and Resource Packs stores",
-        "- drag-and-drop support for mod files, modpacks and presets",
-        "- jarmod patching support (useful for old Minecraft versions)",
-        "- OptiFine support for b1.6.6, b1.7.3, 1.2.5 and 1.5.2",
-        widget::text("UX").size(24),
-        "- \"Open Mod Page\" in mod description now shows URL in tooltip",
-        "- added bullet/numbered list support in curseforge mod descriptions",
-        "- mod update checking is now up to 3-4x faster!",
-
+        widget::text(t!("changelog-modding")).size(32),
+        lines(t!("changelog-modding-items")),
+        widget::text(t!("changelog-ux")).size(24),
+        lines(t!("changelog-modding-ux-items")),
         widget::horizontal_rule(4.0),
-        widget::text("Portable Mode & Custom Paths").size(32),
-        "- You can control where QuantumLauncher stores data by placing a qldir.txt file in one of the following locations:",
-        "-- Next to the launcher executable",
-        "-- In the working directory where it's run",
-        "-- In the global [QuantumLauncher folder](https://mrmayman.github.io/quantumlauncher/faq#files-location)",
-
-        "- Leave the file blank for full portable mode, or write a path inside it to set a custom data location.",
-        "- > Note: On macOS you may need to extract the .app from the DMG for portable mode.",
-
+        widget::text(t!("changelog-portable")).size(32),
+        lines(t!("changelog-portable-items")),
         widget::horizontal_rule(4.0),
-        widget::text("Menu Overhauls").size(32),
-        "- Welcome screen redesigned to guide new users more clearly",
-        "- Error screen looks better, now has a \"Copy Log + Error\" button",
-        "- Fabric Install screen is now simpler and less cluttered",
-
+        widget::text(t!("changelog-menus")).size(32),
+        lines(t!("changelog-menus-items")),
         widget::horizontal_rule(4.0),
-        widget::text("UI/UX").size(32),
-        "- new Catppuccin color scheme",
-        "- improved aesthetics of light themes",
-        "- animated pulsating \"...\" dots in a few \"Loading\" screens",
-        "- minor tweaks to buttons and layout in many screens",
-        "- added a \"Clean Java Installs\" button (for reinstalling java) in launcher settings",
-        "- old versions in the version list now have cleaner names (\"beta/b1.7/b1.7.3\" -> \"b1.7.3\")",
-        "- window size now respects UI scale",
-
+        widget::text(t!("changelog-ui-ux")).size(32),
+        lines(t!("changelog-ui-ux-items")),
         widget::horizontal_rule(4.0),
-        widget::text("Keyboard Navigation").size(32),
-        "- Up/Down arrow keys to select instance",
-        "- Ctrl+Enter -> launch instance",
-        "- Ctrl+Backspace -> kill instance",
-        "- Ctrl+Q -> exit launcher (only in main menu, when nothing's running)",
-        "- (note: on macOS it's Cmd instead of Ctrl)",
-        "- More coming in the future!",
-
+        widget::text(t!("changelog-keyboard")).size(32),
+        lines(t!("changelog-keyboard-items")),
         widget::horizontal_rule(4.0),
-        widget::text("Game Crashed message").size(32),
-        "- there is now a \"Join Discord\" button in Logs tab",
-        "- there's no longer a tip mentioning a \"second terminal\" upon crash (no longer needed)",
-
+        widget::text(t!("changelog-crash")).size(32),
+        lines(t!("changelog-crash-items")),
         widget::horizontal_rule(4.0),
-        widget::text("Dependencies (Technical)").size(32),
-        "- dropped OpenSSL and LZMA dependencies on linux",
-        "-- now depends only on libgcc_s and libc (glibc)",
-        "-- replaced OpenSSL with rustls",
-
+        widget::text(t!("changelog-dependencies")).size(32),
+        lines(t!("changelog-dependencies-items")),
         widget::horizontal_rule(4.0),
-        widget::text("Fixes").size(32),
-        "- crash messages now appear in Logs tab (critical!)",
-        "- forge now works after renaming instance",
-        "- multiplayer now works in 1.16.x for offline accounts",
-        "- mod updating and preset importing are now working again! (fixed error for curseforge, index corruption for modrinth)",
-        "- fixed extreme launcher log file spam",
-        widget::text("UI/UX").size(24),
-        "- UI scaling now has a minimum limit preventing unusable layouts",
-        "- many mod-related operations now return you to Mods screen, instead of launch screen",
-        "- pressing Esc in mod descriptions now exits the description only (not the entire store)",
-        "- fixed mod store \"forgetting\" scroll position when opening description and exiting",
-        widget::text("Platform-Specific").size(24),
-        "- fixed 1.13-1.16 crashing on linux ARM",
-        "- fixed 1.8.9 crashing on macOS ARM",
-        "- fixed Java 8 (1.16.5 and below) compatibility on Windows ARM and macOS",
-    ].padding(10).spacing(5).into()
+        widget::text(t!("changelog-fixes")).size(32),
+        lines(t!("changelog-fixes-items")),
+        widget::text(t!("changelog-ui-ux")).size(24),
+        lines(t!("changelog-fixes-ui-ux-items")),
+        widget::text(t!("changelog-platform")).size(24),
+        lines(t!("changelog-platform-items")),
+    ]
+    .padding(10)
+    .spacing(5)
+    .into()
+}
+
+/// One line of text per line of a (multiline) translation,
+/// spaced like the rest of the changelog.
+fn lines<'a>(text: String) -> Element<'a> {
+    widget::column(text.lines().map(|n| widget::text(n.to_owned()).into()))
+        .spacing(5)
+        .into()
 }
 
 #[allow(unused)]
@@ -230,8 +195,11 @@ impl MenuWelcome {
             MenuWelcome::P1InitialScreen => widget::column![
                 widget::vertical_space(),
                 center_x(widget::image(IMG_LOGO.clone()).width(200)),
-                center_x(widget::text("Welcome to QuantumLauncher!").size(20)),
-                center_x(widget::button("Get Started").on_press(Message::WelcomeContinueToTheme)),
+                center_x(widget::text(t!("welcome-title")).size(20)),
+                center_x(
+                    widget::button(widget::text(t!("welcome-get-started")))
+                        .on_press(Message::WelcomeContinueToTheme)
+                ),
                 widget::vertical_space(),
             ]
             .align_x(iced::alignment::Horizontal::Center)
@@ -242,29 +210,32 @@ impl MenuWelcome {
                 let (light, dark) = get_theme_selector(config);
                 widget::column![
                     widget::vertical_space(),
-                    center_x(widget::text("Customize your launcher!").size(24)),
+                    center_x(widget::text(t!("welcome-customize")).size(24)),
                     widget::row![
                         widget::horizontal_space(),
-                        "Select Theme:",
+                        widget::text(t!("welcome-theme")),
                         widget::row![light, dark].spacing(5),
                         widget::horizontal_space(),
                     ]
                     .spacing(10),
                     widget::row![
                         widget::horizontal_space(),
-                        "Select Color Scheme:",
+                        widget::text(t!("welcome-color-scheme")),
                         style,
                         widget::horizontal_space(),
                     ]
                     .spacing(10),
                     widget::Space::with_height(5),
-                    center_x("Oh, and also..."),
+                    center_x(widget::text(t!("welcome-also"))),
                     center_x(
-                        button_with_icon(icon_manager::chat(), "Join our Discord", 16)
+                        button_with_icon(icon_manager::chat(), t!("welcome-discord"), 16)
                             .on_press(Message::CoreOpenLink(DISCORD.to_owned()))
                     ),
                     widget::Space::with_height(5),
-                    center_x(widget::button("Continue").on_press(Message::WelcomeContinueToAuth)),
+                    center_x(
+                        widget::button(widget::text(t!("welcome-continue")))
+                            .on_press(Message::WelcomeContinueToAuth)
+                    ),
                     widget::vertical_space(),
                 ]
                 .spacing(10)
@@ -273,12 +244,12 @@ impl MenuWelcome {
             MenuWelcome::P3Auth => widget::column![
                 widget::vertical_space(),
                 center_x(
-                    widget::text_input("Enter username...", &config.username)
+                    widget::text_input(&t!("launch-enter-username"), &config.username)
                         .width(200)
                         .on_input(Message::LaunchUsernameSet)
                 ),
                 center_x(
-                    widget::button(center_x("Continue"))
+                    widget::button(center_x(widget::text(t!("welcome-continue"))))
                         .width(200)
                         .on_press_maybe((!config.username.is_empty()).then_some(
                             Message::LaunchScreenOpen {
@@ -288,20 +259,29 @@ impl MenuWelcome {
                         ))
                 ),
                 widget::Space::with_height(7),
-                center_x(widget::text("OR").size(20)),
+                center_x(widget::text(t!("welcome-or")).size(20)),
                 widget::Space::with_height(7),
                 center_x(
-                    widget::button("Login to Microsoft").on_press(Message::Account(
-                        AccountMessage::OpenMicrosoft {
+                    widget::button(widget::text(t!("welcome-login-microsoft"))).on_press(
+                        Message::Account(AccountMessage::OpenMicrosoft {
+                            is_from_welcome_screen: true
+                        })
+                    )
+                ),
+                center_x(
+                    widget::button(widget::text(t!("welcome-login-elyby"))).on_press(
+                        Message::Account(AccountMessage::OpenElyBy {
+                            is_from_welcome_screen: true
+                        })
+                    )
+                ),
+                center_x(
+                    widget::button(widget::text(t!("welcome-login-authlib"))).on_press(
+                        Message::Account(AccountMessage::OpenAuthlibInjector {
                             is_from_welcome_screen: true
-                        }
-                    ))
+                        })
+                    )
                 ),
-                center_x(widget::button("Login to ely.by").on_press(Message::Account(
-                    AccountMessage::OpenElyBy {
-                        is_from_welcome_screen: true
-                    }
-                ))),
                 widget::vertical_space(),
             ]
             .spacing(5)
@@ -2,16 +2,31 @@ use iced::{widget, Length};
 use ql_core::InstanceSelection;
 
 use crate::{
+    i18n::t,
     icon_manager,
+    menu_renderer::back_button,
     menu_renderer::{button_with_icon, FONT_MONO},
-    state::{EditInstanceMessage, MenuEditInstance, Message},
-    stylesheet::{color::Color, styles::LauncherTheme},
+    state::{
+        ChangeVersionMessage, CloneInstanceMessage, EditInstanceMessage, InstanceMeta,
+        MenuChangeVersion, MenuCloneInstance, MenuEditInstance, Message,
+    },
+    stylesheet::{color::Color, styles::LauncherTheme, widgets::StyleButton},
 };
 
 use super::Element;
 
+/// Colour tags that can be picked for an instance
+/// (any hex colour works if set in `config.json`)
+const TAG_COLORS: &[&str] = &[
+    "#e05555", "#e0904a", "#d8c14a", "#5cb85c", "#4a90e0", "#9b59d0",
+];
+
 impl MenuEditInstance {
-    pub fn view<'a>(&'a self, selected_instance: &InstanceSelection) -> Element<'a> {
+    pub fn view<'a>(
+        &'a self,
+        selected_instance: &InstanceSelection,
+        meta: Option<&'a InstanceMeta>,
+    ) -> Element<'a> {
         // 2 ^ 8 = 256 MB
         const MEM_256_MB_IN_TWOS_EXPONENT: f32 = 8.0;
         // 2 ^ 13 = 8192 MB
@@ -19,119 +34,230 @@ impl MenuEditInstance {
 
         let ts = |n: &LauncherTheme| n.style_text(Color::SecondLight);
 
-        widget::scrollable(
-            widget::column![
-                widget::container(
-                    widget::row![
-                        widget::text(selected_instance.get_name().to_owned()).size(20).font(FONT_MONO),
-                        widget::horizontal_space(),
-                        widget::text!("{} | {}  ",
-                            self.config.mod_type,
-                            if selected_instance.is_server() {
-                                "Server"
-                            } else {
-                                "Client"
-                            }
-                        )
-                    ].padding(10).spacing(5),
-                )
-                .style(|n: &LauncherTheme| n.style_container_sharp_box(0.0, Color::Dark)),
-
-                widget::container(widget::column!(
-                    widget::row!(
-                        widget::button("Rename").on_press(Message::EditInstance(EditInstanceMessage::RenameApply)),
-                        widget::text_input("Rename Instance", &self.instance_name).on_input(|n| Message::EditInstance(EditInstanceMessage::RenameEdit(n))),
-                    ).spacing(5),
+        widget::scrollable(widget::column![
+            widget::container(
+                widget::row![
+                    widget::text(selected_instance.get_name().to_owned())
+                        .size(20)
+                        .font(FONT_MONO),
+                    widget::horizontal_space(),
+                    widget::text!(
+                        "{} | {}  ",
+                        self.config.mod_type,
+                        if selected_instance.is_server() {
+                            t!("edit-server")
+                        } else {
+                            t!("edit-client")
+                        }
+                    )
+                ]
+                .padding(10)
+                .spacing(5),
+            )
+            .style(|n: &LauncherTheme| n.style_container_sharp_box(0.0, Color::Dark)),
+            widget::container(
+                widget::column!(widget::row!(
+                    widget::button(widget::text(t!("edit-rename")))
+                        .on_press(Message::EditInstance(EditInstanceMessage::RenameApply)),
+                    widget::text_input(&t!("edit-rename-placeholder"), &self.instance_name)
+                        .on_input(|n| Message::EditInstance(EditInstanceMessage::RenameEdit(n))),
                 )
+                .spacing(5),)
+                .push_maybe((!selected_instance.is_server()).then(|| {
+                    widget::button(widget::text(t!("edit-change-version")))
+                        .on_press(Message::ChangeVersion(ChangeVersionMessage::Open))
+                }))
+                .push(self.get_sidebar_options(meta))
                 .padding(10)
-                .spacing(10))
-                .style(|n: &LauncherTheme| n.style_container_sharp_box(0.0, Color::ExtraDark)),
-
-                widget::container(
-                    widget::column![
-                        "Custom Java executable (full path)",
-                        widget::text_input(
-                            "Leave blank if none",
-                            self.config
-                                .java_override
-                                .as_deref()
-                                .unwrap_or_default()
+                .spacing(10)
+            )
+            .style(|n: &LauncherTheme| n.style_container_sharp_box(0.0, Color::ExtraDark)),
+            widget::container(
+                widget::column![
+                    widget::text(t!("edit-java-override")),
+                    widget::text_input(
+                        &t!("edit-java-override-placeholder"),
+                        self.config.java_override.as_deref().unwrap_or_default()
+                    )
+                    .on_input(|t| Message::EditInstance(EditInstanceMessage::JavaOverride(t)))
+                ]
+                .padding(10)
+                .spacing(10)
+            )
+            .style(|n: &LauncherTheme| n.style_container_sharp_box(0.0, Color::Dark)),
+            widget::container(
+                widget::column![
+                    widget::text(t!("edit-memory")),
+                    widget::text(t!("edit-memory-normal")).size(12).style(ts),
+                    widget::text(t!("edit-memory-old")).size(12).style(ts),
+                    widget::text(t!("edit-memory-heavy")).size(12).style(ts),
+                    widget::slider(
+                        MEM_256_MB_IN_TWOS_EXPONENT..=MEM_8192_MB_IN_TWOS_EXPONENT,
+                        self.slider_value,
+                        |n| Message::EditInstance(EditInstanceMessage::MemoryChanged(n))
+                    )
+                    .step(0.1),
+                    widget::text(&self.slider_text),
+                ]
+                .padding(10)
+                .spacing(5),
+            )
+            .style(|n: &LauncherTheme| n.style_container_sharp_box(0.0, Color::ExtraDark)),
+            widget::container(
+                widget::Column::new()
+                    .push_maybe(
+                        (!selected_instance.is_server()).then_some(
+                            widget::column![widget::checkbox(
+                                t!("edit-close-on-start"),
+                                self.config.close_on_start.unwrap_or(false)
+                            )
+                            .on_toggle(|t| Message::EditInstance(
+                                EditInstanceMessage::CloseLauncherToggle(t)
+                            )),]
+                            .spacing(5)
                         )
-                        .on_input(|t| Message::EditInstance(EditInstanceMessage::JavaOverride(t)))
-                    ]
-                    .padding(10)
-                    .spacing(10)
-                ).style(|n: &LauncherTheme| n.style_container_sharp_box(0.0, Color::Dark)),
-                widget::container(
-                    widget::column![
-                        "Allocated memory",
-                        widget::text("For normal Minecraft, allocate 2 - 3 GB").size(12).style(ts),
-                        widget::text("For old versions, allocate 512 MB - 1 GB").size(12).style(ts),
-                        widget::text("For heavy modpacks/very high render distances, allocate 4 - 8 GB").size(12).style(ts),
-                        widget::slider(MEM_256_MB_IN_TWOS_EXPONENT..=MEM_8192_MB_IN_TWOS_EXPONENT, self.slider_value, |n| Message::EditInstance(EditInstanceMessage::MemoryChanged(n))).step(0.1),
-                        widget::text(&self.slider_text),
-                    ]
-                    .padding(10)
-                    .spacing(5),
-                ).style(|n: &LauncherTheme| n.style_container_sharp_box(0.0, Color::ExtraDark)),
-                widget::container(
-                    widget::Column::new()
-                    .push_maybe((!selected_instance.is_server()).then_some(widget::column![
-                        widget::checkbox("Close launcher after game opens", self.config.close_on_start.unwrap_or(false))
-                            .on_toggle(|t| Message::EditInstance(EditInstanceMessage::CloseLauncherToggle(t))),
-                    ].spacing(5)))
+                    )
                     .push(
                         widget::column![
-                            widget::checkbox("DEBUG: Enable log system (recommended)", self.config.enable_logger.unwrap_or(true))
-                                .on_toggle(|t| Message::EditInstance(EditInstanceMessage::LoggingToggle(t))),
-                            widget::text("Once disabled, logs will be printed in launcher STDOUT.\nRun the launcher executable from the terminal/command prompt to see it").size(12).style(ts),
+                            widget::checkbox(
+                                t!("edit-logger"),
+                                self.config.enable_logger.unwrap_or(true)
+                            )
+                            .on_toggle(|t| Message::EditInstance(
+                                EditInstanceMessage::LoggingToggle(t)
+                            )),
+                            widget::text(t!("edit-logger-desc")).size(12).style(ts),
                             widget::horizontal_space(),
-                        ].spacing(5)
+                        ]
+                        .spacing(5)
                     )
                     .padding(10)
                     .spacing(10)
-                ).style(|n: &LauncherTheme| n.style_container_sharp_box(0.0, Color::Dark)),
-                widget::container(
+            )
+            .style(|n: &LauncherTheme| n.style_container_sharp_box(0.0, Color::Dark)),
+            widget::container(
+                widget::column!(
+                    widget::text(t!("edit-java-args")),
                     widget::column!(
-                        "Java arguments:",
-                        widget::column!(
-                            Self::get_java_args_list(
-                                self.config.java_args.as_ref(),
-                                |n| Message::EditInstance(EditInstanceMessage::JavaArgDelete(n)),
-                                |n| Message::EditInstance(EditInstanceMessage::JavaArgShiftUp(n)),
-                                |n| Message::EditInstance(EditInstanceMessage::JavaArgShiftDown(n)),
-                                &|n, i| Message::EditInstance(EditInstanceMessage::JavaArgEdit(n, i))
-                            ),
-                            button_with_icon(icon_manager::create(), "Add", 16)
-                                .on_press(Message::EditInstance(EditInstanceMessage::JavaArgsAdd))
+                        Self::get_java_args_list(
+                            self.config.java_args.as_ref(),
+                            |n| Message::EditInstance(EditInstanceMessage::JavaArgDelete(n)),
+                            |n| Message::EditInstance(EditInstanceMessage::JavaArgShiftUp(n)),
+                            |n| Message::EditInstance(EditInstanceMessage::JavaArgShiftDown(n)),
+                            &|n, i| Message::EditInstance(EditInstanceMessage::JavaArgEdit(n, i))
                         ),
-                    ).padding(10).spacing(10).width(Length::Fill)
-                ).style(|n: &LauncherTheme| n.style_container_sharp_box(0.0, Color::ExtraDark)),
-                widget::container(
+                        button_with_icon(icon_manager::create(), t!("edit-add-arg"), 16)
+                            .on_press(Message::EditInstance(EditInstanceMessage::JavaArgsAdd))
+                    ),
+                )
+                .padding(10)
+                .spacing(10)
+                .width(Length::Fill)
+            )
+            .style(|n: &LauncherTheme| n.style_container_sharp_box(0.0, Color::ExtraDark)),
+            widget::container(
+                widget::column!(
+                    widget::text(t!("edit-game-args")),
                     widget::column!(
-                        "Game arguments:",
-                        widget::column!(
-                            Self::get_java_args_list(
-                                self.config.game_args.as_ref(),
-                                |n| Message::EditInstance(EditInstanceMessage::GameArgDelete(n)),
-                                |n| Message::EditInstance(EditInstanceMessage::GameArgShiftUp(n)),
-                                |n| Message::EditInstance(EditInstanceMessage::GameArgShiftDown(n)),
-                                &|n, i| Message::EditInstance(EditInstanceMessage::GameArgEdit(n, i))
-                            ),
-                            button_with_icon(icon_manager::create(), "Add", 16)
-                                .on_press(Message::EditInstance(EditInstanceMessage::GameArgsAdd))
+                        Self::get_java_args_list(
+                            self.config.game_args.as_ref(),
+                            |n| Message::EditInstance(EditInstanceMessage::GameArgDelete(n)),
+                            |n| Message::EditInstance(EditInstanceMessage::GameArgShiftUp(n)),
+                            |n| Message::EditInstance(EditInstanceMessage::GameArgShiftDown(n)),
+                            &|n, i| Message::EditInstance(EditInstanceMessage::GameArgEdit(n, i))
                         ),
-                    ).padding(10).spacing(10).width(Length::Fill)
-                ).style(|n: &LauncherTheme| n.style_container_sharp_box(0.0, Color::Dark)),
-                widget::container(
-                    button_with_icon(icon_manager::delete(), "Delete Instance", 16)
-                        .on_press(Message::DeleteInstanceMenu)
+                        button_with_icon(icon_manager::create(), t!("edit-add-arg"), 16)
+                            .on_press(Message::EditInstance(EditInstanceMessage::GameArgsAdd))
+                    ),
                 )
-                .width(Length::Fill)
                 .padding(10)
-                .style(|n: &LauncherTheme| n.style_container_sharp_box(0.0, Color::ExtraDark)),
+                .spacing(10)
+                .width(Length::Fill)
+            )
+            .style(|n: &LauncherTheme| n.style_container_sharp_box(0.0, Color::Dark)),
+            widget::container(
+                button_with_icon(icon_manager::delete(), t!("instance-delete"), 16)
+                    .on_press(Message::DeleteInstanceMenu)
+            )
+            .width(Length::Fill)
+            .padding(10)
+            .style(|n: &LauncherTheme| n.style_container_sharp_box(0.0, Color::ExtraDark)),
+        ])
+        .style(LauncherTheme::style_scrollable_flat_extra_dark)
+        .into()
+    }
+
+    fn get_sidebar_options<'a>(&'a self, meta: Option<&'a InstanceMeta>) -> Element<'a> {
+        let selected_color = self.config.color.as_deref();
+        let colors = TAG_COLORS.iter().filter_map(|hex| {
+            let color = iced::Color::parse(hex)?;
+            let is_selected = selected_color.is_some_and(|n| n.eq_ignore_ascii_case(hex));
+            Some(
+                widget::button(widget::Space::new(14, 14))
+                    .padding(2)
+                    .style(move |n: &LauncherTheme, status| {
+                        let mut style = n.style_button(status, StyleButton::Round);
+                        style.background = Some(color.into());
+                        if !is_selected {
+                            style.border.width = 0.0;
+                        }
+                        style
+                    })
+                    .on_press(Message::EditInstance(EditInstanceMessage::ColorSelected(
+                        Some((*hex).to_owned()),
+                    )))
+                    .into(),
+            )
+        });
+        let icon = meta.and_then(|n| n.icon.clone());
+
+        widget::column![
+            widget::row![
+                widget::text(t!("edit-group")),
+                widget::text_input(
+                    &t!("edit-no-group"),
+                    self.config.group.as_deref().unwrap_or_default()
+                )
+                .on_input(|n| Message::EditInstance(EditInstanceMessage::GroupInput(n))),
             ]
-        ).style(LauncherTheme::style_scrollable_flat_extra_dark).into()
+            .spacing(10)
+            .align_y(iced::Alignment::Center),
+            widget::checkbox(
+                t!("edit-favorite"),
+                self.config.is_favorite.unwrap_or(false)
+            )
+            .on_toggle(|t| Message::EditInstance(EditInstanceMessage::FavoriteToggle(t))),
+            widget::row![widget::text(t!("edit-color"))]
+                .extend(colors)
+                .push(
+                    widget::button(widget::text(t!("edit-color-none")).size(12)).on_press(
+                        Message::EditInstance(EditInstanceMessage::ColorSelected(None))
+                    )
+                )
+                .spacing(5)
+                .align_y(iced::Alignment::Center),
+            widget::row![widget::text(t!("edit-icon"))]
+                .push_maybe(icon.clone().map(|n| widget::image(n).width(32).height(32)))
+                .push(
+                    widget::button(widget::text(t!("edit-icon-choose")))
+                        .on_press(Message::EditInstance(EditInstanceMessage::IconSelect))
+                )
+                .push(
+                    widget::button(widget::text(t!("edit-icon-remove"))).on_press_maybe(
+                        icon.is_some()
+                            .then_some(Message::EditInstance(EditInstanceMessage::IconRemove))
+                    )
+                )
+                .spacing(5)
+                .align_y(iced::Alignment::Center),
+            widget::text(t!("edit-notes")),
+            widget::text_editor(&self.notes)
+                .placeholder(t!("edit-notes-placeholder"))
+                .on_action(|n| Message::EditInstance(EditInstanceMessage::NotesEdit(n)))
+                .height(100),
+        ]
+        .spacing(10)
+        .into()
     }
 
     fn get_java_args_list<'a>(
@@ -166,7 +292,7 @@ impl MenuEditInstance {
                         .padding(5)
                 )
                 .on_press(msg_down(i)),
-                widget::text_input("Enter argument...", arg)
+                widget::text_input(&t!("edit-arg-placeholder"), arg)
                     .size(ITEM_SIZE + 8)
                     .on_input(move |n| edit_msg(n, i))
             )
@@ -175,3 +301,115 @@ impl MenuEditInstance {
         .into()
     }
 }
+
+impl MenuChangeVersion {
+    pub fn view(&self, tick_timer: usize) -> Element<'_> {
+        if let Some(progress) = &self.progress {
+            return widget::column![
+                widget::text(t!("change-version-progress")).size(20),
+                progress.view()
+            ]
+            .padding(10)
+            .spacing(10)
+            .into();
+        }
+
+        let Some(combo_state) = &self.combo_state else {
+            let dots = ".".repeat((tick_timer % 3) + 1);
+            return widget::column![
+                back_button().on_press(Message::LaunchScreenOpen {
+                    message: None,
+                    clear_selection: false
+                }),
+                widget::text(t!("change-version-loading", dots = dots)).size(20),
+            ]
+            .padding(10)
+            .spacing(10)
+            .into();
+        };
+
+        widget::column![
+            back_button().on_press(Message::LaunchScreenOpen {
+                message: None,
+                clear_selection: false
+            }),
+            widget::text(t!("edit-change-version")).size(20),
+            widget::text(t!(
+                "change-version-current",
+                version = self.current.as_str()
+            )),
+            widget::combo_box(
+                combo_state,
+                &t!("create-select-version"),
+                self.selected.as_ref(),
+                |n| Message::ChangeVersion(ChangeVersionMessage::Selected(n))
+            ),
+            widget::text(t!("change-version-desc")).size(12),
+            widget::button(widget::text(t!("change-version-button"))).on_press_maybe(
+                self.selected
+                    .as_ref()
+                    .is_some_and(|n| n.name != self.current)
+                    .then_some(Message::ChangeVersion(ChangeVersionMessage::Start))
+            ),
+        ]
+        .padding(10)
+        .spacing(10)
+        .into()
+    }
+}
+
+impl MenuCloneInstance {
+    pub fn view(&self) -> Element<'_> {
+        if let Some(progress) = &self.progress {
+            return widget::column![
+                widget::text(t!("clone-progress", name = self.instance.get_name())).size(20),
+                progress.view()
+            ]
+            .padding(10)
+            .spacing(10)
+            .into();
+        }
+
+        let is_server = self.instance.is_server();
+        widget::column![
+            back_button().on_press(match &self.instance {
+                InstanceSelection::Instance(_) => Message::LaunchScreenOpen {
+                    message: None,
+                    clear_selection: false,
+                },
+                InstanceSelection::Server(name) => Message::ServerManageOpen {
+                    selected_server: Some(name.clone()),
+                    message: None,
+                },
+            }),
+            widget::text(t!("clone-title", name = self.instance.get_name())).size(20),
+            widget::text_input(&t!("clone-name-placeholder"), &self.name)
+                .on_input(|n| Message::CloneInstance(CloneInstanceMessage::NameInput(n))),
+            widget::text(t!("clone-desc")).size(12),
+            widget::checkbox(
+                if is_server {
+                    t!("clone-world")
+                } else {
+                    t!("clone-worlds")
+                },
+                self.options.worlds
+            )
+            .on_toggle(|t| Message::CloneInstance(CloneInstanceMessage::WorldsToggle(t))),
+            widget::checkbox(t!("clone-logs"), self.options.logs)
+                .on_toggle(|t| Message::CloneInstance(CloneInstanceMessage::LogsToggle(t))),
+        ]
+        .push_maybe((!is_server).then(|| {
+            widget::checkbox(t!("clone-screenshots"), self.options.screenshots)
+                .on_toggle(|t| Message::CloneInstance(CloneInstanceMessage::ScreenshotsToggle(t)))
+        }))
+        .push(
+            button_with_icon(icon_manager::create(), t!("clone-button"), 16).on_press_maybe(
+                (!self.name.trim().is_empty())
+                    .then_some(Message::CloneInstance(CloneInstanceMessage::Start)),
+            ),
+        )
+        .padding(10)
+        .spacing(10)
+        .into()
+    }
+}
@@ -0,0 +1,98 @@
+use iced::{widget, Length};
+
+use crate::{
+    i18n::t,
+    keybinds::KeyAction,
+    state::{KeyOverlay, KeybindMessage, Launcher, MenuInstanceSwitcher, Message},
+    stylesheet::{color::Color, styles::LauncherTheme, widgets::StyleButton},
+};
+
+use super::Element;
+
+impl Launcher {
+    /// The popup opened by a keyboard shortcut (if any),
+    /// drawn over the rest of the launcher.
+    pub fn view_key_overlay(&self) -> Option<Element<'_>> {
+        let inner = match self.key_overlay.as_ref()? {
+            KeyOverlay::Switcher(menu) => self.view_switcher(menu),
+            KeyOverlay::Cheatsheet => self.view_cheatsheet(),
+        };
+
+        let popup = widget::container(inner)
+            .width(400)
+            .padding(10)
+            .style(LauncherTheme::style_container_box);
+
+        Some(widget::opaque(
+            widget::mouse_area(
+                widget::center(widget::opaque(popup)).style(|_: &LauncherTheme| {
+                    widget::container::Style {
+                        background: Some(iced::Color::from_rgba8(0, 0, 0, 0.6).into()),
+                        ..Default::default()
+                    }
+                }),
+            )
+            .on_press(Message::Keybind(KeybindMessage::OverlayClose)),
+        ))
+    }
+
+    fn view_switcher<'a>(&'a self, menu: &'a MenuInstanceSwitcher) -> Element<'a> {
+        let results = self.switcher_results(&menu.query);
+        let list: Element = if results.is_empty() {
+            widget::text(t!("keys-switcher-no-results"))
+                .size(14)
+                .style(|t: &LauncherTheme| t.style_text(Color::Mid))
+                .into()
+        } else {
+            widget::column(results.into_iter().enumerate().map(|(i, name)| {
+                let is_selected = i == menu.selected;
+                widget::button(widget::text(name).size(14))
+                    .style(move |n: &LauncherTheme, status| {
+                        n.style_button(
+                            if is_selected {
+                                widget::button::Status::Hovered
+                            } else {
+                                status
+                            },
+                            StyleButton::FlatDark,
+                        )
+                    })
+                    .width(Length::Fill)
+                    .on_press(Message::Keybind(KeybindMessage::SwitcherPick(name.clone())))
+                    .into()
+            }))
+            .into()
+        };
+
+        widget::column![
+            widget::text_input(&t!("keys-switcher-placeholder"), &menu.query)
+                .id(widget::text_input::Id::new("KeyOverlay:switcher"))
+                .on_input(|n| Message::Keybind(KeybindMessage::SwitcherInput(n)))
+                .on_submit(Message::Keybind(KeybindMessage::SwitcherSubmit)),
+            list,
+            widget::text(t!("keys-switcher-help")).size(12),
+        ]
+        .spacing(10)
+        .into()
+    }
+
+    fn view_cheatsheet(&self) -> Element<'_> {
+        widget::column![
+            widget::text(t!("keys-cheatsheet-title")).size(20),
+            widget::column(KeyAction::ALL.iter().map(|action| {
+                widget::row![
+                    widget::text(action.to_string()).size(14),
+                    widget::horizontal_space(),
+                    widget::text(self.keybinds.label(*action))
+                        .size(14)
+                        .style(|t: &LauncherTheme| t.style_text(Color::SecondLight)),
+                ]
+                .into()
+            }))
+            .spacing(5),
+            widget::text(t!("keys-cheatsheet-help")).size(12),
+        ]
+        .spacing(10)
+        .into()
+    }
+}
@@ -4,18 +4,20 @@ use iced::{widget, Length};
 use ql_core::{InstanceSelection, LAUNCHER_VERSION_NAME};
 
 use crate::{
+    i18n::t,
     icon_manager,
+    keybinds::KeyAction,
     menu_renderer::DISCORD,
     message_handler::SIDEBAR_DRAG_LEEWAY,
     state::{
-        AccountMessage, CreateInstanceMessage, InstanceLog, LaunchTabId, Launcher,
-        LauncherSettingsMessage, ManageModsMessage, MenuLaunch, Message, State, NEW_ACCOUNT_NAME,
-        OFFLINE_ACCOUNT_NAME,
+        AccountMessage, CloneInstanceMessage, CreateInstanceMessage, InstanceLog, LaunchTabId,
+        Launcher, LauncherSettingsMessage, ManageModsMessage, MenuLaunch, Message, SidebarMessage,
+        SidebarSection, SkinMessage, State, NEW_ACCOUNT_NAME, OFFLINE_ACCOUNT_NAME,
     },
     stylesheet::{color::Color, styles::LauncherTheme, widgets::StyleButton},
 };
 
-use super::{button_with_icon, shortcut_ctrl, tooltip, Element};
+use super::{button_with_icon, shortcut, shortcut_ctrl, tooltip, Element};
 
 pub const TAB_HEIGHT: f32 = 31.0;
 
@@ -58,15 +60,19 @@ impl Launcher {
                 widget::column![
                     widget::vertical_space(),
                     if menu.is_viewing_server {
-                        widget::button("View Instances...").on_press(Message::LaunchScreenOpen {
-                            message: None,
-                            clear_selection: true,
-                        })
+                        widget::button(widget::text(t!("launch-view-instances"))).on_press(
+                            Message::LaunchScreenOpen {
+                                message: None,
+                                clear_selection: true,
+                            },
+                        )
                     } else {
-                        widget::button("View Servers...").on_press(Message::ServerManageOpen {
-                            selected_server: None,
-                            message: None,
-                        })
+                        widget::button(widget::text(t!("launch-view-servers"))).on_press(
+                            Message::ServerManageOpen {
+                                selected_server: None,
+                                message: None,
+                            },
+                        )
                     },
                 ],
                 get_footer_text(menu),
@@ -93,12 +99,25 @@ impl Launcher {
                         main_buttons,
                         widget::horizontal_rule(10)
                             .style(|n: &LauncherTheme| n.style_rule(Color::SecondDark, 2)),
-                        widget::button("Export Instance").on_press(Message::ExportInstanceOpen),
+                        widget::row![
+                            widget::button(widget::text(t!("launch-export")))
+                                .on_press(Message::ExportInstanceOpen),
+                            widget::button(widget::text(t!("launch-clone")))
+                                .on_press(Message::CloneInstance(CloneInstanceMessage::Open)),
+                        ]
+                        .push_maybe((!menu.is_viewing_server).then(|| {
+                            tooltip(
+                                widget::button(widget::text(t!("launch-shortcut")))
+                                    .on_press(Message::ShortcutCreate),
+                                widget::text(t!("launch-shortcut-tooltip")).size(12),
+                            )
+                        }))
+                        .spacing(5),
                     )
                     .push_maybe({
                         if let Some(selected_instance) = selected_instance_s {
                             if self.is_process_running(menu, selected_instance) {
-                                Some(widget::text("Running...").size(20))
+                                Some(widget::text(t!("launch-running")).size(20))
                             } else {
                                 None
                             }
@@ -124,11 +143,14 @@ impl Launcher {
                     .into(),
                 LaunchTabId::Edit => {
                     if let Some(menu) = &menu.edit_instance {
-                        menu.view(selected)
+                        menu.view(
+                            selected,
+                            self.get_meta(selected.is_server()).get(selected.get_name()),
+                        )
                     } else {
                         widget::column!(
-                            "Error: Could not read config json!",
-                            button_with_icon(icon_manager::delete(), "Delete Instance", 16)
+                            widget::text(t!("launch-config-error")),
+                            button_with_icon(icon_manager::delete(), t!("instance-delete"), 16)
                                 .on_press(Message::DeleteInstanceMenu)
                         )
                         .padding(10)
@@ -138,7 +160,7 @@ impl Launcher {
                 }
             }
         } else {
-            widget::column!("Select an instance", last_parts)
+            widget::column!(widget::text(t!("launch-select-instance")), last_parts)
                 .padding(10)
                 .spacing(10)
                 .into()
@@ -178,28 +200,31 @@ impl Launcher {
             );
 
             widget::column![widget::row!(
-                widget::button(widget::text("Copy Log").size(14)).on_press(if is_server {
-                    Message::ServerManageCopyLog
-                } else {
-                    Message::LaunchCopyLog
-                }),
-                widget::button(widget::text("Join Discord").size(14))
+                widget::button(widget::text(t!("launch-copy-log")).size(14)).on_press(
+                    if is_server {
+                        Message::ServerManageCopyLog
+                    } else {
+                        Message::LaunchCopyLog
+                    }
+                ),
+                widget::button(widget::text(t!("common-join-discord")).size(14))
                     .on_press(Message::CoreOpenLink(DISCORD.to_owned())),
-                widget::text("Having issues? Copy and send the game log for support").size(12),
+                widget::text(t!("launch-log-help")).size(12),
             )
             .spacing(10)]
             .push_maybe(
                 has_crashed.then_some(
-                    widget::text!(
-                        "The {} has crashed!",
-                        if is_server { "server" } else { "game" }
-                    )
+                    widget::text(if is_server {
+                        t!("launch-server-crashed")
+                    } else {
+                        t!("launch-game-crashed")
+                    })
                     .size(18),
                 ),
             )
             .push_maybe(
                 is_server.then_some(
-                    widget::text_input("Enter command...", command)
+                    widget::text_input(&t!("launch-enter-command"), command)
                         .on_input(move |n| {
                             Message::ServerManageEditCommand(
                                 selected_instance.unwrap().to_owned(),
@@ -227,51 +252,27 @@ impl Launcher {
     ) -> Element<'a> {
         let difference = self.mouse_pos.0 - f32::from(menu.sidebar_width);
 
-        let list = if menu.is_viewing_server {
-            self.server_list.as_deref()
-        } else {
-            self.client_list.as_deref()
-        };
+        let sections = self.sidebar_sections(menu.is_viewing_server);
 
         let is_hovered = difference < SIDEBAR_DRAG_LEEWAY
             && difference > 0.0
             && (!self.is_log_open || (self.mouse_pos.1 < self.window_size.1 / 2.0));
 
-        let list = widget::row!(if let Some(instances) = list {
+        let list = widget::row!(if let Some(sections) = sections {
+            let has_headers = Self::sidebar_has_headers(&sections);
             widget::column![
                 get_sidebar_new_button(menu),
-                widget::scrollable(widget::column(instances.iter().map(|name| {
-                    let playing_icon = if self.is_process_running(menu, name) {
-                        Some(widget::row![
-                            widget::horizontal_space(),
-                            icon_manager::play(),
-                            widget::Space::with_width(10),
-                        ])
-                    } else {
-                        None
-                    };
-
-                    let text = widget::text(name).size(16);
-
-                    if selected_instance_s == Some(name) {
-                        widget::container(widget::row!(widget::Space::with_width(5), text))
-                            .style(LauncherTheme::style_container_selected_flat_button)
-                            .width(menu.sidebar_width)
-                            .padding(5)
-                            .into()
-                    } else {
-                        widget::button(widget::row![text].push_maybe(playing_icon))
-                            .style(|n: &LauncherTheme, status| {
-                                n.style_button(status, StyleButton::FlatExtraDark)
-                            })
-                            .on_press(Message::LaunchInstanceSelected {
-                                name: name.clone(),
-                                is_server: menu.is_viewing_server,
-                            })
-                            .width(menu.sidebar_width)
-                            .into()
+                widget::scrollable(widget::column(sections.into_iter().map(
+                    |(section, names)| {
+                        self.get_sidebar_section(
+                            menu,
+                            selected_instance_s,
+                            section,
+                            names,
+                            has_headers,
+                        )
                     }
-                })))
+                )))
                 .height(Length::Fill)
                 .style(LauncherTheme::style_scrollable_flat_extra_dark)
                 .id(iced::widget::scrollable::Id::new("MenuLaunch:sidebar"))
@@ -284,7 +285,7 @@ impl Launcher {
             .spacing(5)
         } else {
             let dots = ".".repeat((self.tick_timer % 3) + 1);
-            widget::column![widget::text!("Loading{dots}")]
+            widget::column![widget::text!("{}{dots}", t!("common-loading"))]
         }
         .width(menu.sidebar_width))
         .push_maybe(is_hovered.then_some(
@@ -296,12 +297,141 @@ impl Launcher {
             .into()
     }
 
+    fn get_sidebar_section<'a>(
+        &'a self,
+        menu: &'a MenuLaunch,
+        selected_instance_s: Option<&'a str>,
+        section: SidebarSection,
+        names: Vec<&'a String>,
+        has_header: bool,
+    ) -> Element<'a> {
+        let is_collapsed = has_header && self.is_section_collapsed(&section);
+        let is_drop_target = menu
+            .sidebar_drag
+            .as_ref()
+            .is_some_and(|n| n.to.as_ref() == Some(&section) && n.from != section);
+
+        let header = has_header.then(|| {
+            widget::button(
+                widget::row![
+                    if is_collapsed {
+                        icon_manager::arrow_up_with_size(12)
+                    } else {
+                        icon_manager::arrow_down_with_size(12)
+                    },
+                    widget::text!("{section} ({})", names.len()).size(14),
+                ]
+                .spacing(8)
+                .align_y(iced::Alignment::Center),
+            )
+            .style(move |n: &LauncherTheme, status| {
+                n.style_button(
+                    status,
+                    if is_drop_target {
+                        StyleButton::FlatDark
+                    } else {
+                        StyleButton::FlatExtraDark
+                    },
+                )
+            })
+            .on_press(Message::Sidebar(SidebarMessage::SectionToggle(
+                section.clone(),
+            )))
+            .width(menu.sidebar_width)
+        });
+
+        let entries = (!is_collapsed).then(|| {
+            widget::column(
+                names
+                    .into_iter()
+                    .map(|name| self.get_sidebar_entry(menu, selected_instance_s, name)),
+            )
+        });
+
+        widget::mouse_area(widget::Column::new().push_maybe(header).push_maybe(entries))
+            .on_enter(Message::Sidebar(SidebarMessage::SectionEnter(
+                section.clone(),
+            )))
+            .on_exit(Message::Sidebar(SidebarMessage::SectionLeave(section)))
+            .into()
+    }
+
+    fn get_sidebar_entry<'a>(
+        &'a self,
+        menu: &'a MenuLaunch,
+        selected_instance_s: Option<&'a str>,
+        name: &'a String,
+    ) -> Element<'a> {
+        let meta = self.get_meta(menu.is_viewing_server).get(name);
+        let is_selected = selected_instance_s == Some(name);
+        let is_hovered = menu.sidebar_hovered.as_ref() == Some(name);
+
+        let color_tag: Element = if let Some(color) = meta.and_then(|n| n.color) {
+            widget::container(widget::Space::new(4, 16))
+                .style(move |_: &LauncherTheme| widget::container::Style {
+                    background: Some(color.into()),
+                    ..Default::default()
+                })
+                .into()
+        } else {
+            widget::Space::with_width(4).into()
+        };
+
+        let playing_icon = self.is_process_running(menu, name).then(|| {
+            widget::row![
+                widget::horizontal_space(),
+                icon_manager::play(),
+                widget::Space::with_width(5),
+            ]
+        });
+
+        let entry = widget::row![color_tag]
+            .push_maybe(
+                meta.and_then(|n| n.icon.clone())
+                    .map(|icon| widget::image(icon).width(16).height(16)),
+            )
+            .push(widget::text(name).size(16))
+            .push_maybe(playing_icon)
+            .spacing(5)
+            .align_y(iced::Alignment::Center);
+
+        widget::mouse_area(
+            widget::container(entry)
+                .style(move |n: &LauncherTheme| {
+                    if is_selected {
+                        return n.style_container_selected_flat_button();
+                    }
+                    let style = n.style_button(
+                        if is_hovered {
+                            widget::button::Status::Hovered
+                        } else {
+                            widget::button::Status::Active
+                        },
+                        StyleButton::FlatExtraDark,
+                    );
+                    widget::container::Style {
+                        text_color: Some(style.text_color),
+                        background: style.background,
+                        border: style.border,
+                        shadow: style.shadow,
+                    }
+                })
+                .width(menu.sidebar_width)
+                .padding(5),
+        )
+        .on_press(Message::Sidebar(SidebarMessage::Press(name.clone())))
+        .on_enter(Message::Sidebar(SidebarMessage::HoverEnter(name.clone())))
+        .on_exit(Message::Sidebar(SidebarMessage::HoverLeave(name.clone())))
+        .interaction(iced::mouse::Interaction::Pointer)
+        .into()
+    }
+
     fn is_process_running(&self, menu: &MenuLaunch, name: &str) -> bool {
         (!menu.is_viewing_server && self.client_processes.contains_key(name))
             || (menu.is_viewing_server && self.server_processes.contains_key(name))
     }
 
-    fn get_accounts_bar(&self, menu: &MenuLaunch) -> Element {
+    fn get_accounts_bar(&self, menu: &MenuLaunch) -> Element<'_> {
         let something_is_happening = self.java_recv.is_some() || menu.login_progress.is_some();
 
         let dropdown: Element = if something_is_happening {
@@ -320,12 +450,26 @@ impl Launcher {
 
         widget::column![
             widget::row![
-                widget::text(" Accounts:").size(14),
+                widget::text!(" {}", t!("launch-accounts")).size(14),
                 widget::horizontal_space(),
             ]
             .push_maybe(
                 self.is_account_selected().then_some(
-                    widget::button(widget::text("Logout").size(11))
+                    widget::button(widget::text(t!("launch-skin")).size(11))
+                        .padding(iced::Padding {
+                            top: 3.0,
+                            right: 8.0,
+                            bottom: 3.0,
+                            left: 8.0
+                        })
+                        .on_press(Message::Skin(SkinMessage::Open))
+                        .style(|n: &LauncherTheme, status| n
+                            .style_button(status, StyleButton::FlatExtraDark))
+                )
+            )
+            .push_maybe(
+                self.is_account_selected().then_some(
+                    widget::button(widget::text(t!("launch-logout")).size(11))
                         .padding(iced::Padding {
                             top: 3.0,
                             right: 8.0,
@@ -340,61 +484,136 @@ impl Launcher {
             .width(menu.sidebar_width - 10),
             dropdown
         ]
+        .push_maybe(self.get_relogin_prompt())
         .push_maybe(
-            (self.accounts_selected.as_deref() == Some(OFFLINE_ACCOUNT_NAME)).then_some(
-                widget::text_input("Enter username...", &self.config.username)
-                    .on_input(Message::LaunchUsernameSet)
-                    .width(menu.sidebar_width - 10),
-            ),
+            (self.accounts_selected.as_deref() == Some(OFFLINE_ACCOUNT_NAME))
+                .then(|| self.get_offline_profiles(menu)),
         )
         .padding(5)
         .spacing(5)
         .into()
     }
 
+    /// Shown if the selected account's login expired
+    fn get_relogin_prompt(&self) -> Option<Element<'_>> {
+        let account = self.accounts_selected.as_ref()?;
+        let needs_relogin = self
+            .config
+            .accounts
+            .as_ref()?
+            .get(account)?
+            .needs_relogin
+            .unwrap_or_default();
+        needs_relogin.then(|| {
+            widget::row![
+                widget::text(t!("launch-login-expired")).size(12),
+                widget::horizontal_space(),
+                widget::button(widget::text(t!("launch-relogin")).size(11))
+                    .padding(iced::Padding {
+                        top: 3.0,
+                        right: 8.0,
+                        bottom: 3.0,
+                        left: 8.0,
+                    })
+                    .on_press(Message::Account(AccountMessage::Relogin(account.clone()))),
+            ]
+            .align_y(iced::Alignment::Center)
+            .into()
+        })
+    }
+
+    fn get_offline_profiles(&self, menu: &MenuLaunch) -> Element<'_> {
+        let profiles = self.config.offline_profiles.as_deref().unwrap_or_default();
+        let is_saved = profiles.contains(&self.config.username);
+
+        let small_button = |text| {
+            widget::button(widget::text(text).size(11))
+                .padding(iced::Padding {
+                    top: 3.0,
+                    right: 8.0,
+                    bottom: 3.0,
+                    left: 8.0,
+                })
+                .style(|n: &LauncherTheme, status| {
+                    n.style_button(status, StyleButton::FlatExtraDark)
+                })
+        };
+
+        widget::column![]
+            .push_maybe((!profiles.is_empty()).then(|| {
+                widget::pick_list(
+                    profiles,
+                    is_saved.then(|| self.config.username.clone()),
+                    |n| Message::Account(AccountMessage::OfflineProfileSelected(n)),
+                )
+                .placeholder(t!("launch-saved-usernames"))
+                .width(menu.sidebar_width - 10)
+            }))
+            .push(
+                widget::row![
+                    widget::text_input(&t!("launch-enter-username"), &self.config.username)
+                        .on_input(Message::LaunchUsernameSet),
+                    if is_saved {
+                        small_button(t!("launch-forget"))
+                            .on_press(Message::Account(AccountMessage::OfflineProfileRemove))
+                    } else {
+                        small_button(t!("launch-save")).on_press_maybe(
+                            (!self.config.username.is_empty())
+                                .then_some(Message::Account(AccountMessage::OfflineProfileSave)),
+                        )
+                    },
+                ]
+                .align_y(iced::Alignment::Center)
+                .spacing(5)
+                .width(menu.sidebar_width - 10),
+            )
+            .spacing(5)
+            .into()
+    }
+
     pub fn is_account_selected(&self) -> bool {
         !(self.accounts_selected.is_none()
             || self.accounts_selected.as_deref() == Some(NEW_ACCOUNT_NAME)
             || self.accounts_selected.as_deref() == Some(OFFLINE_ACCOUNT_NAME))
     }
 
-    fn get_client_play_button(&self, selected_instance: Option<&str>) -> Element {
-        let play_button = button_with_icon(icon_manager::play(), "Play", 16).width(98);
+    fn get_client_play_button(&self, selected_instance: Option<&str>) -> Element<'_> {
+        let play_button = button_with_icon(icon_manager::play(), t!("launch-play"), 16).width(98);
 
         let is_account_selected = self.is_account_selected();
 
         if self.config.username.is_empty() && !is_account_selected {
-            tooltip(play_button, "Username is empty!")
+            tooltip(play_button, widget::text(t!("launch-username-empty")))
         } else if self.config.username.contains(' ') && !is_account_selected {
-            tooltip(play_button, "Username contains spaces!")
+            tooltip(play_button, widget::text(t!("launch-username-spaces")))
         } else if let Some(selected_instance) = selected_instance {
             if self.client_processes.contains_key(selected_instance) {
                 tooltip(
-                    button_with_icon(icon_manager::play(), "Kill", 16)
+                    button_with_icon(icon_manager::play(), t!("launch-kill"), 16)
                         .on_press(Message::LaunchKill)
                         .width(98),
-                    shortcut_ctrl("Backspace"),
+                    shortcut(&self.keybinds, KeyAction::Kill),
                 )
             } else if self.is_launching_game {
                 tooltip(
-                    button_with_icon(icon_manager::play(), "Loading...", 16).width(98),
-                    shortcut_ctrl("Please wait..."),
+                    button_with_icon(icon_manager::play(), t!("launch-loading"), 16).width(98),
+                    shortcut_ctrl(&t!("launch-please-wait")),
                 )
             } else {
                 tooltip(
                     play_button.on_press(Message::LaunchStart),
-                    shortcut_ctrl("Enter"),
+                    shortcut(&self.keybinds, KeyAction::Launch),
                 )
             }
         } else {
-            tooltip(play_button, "Select an instance first!")
+            tooltip(play_button, widget::text(t!("launch-select-first")))
         }
     }
 
     fn get_files_button(
         selected_instance: &InstanceSelection,
     ) -> widget::Button<'_, Message, LauncherTheme> {
-        button_with_icon(icon_manager::folder(), "Files", 16)
+        button_with_icon(icon_manager::folder(), t!("launch-files"), 16)
             .on_press(Message::CoreOpenPath(
                 selected_instance.get_dot_minecraft_path(),
             ))
@@ -404,21 +623,21 @@ impl Launcher {
     fn get_server_play_button<'a>(&self, selected_server: Option<&'a str>) -> Element<'a> {
         if selected_server.is_some_and(|n| self.server_processes.contains_key(n)) {
             tooltip(
-                button_with_icon(icon_manager::play(), "Stop", 16)
+                button_with_icon(icon_manager::play(), t!("launch-server-stop"), 16)
                     .width(97)
                     .on_press_maybe((selected_server.is_some()).then(|| {
                         Message::ServerManageKillServer(selected_server.unwrap().to_owned())
                     })),
-                shortcut_ctrl("Escape"),
+                shortcut(&self.keybinds, KeyAction::Kill),
             )
         } else {
             tooltip(
-                button_with_icon(icon_manager::play(), "Start", 16)
+                button_with_icon(icon_manager::play(), t!("launch-server-start"), 16)
                     .width(97)
                     .on_press_maybe((selected_server.is_some()).then(|| {
                         Message::ServerManageStartServer(selected_server.unwrap().to_owned())
                     })),
-                "By starting the server, you agree to the EULA",
+                widget::text(t!("launch-server-eula")),
             )
         }
     }
@@ -426,10 +645,13 @@ impl Launcher {
 
 fn get_sidebar_new_button(menu: &MenuLaunch) -> widget::Button<'_, Message, LauncherTheme> {
     widget::button(
-        widget::row![icon_manager::create(), widget::text("New").size(16)]
-            .align_y(iced::alignment::Vertical::Center)
-            .height(TAB_HEIGHT - 10.0)
-            .spacing(10),
+        widget::row![
+            icon_manager::create(),
+            widget::text(t!("launch-new")).size(16)
+        ]
+        .align_y(iced::alignment::Vertical::Center)
+        .height(TAB_HEIGHT - 10.0)
+        .spacing(10),
     )
     .style(|n, status| n.style_button(status, StyleButton::FlatDark))
     .on_press(if menu.is_viewing_server {
@@ -481,7 +703,7 @@ fn get_tab_selector<'a>(selected_instance_s: Option<&'a str>, menu: &'a MenuLaun
 fn get_mods_button(
     selected_instance_s: Option<&str>,
 ) -> widget::Button<'_, Message, LauncherTheme> {
-    button_with_icon(icon_manager::download(), "Mods", 15)
+    button_with_icon(icon_manager::download(), t!("launch-mods"), 15)
         .on_press_maybe(
             (selected_instance_s.is_some())
                 .then_some(Message::ManageMods(ManageModsMessage::ScreenOpen)),
@@ -489,7 +711,7 @@ fn get_mods_button(
         .width(98)
 }
 
-fn render_tab_button(n: LaunchTabId, menu: &MenuLaunch) -> Element {
+fn render_tab_button(n: LaunchTabId, menu: &MenuLaunch) -> Element<'_> {
     let txt = widget::row!(
         widget::horizontal_space(),
         widget::text(n.to_string()),
@@ -513,23 +735,21 @@ fn render_tab_button(n: LaunchTabId, menu: &MenuLaunch) -> Element {
 }
 
 fn get_no_logs_message<'a>() -> widget::Column<'a, Message, LauncherTheme> {
-    const BASE_MESSAGE: &str = "No logs found";
+    let base_message = widget::text(t!("launch-no-logs"));
 
     if cfg!(target_arch = "aarch64") || cfg!(target_arch = "x86") {
         let experimental_message = widget::column!(
-            widget::text(
-                "Note: This version is experimental. If you want to get help join our discord"
-            ),
-            button_with_icon(icon_manager::chat(), "Join Discord", 16)
+            widget::text(t!("launch-experimental")),
+            button_with_icon(icon_manager::chat(), t!("common-join-discord"), 16)
                 .on_press(Message::CoreOpenLink(DISCORD.to_owned())),
         );
-        widget::column!(BASE_MESSAGE, experimental_message)
+        widget::column!(base_message, experimental_message)
     } else {
-        widget::column!(BASE_MESSAGE)
+        widget::column!(base_message)
     }
 }
 
-fn get_footer_text(menu: &MenuLaunch) -> Element {
+fn get_footer_text(menu: &MenuLaunch) -> Element<'_> {
     let version_message = widget::column!(
         widget::vertical_space(),
         widget::row!(
@@ -538,7 +758,7 @@ fn get_footer_text(menu: &MenuLaunch) -> Element {
         ),
         widget::row!(
             widget::horizontal_space(),
-            widget::text("A Minecraft Launcher by Mrmayman").size(10)
+            widget::text(t!("launch-footer")).size(10)
         ),
     );
 
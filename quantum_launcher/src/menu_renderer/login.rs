@@ -1,6 +1,7 @@
 use iced::widget;
 
 use crate::{
+    i18n::t,
     icon_manager,
     state::{AccountMessage, MenuLoginElyBy, MenuLoginMS, Message, NEW_ACCOUNT_NAME},
 };
@@ -8,12 +9,12 @@ use crate::{
 use super::{back_button, button_with_icon, Element};
 
 impl MenuLoginElyBy {
-    pub fn view(&self, tick_timer: usize) -> Element {
+    pub fn view(&self, tick_timer: usize) -> Element<'_> {
         let status: Element = if self.is_loading {
             let dots = ".".repeat((tick_timer % 3) + 1);
-            widget::text!("Loading{dots}").into()
+            widget::text(t!("common-loading-dots", dots = dots)).into()
         } else {
-            button_with_icon(icon_manager::tick(), "Login", 16)
+            button_with_icon(icon_manager::tick(), t!("login-button"), 16)
                 .on_press(Message::Account(AccountMessage::ElyByLogin))
                 .into()
         };
@@ -25,7 +26,7 @@ impl MenuLoginElyBy {
             left: 10.0,
         };
 
-        let password_input = widget::text_input("Enter Password...", &self.password)
+        let password_input = widget::text_input(&t!("login-password-placeholder"), &self.password)
             .padding(padding)
             .on_input(|n| Message::Account(AccountMessage::ElyByPasswordInput(n)));
         let password_input = if self.password.is_empty() || self.show_password {
@@ -42,42 +43,65 @@ impl MenuLoginElyBy {
             }),
             widget::row![
                 widget::horizontal_space(),
-                widget::column![
-                    widget::vertical_space(),
-                    widget::text("Username/Email:").size(12),
-                    widget::text_input("Enter Username/Email...", &self.username)
-                        .padding(padding)
-                        .on_input(|n| Message::Account(AccountMessage::ElyByUsernameInput(n))),
-                    widget::text("Password:").size(12),
-                    password_input,
-                    widget::checkbox("Show Password", self.show_password)
-                        .size(14)
-                        .text_size(14)
-                        .on_toggle(|t| Message::Account(AccountMessage::ElyByShowPassword(t))),
-                    widget::Column::new().push_maybe(self.otp.as_deref().map(|otp| {
+                widget::column![widget::vertical_space()]
+                    .push_maybe(self.custom_server.as_deref().map(|server| {
                         widget::column![
-                            widget::text("OTP:").size(12),
-                            widget::text_input("Enter Username/Email...", otp)
+                            widget::text(t!("login-server")).size(12),
+                            widget::text_input(&t!("login-server-placeholder"), server)
                                 .padding(padding)
-                                .on_input(|n| Message::Account(AccountMessage::ElyByOtpInput(n))),
+                                .on_input(|n| Message::Account(
+                                    AccountMessage::AuthlibServerInput(n)
+                                )),
                         ]
                         .spacing(5)
-                    })),
-                    status,
-                    widget::Space::with_height(5),
-                    widget::row![
-                        widget::text("Or").size(14),
-                        widget::button(widget::text("Create an account").size(14)).on_press(
-                            Message::CoreOpenLink("https://account.ely.by/register".to_owned())
-                        )
-                    ]
-                    .align_y(iced::Alignment::Center)
-                    .spacing(5)
-                    .wrap(),
-                    widget::vertical_space(),
-                ]
-                .align_x(iced::Alignment::Center)
-                .spacing(5),
+                    }))
+                    .push(
+                        widget::column![
+                            widget::text(t!("login-username")).size(12),
+                            widget::text_input(&t!("login-username-placeholder"), &self.username)
+                                .padding(padding)
+                                .on_input(|n| Message::Account(
+                                    AccountMessage::ElyByUsernameInput(n)
+                                )),
+                            widget::text(t!("login-password")).size(12),
+                            password_input,
+                            widget::checkbox(t!("login-show-password"), self.show_password)
+                                .size(14)
+                                .text_size(14)
+                                .on_toggle(|t| Message::Account(
+                                    AccountMessage::ElyByShowPassword(t)
+                                )),
+                            widget::Column::new().push_maybe(self.otp.as_deref().map(|otp| {
+                                widget::column![
+                                    widget::text(t!("login-otp")).size(12),
+                                    widget::text_input(&t!("login-otp-placeholder"), otp)
+                                        .padding(padding)
+                                        .on_input(|n| Message::Account(
+                                            AccountMessage::ElyByOtpInput(n)
+                                        )),
+                                ]
+                                .spacing(5)
+                            })),
+                            status,
+                        ]
+                        .align_x(iced::Alignment::Center)
+                        .spacing(5)
+                    )
+                    .push_maybe(self.custom_server.is_none().then(|| {
+                        widget::row![
+                            widget::text(t!("login-or")).size(14),
+                            widget::button(widget::text(t!("login-create-account")).size(14))
+                                .on_press(Message::CoreOpenLink(
+                                    "https://account.ely.by/register".to_owned()
+                                ))
+                        ]
+                        .align_y(iced::Alignment::Center)
+                        .spacing(5)
+                        .wrap()
+                    }))
+                    .push(widget::vertical_space())
+                    .align_x(iced::Alignment::Center)
+                    .spacing(5),
                 widget::horizontal_space(),
             ]
         ]
@@ -88,22 +112,36 @@ impl MenuLoginElyBy {
 
 impl MenuLoginMS {
     pub fn view<'a>(&self) -> Element<'a> {
+        let back_msg = if self.is_from_welcome_screen {
+            Message::WelcomeContinueToAuth
+        } else {
+            Message::Account(AccountMessage::Selected(NEW_ACCOUNT_NAME.to_owned()))
+        };
+        let remaining = self
+            .expires_at
+            .saturating_duration_since(std::time::Instant::now())
+            .as_secs();
+
         widget::column![
-            back_button().on_press(if self.is_from_welcome_screen {
-                Message::WelcomeContinueToAuth
-            } else {
-                Message::Account(AccountMessage::Selected(NEW_ACCOUNT_NAME.to_owned()))
-            }),
+            back_button().on_press(back_msg.clone()),
             widget::row!(
                 widget::horizontal_space(),
                 widget::column!(
                     widget::vertical_space(),
-                    widget::text("Login to Microsoft").size(20),
-                    "Open this link and enter the code:",
-                    widget::text!("Code: {}", self.code),
-                    widget::button("Copy").on_press(Message::CoreCopyText(self.code.clone())),
-                    widget::text!("Link: {}", self.url),
-                    widget::button("Open").on_press(Message::CoreOpenLink(self.url.clone())),
+                    widget::text(t!("login-ms-title")).size(20),
+                    widget::text(t!("login-ms-help")),
+                    widget::text(t!("login-ms-code", code = self.code.as_str())),
+                    widget::button(widget::text(t!("login-ms-copy")))
+                        .on_press(Message::CoreCopyText(self.code.clone())),
+                    widget::text(t!("login-ms-link", url = self.url.as_str())),
+                    widget::button(widget::text(t!("login-ms-open")))
+                        .on_press(Message::CoreOpenLink(self.url.clone())),
+                    widget::text(t!(
+                        "login-ms-waiting",
+                        expires = format!("{}:{:02}", remaining / 60, remaining % 60)
+                    ))
+                    .size(12),
+                    widget::button(widget::text(t!("login-ms-cancel"))).on_press(back_msg),
                     widget::vertical_space(),
                 )
                 .spacing(5)
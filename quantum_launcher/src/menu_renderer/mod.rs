@@ -1,9 +1,13 @@
 use iced::{widget, Length};
-use ql_core::{InstanceSelection, Progress};
+use ql_core::{InstanceSelection, Progress, VersionType};
+use ql_instances::VersionFilter;
 
 use crate::{
     config::LauncherConfig,
+    i18n::t,
     icon_manager,
+    keybinds::{KeyAction, Keybinds},
+    message_handler::format_size,
     state::{
         AccountMessage, CreateInstanceMessage, InstallModsMessage, LauncherSettingsMessage,
         LicenseTab, ManageModsMessage, MenuCreateInstance, MenuCurseforgeManualDownload,
@@ -14,11 +18,14 @@ use crate::{
 
 pub mod changelog;
 mod edit_instance;
+mod keybinds;
 mod launch;
 mod log;
 mod login;
 mod mods;
 mod settings;
+mod skins;
+mod url_install;
 
 pub const DISCORD: &str = "https://discord.gg/bWqRaSXar5";
 pub const GITHUB: &str = "https://github.com/Mrmayman/quantumlauncher";
@@ -43,12 +50,12 @@ pub fn tooltip<'a>(e: impl Into<Element<'a>>, tooltip: impl Into<Element<'a>>) -
 }
 
 pub fn back_button<'a>() -> widget::Button<'a, Message, LauncherTheme, iced::Renderer> {
-    button_with_icon(icon_manager::back_with_size(14), "Back", 14)
+    button_with_icon(icon_manager::back_with_size(14), t!("common-back"), 14)
 }
 
 pub fn button_with_icon<'element>(
     icon: Element<'element>,
-    text: &'element str,
+    text: impl widget::text::IntoFragment<'element>,
     size: u16,
 ) -> iced::widget::Button<'element, Message, LauncherTheme, iced::Renderer> {
     widget::button(
@@ -61,23 +68,37 @@ pub fn button_with_icon<'element>(
 
 pub fn shortcut_ctrl<'a>(key: &str) -> Element<'a> {
     #[cfg(target_os = "macos")]
-    return widget::text!("Command + {key}").size(12).into();
+    return widget::text(t!("shortcut-command", key = key))
+        .size(12)
+        .into();
 
     #[cfg(not(target_os = "macos"))]
-    return widget::text!("Control + {key}").size(12).into();
+    return widget::text(t!("shortcut-control", key = key))
+        .size(12)
+        .into();
+}
+
+/// Tooltip text for the configured shortcut of `action`.
+pub fn shortcut<'a>(keybinds: &Keybinds, action: KeyAction) -> Element<'a> {
+    widget::text(keybinds.label(action)).size(12).into()
 }
 
 impl MenuCreateInstance {
-    pub fn view(&self) -> Element {
+    pub fn view(&self) -> Element<'_> {
         match self {
             MenuCreateInstance::LoadingList { .. } => widget::column![
                 widget::row![
                     back_button().on_press(Message::CreateInstance(CreateInstanceMessage::Cancel)),
-                    button_with_icon(icon_manager::folder(), "Import Instance", 16)
+                    button_with_icon(icon_manager::folder(), t!("create-import"), 16)
                         .on_press(Message::CreateInstance(CreateInstanceMessage::Import)),
+                    tooltip(
+                        button_with_icon(icon_manager::folder(), t!("create-import-folder"), 16)
+                            .on_press(Message::CreateInstance(CreateInstanceMessage::ImportFolder)),
+                        widget::text(t!("create-import-folder-tooltip")).size(12),
+                    ),
                 ]
                 .spacing(5),
-                widget::text("Loading version list...").size(20),
+                widget::text(t!("create-loading-versions")).size(20),
             ]
             .padding(10)
             .spacing(10)
@@ -86,47 +107,102 @@ impl MenuCreateInstance {
                 instance_name,
                 selected_version,
                 download_assets,
+                filter,
                 combo_state,
-                ..
             } => {
                 widget::scrollable(
                     widget::column![
                         widget::row![
-                            back_button()
-                                .on_press(
-                                    Message::LaunchScreenOpen {
-                                        message: None,
-                                        clear_selection: false
-                                }),
-                            button_with_icon(icon_manager::folder(), "Import Instance", 16)
+                            back_button().on_press(Message::LaunchScreenOpen {
+                                message: None,
+                                clear_selection: false
+                            }),
+                            button_with_icon(icon_manager::folder(), t!("create-import"), 16)
                                 .on_press(Message::CreateInstance(CreateInstanceMessage::Import)),
+                            tooltip(
+                                button_with_icon(
+                                    icon_manager::folder(),
+                                    t!("create-import-folder"),
+                                    16
+                                )
+                                .on_press(Message::CreateInstance(
+                                    CreateInstanceMessage::ImportFolder
+                                )),
+                                widget::text(t!("create-import-folder-tooltip-long")).size(12),
+                            ),
                         ]
                         .spacing(5),
-                        widget::combo_box(combo_state, "Select a version...", selected_version.as_ref(), |version| {
-                            Message::CreateInstance(CreateInstanceMessage::VersionSelected(version))
-                        }),
-                        widget::text_input("Enter instance name...", instance_name)
-                            .on_input(|n| Message::CreateInstance(CreateInstanceMessage::NameInput(n))),
-                        tooltip(
-                            widget::checkbox("Download assets?", *download_assets).on_toggle(|t| Message::CreateInstance(CreateInstanceMessage::ChangeAssetToggle(t))),
-                            widget::text("If disabled, creating instance will be MUCH faster, but no sound or music will play in-game").size(12),
+                        view_version_filter(filter),
+                        widget::combo_box(
+                            combo_state,
+                            &t!("create-select-version"),
+                            selected_version.as_ref(),
+                            |version| {
+                                Message::CreateInstance(CreateInstanceMessage::VersionSelected(
+                                    version,
+                                ))
+                            }
                         ),
-                        widget::button(widget::row![icon_manager::create(), "Create Instance"]
+                    ]
+                    .push_maybe(selected_version.as_ref().map(|version| {
+                        let text = match version.release_time {
+                            Some(date) => t!(
+                                "create-version-info-date",
+                                kind = version.kind.to_string(),
+                                date = date.format("%Y-%m-%d").to_string(),
+                                source = version.source.to_string()
+                            ),
+                            None => t!(
+                                "create-version-info",
+                                kind = version.kind.to_string(),
+                                source = version.source.to_string()
+                            ),
+                        };
+                        widget::text(text).size(12)
+                    }))
+                    .push(
+                        widget::column![
+                            widget::text_input(&t!("create-name-placeholder"), instance_name)
+                                .on_input(|n| Message::CreateInstance(
+                                    CreateInstanceMessage::NameInput(n)
+                                )),
+                            tooltip(
+                                widget::checkbox(t!("create-download-assets"), *download_assets)
+                                    .on_toggle(|t| Message::CreateInstance(
+                                        CreateInstanceMessage::ChangeAssetToggle(t)
+                                    )),
+                                widget::text(t!("create-download-assets-tooltip")).size(12),
+                            ),
+                            widget::button(
+                                widget::row![
+                                    icon_manager::create(),
+                                    widget::text(t!("create-button"))
+                                ]
                                 .spacing(10)
                                 .padding(5)
-                        ).on_press_maybe((selected_version.is_some() && !instance_name.is_empty()).then(|| Message::CreateInstance(CreateInstanceMessage::Start))),
-                        widget::text("To install Fabric/Forge/OptiFine/Quilt, click on Mods after installing the instance").size(12),
-                    ].push_maybe(
-                        {
-                            let real_platform = if cfg!(target_arch = "x86") { "x86_64" } else { "aarch64" };
-                            (cfg!(target_os = "linux") && (cfg!(target_arch = "x86") || cfg!(target_arch = "arm")))
-                            .then_some(
-                                widget::column![
-                                    // WARN: Linux i686 and arm32
-                                    widget::text("Warning: On your platform (Linux 32 bit) only Minecraft 1.16.5 and below are supported.").size(20),
-                                    widget::text!("If your computer isn't outdated, you might have wanted to download QuantumLauncher 64 bit ({real_platform})"),
-                                ]
-                            )})
+                            )
+                            .on_press_maybe(
+                                (selected_version.is_some() && !instance_name.is_empty())
+                                    .then(|| Message::CreateInstance(CreateInstanceMessage::Start))
+                            ),
+                            widget::text(t!("create-loader-help")).size(12),
+                        ]
+                        .spacing(10),
+                    )
+                    .push_maybe({
+                        let real_platform = if cfg!(target_arch = "x86") {
+                            "x86_64"
+                        } else {
+                            "aarch64"
+                        };
+                        (cfg!(target_os = "linux")
+                            && (cfg!(target_arch = "x86") || cfg!(target_arch = "arm")))
+                        .then_some(widget::column![
+                            // WARN: Linux i686 and arm32
+                            widget::text(t!("create-32bit-warning")).size(20),
+                            widget::text(t!("create-32bit-help", platform = real_platform)),
+                        ])
+                    })
                     .spacing(10)
                     .padding(10),
                 )
@@ -136,14 +212,14 @@ impl MenuCreateInstance {
                 .into()
             }
             MenuCreateInstance::DownloadingInstance(progress) => widget::column![
-                widget::text("Downloading Instance..").size(20),
+                widget::text(t!("create-downloading")).size(20),
                 progress.view()
             ]
             .padding(10)
             .spacing(5)
             .into(),
             MenuCreateInstance::ImportingInstance(progress) => widget::column![
-                widget::text("Importing Instance..").size(20),
+                widget::text(t!("create-importing")).size(20),
                 progress.view()
             ]
             .padding(10)
@@ -153,33 +229,60 @@ impl MenuCreateInstance {
     }
 }
 
+fn view_version_filter(filter: &VersionFilter) -> Element<'_> {
+    widget::row(VersionType::ALL.iter().map(|kind| {
+        let kind = *kind;
+        widget::checkbox(kind.to_string(), filter.kinds.contains(&kind))
+            .on_toggle(move |t| {
+                Message::CreateInstance(CreateInstanceMessage::FilterTypeToggled(kind, t))
+            })
+            .size(14)
+            .text_size(14)
+            .into()
+    }))
+    .push(widget::horizontal_space())
+    .push(
+        widget::text_input(
+            &t!("create-filter-since"),
+            filter.since.as_deref().unwrap_or_default(),
+        )
+        .on_input(|n| Message::CreateInstance(CreateInstanceMessage::FilterSinceInput(n)))
+        .size(14)
+        .width(200),
+    )
+    .spacing(10)
+    .align_y(iced::Alignment::Center)
+    .into()
+}
+
 impl MenuLauncherUpdate {
-    pub fn view(&self) -> Element {
+    pub fn view(&self) -> Element<'_> {
         if let Some(progress) = &self.progress {
-            widget::column!("Updating QuantumLauncher...", progress.view())
+            widget::column!(widget::text(t!("update-downloading")), progress.view())
         } else {
             widget::column!(
-                "A new launcher update has been found! Do you want to download it?",
+                widget::text(t!("update-found")),
                 widget::row!(
-                    button_with_icon(icon_manager::download(), "Download", 16)
+                    button_with_icon(icon_manager::download(), t!("update-download"), 16)
                         .on_press(Message::UpdateDownloadStart),
-                    back_button().on_press(
-                        Message::LaunchScreenOpen {
-                            message: None,
-                            clear_selection: false
-                        }
+                    back_button().on_press(Message::LaunchScreenOpen {
+                        message: None,
+                        clear_selection: false
+                    }),
+                    button_with_icon(icon_manager::globe(), t!("update-website"), 16).on_press(
+                        Message::CoreOpenLink(
+                            "https://mrmayman.github.com/quantumlauncher".to_owned()
+                        )
                     ),
-                    button_with_icon(icon_manager::globe(), "Open Website", 16)
-                        .on_press(Message::CoreOpenLink("https://mrmayman.github.com/quantumlauncher".to_owned())),
-                ).push_maybe((cfg!(target_os = "linux")).then_some(
-                    widget::column!(
-                        // WARN: Package manager
-                        "Note: If you installed this launcher from a package manager (flatpak/apt/dnf/pacman/..) it's recommended to update from there",
-                        "If you just downloaded it from the website then continue from here."
-                    )
-                )).push_maybe((cfg!(target_os = "macos")).then_some(
+                )
+                .push_maybe((cfg!(target_os = "linux")).then_some(widget::column!(
+                    // WARN: Package manager
+                    widget::text(t!("update-package-manager")),
+                    widget::text(t!("update-package-manager-help"))
+                )))
+                .push_maybe((cfg!(target_os = "macos")).then_some(
                     // WARN: macOS updater
-                    "Note: The updater may be broken on macOS, so download the new version from the website"
+                    widget::text(t!("update-macos"))
                 ))
                 .spacing(5),
             )
@@ -190,7 +293,7 @@ impl MenuLauncherUpdate {
     }
 }
 
-pub fn get_theme_selector(config: &LauncherConfig) -> (Element, Element) {
+pub fn get_theme_selector(config: &LauncherConfig) -> (Element<'_>, Element<'_>) {
     const PADDING: iced::Padding = iced::Padding {
         top: 5.0,
         bottom: 5.0,
@@ -201,21 +304,21 @@ pub fn get_theme_selector(config: &LauncherConfig) -> (Element, Element) {
     let theme = config.theme.as_deref().unwrap_or("Dark");
     let (light, dark): (Element, Element) = if theme == "Dark" {
         (
-            widget::button(widget::text("Light").size(14))
+            widget::button(widget::text(t!("theme-light")).size(14))
                 .on_press(Message::LauncherSettings(
                     LauncherSettingsMessage::ThemePicked("Light".to_owned()),
                 ))
                 .into(),
-            widget::container(widget::text("Dark").size(14))
+            widget::container(widget::text(t!("theme-dark")).size(14))
                 .padding(PADDING)
                 .into(),
         )
     } else {
         (
-            widget::container(widget::text("Light").size(14))
+            widget::container(widget::text(t!("theme-light")).size(14))
                 .padding(PADDING)
                 .into(),
-            widget::button(widget::text("Dark").size(14))
+            widget::button(widget::text(t!("theme-dark")).size(14))
                 .on_press(Message::LauncherSettings(
                     LauncherSettingsMessage::ThemePicked("Dark".to_owned()),
                 ))
@@ -225,7 +328,7 @@ pub fn get_theme_selector(config: &LauncherConfig) -> (Element, Element) {
     (light, dark)
 }
 
-fn get_color_schemes(config: &LauncherConfig) -> Element {
+fn get_color_schemes(config: &LauncherConfig) -> Element<'_> {
     // HOOK: Add more themes
     let styles = [
         "Brown".to_owned(),
@@ -258,57 +361,60 @@ fn back_to_launch_screen(
 }
 
 impl<T: Progress> ProgressBar<T> {
-    pub fn view(&self) -> Element {
+    pub fn view(&self) -> Element<'_> {
         let total = T::total();
-        if let Some(message) = &self.message {
-            widget::column!(
-                widget::progress_bar(0.0..=total, self.num),
-                widget::text(message)
-            )
-        } else {
-            widget::column!(widget::progress_bar(0.0..=total, self.num),)
-        }
-        .spacing(10)
-        .into()
+        let downloads = ql_core::download::stats();
+        widget::column!(widget::progress_bar(0.0..=total, self.num))
+            .push_maybe(self.message.as_ref().map(widget::text))
+            .push_maybe((downloads.jobs > 0 && downloads.total > 0).then(|| {
+                widget::text(t!(
+                    "progress-downloaded",
+                    downloaded = format_size(downloads.downloaded),
+                    total = format_size(downloads.total),
+                    jobs = downloads.jobs
+                ))
+                .size(12)
+            }))
+            .spacing(10)
+            .into()
     }
 }
 
 impl MenuCurseforgeManualDownload {
-    pub fn view(&self) -> Element {
+    pub fn view(&self) -> Element<'_> {
         widget::column![
-            "Some Curseforge mods have blocked this launcher!\nYou need to manually download the files and add them to your mods",
+            widget::text(t!("curseforge-blocked")),
+            widget::scrollable(widget::column(self.unsupported.iter().map(|entry| {
+                let url = format!(
+                    "https://www.curseforge.com/minecraft/{}/{}/download/{}",
+                    entry.project_type, entry.slug, entry.file_id
+                );
 
-            widget::scrollable(
-                widget::column(self.unsupported.iter().map(|entry| {
-                    let url = format!(
-                        "https://www.curseforge.com/minecraft/{}/{}/download/{}",
-                        entry.project_type,
-                        entry.slug,
-                        entry.file_id
-                    );
-
-                    widget::row![
-                        widget::button(widget::text("Open link").size(14)).on_press(Message::CoreOpenLink(url)),
-                        widget::text(&entry.name)
-                    ]
-                    .align_y(iced::Alignment::Center)
-                    .spacing(10)
-                    .into()
-                }))
-            )
+                widget::row![
+                    widget::button(widget::text(t!("curseforge-open-link")).size(14))
+                        .on_press(Message::CoreOpenLink(url)),
+                    widget::text(&entry.name)
+                ]
+                .align_y(iced::Alignment::Center)
+                .spacing(10)
+                .into()
+            })))
             .width(Length::Fill)
             .height(Length::Fill)
             .style(LauncherTheme::style_scrollable_flat_extra_dark),
-
-            "Warning: Ignoring this may lead to crashes!",
+            widget::text(t!("curseforge-warning")),
             widget::row![
-                widget::button("+ Select above downloaded files").on_press(Message::ManageMods(ManageModsMessage::AddFile)),
-                widget::button("Continue").on_press(if self.is_store {
-                    Message::InstallMods(InstallModsMessage::Open)
-                } else {
-                    Message::ManageMods(ManageModsMessage::ScreenOpenWithoutUpdate)
-                }),
-            ].spacing(5)
+                widget::button(widget::text(t!("curseforge-select-files")))
+                    .on_press(Message::ManageMods(ManageModsMessage::AddFile)),
+                widget::button(widget::text(t!("curseforge-continue"))).on_press(
+                    if self.is_store {
+                        Message::InstallMods(InstallModsMessage::Open)
+                    } else {
+                        Message::ManageMods(ManageModsMessage::ScreenOpenWithoutUpdate)
+                    }
+                ),
+            ]
+            .spacing(5)
         ]
         .padding(10)
         .spacing(10)
@@ -317,10 +423,10 @@ impl MenuCurseforgeManualDownload {
 }
 
 impl MenuServerCreate {
-    pub fn view(&self) -> Element {
+    pub fn view(&self) -> Element<'_> {
         match self {
             MenuServerCreate::LoadingList => {
-                widget::column!(widget::text("Loading version list...").size(20),)
+                widget::column!(widget::text(t!("create-loading-versions")).size(20),)
             }
             MenuServerCreate::Loaded {
                 name,
@@ -333,23 +439,26 @@ impl MenuServerCreate {
                         selected_server: None,
                         message: None
                     }),
-                    widget::text("Create new server").size(20),
+                    widget::text(t!("server-create-title")).size(20),
                     widget::combo_box(
                         versions,
-                        "Select a version...",
+                        &t!("create-select-version"),
                         selected_version.as_ref(),
                         Message::ServerCreateVersionSelected
                     ),
-                    widget::text_input("Enter server name...", name)
+                    widget::text_input(&t!("server-create-name-placeholder"), name)
                         .on_input(Message::ServerCreateNameInput),
-                    widget::button("Create Server").on_press_maybe(
+                    widget::button(widget::text(t!("server-create-button"))).on_press_maybe(
                         (selected_version.is_some() && !name.is_empty())
                             .then(|| Message::ServerCreateStart)
                     ),
                 )
             }
             MenuServerCreate::Downloading { progress } => {
-                widget::column!(widget::text("Creating Server...").size(20), progress.view())
+                widget::column!(
+                    widget::text(t!("server-create-downloading")).size(20),
+                    progress.view()
+                )
             }
         }
         .padding(10)
@@ -359,7 +468,7 @@ impl MenuServerCreate {
 }
 
 impl MenuLicense {
-    pub fn view(&self) -> Element {
+    pub fn view(&self) -> Element<'_> {
         widget::row![
             self.view_sidebar(),
             widget::scrollable(
@@ -372,7 +481,7 @@ impl MenuLicense {
         .into()
     }
 
-    fn view_sidebar(&self) -> Element {
+    fn view_sidebar(&self) -> Element<'_> {
         widget::column![
             widget::column![back_button().on_press(Message::LauncherSettings(
                 LauncherSettingsMessage::ChangeTab(crate::state::LauncherSettingsTab::About)
@@ -414,17 +523,23 @@ pub fn view_account_login<'a>() -> Element<'a> {
         widget::row![
             widget::horizontal_space(),
             widget::column![
-                widget::text("Login").size(20),
-                widget::button("Login with Microsoft").on_press(Message::Account(
-                    AccountMessage::OpenMicrosoft {
+                widget::text(t!("login-title")).size(20),
+                widget::button(widget::text(t!("login-with-microsoft"))).on_press(
+                    Message::Account(AccountMessage::OpenMicrosoft {
+                        is_from_welcome_screen: false
+                    })
+                ),
+                widget::button(widget::text(t!("login-with-elyby"))).on_press(Message::Account(
+                    AccountMessage::OpenElyBy {
                         is_from_welcome_screen: false
                     }
                 )),
-                widget::button("Login with ely.by").on_press(Message::Account(
-                    AccountMessage::OpenElyBy {
+                widget::button(widget::text(t!("login-with-authlib"))).on_press(Message::Account(
+                    AccountMessage::OpenAuthlibInjector {
                         is_from_welcome_screen: false
                     }
                 )),
+                widget::text(t!("login-authlib-examples")).size(12),
             ]
             .align_x(iced::Alignment::Center)
             .spacing(5),
@@ -2,6 +2,7 @@ use iced::widget;
 use ql_core::InstanceSelection;
 
 use crate::{
+    i18n::t,
     icon_manager,
     menu_renderer::{back_button, button_with_icon, Element},
     state::{
@@ -12,7 +13,7 @@ use crate::{
 };
 
 impl MenuInstallOptifine {
-    pub fn view(&self) -> Element {
+    pub fn view(&self) -> Element<'_> {
         if let Some(optifine) = &self.optifine_install_progress {
             widget::column!(
                 optifine.view(),
@@ -27,7 +28,7 @@ impl MenuInstallOptifine {
                 },
             )
         } else if self.is_b173_being_installed {
-            widget::column![widget::text("Installing OptiFine for Beta 1.7.3...").size(20)]
+            widget::column![widget::text(t!("optifine-installing-b173")).size(20)]
         } else {
             self.install_optifine_screen()
         }
@@ -45,10 +46,10 @@ impl MenuInstallOptifine {
             )),
             widget::container(
                 widget::column!(
-                    widget::text("Install OptiFine").size(20),
-                    "Step 1: Open the OptiFine download page and download the installer.",
-                    "WARNING: Make sure to download the correct version.",
-                    widget::button("Open download page")
+                    widget::text(t!("mods-install-optifine")).size(20),
+                    widget::text(t!("optifine-step-1")),
+                    widget::text(t!("optifine-step-1-warning")),
+                    widget::button(widget::text(t!("optifine-open-page")))
                         .on_press(Message::CoreOpenLink(self.get_url().to_owned()))
                 )
                 .padding(10)
@@ -56,10 +57,10 @@ impl MenuInstallOptifine {
             ),
             widget::container(
                 widget::column!(
-                    "Step 2: Select the installer file",
-                    widget::button("Select File").on_press(Message::InstallOptifine(
-                        InstallOptifineMessage::SelectInstallerStart
-                    ))
+                    widget::text(t!("optifine-step-2")),
+                    widget::button(widget::text(t!("optifine-select-file"))).on_press(
+                        Message::InstallOptifine(InstallOptifineMessage::SelectInstallerStart)
+                    )
                 )
                 .padding(10)
                 .spacing(10)
@@ -69,7 +70,7 @@ impl MenuInstallOptifine {
 }
 
 impl MenuInstallFabric {
-    pub fn view(&self, selected_instance: &InstanceSelection, tick_timer: usize) -> Element {
+    pub fn view(&self, selected_instance: &InstanceSelection, tick_timer: usize) -> Element<'_> {
         match self {
             MenuInstallFabric::Loading { is_quilt, .. } => {
                 let loader_name = if *is_quilt { "Quilt" } else { "Fabric" };
@@ -79,7 +80,7 @@ impl MenuInstallFabric {
                     back_button().on_press(Message::ManageMods(
                         ManageModsMessage::ScreenOpenWithoutUpdate
                     )),
-                    widget::text!("Loading {loader_name} version list{dots}",).size(20)
+                    widget::text(t!("fabric-loading", loader = loader_name, dots = dots)).size(20)
                 ]
             }
             MenuInstallFabric::Loaded {
@@ -92,7 +93,7 @@ impl MenuInstallFabric {
 
                 if let Some(progress) = progress {
                     widget::column!(
-                        widget::text!("Installing {loader_name}...").size(20),
+                        widget::text(t!("fabric-installing", loader = loader_name)).size(20),
                         progress.view(),
                     )
                 } else {
@@ -100,13 +101,14 @@ impl MenuInstallFabric {
                         back_button().on_press(Message::ManageMods(
                             ManageModsMessage::ScreenOpenWithoutUpdate
                         )),
-                        widget::text!(
-                            "Install {loader_name} (instance: {})",
-                            selected_instance.get_name()
-                        )
+                        widget::text(t!(
+                            "fabric-title",
+                            loader = loader_name,
+                            instance = selected_instance.get_name()
+                        ))
                         .size(20),
                         widget::column![
-                            widget::text!("{loader_name} version: (Ignore if you aren't sure)"),
+                            widget::text(t!("fabric-version", loader = loader_name)),
                             widget::pick_list(
                                 fabric_versions.as_slice(),
                                 Some(fabric_version),
@@ -116,7 +118,7 @@ impl MenuInstallFabric {
                             ),
                         ]
                         .spacing(5),
-                        button_with_icon(icon_manager::download(), "Install", 16)
+                        button_with_icon(icon_manager::download(), t!("fabric-install"), 16)
                             .on_press(Message::InstallFabric(InstallFabricMessage::ButtonClicked)),
                     ]
                 }
@@ -126,11 +128,10 @@ impl MenuInstallFabric {
                     back_button().on_press(Message::ManageMods(
                         ManageModsMessage::ScreenOpenWithoutUpdate
                     )),
-                    if *is_quilt {
-                        "Quilt is unsupported for this Minecraft version."
-                    } else {
-                        "Fabric is unsupported for this Minecraft version."
-                    }
+                    widget::text(t!(
+                        "fabric-unsupported",
+                        loader = if *is_quilt { "Quilt" } else { "Fabric" }
+                    ))
                 )
             }
         }
@@ -141,9 +142,9 @@ impl MenuInstallFabric {
 }
 
 impl MenuInstallForge {
-    pub fn view(&self) -> Element {
+    pub fn view(&self) -> Element<'_> {
         let main_block = widget::column!(
-            widget::text("Installing Forge/NeoForge...").size(20),
+            widget::text(t!("forge-installing")).size(20),
             self.forge_progress.view()
         )
         .spacing(10);
@@ -1,19 +1,20 @@
 use iced::{widget, Length};
 
 use crate::{
+    i18n::t,
     icon_manager,
     menu_renderer::{back_button, button_with_icon, Element},
-    state::{MenuExportInstance, Message},
+    state::{ExportFormat, MenuExportInstance, Message},
 };
 
 impl MenuExportInstance {
-    pub fn view(&self, tick_timer: usize) -> Element {
+    pub fn view(&self, tick_timer: usize) -> Element<'_> {
         widget::column![
             back_button().on_press(Message::LaunchScreenOpen {
                 message: None,
                 clear_selection: false
             }),
-            "Select the contents of the \".minecraft\" folder you want to keep",
+            widget::text(t!("export-select")),
             widget::scrollable(if let Some(entries) = &self.entries {
                 widget::column(entries.iter().enumerate().map(|(i, (entry, enabled))| {
                     let name = if entry.is_file {
@@ -28,18 +29,20 @@ impl MenuExportInstance {
                 .padding(5)
             } else {
                 let dots = ".".repeat((tick_timer % 3) + 1);
-                widget::column!(widget::text!("Loading{dots}"))
+                widget::column!(widget::text(t!("common-loading-dots", dots = dots)))
             })
             .width(Length::Fill)
             .height(Length::Fill),
             widget::column![
-                widget::text("Format:").size(12),
+                widget::text(t!("export-format")).size(12),
                 widget::row![
-                    widget::pick_list(["QuantumLauncher"], Some("QuantumLauncher"), |_| {
-                        Message::Nothing
-                    })
+                    widget::pick_list(
+                        ExportFormat::ALL,
+                        Some(self.format),
+                        Message::ExportInstanceFormat
+                    )
                     .text_line_height(1.68),
-                    button_with_icon(icon_manager::save(), "Export", 16)
+                    button_with_icon(icon_manager::save(), t!("export-button"), 16)
                         .on_press(Message::ExportInstanceStart),
                 ]
                 .spacing(5)
@@ -2,6 +2,7 @@ use iced::{widget, Length};
 use ql_core::InstanceSelection;
 
 use crate::{
+    i18n::t,
     icon_manager,
     menu_renderer::{back_button, button_with_icon, Element},
     state::{ManageJarModsMessage, ManageModsMessage, MenuEditJarMods, Message, SelectedState},
@@ -9,7 +10,7 @@ use crate::{
 };
 
 impl MenuEditJarMods {
-    pub fn view(&self, selected_instance: &InstanceSelection) -> Element {
+    pub fn view(&self, selected_instance: &InstanceSelection) -> Element<'_> {
         let menu_main = widget::row!(
             widget::container(
                 widget::scrollable(
@@ -25,33 +26,27 @@ impl MenuEditJarMods {
 
                                 button_with_icon(
                                     icon_manager::folder_with_size(14),
-                                    "Open Folder",
+                                    t!("jarmods-open-folder"),
                                     15,
                                 )
                                 .on_press_maybe(path.map(Message::CoreOpenPath))
                             },
-                            button_with_icon(icon_manager::create(), "Add file", 15)
+                            button_with_icon(icon_manager::create(), t!("jarmods-add-file"), 15)
                                 .on_press(Message::ManageJarMods(ManageJarModsMessage::AddFile)),
                         ]
                         .spacing(5),
                         widget::row![
-                            "You can find some good jar mods at McArchive",
-                            widget::button("Open").on_press(Message::CoreOpenLink(
-                                "https://mcarchive.net".to_owned()
-                            ))
+                            widget::text(t!("jarmods-mcarchive")),
+                            widget::button(widget::text(t!("jarmods-open"))).on_press(
+                                Message::CoreOpenLink("https://mcarchive.net".to_owned())
+                            )
                         ]
                         .spacing(5)
                         .wrap(),
                         widget::column![
-                            "WARNING: Jarmods are mainly for OLD Minecraft versions.",
-                            widget::text(
-                                "This is easier than copying .class files into Minecraft's jar"
-                            )
-                            .size(12),
-                            widget::text(
-                                "If you just want some mods (for newer Minecraft), click Back"
-                            )
-                            .size(12),
+                            widget::text(t!("jarmods-warning")),
+                            widget::text(t!("jarmods-warning-help")).size(12),
+                            widget::text(t!("jarmods-warning-back")).size(12),
                         ],
                     )
                     .padding(10)
@@ -69,7 +64,7 @@ impl MenuEditJarMods {
             widget::stack!(
                 menu_main,
                 widget::center(widget::button(
-                    widget::text("Drag and drop jarmod files to add them").size(20)
+                    widget::text(t!("jarmods-drag-drop")).size(20)
                 ))
             )
             .into()
@@ -78,9 +73,9 @@ impl MenuEditJarMods {
         }
     }
 
-    fn get_mod_list(&self) -> Element {
+    fn get_mod_list(&self) -> Element<'_> {
         if self.jarmods.mods.is_empty() {
-            return widget::column!("Add some mods to get started")
+            return widget::column!(widget::text(t!("jarmods-empty")))
                 .spacing(10)
                 .padding(10)
                 .width(Length::Fill)
@@ -90,17 +85,19 @@ impl MenuEditJarMods {
         widget::container(
             widget::column!(
                 widget::column![
-                    widget::text("Select some jarmods to perform actions on them").size(14),
+                    widget::text(t!("jarmods-select-help")).size(14),
                     widget::row![
-                        widget::button("Delete")
+                        widget::button(widget::text(t!("mods-delete")))
                             .on_press(Message::ManageJarMods(ManageJarModsMessage::DeleteSelected)),
-                        widget::button("Toggle")
+                        widget::button(widget::text(t!("mods-toggle")))
                             .on_press(Message::ManageJarMods(ManageJarModsMessage::ToggleSelected)),
-                        widget::button(if matches!(self.selected_state, SelectedState::All) {
-                            "Unselect All"
-                        } else {
-                            "Select All"
-                        })
+                        widget::button(widget::text(
+                            if matches!(self.selected_state, SelectedState::All) {
+                                t!("mods-unselect-all")
+                            } else {
+                                t!("mods-select-all")
+                            }
+                        ))
                         .on_press(Message::ManageJarMods(ManageJarModsMessage::SelectAll)),
                         widget::button(icon_manager::arrow_up())
                             .on_press(Message::ManageJarMods(ManageJarModsMessage::MoveUp)),
@@ -120,16 +117,16 @@ impl MenuEditJarMods {
         .into()
     }
 
-    fn get_mod_list_contents(&self) -> Element {
+    fn get_mod_list_contents(&self) -> Element<'_> {
         widget::scrollable(
             widget::column({
                 self.jarmods.mods.iter().map(|jarmod| {
                     widget::checkbox(
-                        format!(
-                            "{}{}",
-                            if jarmod.enabled { "" } else { "(DISABLED) " },
-                            jarmod.filename
-                        ),
+                        if jarmod.enabled {
+                            jarmod.filename.clone()
+                        } else {
+                            t!("mods-disabled", name = jarmod.filename.as_str())
+                        },
                         self.selected_mods.contains(&jarmod.filename),
                     )
                     .on_toggle(move |t| {
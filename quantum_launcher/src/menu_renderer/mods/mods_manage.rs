@@ -1,13 +1,15 @@
 use iced::{widget, Length};
-use ql_core::{InstanceSelection, SelectedMod};
+use ql_core::{InstanceSelection, ModId, SelectedMod};
+use ql_mod_manager::store::ModSnapshot;
 
 use crate::{
+    i18n::t,
     icon_manager,
     menu_renderer::{back_button, back_to_launch_screen, button_with_icon, tooltip, Element},
     state::{
-        EditPresetsMessage, InstallFabricMessage, InstallModsMessage, InstallOptifineMessage,
-        ManageJarModsMessage, ManageModsMessage, MenuEditMods, Message, ModListEntry,
-        SelectedState,
+        EditPresetsMessage, ImageState, InstallFabricMessage, InstallModsMessage,
+        InstallOptifineMessage, ManageJarModsMessage, ManageModsMessage, MenuEditMods,
+        MenuModsDownload, Message, ModListEntry, SelectedState,
     },
     stylesheet::{color::Color, styles::LauncherTheme},
 };
@@ -17,13 +19,21 @@ impl MenuEditMods {
         &'a self,
         selected_instance: &'a InstanceSelection,
         tick_timer: usize,
+        images: &'a ImageState,
+        window_size: (f32, f32),
     ) -> Element<'a> {
         if let Some(progress) = &self.mod_update_progress {
-            return widget::column!(widget::text("Updating mods").size(20), progress.view())
+            return widget::column!(widget::text(t!("mods-updating")).size(20), progress.view())
                 .padding(10)
                 .spacing(10)
                 .into();
         }
+        if self.reviewing_updates {
+            return self.view_update_review(images, window_size);
+        }
+        if let Some(snapshots) = &self.snapshots {
+            return Self::view_snapshots(snapshots);
+        }
 
         let menu_main = widget::row!(
             widget::container(
@@ -31,25 +41,36 @@ impl MenuEditMods {
                     widget::column!(
                         widget::row![
                             back_button().on_press(back_to_launch_screen(selected_instance, None)),
-                            button_with_icon(icon_manager::create_with_size(14), "Add File", 14)
-                                .on_press(Message::ManageMods(ManageModsMessage::AddFile))
+                            button_with_icon(
+                                icon_manager::create_with_size(14),
+                                t!("mods-add-file"),
+                                14
+                            )
+                            .on_press(Message::ManageMods(ManageModsMessage::AddFile))
                         ]
                         .spacing(7),
                         self.get_mod_installer_buttons(selected_instance),
                         widget::column!(
                             button_with_icon(
                                 icon_manager::download_with_size(14),
-                                "Download Content",
+                                t!("mods-download-content"),
                                 15
                             )
                             .on_press(Message::InstallMods(InstallModsMessage::Open)),
-                            button_with_icon(icon_manager::save(), "Mod Presets", 15)
+                            button_with_icon(icon_manager::save(), t!("mods-presets"), 15)
                                 .on_press(Message::EditPresets(EditPresetsMessage::Open)),
-                            button_with_icon(icon_manager::jar_file(), "Jarmod Patches", 15)
+                            button_with_icon(icon_manager::jar_file(), t!("mods-jarmods"), 15)
                                 .on_press(Message::ManageJarMods(ManageJarModsMessage::Open))
                         )
                         .spacing(5),
                         Self::open_mod_folder_button(selected_instance),
+                        widget::row![
+                            widget::button(widget::text(t!("mods-undo")).size(13))
+                                .on_press(Message::ManageMods(ManageModsMessage::UndoLastChange)),
+                            widget::button(widget::text(t!("mods-snapshots")).size(13))
+                                .on_press(Message::ManageMods(ManageModsMessage::SnapshotsOpen)),
+                        ]
+                        .spacing(5),
                         self.get_mod_update_pane(tick_timer),
                     )
                     .padding(10)
@@ -65,9 +86,7 @@ impl MenuEditMods {
         if self.drag_and_drop_hovered {
             widget::stack!(
                 menu_main,
-                widget::center(widget::button(
-                    widget::text("Drag and drop mod files to add them").size(20)
-                ))
+                widget::center(widget::button(widget::text(t!("mods-drag-drop")).size(20)))
             )
             .into()
         } else {
@@ -75,10 +94,10 @@ impl MenuEditMods {
         }
     }
 
-    fn get_mod_update_pane(&self, tick_timer: usize) -> Element {
+    fn get_mod_update_pane(&self, tick_timer: usize) -> Element<'_> {
         if self.update_check_handle.is_some() {
             let dots = ".".repeat((tick_timer % 3) + 1);
-            widget::text!("Checking for mod updates{dots}")
+            widget::text(t!("mods-checking-updates", dots = dots))
                 .size(13)
                 .into()
         } else if self.available_updates.is_empty() {
@@ -86,17 +105,15 @@ impl MenuEditMods {
         } else {
             widget::container(
                 widget::column!(
-                    widget::text("Mod Updates Available!").size(15),
+                    widget::text(t!("mods-updates-available")).size(15),
                     widget::column(self.available_updates.iter().enumerate().map(
-                        |(i, (id, name, is_enabled))| {
+                        |(i, (update, is_enabled))| {
                             widget::checkbox(
-                                format!(
-                                    "{} - {name}",
-                                    self.mods
-                                        .mods
-                                        .get(&id.get_index_str())
-                                        .map(|n| n.name.clone())
-                                        .unwrap_or_default()
+                                t!(
+                                    "mods-update-entry",
+                                    name = update.name.as_str(),
+                                    old = update.old_version.as_str(),
+                                    new = update.new_version.as_str()
                                 ),
                                 *is_enabled,
                             )
@@ -108,8 +125,8 @@ impl MenuEditMods {
                         }
                     ))
                     .spacing(10),
-                    button_with_icon(icon_manager::update(), "Update", 16)
-                        .on_press(Message::ManageMods(ManageModsMessage::UpdateMods)),
+                    button_with_icon(icon_manager::update(), t!("mods-review-update"), 16)
+                        .on_press(Message::ManageMods(ManageModsMessage::UpdateReview(true))),
                 )
                 .padding(10)
                 .spacing(10)
@@ -119,11 +136,127 @@ impl MenuEditMods {
         }
     }
 
-    fn get_mod_installer_buttons(&self, selected_instance: &InstanceSelection) -> Element {
+    fn view_update_review<'a>(
+        &'a self,
+        images: &'a ImageState,
+        window_size: (f32, f32),
+    ) -> Element<'a> {
+        let updates = self
+            .available_updates
+            .iter()
+            .filter(|(_, is_enabled)| *is_enabled)
+            .map(|(update, _)| {
+                widget::container(
+                    widget::column![
+                        widget::text(&update.name).size(20),
+                        widget::text(t!(
+                            "mods-update-versions",
+                            old = update.old_version.as_str(),
+                            new = update.new_version.as_str(),
+                            date = update.release_date.format("%Y-%m-%d").to_string()
+                        ))
+                        .size(14),
+                    ]
+                    .push_maybe(update.dependencies_changed.then(|| {
+                        widget::text(t!("mods-update-dependencies-changed"))
+                            .size(14)
+                            .style(|n: &LauncherTheme| n.style_text(Color::SecondLight))
+                    }))
+                    .push(
+                        widget::column(update.changelogs.iter().map(|(version, body)| {
+                            widget::column![
+                                widget::text(version).size(16),
+                                if body.trim().is_empty() {
+                                    widget::text(t!("mods-no-changelog")).size(12).into()
+                                } else if let ModId::Curseforge(_) = update.id {
+                                    // Curseforge changelogs are HTML
+                                    MenuModsDownload::render_html(body, images, window_size)
+                                } else {
+                                    MenuModsDownload::render_markdown(body, images, window_size)
+                                }
+                            ]
+                            .spacing(5)
+                            .into()
+                        }))
+                        .spacing(10),
+                    )
+                    .padding(10)
+                    .spacing(10),
+                )
+                .width(Length::Fill)
+                .into()
+            });
+
+        widget::scrollable(
+            widget::column![
+                widget::row![
+                    back_button()
+                        .on_press(Message::ManageMods(ManageModsMessage::UpdateReview(false))),
+                    button_with_icon(icon_manager::update(), t!("mods-apply-updates"), 16)
+                        .on_press(Message::ManageMods(ManageModsMessage::UpdateMods)),
+                ]
+                .spacing(10),
+                widget::text(t!("mods-review-title")).size(24),
+                widget::column(updates).spacing(10),
+            ]
+            .padding(10)
+            .spacing(10),
+        )
+        .style(LauncherTheme::style_scrollable_flat_extra_dark)
+        .width(Length::Fill)
+        .height(Length::Fill)
+        .into()
+    }
+
+    fn view_snapshots(snapshots: &[ModSnapshot]) -> Element<'_> {
+        widget::scrollable(
+            widget::column![
+                back_button().on_press(Message::ManageMods(ManageModsMessage::SnapshotsClose)),
+                widget::text(t!("mods-snapshots-title")).size(24),
+                widget::text(t!("mods-snapshots-desc")).size(13),
+            ]
+            .push(if snapshots.is_empty() {
+                Element::from(widget::text(t!("mods-snapshots-empty")).size(14))
+            } else {
+                widget::column(snapshots.iter().map(|snapshot| {
+                    let created = chrono::DateTime::parse_from_rfc3339(&snapshot.created)
+                        .map_or(snapshot.created.clone(), |n| {
+                            n.format("%Y-%m-%d %H:%M:%S").to_string()
+                        });
+                    widget::row![
+                        widget::button(widget::text(t!("mods-snapshot-restore")).size(13))
+                            .on_press(Message::ManageMods(ManageModsMessage::SnapshotRestore(
+                                snapshot.id.clone()
+                            ))),
+                        widget::text(t!(
+                            "mods-snapshot-entry",
+                            created = created,
+                            reason = snapshot.reason.as_str(),
+                            count = snapshot.mod_count()
+                        ))
+                        .size(14),
+                    ]
+                    .align_y(iced::alignment::Vertical::Center)
+                    .spacing(10)
+                    .into()
+                }))
+                .spacing(5)
+                .into()
+            })
+            .padding(10)
+            .spacing(10),
+        )
+        .style(LauncherTheme::style_scrollable_flat_extra_dark)
+        .width(Length::Fill)
+        .height(Length::Fill)
+        .into()
+    }
+
+    fn get_mod_installer_buttons(&self, selected_instance: &InstanceSelection) -> Element<'_> {
         match self.config.mod_type.as_str() {
             "Vanilla" => match selected_instance {
                 InstanceSelection::Instance(_) => widget::column![
-                    "Install:",
+                    widget::text(t!("mods-install-loader")),
                     widget::row!(
                         install_ldr("Fabric").on_press(Message::InstallFabric(
                             InstallFabricMessage::ScreenOpen { is_quilt: false }
@@ -146,7 +279,7 @@ impl MenuEditMods {
                 .spacing(5)
                 .into(),
                 InstanceSelection::Server(_) => widget::column!(
-                    "Install:",
+                    widget::text(t!("mods-install-loader")),
                     widget::row!(
                         install_ldr("Fabric").on_press(Message::InstallFabric(
                             InstallFabricMessage::ScreenOpen { is_quilt: false }
@@ -176,8 +309,8 @@ impl MenuEditMods {
 
             "Forge" => widget::column!(
                 tooltip(
-                    widget::button("Install OptiFine"),
-                    "Coming in a future launcher version..."
+                    widget::button(widget::text(t!("mods-install-optifine"))),
+                    widget::text(t!("mods-coming-soon"))
                 ),
                 Self::get_uninstall_panel(
                     &self.config.mod_type,
@@ -187,7 +320,7 @@ impl MenuEditMods {
             .spacing(5)
             .into(),
             "OptiFine" => widget::column!(
-                widget::button("Install Forge"),
+                widget::button(widget::text(t!("mods-install-forge"))),
                 Self::get_uninstall_panel(
                     &self.config.mod_type,
                     Message::UninstallLoaderOptiFineStart,
@@ -207,17 +340,19 @@ impl MenuEditMods {
                 Self::get_uninstall_panel(&self.config.mod_type, Message::UninstallLoaderPaperStart)
             }
 
-            _ => {
-                widget::column!(widget::text!("Unknown mod type: {}", self.config.mod_type)).into()
-            }
+            _ => widget::column!(widget::text(t!(
+                "mods-unknown-type",
+                mod_type = self.config.mod_type.as_str()
+            )))
+            .into(),
         }
     }
 
-    fn get_uninstall_panel(mod_type: &str, uninstall_loader_message: Message) -> Element {
+    fn get_uninstall_panel(mod_type: &str, uninstall_loader_message: Message) -> Element<'_> {
         widget::button(
             widget::row![
                 icon_manager::delete_with_size(14),
-                widget::text!("Uninstall {mod_type}").size(15)
+                widget::text(t!("mods-uninstall-loader", loader = mod_type)).size(15)
             ]
             .align_y(iced::alignment::Vertical::Center)
             .spacing(11)
@@ -230,20 +365,24 @@ impl MenuEditMods {
         .into()
     }
 
-    fn open_mod_folder_button(selected_instance: &InstanceSelection) -> Element {
+    fn open_mod_folder_button(selected_instance: &InstanceSelection) -> Element<'_> {
         let path = {
             let path = selected_instance.get_dot_minecraft_path().join("mods");
             path.exists().then_some(path)
         };
 
-        button_with_icon(icon_manager::folder_with_size(14), "Open Mods Folder", 15)
-            .on_press_maybe(path.map(Message::CoreOpenPath))
-            .into()
+        button_with_icon(
+            icon_manager::folder_with_size(14),
+            t!("mods-open-folder"),
+            15,
+        )
+        .on_press_maybe(path.map(Message::CoreOpenPath))
+        .into()
     }
 
-    fn get_mod_list(&self) -> Element {
+    fn get_mod_list(&self) -> Element<'_> {
         if self.sorted_mods_list.is_empty() {
-            return widget::column!("Download some mods to get started")
+            return widget::column!(widget::text(t!("mods-empty")))
                 .spacing(10)
                 .padding(10)
                 .width(Length::Fill)
@@ -255,28 +394,38 @@ impl MenuEditMods {
                 widget::column![]
                     .push_maybe(
                         (self.config.mod_type == "Vanilla" && !self.sorted_mods_list.is_empty())
-                        .then_some(
-                            widget::container(
-                                widget::text(
+                            .then_some(
+                                widget::container(
                                     // WARN: No loader installed
-                                    "You haven't installed any mod loader! Install Fabric/Forge/Quilt/NeoForge as per your mods"
-                                ).size(12)
-                            ).padding(10).width(Length::Fill).style(|n: &LauncherTheme| n.style_container_sharp_box(0.0, Color::ExtraDark)),
-                        )
+                                    widget::text(t!("mods-no-loader")).size(12)
+                                )
+                                .padding(10)
+                                .width(Length::Fill)
+                                .style(|n: &LauncherTheme| n
+                                    .style_container_sharp_box(0.0, Color::ExtraDark)),
+                            )
                     )
-                    .push(widget::text("Select some mods to perform actions on them").size(14))
+                    .push(widget::text(t!("mods-select-help")).size(14))
                     .push(
                         widget::row![
-                            button_with_icon(icon_manager::delete_with_size(13), "Delete", 13)
-                                .on_press(Message::ManageMods(ManageModsMessage::DeleteSelected)),
-                            button_with_icon(icon_manager::toggle_off_with_size(13), "Toggle", 13)
-                                .on_press(Message::ManageMods(ManageModsMessage::ToggleSelected)),
+                            button_with_icon(
+                                icon_manager::delete_with_size(13),
+                                t!("mods-delete"),
+                                13
+                            )
+                            .on_press(Message::ManageMods(ManageModsMessage::DeleteSelected)),
+                            button_with_icon(
+                                icon_manager::toggle_off_with_size(13),
+                                t!("mods-toggle"),
+                                13
+                            )
+                            .on_press(Message::ManageMods(ManageModsMessage::ToggleSelected)),
                             button_with_icon(
                                 icon_manager::tick_with_size(13),
                                 if matches!(self.selected_state, SelectedState::All) {
-                                    "Unselect All"
+                                    t!("mods-unselect-all")
                                 } else {
-                                    "Select All"
+                                    t!("mods-select-all")
                                 },
                                 13
                             )
@@ -295,7 +444,7 @@ impl MenuEditMods {
         .into()
     }
 
-    fn get_mod_list_contents(&self) -> Element {
+    fn get_mod_list_contents(&self) -> Element<'_> {
         widget::scrollable(
             widget::row![
                 widget::column({
@@ -305,11 +454,11 @@ impl MenuEditMods {
                             ModListEntry::Downloaded { id, config } => {
                                 widget::row!(if config.manually_installed {
                                     widget::row!(widget::checkbox(
-                                        format!(
-                                            "{}{}",
-                                            if config.enabled { "" } else { "(DISABLED) " },
-                                            config.name
-                                        ),
+                                        if config.enabled {
+                                            config.name.clone()
+                                        } else {
+                                            t!("mods-disabled", name = config.name.as_str())
+                                        },
                                         self.selected_mods.contains(&SelectedMod::Downloaded {
                                             name: config.name.clone(),
                                             id: (*id).clone()
@@ -322,7 +471,10 @@ impl MenuEditMods {
                                         ))
                                     }))
                                 } else {
-                                    widget::row!(widget::text!("- (DEPENDENCY) {}", config.name))
+                                    widget::row!(widget::text(t!(
+                                        "mods-dependency",
+                                        name = config.name.as_str()
+                                    )))
                                 },)
                                 .into()
                             }
@@ -10,6 +10,7 @@ use markup5ever_rcdom::{Node, RcDom};
 
 use crate::{
     draw_children,
+    i18n::t,
     menu_renderer::FONT_MONO,
     state::{ImageState, MenuModsDownload, Message},
 };
@@ -77,7 +78,7 @@ impl MenuModsDownload {
                 *element = if data.monospace {
                     widget::row![
                         widget::text(text.clone()).font(FONT_MONO),
-                        widget::button(widget::text("Copy").size(12))
+                        widget::button(widget::text(t!("store-copy")).size(12))
                             .on_press(Message::CoreCopyText(text)),
                     ]
                     .spacing(5)
@@ -187,7 +188,7 @@ fn render_html<'a>(
                 } else {
                     let mut images_to_load = images.to_load.lock().unwrap();
                     images_to_load.insert(url);
-                    widget::text("(Loading image...)").into()
+                    widget::text(t!("store-loading-image")).into()
                 }
             } else {
                 *element = widget::text("[HTML error: malformed image]]").into();
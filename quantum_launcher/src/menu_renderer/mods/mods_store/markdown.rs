@@ -19,6 +19,7 @@ use comrak::nodes::NodeValue;
 use iced::widget;
 
 use crate::{
+    i18n::t,
     menu_renderer::{Element, FONT_MONO},
     state::{ImageState, MenuModsDownload, Message},
 };
@@ -104,7 +105,7 @@ impl MenuModsDownload {
             NodeValue::DescriptionDetails => todoh!("description details"),
             NodeValue::CodeBlock(block) => widget::container(
                 widget::column!(
-                    widget::button(widget::text("Copy").size(12))
+                    widget::button(widget::text(t!("store-copy")).size(12))
                         .on_press(Message::CoreCopyText(block.literal.clone())),
                     widget::text(block.literal.clone()).font(FONT_MONO),
                 )
@@ -123,7 +124,7 @@ impl MenuModsDownload {
             NodeValue::SoftBreak | NodeValue::LineBreak => widget::column!().into(),
             NodeValue::Code(code) => widget::row![
                 widget::text(code.literal.clone()).font(FONT_MONO),
-                widget::button(widget::text("Copy").size(12))
+                widget::button(widget::text(t!("store-copy")).size(12))
                     .on_press(Message::CoreCopyText(code.literal.clone())),
             ]
             .spacing(5)
@@ -144,7 +145,7 @@ impl MenuModsDownload {
                 } else {
                     let mut images_to_load = images.to_load.lock().unwrap();
                     images_to_load.insert(link.url.clone());
-                    widget::text("(Loading image...)").into()
+                    widget::text(t!("store-loading-image")).into()
                 }
             }
             NodeValue::FootnoteReference(_) => todoh!("footnote reference"),
@@ -3,6 +3,7 @@ use ql_core::{ModId, StoreBackendType};
 use ql_mod_manager::store::{QueryType, SearchMod};
 
 use crate::{
+    i18n::t,
     icon_manager,
     menu_renderer::{back_button, button_with_icon, Element},
     state::{ImageState, InstallModsMessage, ManageModsMessage, MenuModsDownload, Message},
@@ -21,14 +22,15 @@ impl MenuModsDownload {
         widget::row!(
             widget::scrollable(
                 widget::column!(
-                    widget::text_input("Search...", &self.query)
+                    widget::text_input(&t!("store-search"), &self.query)
+                        .id(widget::text_input::Id::new("MenuModsDownload:search"))
                         .on_input(|n| Message::InstallMods(InstallModsMessage::SearchInput(n))),
                     if self.mods_download_in_progress.is_empty() {
                         widget::column!(
                             back_button()
                                 .on_press(Message::ManageMods(ManageModsMessage::ScreenOpen)),
                             widget::Space::with_height(5.0),
-                            widget::text("Select store:").size(18),
+                            widget::text(t!("store-select-store")).size(18),
                             widget::radio(
                                 "Modrinth",
                                 StoreBackendType::Modrinth,
@@ -46,7 +48,7 @@ impl MenuModsDownload {
                             .text_size(14)
                             .size(14),
                             widget::Space::with_height(5),
-                            widget::text("Select Type:").size(18),
+                            widget::text(t!("store-select-type")).size(18),
                             widget::column(QueryType::ALL.iter().map(|n| {
                                 widget::radio(n.to_string(), *n, Some(self.query_type), |v| {
                                     Message::InstallMods(InstallModsMessage::ChangeQueryType(v))
@@ -61,14 +63,20 @@ impl MenuModsDownload {
                     } else {
                         // Mods are being installed. Can't back out.
                         // Show list of mods being installed.
-                        widget::column!("Installing:", {
+                        widget::column!(widget::text(t!("store-installing")), {
                             widget::column(self.mods_download_in_progress.iter().filter_map(|id| {
                                 let search = self.results.as_ref()?;
                                 let hit = search
                                     .mods
                                     .iter()
                                     .find(|hit| hit.id == id.get_internal_id())?;
-                                Some(widget::text!("- {}", hit.title).into())
+                                Some(
+                                    widget::text(t!(
+                                        "store-installing-entry",
+                                        name = hit.title.as_str()
+                                    ))
+                                    .into(),
+                                )
                             }))
                         })
                     },
@@ -87,40 +95,45 @@ impl MenuModsDownload {
                         && !self.mod_index.mods.contains_key("YL57xq9U") // Modrinth ID
                         && !self.mod_index.mods.contains_key("CF:455508")) // CurseForge ID
                     .then_some(
-                        widget::column![
-                            widget::text(
-                                "You haven't installed any shader mod! Either install:\n- Fabric + Sodium + Iris (recommended), or\n- OptiFine"
-                            ).size(12)
-                        ].padding(10)
+                        widget::column![widget::text(t!("store-no-shader-mod")).size(12)]
+                            .padding(10)
                     )
                 )
                 .push_maybe(
-                    (self.query_type == QueryType::Mods
-                        && self.config.mod_type == "Vanilla")
-                    .then_some(
-                        widget::container(
-                            widget::text(
+                    (self.query_type == QueryType::Mods && self.config.mod_type == "Vanilla")
+                        .then_some(
+                            widget::container(
                                 // WARN: No loader installed
-                                "You haven't installed any mod loader! Install Fabric (recommended), Forge, Quilt or NeoForge"
-                            ).size(12)
-                        ).padding(10).width(Length::Fill).style(|n: &LauncherTheme| n.style_container_sharp_box(0.0, Color::ExtraDark)),
-                    )
-                ).push_maybe((self.query_type == QueryType::Mods && self.json.lock().unwrap().is_legacy_version())
+                                widget::text(t!("store-no-loader")).size(12)
+                            )
+                            .padding(10)
+                            .width(Length::Fill)
+                            .style(|n: &LauncherTheme| n
+                                .style_container_sharp_box(0.0, Color::ExtraDark)),
+                        )
+                )
+                .push_maybe(
+                    (self.query_type == QueryType::Mods
+                        && self.json.lock().unwrap().is_legacy_version())
                     .then_some(
                         widget::container(
-                            widget::text(
-                                // WARN: Store for old versions
-                                "Installing Mods for old versions is experimental and may be broken"
-                            ).size(12)
-                        ).padding(10).width(Length::Fill).style(|n: &LauncherTheme| n.style_container_sharp_box(0.0, Color::ExtraDark)),
+                            // WARN: Store for old versions
+                            widget::text(t!("store-old-version")).size(12)
+                        )
+                        .padding(10)
+                        .width(Length::Fill)
+                        .style(
+                            |n: &LauncherTheme| n.style_container_sharp_box(0.0, Color::ExtraDark)
+                        ),
                     )
-                ).push(
+                )
+                .push(
                     widget::scrollable(mods_list.spacing(10).padding(10))
-                        .style(|theme: &LauncherTheme, status|
-                            theme
-                                .style_scrollable_flat_extra_dark(status)
-                        )
-                        .id(iced::widget::scrollable::Id::new("MenuModsDownload:main:mods_list"))
+                        .style(|theme: &LauncherTheme, status| theme
+                            .style_scrollable_flat_extra_dark(status))
+                        .id(iced::widget::scrollable::Id::new(
+                            "MenuModsDownload:main:mods_list"
+                        ))
                         .height(Length::Fill)
                         .width(Length::Fill)
                         .on_scroll(|viewport| {
@@ -138,7 +151,7 @@ impl MenuModsDownload {
     ) -> widget::Column<'a, Message, LauncherTheme> {
         if let Some(results) = self.results.as_ref() {
             if results.mods.is_empty() {
-                widget::column!["No results found."]
+                widget::column![widget::text(t!("store-no-results"))]
             } else {
                 widget::column(
                     results
@@ -151,7 +164,7 @@ impl MenuModsDownload {
             .push(widget::horizontal_space())
         } else {
             let dots = ".".repeat((tick_timer % 3) + 1);
-            widget::column!(widget::text!("Loading{dots}"))
+            widget::column!(widget::text(t!("common-loading-dots", dots = dots)))
         }
     }
 
@@ -247,7 +260,7 @@ impl MenuModsDownload {
             widget::column!(Self::render_markdown(info, images, window_size))
         } else {
             let dots = ".".repeat((tick_timer % 3) + 1);
-            widget::column!(widget::text!("Loading...{dots}"))
+            widget::column!(widget::text(t!("common-loading-dots", dots = dots)))
         };
 
         let url = format!(
@@ -266,13 +279,13 @@ impl MenuModsDownload {
                     back_button()
                         .on_press(Message::InstallMods(InstallModsMessage::BackToMainScreen)),
                     widget::tooltip(
-                        button_with_icon(icon_manager::globe(), "Open Mod Page", 16)
+                        button_with_icon(icon_manager::globe(), t!("store-open-page"), 16)
                             .on_press(Message::CoreOpenLink(url.clone())),
                         widget::text(url),
                         widget::tooltip::Position::Bottom
                     )
                     .style(|n| n.style_container_sharp_box(0.0, Color::ExtraDark)),
-                    button_with_icon(icon_manager::save(), "Copy ID", 16)
+                    button_with_icon(icon_manager::save(), t!("store-copy-id"), 16)
                         .on_press(Message::CoreCopyText(hit.id.clone())),
                 )
                 .spacing(5),
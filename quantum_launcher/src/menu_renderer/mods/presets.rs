@@ -4,6 +4,7 @@ use iced::widget;
 use ql_core::SelectedMod;
 
 use crate::{
+    i18n::t,
     icon_manager,
     menu_renderer::{button_with_icon, launch::TAB_HEIGHT, Element},
     state::{
@@ -14,12 +15,12 @@ use crate::{
 };
 
 impl MenuEditPresets {
-    pub fn view(&self, window_size: (f32, f32)) -> Element {
+    pub fn view(&self, window_size: (f32, f32)) -> Element<'_> {
         if let Some(progress) = &self.progress {
             return widget::column!(
-                widget::text("Installing mods").size(20),
+                widget::text(t!("presets-installing")).size(20),
                 progress.view(),
-                widget::text("Check debug log (at the bottom) for more info").size(12),
+                widget::text(t!("presets-installing-help")).size(12),
             )
             .padding(10)
             .spacing(10)
@@ -30,64 +31,55 @@ impl MenuEditPresets {
             is_building: true, ..
         } = &self.inner
         {
-            return widget::column!(widget::text("Building Preset").size(20),)
+            return widget::column!(widget::text(t!("presets-building")).size(20),)
                 .padding(10)
                 .spacing(10)
                 .into();
         }
 
         let p_main = widget::column![
-            widget::container(
-                widget::row![
-                    widget::Space::with_width(16.0),
+            widget::container(widget::row![
+                widget::Space::with_width(16.0),
+                create_generic_tab_button(
+                    widget::row![icon_manager::back(), widget::text(t!("common-back"))]
+                        .padding(5)
+                        .spacing(10)
+                        .into()
+                )
+                .on_press(Message::ManageMods(ManageModsMessage::ScreenOpen)),
+                widget::Space::with_width(16.0),
+                self.get_tab_button("Create", t!("presets-tab-create")),
+                self.get_tab_button("Recommended", t!("presets-tab-recommended")),
+                widget::horizontal_space(),
+                widget::tooltip(
                     create_generic_tab_button(
-                        widget::row![icon_manager::back(), "Back"]
-                            .padding(5)
+                        widget::row![icon_manager::folder(), widget::text(t!("presets-import"))]
                             .spacing(10)
+                            .padding(5)
                             .into()
                     )
-                    .on_press(Message::ManageMods(ManageModsMessage::ScreenOpen)),
-                    widget::Space::with_width(16.0),
-
-                    self.get_tab_button("Create"),
-                    self.get_tab_button("Recommended"),
-
-                    widget::horizontal_space(),
-
-                    widget::tooltip(
-                        create_generic_tab_button(
-                            widget::row![icon_manager::folder(), "Import"]
-                                .spacing(10)
-                                .padding(5)
-                                .into()
-                        )
-                        .on_press(Message::EditPresets(EditPresetsMessage::Load)),
-                        widget::column![
-                            widget::text("Note: Sideloaded .jar mods in untrusted presets could have viruses").size(12),
-                            widget::text("To get rid of them, after installing remove all mods in the list ending in \".jar\"").size(12)
-                        ],
-                        widget::tooltip::Position::Bottom)
-                            .style(|n: &LauncherTheme| n.style_container_sharp_box(0.0, Color::ExtraDark)),
-                ]
-            )
+                    .on_press(Message::EditPresets(EditPresetsMessage::Load)),
+                    widget::column![
+                        widget::text(t!("presets-import-warning")).size(12),
+                        widget::text(t!("presets-import-warning-help")).size(12)
+                    ],
+                    widget::tooltip::Position::Bottom
+                )
+                .style(|n: &LauncherTheme| n.style_container_sharp_box(0.0, Color::ExtraDark)),
+            ])
             .style(|n| n.style_container_sharp_box(0.0, Color::ExtraDark)),
-
             widget::scrollable(
-                widget::container(
-                    self.get_create_preset_page()
-                )
-                .padding(10)
-                .width(window_size.0)
-                .style(|n: &LauncherTheme| n.style_container_sharp_box(0.0, Color::Dark))
+                widget::container(self.get_create_preset_page())
+                    .padding(10)
+                    .width(window_size.0)
+                    .style(|n: &LauncherTheme| n.style_container_sharp_box(0.0, Color::Dark))
             )
         ];
 
         if self.drag_and_drop_hovered {
             widget::stack!(
                 p_main,
-                widget::center(widget::button(
-                    widget::text("Drag and drop mod files to add them").size(20)
-                ))
+                widget::center(widget::button(widget::text(t!("mods-drag-drop")).size(20)))
             )
             .into()
         } else {
@@ -95,9 +87,9 @@ impl MenuEditPresets {
         }
     }
 
-    fn get_tab_button<'a>(&'a self, n: &'a str) -> Element<'a> {
+    fn get_tab_button<'a>(&'a self, n: &'a str, label: String) -> Element<'a> {
         if self.inner.id() == n {
-            widget::container(n)
+            widget::container(widget::text(label))
                 .style(LauncherTheme::style_container_selected_flat_button)
                 .padding(iced::Padding {
                     top: 5.0,
@@ -108,7 +100,7 @@ impl MenuEditPresets {
                 .height(TAB_HEIGHT)
                 .into()
         } else {
-            widget::button(n)
+            widget::button(widget::text(label))
                 .style(|n: &LauncherTheme, status| {
                     n.style_button(status, StyleButton::FlatExtraDark)
                 })
@@ -120,52 +112,55 @@ impl MenuEditPresets {
         }
     }
 
-    fn get_create_preset_page(&self) -> Element {
+    fn get_create_preset_page(&self) -> Element<'_> {
         match &self.inner {
             MenuEditPresetsInner::Build {
                 selected_state,
                 selected_mods,
                 ..
             } => widget::column!(
-                "Presets are small bundles of mods and their configuration that you can share with anyone.",
-                "You can import presets, create them or download recommended mods (if you haven't installed any yet).",
+                widget::text(t!("presets-desc")),
+                widget::text(t!("presets-desc-help")),
                 if selected_mods.is_empty() {
-                    widget::column!["You have no mods installed! Go to Recommended to find some good ones."]
+                    widget::column![widget::text(t!("presets-no-mods"))]
                 } else {
                     widget::column![
-                        widget::text("Create Preset").size(20),
-                        "Select Mods to keep",
-                        widget::button(if let SelectedState::All = selected_state {
-                            "Unselect All"
+                        widget::text(t!("presets-create-title")).size(20),
+                        widget::text(t!("presets-select-mods")),
+                        widget::button(widget::text(if let SelectedState::All = selected_state {
+                            t!("mods-unselect-all")
                         } else {
-                            "Select All"
-                        })
+                            t!("mods-select-all")
+                        }))
                         .on_press(Message::EditPresets(EditPresetsMessage::SelectAll)),
                         widget::container(self.get_mods_list(selected_mods).padding(10)),
-                        button_with_icon(icon_manager::save(), "Build Preset", 16)
+                        button_with_icon(icon_manager::save(), t!("presets-build"), 16)
                             .on_press(Message::EditPresets(EditPresetsMessage::BuildYourOwn)),
                     ]
-                }.spacing(10)
+                }
+                .spacing(10)
             )
             .spacing(10)
             .into(),
-            MenuEditPresetsInner::Recommended {
-                progress,
-                error,
-            } => {
+            MenuEditPresetsInner::Recommended { progress, error } => {
                 if let Some(error) = error {
                     widget::column!(
-                        widget::text!("Error loading presets: {error}"),
-                        widget::button("Copy Error").on_press(Message::CoreCopyText(error.clone()))
+                        widget::text(t!("presets-error", error = error.as_str())),
+                        widget::button(widget::text(t!("error-copy")))
+                            .on_press(Message::CoreCopyText(error.clone()))
                     )
                     .spacing(10)
                     .into()
                 } else if let Some(mods) = &self.recommended_mods {
                     widget::column!(
-                        button_with_icon(icon_manager::download(), "Download Recommended Mods", 16)
-                            .on_press(Message::EditPresets(
-                                EditPresetsMessage::RecommendedDownload
-                            )),
+                        button_with_icon(
+                            icon_manager::download(),
+                            t!("presets-download-recommended"),
+                            16
+                        )
+                        .on_press(Message::EditPresets(
+                            EditPresetsMessage::RecommendedDownload
+                        )),
                         widget::column(mods.iter().enumerate().map(|(i, (e, n))| {
                             let elem: Element = if n.enabled_by_default {
                                 widget::text!("- {}", n.name).into()
@@ -213,14 +208,14 @@ impl MenuEditPresets {
                     })
                     .into()
             } else {
-                widget::text!(" - (DEPENDENCY) {}", entry.name()).into()
+                widget::text(t!("mods-dependency", name = entry.name())).into()
             }
         }))
         .spacing(5)
     }
 }
 
-fn create_generic_tab_button(n: Element) -> widget::Button<'_, Message, LauncherTheme> {
+fn create_generic_tab_button(n: Element<'_>) -> widget::Button<'_, Message, LauncherTheme> {
     widget::button(n)
         .padding(0)
         .style(|n, status| n.style_button(status, StyleButton::FlatExtraDark))
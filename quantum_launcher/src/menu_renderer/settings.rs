@@ -1,12 +1,17 @@
 use iced::{widget, Length};
-use ql_core::LAUNCHER_DIR;
+use ql_core::{mirror::MirrorSource, LAUNCHER_DIR};
 
 use crate::{
     config::LauncherConfig,
+    i18n::{self, t, Language},
     icon_manager,
-    state::{LauncherSettingsMessage, LauncherSettingsTab, MenuLauncherSettings, Message},
+    message_handler::format_size,
+    state::{
+        KeybindMessage, LauncherSettingsMessage, LauncherSettingsTab, MenuLauncherSettings, Message,
+    },
     stylesheet::{
         color::Color,
+        custom::CustomThemes,
         styles::{LauncherTheme, LauncherThemeColor},
         widgets::StyleButton,
     },
@@ -29,7 +34,11 @@ const PADDING_LEFT: iced::Padding = iced::Padding {
 };
 
 impl MenuLauncherSettings {
-    pub fn view<'a>(&'a self, config: &'a LauncherConfig) -> Element<'a> {
+    pub fn view<'a>(
+        &'a self,
+        config: &'a LauncherConfig,
+        custom_themes: &'a CustomThemes,
+    ) -> Element<'a> {
         widget::row![
             widget::container(
                 widget::column![
@@ -40,7 +49,7 @@ impl MenuLauncherSettings {
                     .padding(PADDING_NOT_BOTTOM),
                     widget::row![
                         icon_manager::settings_with_size(20),
-                        widget::text("Settings").size(20),
+                        widget::text(t!("settings-title")).size(20),
                     ]
                     .padding(iced::Padding {
                         top: 5.0,
@@ -75,24 +84,172 @@ impl MenuLauncherSettings {
             .height(Length::Fill)
             .width(180)
             .style(|n: &LauncherTheme| n.style_container_sharp_box(0.0, Color::ExtraDark)),
-            widget::scrollable(self.selected_tab.view(config, self))
+            widget::scrollable(self.selected_tab.view(config, self, custom_themes))
                 .width(Length::Fill)
                 .style(LauncherTheme::style_scrollable_flat_dark)
         ]
         .into()
     }
 
-    fn view_options<'a>(&'a self, config: &'a LauncherConfig) -> Element<'a> {
+    fn view_storage(&self) -> Element<'_> {
+        let header = widget::row![
+            widget::text(t!("settings-tab-storage")).size(20),
+            widget::horizontal_space(),
+            button_with_icon(
+                icon_manager::refresh_clock(),
+                t!("settings-storage-rescan"),
+                14
+            )
+            .on_press(Message::LauncherSettings(
+                LauncherSettingsMessage::StorageScan
+            )),
+        ]
+        .align_y(iced::Alignment::Center);
+
+        let Some(usage) = &self.disk_usage else {
+            return widget::column![header]
+                .push_maybe(self.storage_status.as_deref().map(widget::text))
+                .padding(10)
+                .spacing(10)
+                .into();
+        };
+
+        let instances = widget::column(usage.instances.iter().map(|n| {
+            widget::column![
+                widget::text(if n.instance.is_server() {
+                    t!(
+                        "settings-storage-server",
+                        name = n.instance.get_name(),
+                        size = format_size(n.total)
+                    )
+                } else {
+                    t!(
+                        "settings-storage-instance",
+                        name = n.instance.get_name(),
+                        size = format_size(n.total)
+                    )
+                }),
+                widget::text(t!(
+                    "settings-storage-breakdown",
+                    mods = format_size(n.mods),
+                    worlds = format_size(n.worlds),
+                    libraries = format_size(n.libraries),
+                    logs = format_size(n.logs),
+                    crash_reports = format_size(n.crash_reports),
+                    screenshots = format_size(n.screenshots),
+                    jarmods = format_size(n.build_jar),
+                    other = format_size(n.other()),
+                ))
+                .size(12),
+            ]
+            .spacing(2)
+            .into()
+        }))
+        .spacing(10);
+
+        let cleanup = |icon, label, message, desc| {
+            widget::column![
+                button_with_icon(icon, label, 16).on_press(Message::LauncherSettings(message)),
+                widget::text(desc).size(12),
+            ]
+            .spacing(5)
+        };
+
+        widget::column![
+            widget::column![header]
+                .push_maybe(self.storage_status.as_deref().map(widget::text))
+                .padding(PADDING_NOT_BOTTOM)
+                .spacing(10),
+            widget::column![
+                widget::text(t!(
+                    "settings-storage-java",
+                    size = format_size(usage.java_installs)
+                )),
+                widget::text(t!(
+                    "settings-storage-assets",
+                    size = format_size(usage.assets),
+                    unused = format_size(usage.orphan_assets)
+                )),
+                widget::text(t!(
+                    "settings-storage-libraries",
+                    size = format_size(usage.shared_libraries)
+                )),
+                widget::text(t!(
+                    "settings-storage-launcher-logs",
+                    size = format_size(usage.launcher_logs)
+                )),
+                widget::text(t!(
+                    "settings-storage-forge",
+                    size = format_size(usage.forge_leftovers)
+                )),
+            ]
+            .padding(PADDING_LEFT)
+            .spacing(5),
+            widget::horizontal_rule(1),
+            widget::column![
+                cleanup(
+                    icon_manager::delete(),
+                    t!("settings-storage-prune-logs"),
+                    LauncherSettingsMessage::StoragePruneLogs,
+                    t!("settings-storage-prune-logs-desc")
+                ),
+                cleanup(
+                    icon_manager::delete(),
+                    t!("settings-storage-delete-assets"),
+                    LauncherSettingsMessage::StorageDeleteOrphanAssets,
+                    t!("settings-storage-delete-assets-desc")
+                ),
+                cleanup(
+                    icon_manager::delete(),
+                    t!("settings-storage-delete-forge"),
+                    LauncherSettingsMessage::StorageDeleteForgeLeftovers,
+                    t!("settings-storage-delete-forge-desc")
+                ),
+                cleanup(
+                    icon_manager::delete(),
+                    t!("settings-storage-clean-libraries"),
+                    LauncherSettingsMessage::CleanLibraryStore,
+                    t!("settings-storage-clean-libraries-desc")
+                ),
+            ]
+            .padding(10)
+            .spacing(10),
+            widget::horizontal_rule(1),
+            widget::column![
+                widget::text(t!("settings-storage-instances")).size(16),
+                instances
+            ]
+            .padding(10)
+            .spacing(10),
+        ]
+        .spacing(SETTINGS_SPACING)
+        .into()
+    }
+
+    fn view_options<'a>(
+        &'a self,
+        config: &'a LauncherConfig,
+        custom_themes: &'a CustomThemes,
+    ) -> Element<'a> {
         let (light, dark) = get_theme_selector(config);
 
-        let color_scheme_picker = LauncherThemeColor::ALL.iter().map(|color| {
+        let colors = LauncherThemeColor::ALL.iter().cloned().chain(
+            custom_themes
+                .themes
+                .iter()
+                .map(|n| LauncherThemeColor::Custom(n.clone())),
+        );
+        let color_scheme_picker = colors.map(|color| {
             widget::button(widget::text(color.to_string()).size(14))
-                .style(|theme: &LauncherTheme, s| {
-                    LauncherTheme {
-                        lightness: theme.lightness,
-                        color: *color,
+                .style({
+                    let color = color.clone();
+                    move |theme: &LauncherTheme, s| {
+                        LauncherTheme {
+                            lightness: theme.lightness,
+                            color: color.clone(),
+                        }
+                        .style_button(s, StyleButton::Round)
                     }
-                    .style_button(s, StyleButton::Round)
                 })
                 .on_press(Message::LauncherSettings(
                     LauncherSettingsMessage::StylePicked(color.to_string()),
@@ -101,46 +258,75 @@ impl MenuLauncherSettings {
         });
 
         widget::column!(
-            widget::column![widget::text("User Interface").size(20)].padding(PADDING_NOT_BOTTOM),
-            widget::column!("Theme:", widget::row![light, dark].spacing(5))
-                .padding(iced::Padding {
-                    top: 0.0,
-                    bottom: 10.0,
-                    left: 10.0,
-                    right: 10.0,
-                })
-                .spacing(5),
+            widget::column![widget::text(t!("settings-ui-title")).size(20)]
+                .padding(PADDING_NOT_BOTTOM),
+            view_language(config),
             widget::horizontal_rule(1),
             widget::column!(
-                "Color scheme:",
+                widget::text(t!("settings-theme")),
+                widget::row![light, dark].spacing(5)
+            )
+            .padding(iced::Padding {
+                top: 0.0,
+                bottom: 10.0,
+                left: 10.0,
+                right: 10.0,
+            })
+            .spacing(5),
+            widget::horizontal_rule(1),
+            widget::column!(
+                widget::text(t!("settings-color-scheme")),
                 widget::row(color_scheme_picker).spacing(5).wrap()
             )
+            .push_maybe((!custom_themes.errors.is_empty()).then(|| {
+                widget::column(custom_themes.errors.iter().map(|n| {
+                    widget::text(t!("settings-theme-error", error = n.as_str()))
+                        .size(12)
+                        .style(|t: &LauncherTheme| t.style_text(Color::SecondLight))
+                        .into()
+                }))
+                .spacing(5)
+            }))
+            .push(
+                widget::row![
+                    button_with_icon(icon_manager::folder(), t!("settings-open-themes"), 14)
+                        .on_press(Message::CoreOpenPath(CustomThemes::get_dir())),
+                    widget::text(t!("settings-themes-help")).size(12),
+                ]
+                .spacing(10)
+                .align_y(iced::Alignment::Center)
+            )
             .padding(10)
             .spacing(5),
             widget::horizontal_rule(1),
             widget::column![
-                widget::row![widget::text!("UI Scale ({:.2}x)  ", self.temp_scale)]
-                    .push_maybe(
-                        ((self.temp_scale - config.ui_scale.unwrap_or(1.0)).abs() > 0.01)
-                            .then_some(
-                                widget::button(widget::text("Apply").size(12))
-                                    .padding(iced::Padding {
-                                        top: 2.0,
-                                        bottom: 2.0,
-                                        right: 5.0,
-                                        left: 5.0,
-                                    })
-                                    .on_press(Message::LauncherSettings(
-                                        LauncherSettingsMessage::UiScaleApply
-                                    ))
-                            )
+                widget::row![widget::text!(
+                    "{}  ",
+                    t!(
+                        "settings-ui-scale",
+                        scale = format!("{:.2}", self.temp_scale)
+                    )
+                )]
+                .push_maybe(
+                    ((self.temp_scale - config.ui_scale.unwrap_or(1.0)).abs() > 0.01).then_some(
+                        widget::button(widget::text(t!("settings-apply")).size(12))
+                            .padding(iced::Padding {
+                                top: 2.0,
+                                bottom: 2.0,
+                                right: 5.0,
+                                left: 5.0,
+                            })
+                            .on_press(Message::LauncherSettings(
+                                LauncherSettingsMessage::UiScaleApply
+                            ))
                     )
-                    .align_y(iced::Alignment::Center),
+                )
+                .align_y(iced::Alignment::Center),
                 widget::slider(0.5..=2.0, self.temp_scale, |n| Message::LauncherSettings(
                     LauncherSettingsMessage::UiScale(n)
                 ))
                 .step(0.1),
-                widget::text("Warning: slightly buggy").size(12),
+                widget::text(t!("settings-ui-scale-warning")).size(12),
             ]
             .padding(10)
             .spacing(5),
@@ -155,38 +341,50 @@ impl LauncherSettingsTab {
         &'a self,
         config: &'a crate::config::LauncherConfig,
         menu: &'a MenuLauncherSettings,
+        custom_themes: &'a CustomThemes,
     ) -> Element<'a> {
         match self {
-            LauncherSettingsTab::UserInterface => menu.view_options(config),
+            LauncherSettingsTab::UserInterface => menu.view_options(config, custom_themes),
             LauncherSettingsTab::Internal => widget::column![
                 widget::column![
-                    widget::text("Advanced").size(20),
-                    button_with_icon(icon_manager::folder(), "Open Launcher Folder", 16)
-                        .on_press(Message::CoreOpenPath(LAUNCHER_DIR.clone()))
+                    widget::text(t!("settings-tab-advanced")).size(20),
+                    button_with_icon(
+                        icon_manager::folder(),
+                        t!("settings-open-launcher-folder"),
+                        16
+                    )
+                    .on_press(Message::CoreOpenPath(LAUNCHER_DIR.clone())),
+                    widget::button(widget::text(t!("keys-cheatsheet-title")))
+                        .on_press(Message::Keybind(KeybindMessage::CheatsheetOpen)),
                 ]
                 .spacing(10)
                 .padding(10),
                 widget::horizontal_rule(1),
+                view_downloads(config),
+                widget::horizontal_rule(1),
+                view_mirrors(config),
+                widget::horizontal_rule(1),
+                view_network(config),
+                widget::horizontal_rule(1),
                 widget::column![
-                    button_with_icon(icon_manager::delete(), "Clear Java installs", 16).on_press(
-                        Message::LauncherSettings(LauncherSettingsMessage::ClearJavaInstalls)
-                    ),
-                    widget::text(
-                        "Might fix some Java problems.\nPerfectly safe, will be redownloaded."
-                    )
-                    .size(12),
+                    button_with_icon(icon_manager::delete(), t!("settings-clear-java"), 16)
+                        .on_press(Message::LauncherSettings(
+                            LauncherSettingsMessage::ClearJavaInstalls
+                        )),
+                    widget::text(t!("settings-clear-java-desc")).size(12),
                 ]
                 .padding(10)
                 .spacing(10),
             ]
             .spacing(SETTINGS_SPACING)
             .into(),
+            LauncherSettingsTab::Storage => menu.view_storage(),
             LauncherSettingsTab::About => {
                 let gpl3_button =
                     // widget::button(widget::rich_text![widget::span("GNU GPLv3 License").underline(true)].size(12))
                     // iced bug (or maybe some dumb mistake I made),
                     // putting underlines in buttons makes them unclickable.
-                    widget::button(widget::text("GNU GPLv3 License").size(12))
+                    widget::button(widget::text(t!("settings-about-license")).size(12))
                         .padding(0)
                         // .style(|n: &LauncherTheme, status| n.style_button(status, StyleButton::FlatExtraDark))
                         // Since I can't underline the buttons,
@@ -195,11 +393,10 @@ impl LauncherSettingsTab {
                         .on_press(Message::LicenseChangeTab(crate::state::LicenseTab::Gpl3));
 
                 let links = widget::row![
-                    button_with_icon(icon_manager::globe(), "Website", 16).on_press(
-                        Message::CoreOpenLink(
+                    button_with_icon(icon_manager::globe(), t!("settings-about-website"), 16)
+                        .on_press(Message::CoreOpenLink(
                             "https://mrmayman.github.io/quantumlauncher".to_owned()
-                        )
-                    ),
+                        )),
                     button_with_icon(icon_manager::github(), "Github", 16)
                         .on_press(Message::CoreOpenLink(GITHUB.to_owned())),
                     button_with_icon(icon_manager::discord(), "Discord", 16)
@@ -215,39 +412,31 @@ impl LauncherSettingsTab {
                 .wrap();
 
                 let menus = widget::row![
-                    widget::button("Changelog").on_press(Message::CoreOpenChangeLog),
-                    widget::button("Welcome Screen").on_press(Message::CoreOpenIntro),
-                    widget::button("Licenses").on_press(Message::LicenseOpen),
+                    widget::button(widget::text(t!("settings-about-changelog")))
+                        .on_press(Message::CoreOpenChangeLog),
+                    widget::button(widget::text(t!("settings-about-welcome")))
+                        .on_press(Message::CoreOpenIntro),
+                    widget::button(widget::text(t!("settings-about-licenses")))
+                        .on_press(Message::LicenseOpen),
                 ]
                 .padding(PADDING_LEFT)
                 .spacing(5)
                 .wrap();
 
                 widget::column![
-                    widget::column![widget::text("About QuantumLauncher").size(20)]
+                    widget::column![widget::text(t!("settings-about-title")).size(20)]
                         .padding(PADDING_NOT_BOTTOM),
                     menus,
                     links,
                     widget::horizontal_rule(1),
                     widget::column![
                         widget::row![
-                            widget::text(
-                                "QuantumLauncher is free and open source software under the "
-                            )
-                            .size(12),
+                            widget::text!("{} ", t!("settings-about-free-software")).size(12),
                             gpl3_button,
                         ]
                         .wrap(),
-                        widget::text(
-                            r"No warranty is provided for this software.
-You're free to share, modify, and redistribute it under the same license."
-                        )
-                        .size(12),
-                        widget::text(
-                            r"If you like this launcher, consider sharing it with your friends.
-Every new user motivates me to keep working on this :)"
-                        )
-                        .size(12),
+                        widget::text(t!("settings-about-warranty")).size(12),
+                        widget::text(t!("settings-about-share")).size(12),
                     ]
                     .padding(iced::Padding {
                         top: 10.0,
@@ -263,3 +452,213 @@ Every new user motivates me to keep working on this :)"
         }
     }
 }
+
+fn view_downloads(config: &LauncherConfig) -> Element<'_> {
+    let concurrency = config
+        .download_concurrency
+        .unwrap_or(ql_core::download::DEFAULT_MAX_CONCURRENT) as u32;
+    let speed_limit = config
+        .download_speed_limit
+        .filter(|n| *n > 0)
+        .map(|n| n.to_string())
+        .unwrap_or_default();
+
+    widget::column![
+        widget::text(t!("settings-downloads")).size(16),
+        widget::checkbox(t!("settings-offline-mode"), ql_core::download::is_offline()).on_toggle(
+            |t| { Message::LauncherSettings(LauncherSettingsMessage::ToggleOfflineMode(t)) }
+        ),
+        widget::text(t!("settings-offline-mode-desc")).size(12),
+        widget::text(t!("settings-parallel-downloads", count = concurrency)),
+        widget::slider(1..=64, concurrency, |n| Message::LauncherSettings(
+            LauncherSettingsMessage::DownloadConcurrency(n)
+        )),
+        widget::row![
+            widget::text(t!("settings-speed-limit")),
+            widget::text_input(&t!("settings-speed-unlimited"), &speed_limit)
+                .on_input(|n| Message::LauncherSettings(
+                    LauncherSettingsMessage::DownloadSpeedLimit(n)
+                ))
+                .width(120),
+        ]
+        .align_y(iced::Alignment::Center)
+        .spacing(10),
+        widget::text(t!("settings-downloads-desc")).size(12),
+    ]
+    .padding(10)
+    .spacing(7)
+    .into()
+}
+
+fn view_mirrors(config: &LauncherConfig) -> Element<'_> {
+    let mirrors = config.mirrors.clone().unwrap_or_default();
+
+    widget::column![
+        widget::text(t!("settings-mirrors")).size(16),
+        widget::text(t!("settings-mirrors-desc")).size(12),
+        widget::column(MirrorSource::ALL.iter().map(|source| {
+            let list = mirrors
+                .mirrors
+                .get(source)
+                .map(|n| n.join(","))
+                .unwrap_or_default();
+            widget::row![
+                widget::text!("{source}:").width(130),
+                widget::text_input(&t!("settings-mirror-official"), &list).on_input(|n| {
+                    Message::LauncherSettings(LauncherSettingsMessage::MirrorEdit(*source, n))
+                }),
+            ]
+            .align_y(iced::Alignment::Center)
+            .spacing(10)
+            .into()
+        }))
+        .spacing(5),
+        widget::checkbox(t!("settings-mirror-fallback"), mirrors.fallback_to_official).on_toggle(
+            |t| Message::LauncherSettings(LauncherSettingsMessage::MirrorFallbackToggle(t))
+        ),
+        widget::button(widget::text(t!("settings-mirror-bmclapi"))).on_press(
+            Message::LauncherSettings(LauncherSettingsMessage::MirrorUseBmclapi)
+        ),
+    ]
+    .padding(10)
+    .spacing(7)
+    .into()
+}
+
+fn view_network(config: &LauncherConfig) -> Element<'_> {
+    let network = config.network.clone().unwrap_or_default();
+    let proxy = network.proxy.clone().unwrap_or_default();
+    let timeout = network
+        .timeout_secs
+        .map(|n| n.to_string())
+        .unwrap_or_default();
+
+    let field = |label: String, input: widget::TextInput<'static, Message, _>| {
+        widget::row![widget::text(label).width(130), input]
+            .align_y(iced::Alignment::Center)
+            .spacing(10)
+    };
+    let msg = |f: fn(String) -> LauncherSettingsMessage| move |n| Message::LauncherSettings(f(n));
+
+    widget::column![
+        widget::text(t!("settings-network")).size(16),
+        widget::text(t!("settings-network-restart")).size(12),
+        field(
+            t!("settings-proxy"),
+            widget::text_input(&t!("settings-proxy-placeholder"), &proxy.url)
+                .on_input(msg(LauncherSettingsMessage::NetworkProxyUrl))
+        ),
+        field(
+            t!("settings-proxy-username"),
+            widget::text_input(
+                &t!("settings-none"),
+                proxy.username.as_deref().unwrap_or_default()
+            )
+            .on_input(msg(LauncherSettingsMessage::NetworkProxyUsername))
+        ),
+        field(
+            t!("settings-proxy-password"),
+            widget::text_input(
+                &t!("settings-none"),
+                proxy.password.as_deref().unwrap_or_default()
+            )
+            .secure(true)
+            .on_input(msg(LauncherSettingsMessage::NetworkProxyPassword))
+        ),
+        field(
+            t!("settings-no-proxy"),
+            widget::text_input(
+                &t!("settings-no-proxy-placeholder"),
+                &proxy.no_proxy.join(",")
+            )
+            .on_input(msg(LauncherSettingsMessage::NetworkNoProxy))
+        ),
+        field(
+            t!("settings-timeout"),
+            widget::text_input(&t!("settings-default"), &timeout)
+                .on_input(msg(LauncherSettingsMessage::NetworkTimeout))
+        ),
+        field(
+            t!("settings-user-agent"),
+            widget::text_input(
+                &t!("settings-default"),
+                network.user_agent.as_deref().unwrap_or_default()
+            )
+            .on_input(msg(LauncherSettingsMessage::NetworkUserAgent))
+        ),
+        widget::text(t!(
+            "settings-certificates",
+            certificates = if network.ca_certificates.is_empty() {
+                t!("settings-none")
+            } else {
+                network
+                    .ca_certificates
+                    .iter()
+                    .map(|n| n.to_string_lossy())
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            }
+        )),
+        widget::row![
+            widget::button(widget::text(t!("settings-add-certificate"))).on_press(
+                Message::LauncherSettings(LauncherSettingsMessage::NetworkAddCertificate)
+            ),
+            widget::button(widget::text(t!("settings-clear"))).on_press(Message::LauncherSettings(
+                LauncherSettingsMessage::NetworkClearCertificates
+            )),
+        ]
+        .spacing(5),
+        widget::text(t!("settings-proxy-game")).size(12),
+    ]
+    .padding(10)
+    .spacing(7)
+    .into()
+}
+
+/// An entry of the language picker.
+#[derive(Debug, Clone, PartialEq)]
+enum LanguageChoice {
+    System,
+    Language(&'static Language),
+}
+
+impl std::fmt::Display for LanguageChoice {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            LanguageChoice::System => write!(f, "{}", t!("settings-language-system")),
+            LanguageChoice::Language(language) => write!(f, "{language}"),
+        }
+    }
+}
+
+fn view_language(config: &LauncherConfig) -> Element<'_> {
+    let choices: Vec<LanguageChoice> = std::iter::once(LanguageChoice::System)
+        .chain(i18n::languages().iter().map(LanguageChoice::Language))
+        .collect();
+    let selected = match config.language.as_deref() {
+        Some(id) => choices
+            .iter()
+            .find(|n| matches!(n, LanguageChoice::Language(l) if l.id == id))
+            .cloned(),
+        None => Some(LanguageChoice::System),
+    };
+
+    widget::column![
+        widget::text(t!("settings-language")),
+        widget::pick_list(choices, selected, |n| {
+            Message::LauncherSettings(LauncherSettingsMessage::LanguagePicked(match n {
+                LanguageChoice::System => None,
+                LanguageChoice::Language(language) => Some(language.id.clone()),
+            }))
+        }),
+        widget::text(t!("settings-language-help")).size(12),
+    ]
+    .padding(iced::Padding {
+        top: 0.0,
+        bottom: 10.0,
+        left: 10.0,
+        right: 10.0,
+    })
+    .spacing(5)
+    .into()
+}
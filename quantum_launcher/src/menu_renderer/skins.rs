@@ -0,0 +1,163 @@
+use iced::widget;
+use ql_instances::auth::{
+    skin::{SkinModel, ELYBY_SKINS_PAGE},
+    AccountType,
+};
+
+use crate::{
+    i18n::t,
+    icon_manager,
+    state::{MenuSkins, Message, SkinMessage},
+    stylesheet::{color::Color, styles::LauncherTheme},
+};
+
+use super::{back_button, button_with_icon, Element};
+
+impl MenuSkins {
+    pub fn view(&self, account_type: AccountType, tick_timer: usize) -> Element<'_> {
+        let current: Element = match (&self.profile, &self.preview) {
+            (None, _) => {
+                let dots = ".".repeat((tick_timer % 3) + 1);
+                widget::text(t!("common-loading-dots", dots = dots)).into()
+            }
+            (Some(_), Some(preview)) => widget::image(preview.clone()).into(),
+            (Some(_), None) => widget::text(t!("skins-default")).size(14).into(),
+        };
+
+        widget::scrollable(
+            widget::column![
+                back_button().on_press(Message::LaunchScreenOpen {
+                    message: None,
+                    clear_selection: false
+                }),
+                widget::text(t!("skins-title", account = self.account.as_str())).size(20),
+                widget::row![
+                    widget::container(current).padding(10).style(
+                        |n: &LauncherTheme| n.style_container_sharp_box(0.0, Color::ExtraDark)
+                    ),
+                    self.view_current(account_type, tick_timer),
+                ]
+                .spacing(10),
+                widget::horizontal_rule(1),
+                self.view_library(account_type),
+            ]
+            .padding(10)
+            .spacing(10),
+        )
+        .into()
+    }
+
+    fn view_current(&self, account_type: AccountType, tick_timer: usize) -> Element<'_> {
+        let Some(profile) = &self.profile else {
+            return widget::Column::new().into();
+        };
+
+        let mut col =
+            widget::column![
+                widget::text(t!("skins-model", model = profile.model.to_string())).size(14)
+            ]
+            .spacing(5);
+
+        let is_microsoft = matches!(account_type, AccountType::Microsoft);
+        if !is_microsoft {
+            col = col.push(widget::text(t!("skins-server-only")).size(12));
+            if let AccountType::ElyBy = account_type {
+                col = col.push(
+                    widget::button(widget::text(t!("skins-open-elyby")).size(14))
+                        .on_press(Message::CoreOpenLink(ELYBY_SKINS_PAGE.to_owned())),
+                );
+            }
+        } else if self.is_loading {
+            let dots = ".".repeat((tick_timer % 3) + 1);
+            col = col.push(widget::text(t!("skins-updating", dots = dots)).size(14));
+        } else {
+            col = col.push(
+                widget::button(widget::text(t!("skins-reset")).size(14))
+                    .on_press(Message::Skin(SkinMessage::Reset)),
+            );
+        }
+        col = col.push_maybe(self.texture.is_some().then(|| {
+            widget::button(widget::text(t!("skins-save")).size(14))
+                .on_press(Message::Skin(SkinMessage::SaveCurrent))
+        }));
+
+        col = col.push(widget::text(t!("skins-cape")).size(16));
+        if profile.capes.is_empty() {
+            col = col.push(widget::text(t!("skins-no-capes")).size(14));
+        } else if !is_microsoft {
+            for cape in &profile.capes {
+                col = col.push(widget::text(&cape.alias).size(14));
+            }
+        } else {
+            // `usize::MAX` is "no cape"
+            let active = profile
+                .capes
+                .iter()
+                .position(|n| n.active)
+                .unwrap_or(usize::MAX);
+            col = col.push(
+                widget::radio(t!("skins-cape-none"), usize::MAX, Some(active), |_| {
+                    Message::Skin(SkinMessage::SetCape(None))
+                })
+                .size(14)
+                .text_size(14),
+            );
+            for (i, cape) in profile.capes.iter().enumerate() {
+                let id = cape.id.clone();
+                col = col.push(
+                    widget::radio(cape.alias.as_str(), i, Some(active), move |_| {
+                        Message::Skin(SkinMessage::SetCape(Some(id.clone())))
+                    })
+                    .size(14)
+                    .text_size(14),
+                );
+            }
+        }
+        col.into()
+    }
+
+    fn view_library(&self, account_type: AccountType) -> Element<'_> {
+        let header = widget::row![
+            widget::text(t!("skins-library")).size(20),
+            widget::horizontal_space(),
+            widget::text(t!("skins-import-model")).size(14),
+            widget::pick_list(SkinModel::ALL, Some(self.import_model), |n| {
+                Message::Skin(SkinMessage::ImportModelPicked(n))
+            }),
+            button_with_icon(icon_manager::folder(), t!("skins-import"), 14)
+                .on_press(Message::Skin(SkinMessage::Import)),
+        ]
+        .spacing(10)
+        .align_y(iced::Alignment::Center);
+
+        if self.library.is_empty() {
+            return widget::column![header, widget::text(t!("skins-library-empty")).size(14)]
+                .spacing(10)
+                .into();
+        }
+
+        let skins = widget::row(self.library.iter().map(|(skin, preview)| {
+            widget::column![]
+                .push_maybe(preview.clone().map(widget::image))
+                .push(widget::text(&skin.name).size(14))
+                .push(widget::text!("{}", skin.model).size(12))
+                .push_maybe(matches!(account_type, AccountType::Microsoft).then(|| {
+                    widget::button(widget::text(t!("skins-use")).size(14)).on_press_maybe(
+                        (!self.is_loading && self.profile.is_some())
+                            .then(|| Message::Skin(SkinMessage::Use(skin.name.clone()))),
+                    )
+                }))
+                .push(
+                    widget::button(widget::text(t!("skins-delete")).size(14))
+                        .on_press(Message::Skin(SkinMessage::Delete(skin.name.clone()))),
+                )
+                .spacing(5)
+                .align_x(iced::Alignment::Center)
+                .into()
+        }))
+        .spacing(15)
+        .wrap();
+
+        widget::column![header, skins].spacing(10).into()
+    }
+}
@@ -0,0 +1,80 @@
+use iced::{widget, Length};
+use ql_core::ModId;
+
+use crate::{
+    cli::UrlAction,
+    i18n::t,
+    icon_manager,
+    menu_renderer::button_with_icon,
+    state::{MenuUrlInstall, Message, UrlInstallMessage},
+    stylesheet::{color::Color, styles::LauncherTheme},
+};
+
+use super::Element;
+
+impl MenuUrlInstall {
+    pub fn view<'a>(&'a self, instances: Option<&'a [String]>) -> Element<'a> {
+        let (title, what) = match &self.action {
+            UrlAction::Mod { id, .. } => (
+                t!("url-install-mod-title"),
+                t!(
+                    "url-install-mod",
+                    source = match id {
+                        ModId::Modrinth(_) => "Modrinth",
+                        ModId::Curseforge(_) => "CurseForge",
+                    },
+                    id = id.get_internal_id()
+                ),
+            ),
+            UrlAction::Pack { url, .. } => (
+                t!("url-install-pack-title"),
+                t!("url-install-pack", url = url.as_str()),
+            ),
+            UrlAction::File { path } => (
+                t!("url-install-file-title"),
+                t!("url-install-file", path = path.to_string_lossy()),
+            ),
+        };
+
+        let picker: Element = match instances {
+            Some([]) => widget::text(t!("url-install-no-instances")).into(),
+            Some(instances) => widget::row![
+                widget::text(t!("url-install-instance")),
+                widget::pick_list(instances, self.instance.as_ref(), |n| {
+                    Message::UrlInstall(UrlInstallMessage::InstanceSelected(n))
+                })
+                .width(Length::Fill),
+            ]
+            .align_y(iced::Alignment::Center)
+            .spacing(10)
+            .into(),
+            None => widget::text(t!("common-loading")).into(),
+        };
+
+        widget::column![
+            widget::text(title).size(20),
+            widget::text(what).font(super::FONT_MONO),
+            widget::text(t!("url-install-warning"))
+                .style(|t: &LauncherTheme| t.style_text(Color::Mid)),
+            picker,
+            widget::row![
+                button_with_icon(icon_manager::download(), t!("url-install-confirm"), 16)
+                    .on_press_maybe(
+                        self.instance
+                            .is_some()
+                            .then_some(Message::UrlInstall(UrlInstallMessage::Confirm))
+                    ),
+                widget::button(widget::text(t!("url-install-cancel"))).on_press(
+                    Message::LaunchScreenOpen {
+                        message: None,
+                        clear_selection: false,
+                    }
+                ),
+            ]
+            .spacing(5),
+        ]
+        .padding(10)
+        .spacing(10)
+        .into()
+    }
+}
@@ -6,10 +6,15 @@ use iced::{
 };
 use ql_core::{err, info, info_no_log, jarmod::JarMod, InstanceSelection};
 
-use crate::state::{
-    Launcher, LauncherSettingsTab, MenuCreateInstance, MenuEditMods, MenuExportInstance,
-    MenuInstallFabric, MenuInstallOptifine, MenuLaunch, MenuLauncherSettings, MenuLauncherUpdate,
-    MenuLoginElyBy, MenuLoginMS, MenuServerCreate, Message, State,
+use crate::{
+    keybinds::KeyAction,
+    state::{
+        InstallModsMessage, KeyOverlay, KeybindMessage, LaunchTabId, Launcher, LauncherSettingsTab,
+        ManageModsMessage, MenuChangeVersion, MenuCloneInstance, MenuCreateInstance, MenuEditMods,
+        MenuExportInstance, MenuInstallFabric, MenuInstallOptifine, MenuLaunch,
+        MenuLauncherSettings, MenuLauncherUpdate, MenuLoginElyBy, MenuLoginMS, MenuServerCreate,
+        MenuSkins, Message, State,
+    },
 };
 
 use super::{SIDEBAR_DRAG_LEEWAY, SIDEBAR_LIMIT_LEFT, SIDEBAR_LIMIT_RIGHT};
@@ -109,40 +114,13 @@ impl Launcher {
                     modifiers,
                     ..
                 } => {
+                    if self.key_overlay.is_some() {
+                        return self.key_overlay_event(&key, modifiers, status);
+                    }
                     if let iced::event::Status::Ignored = status {
-                        if let Key::Named(Named::Escape) = key {
-                            return self.key_escape_back(true).1;
-                        }
-                        if let Key::Named(Named::ArrowUp) = key {
-                            return self.key_change_selected_instance(false);
-                        } else if let Key::Named(Named::ArrowDown) = key {
-                            return self.key_change_selected_instance(true);
-                        } else if let Key::Named(Named::Enter) = key {
-                            if modifiers.command() {
-                                return self.launch_start();
-                            }
-                        } else if let Key::Named(Named::Backspace) = key {
-                            match self.selected_instance.clone() {
-                                Some(InstanceSelection::Instance(_)) => {
-                                    return self.kill_selected_instance();
-                                }
-                                Some(InstanceSelection::Server(server)) => {
-                                    self.kill_selected_server(&server);
-                                }
-                                None => {}
-                            }
-                        } else if let Key::Character(ch) = &key {
-                            let safe_to_exit = self.client_processes.is_empty()
-                                && self.server_processes.is_empty()
-                                && (self.key_escape_back(false).0
-                                    || matches!(self.state, State::Launch(_)));
-
-                            if ch == "q" && modifiers.command() && safe_to_exit {
-                                info_no_log!("CTRL-Q pressed, closing launcher...");
-                                std::process::exit(1);
-                            }
+                        if let Some(action) = self.keybinds.action_for(&key, modifiers) {
+                            return self.key_action(action);
                         }
-
                         self.keys_pressed.insert(key);
                     } else {
                         // FUTURE
@@ -191,6 +169,7 @@ impl Launcher {
                         (&mut self.state, button)
                     {
                         menu.sidebar_dragging = false;
+                        return self.sidebar_drop();
                     }
                 }
                 iced::mouse::Event::WheelScrolled { delta } => {
@@ -221,6 +200,117 @@ impl Launcher {
         Task::none()
     }
 
+    fn key_action(&mut self, action: KeyAction) -> Task<Message> {
+        let is_launch_menu = matches!(self.state, State::Launch(_));
+        match action {
+            KeyAction::Back => return self.key_escape_back(true).1,
+            KeyAction::SelectUp | KeyAction::SelectDown => {
+                let down = action == KeyAction::SelectDown;
+                if let State::ModsDownload(_) = &self.state {
+                    return self.key_change_opened_mod(down);
+                }
+                return self.key_change_selected_instance(down);
+            }
+            KeyAction::Launch if is_launch_menu => match self.selected_instance.clone() {
+                Some(InstanceSelection::Instance(_)) => return self.launch_start(),
+                Some(InstanceSelection::Server(server))
+                    if !self.server_processes.contains_key(&server) =>
+                {
+                    return self.update(Message::ServerManageStartServer(server));
+                }
+                Some(InstanceSelection::Server(_)) | None => {}
+            },
+            KeyAction::Kill if is_launch_menu => match self.selected_instance.clone() {
+                Some(InstanceSelection::Instance(_)) => {
+                    return self.kill_selected_instance();
+                }
+                Some(InstanceSelection::Server(server)) => {
+                    self.kill_selected_server(&server);
+                }
+                None => {}
+            },
+            KeyAction::OpenLog if is_launch_menu && self.selected_instance.is_some() => {
+                return self.update(Message::LaunchChangeTab(LaunchTabId::Log));
+            }
+            KeyAction::OpenMods if is_launch_menu && self.selected_instance.is_some() => {
+                return self.update(Message::ManageMods(ManageModsMessage::ScreenOpen));
+            }
+            KeyAction::FocusSearch => {
+                if let State::ModsDownload(menu) = &mut self.state {
+                    menu.opened_mod = None;
+                    return iced::widget::text_input::focus(iced::widget::text_input::Id::new(
+                        "MenuModsDownload:search",
+                    ));
+                }
+            }
+            KeyAction::Switcher => {
+                return self.update(Message::Keybind(KeybindMessage::SwitcherOpen));
+            }
+            KeyAction::Cheatsheet => {
+                return self.update(Message::Keybind(KeybindMessage::CheatsheetOpen));
+            }
+            KeyAction::Quit => {
+                let safe_to_exit = self.client_processes.is_empty()
+                    && self.server_processes.is_empty()
+                    && (self.key_escape_back(false).0 || is_launch_menu);
+                if safe_to_exit {
+                    info_no_log!("Quit shortcut pressed, closing launcher...");
+                    std::process::exit(1);
+                }
+            }
+            KeyAction::Launch | KeyAction::Kill | KeyAction::OpenLog | KeyAction::OpenMods => {}
+        }
+        Task::none()
+    }
+
+    /// Handles keys while a [`KeyOverlay`] is open. Unlike
+    /// other shortcuts, these work even if a text box is focused.
+    fn key_overlay_event(
+        &mut self,
+        key: &Key,
+        modifiers: iced::keyboard::Modifiers,
+        status: iced::event::Status,
+    ) -> Task<Message> {
+        let action = self.keybinds.action_for(key, modifiers);
+        match key {
+            Key::Named(Named::Escape) => self.key_overlay = None,
+            Key::Named(Named::ArrowUp) => self.switcher_move(false),
+            Key::Named(Named::ArrowDown) => self.switcher_move(true),
+            // If the search box is focused it already submits
+            Key::Named(Named::Enter) if status == iced::event::Status::Ignored => {
+                return self.update(Message::Keybind(KeybindMessage::SwitcherSubmit));
+            }
+            _ if action == Some(KeyAction::Cheatsheet)
+                && matches!(self.key_overlay, Some(KeyOverlay::Cheatsheet)) =>
+            {
+                self.key_overlay = None;
+            }
+            _ => {}
+        }
+        Task::none()
+    }
+
+    /// Opens the previous/next mod in the store.
+    fn key_change_opened_mod(&mut self, down: bool) -> Task<Message> {
+        let State::ModsDownload(menu) = &self.state else {
+            return Task::none();
+        };
+        let (Some(opened_mod), Some(results)) = (menu.opened_mod, &menu.results) else {
+            return Task::none();
+        };
+        let new = if down {
+            opened_mod + 1
+        } else if let Some(n) = opened_mod.checked_sub(1) {
+            n
+        } else {
+            return Task::none();
+        };
+        if new >= results.mods.len() {
+            return Task::none();
+        }
+        self.update(Message::InstallMods(InstallModsMessage::Click(new)))
+    }
+
     fn key_escape_back(&mut self, affect: bool) -> (bool, Task<Message>) {
         let mut should_return_to_main_screen = false;
         let mut should_return_to_mods_screen = false;
@@ -247,6 +337,12 @@ impl Launcher {
             | State::LoginElyBy(MenuLoginElyBy {
                 is_loading: false, ..
             })
+            | State::Skins(MenuSkins {
+                is_loading: false, ..
+            })
+            | State::ChangeVersion(MenuChangeVersion { progress: None, .. })
+            | State::CloneInstance(MenuCloneInstance { progress: None, .. })
+            | State::UrlInstall(_)
             | State::Welcome(_) => {
                 should_return_to_main_screen = true;
             }
@@ -257,6 +353,8 @@ impl Launcher {
                         self.state = State::LauncherSettings(MenuLauncherSettings {
                             temp_scale: self.config.ui_scale.unwrap_or(1.0),
                             selected_tab: LauncherSettingsTab::About,
+                            storage_status: None,
+                            disk_usage: None,
                         });
                     }
                 }
@@ -299,6 +397,9 @@ impl Launcher {
             | State::ImportModpack(_)
             | State::CurseforgeManualDownload(_)
             | State::LoginElyBy(_)
+            | State::Skins(_)
+            | State::ChangeVersion(_)
+            | State::CloneInstance(_)
             | State::Launch(_) => {}
         }
 
@@ -331,13 +432,8 @@ impl Launcher {
         let State::Launch(menu) = &self.state else {
             return Task::none();
         };
-        let list = if menu.is_viewing_server {
-            &self.server_list
-        } else {
-            &self.client_list
-        };
-
-        let Some(list) = list else {
+        let is_server = menu.is_viewing_server;
+        let Some(list) = self.sidebar_visible_order(is_server) else {
             return Task::none();
         };
 
@@ -349,32 +445,28 @@ impl Launcher {
             {
                 if down {
                     if idx + 1 < list.len() {
-                        *selected_instance = InstanceSelection::new(
-                            list.get(idx + 1).unwrap(),
-                            menu.is_viewing_server,
-                        );
+                        *selected_instance =
+                            InstanceSelection::new(list.get(idx + 1).unwrap(), is_server);
                         idx + 1
                     } else {
                         idx
                     }
                 } else if idx > 0 {
                     *selected_instance =
-                        InstanceSelection::new(list.get(idx - 1).unwrap(), menu.is_viewing_server);
+                        InstanceSelection::new(list.get(idx - 1).unwrap(), is_server);
                     idx - 1
                 } else {
                     idx
                 }
             } else {
-                debug_assert!(
-                    false,
-                    "Selected instance {selected_instance:?}, not found in list?"
-                );
+                // In a collapsed sidebar section
+                if let Some(first) = list.first() {
+                    *selected_instance = InstanceSelection::new(first, is_server);
+                }
                 0
             }
         } else {
-            self.selected_instance = list
-                .first()
-                .map(|n| InstanceSelection::new(n, menu.is_viewing_server));
+            self.selected_instance = list.first().map(|n| InstanceSelection::new(n, is_server));
             0
         };
 
@@ -11,19 +11,25 @@ use std::{
 
 use iced::Task;
 use ql_core::{
-    err, json::instance_config::InstanceConfigJson, GenericProgress, InstanceSelection,
-    IntoIoError, IntoJsonError, IntoStringError, JsonFileError,
+    download, err, json::instance_config::InstanceConfigJson, GenericProgress, InstanceSelection,
+    IntoIoError, IntoJsonError, IntoStringError, JsonFileError, LAUNCHER_DIR,
+};
+use ql_instances::{
+    auth::{self, AccountData},
+    ReadError,
 };
-use ql_instances::{auth::AccountData, ReadError};
 use ql_mod_manager::{loaders, store::ModIndex};
 use tokio::process::Child;
 
 use crate::{
     get_entries,
+    i18n::t,
+    single_instance::Request,
     state::{
-        ClientProcess, EditPresetsMessage, ManageModsMessage, MenuEditInstance, MenuEditMods,
-        MenuInstallForge, MenuLaunch, MenuLauncherUpdate, ProgressBar, SelectedState, State,
-        NEW_ACCOUNT_NAME, OFFLINE_ACCOUNT_NAME,
+        ClientProcess, EditPresetsMessage, LauncherSettingsMessage, ManageModsMessage,
+        MenuEditInstance, MenuEditMods, MenuInstallForge, MenuLaunch, MenuLauncherUpdate,
+        ProgressBar, SelectedState, State, UrlInstallMessage, NEW_ACCOUNT_NAME,
+        OFFLINE_ACCOUNT_NAME,
     },
     Launcher, Message, ServerProcess,
 };
@@ -36,7 +42,6 @@ mod iced_event;
 
 impl Launcher {
     pub fn launch_game(&mut self, account_data: Option<AccountData>) -> Task<Message> {
-        let selected_instance = self.selected_instance.as_ref().unwrap().get_name();
         let username = if let Some(account_data) = &account_data {
             // Logged in account
             account_data.nice_username.clone()
@@ -44,7 +49,15 @@ impl Launcher {
             // Offline username
             self.config.username.clone()
         };
+        self.launch_game_as(username, account_data)
+    }
 
+    fn launch_game_as(
+        &mut self,
+        username: String,
+        account_data: Option<AccountData>,
+    ) -> Task<Message> {
+        let selected_instance = self.selected_instance.as_ref().unwrap().get_name();
         let (sender, receiver) = std::sync::mpsc::channel();
         self.java_recv = Some(ProgressBar::with_recv(receiver));
 
@@ -135,7 +148,12 @@ impl Launcher {
             }
 
             self.selected_instance = None;
-            return self.go_to_launch_screen(Some("Deleted Instance".to_owned()));
+            return Task::batch([
+                self.go_to_launch_screen(Some(t!("instance-deleted"))),
+                Task::perform(ql_instances::library_store::clean_library_store(), |n| {
+                    Message::LauncherSettings(LauncherSettingsMessage::StorageCleanEnd(n.strerr()))
+                }),
+            ]);
         }
         Task::none()
     }
@@ -161,6 +179,9 @@ impl Launcher {
         let instance_name = selected_instance.get_name();
 
         *edit_instance = Some(MenuEditInstance {
+            notes: iced::widget::text_editor::Content::with_text(
+                config_json.notes.as_deref().unwrap_or_default(),
+            ),
             config: config_json,
             slider_value,
             instance_name: instance_name.to_owned(),
@@ -196,6 +217,8 @@ impl Launcher {
                     locally_installed_mods: HashSet::new(),
                     drag_and_drop_hovered: false,
                     update_check_handle: None,
+                    reviewing_updates: false,
+                    snapshots: None,
                 });
 
                 Ok(locally_installed_mods)
@@ -222,7 +245,7 @@ impl Launcher {
                 let locally_installed_mods =
                     MenuEditMods::update_locally_installed_mods(&idx, selected_instance);
 
-                let (update_cmd, update_check_handle) = if is_vanilla {
+                let (update_cmd, update_check_handle) = if is_vanilla || download::is_offline() {
                     (Task::none(), None)
                 } else {
                     let (a, b) = Task::perform(
@@ -244,6 +267,8 @@ impl Launcher {
                     locally_installed_mods: HashSet::new(),
                     drag_and_drop_hovered: false,
                     update_check_handle,
+                    reviewing_updates: false,
+                    snapshots: None,
                 });
 
                 return Ok(Task::batch([locally_installed_mods, update_cmd]));
@@ -272,10 +297,11 @@ impl Launcher {
         if let State::EditMods(menu) = &mut self.state {
             let updates = menu
                 .available_updates
-                .clone()
-                .into_iter()
-                .map(|(n, _, _)| n)
+                .iter()
+                .filter(|(_, is_enabled)| *is_enabled)
+                .map(|(n, _)| n.id.clone())
                 .collect();
+            menu.reviewing_updates = false;
             let (sender, receiver) = std::sync::mpsc::channel();
             menu.mod_update_progress = Some(ProgressBar::with_recv_and_msg(
                 receiver,
@@ -323,8 +349,13 @@ impl Launcher {
         let command = Task::perform(
             async move {
                 if is_neoforge {
-                    loaders::neoforge::install(instance_selection, Some(f_sender), Some(j_sender))
-                        .await
+                    loaders::neoforge::install(
+                        None,
+                        instance_selection,
+                        Some(f_sender),
+                        Some(j_sender),
+                    )
+                    .await
                 } else {
                     loaders::forge::install(
                         None,
@@ -541,6 +572,38 @@ impl Launcher {
         };
     }
 
+    /// Handles a [`Request`] from another launcher
+    /// that was opened while this one is running.
+    pub fn handle_forwarded(&mut self, request: Request) -> Task<Message> {
+        let focus = iced::window::get_latest().and_then(|id| {
+            Task::batch([
+                iced::window::minimize(id, false),
+                iced::window::gain_focus(id),
+            ])
+        });
+        let task = match request {
+            Request::Focus => Task::none(),
+            Request::Launch(name) => {
+                if !LAUNCHER_DIR.join("instances").join(&name).is_dir() {
+                    self.set_error(format!("No instance named {name}"));
+                    return focus;
+                }
+                let open = if let State::Launch(_) = &self.state {
+                    Task::none()
+                } else {
+                    self.go_to_launch_screen::<String>(None)
+                };
+                let select = self.update(Message::LaunchInstanceSelected {
+                    name,
+                    is_server: false,
+                });
+                Task::batch([open, select, self.launch_start()])
+            }
+            Request::Open(link) => self.update(Message::UrlInstall(UrlInstallMessage::Open(link))),
+        };
+        Task::batch([focus, task])
+    }
+
     pub fn launch_start(&mut self) -> Task<Message> {
         let Some(selected_instance) = &self.selected_instance else {
             return Task::none();
@@ -563,6 +626,7 @@ impl Launcher {
         }
 
         self.is_launching_game = true;
+        let remember_task = self.remember_last_account();
         let account_data = if let Some(account) = &self.accounts_selected {
             if account == NEW_ACCOUNT_NAME || account == OFFLINE_ACCOUNT_NAME {
                 None
@@ -572,9 +636,21 @@ impl Launcher {
         } else {
             None
         };
+        if download::is_offline() {
+            // Can't refresh, so use the old token (fine for singleplayer),
+            // or play with just the username if there isn't any.
+            if let Some(account) = account_data.as_ref().filter(|n| n.access_token.is_none()) {
+                let username = account.nice_username.clone();
+                return Task::batch([remember_task, self.launch_game_as(username, None)]);
+            }
+            return Task::batch([remember_task, self.launch_game(account_data)]);
+        }
         if let Some(account) = &account_data {
-            if account.access_token.is_none() || account.needs_refresh {
-                return self.account_refresh(account);
+            if account.access_token.is_none()
+                || account.needs_refresh
+                || auth::lifecycle::should_refresh(account, std::time::SystemTime::now())
+            {
+                return Task::batch([remember_task, self.account_refresh(account)]);
             }
         }
 
@@ -583,7 +659,74 @@ impl Launcher {
 
         // Or, if the account is freshly added,
         // just directly launch the game.
-        self.launch_game(account_data)
+        Task::batch([remember_task, self.launch_game(account_data)])
+    }
+
+    /// The selected account, in the format of
+    /// [`InstanceConfigJson::last_account`].
+    fn last_account_key(&self) -> Option<String> {
+        match self.accounts_selected.as_deref()? {
+            NEW_ACCOUNT_NAME => None,
+            OFFLINE_ACCOUNT_NAME => Some(format!("Offline:{}", self.config.username)),
+            account => Some(account.to_owned()),
+        }
+    }
+
+    /// Saves the selected account to the selected
+    /// instance's config, to be selected next time.
+    fn remember_last_account(&mut self) -> Task<Message> {
+        let Some(instance @ InstanceSelection::Instance(_)) = self.selected_instance.clone() else {
+            return Task::none();
+        };
+        let last_account = self.last_account_key();
+
+        // The edit menu saves its (otherwise outdated) copy of the config
+        if let State::Launch(MenuLaunch {
+            edit_instance: Some(menu),
+            ..
+        }) = &mut self.state
+        {
+            menu.config.last_account.clone_from(&last_account);
+        }
+
+        Task::perform(
+            async move {
+                let mut config = InstanceConfigJson::read(&instance).await?;
+                if config.last_account == last_account {
+                    return Ok(());
+                }
+                config.last_account = last_account;
+                config.save(&instance).await
+            },
+            |n: Result<(), JsonFileError>| {
+                if let Err(err) = n {
+                    err!("Couldn't save last used account: {err}");
+                }
+                Message::Nothing
+            },
+        )
+    }
+
+    /// Selects the account that the selected instance
+    /// was last launched with, if it still exists.
+    pub fn select_last_account(&mut self) {
+        let State::Launch(MenuLaunch {
+            edit_instance: Some(menu),
+            ..
+        }) = &self.state
+        else {
+            return;
+        };
+        let Some(last_account) = menu.config.last_account.clone() else {
+            return;
+        };
+
+        if let Some(username) = last_account.strip_prefix("Offline:") {
+            self.config.username = username.to_owned();
+            self.accounts_selected = Some(OFFLINE_ACCOUNT_NAME.to_owned());
+        } else if self.accounts.contains_key(&last_account) {
+            self.accounts_selected = Some(last_account);
+        }
     }
 }
 
@@ -616,6 +759,19 @@ pub async fn get_locally_installed_mods(
     set
 }
 
+pub fn format_size(bytes: u64) -> String {
+    const KB: f64 = 1024.0;
+    let bytes = bytes as f64;
+
+    if bytes >= KB * KB * KB {
+        format!("{:.2} GB", bytes / (KB * KB * KB))
+    } else if bytes >= KB * KB {
+        format!("{:.1} MB", bytes / (KB * KB))
+    } else {
+        format!("{:.0} KB", bytes / KB)
+    }
+}
+
 pub fn format_memory(memory_bytes: usize) -> String {
     const MB_TO_GB: usize = 1024;
 
@@ -1,6 +1,8 @@
+use std::time::SystemTime;
+
 use auth::AccountData;
 use iced::Task;
-use ql_core::IntoStringError;
+use ql_core::{download, err, IntoStringError};
 use ql_instances::auth;
 
 use crate::{
@@ -17,10 +19,64 @@ impl Launcher {
             AccountMessage::Response1 { r: Err(err), .. }
             | AccountMessage::Response2(Err(err))
             | AccountMessage::Response3(Err(err))
-            | AccountMessage::ElyByLoginResponse(Err(err))
-            | AccountMessage::RefreshComplete(Err(err)) => {
+            | AccountMessage::ElyByLoginResponse(Err(err)) => {
                 self.set_error(err);
             }
+            AccountMessage::RefreshComplete(Err(err)) => {
+                if err.revoked {
+                    if let Some(account) = self.accounts_selected.clone() {
+                        self.account_mark_relogin(&account);
+                    }
+                }
+                self.set_error(err);
+            }
+            AccountMessage::BackgroundRefreshed(account, result) => {
+                // Failed accounts stay in `accounts_refreshing`, so they
+                // aren't retried every tick (just refreshed on launch)
+                match result {
+                    Ok(data) => {
+                        self.accounts_refreshing.remove(&account);
+                        self.accounts.insert(account, data);
+                    }
+                    Err(err) if err.revoked => {
+                        err!("Login expired for account {account}:\n{err}");
+                        self.account_mark_relogin(&account);
+                    }
+                    Err(err) => {
+                        err!("Couldn't refresh account {account} in the background:\n{err}");
+                        if let Some(data) = self.accounts.get_mut(&account) {
+                            data.expires_at = None;
+                            data.needs_refresh = true;
+                        }
+                    }
+                }
+            }
+            AccountMessage::Relogin(account) => {
+                let Some(data) = self.accounts.get(&account) else {
+                    return Task::none();
+                };
+                let is_from_welcome_screen = false;
+                let auth_server = data.auth_server.clone();
+                return match data.account_type {
+                    auth::AccountType::Microsoft => {
+                        self.update_account(AccountMessage::OpenMicrosoft {
+                            is_from_welcome_screen,
+                        })
+                    }
+                    auth::AccountType::ElyBy => self.update_account(AccountMessage::OpenElyBy {
+                        is_from_welcome_screen,
+                    }),
+                    auth::AccountType::AuthlibInjector => {
+                        let task = self.update_account(AccountMessage::OpenAuthlibInjector {
+                            is_from_welcome_screen,
+                        });
+                        if let State::LoginElyBy(menu) = &mut self.state {
+                            menu.custom_server = auth_server;
+                        }
+                        task
+                    }
+                };
+            }
             AccountMessage::Selected(account) => {
                 return self.account_selected(account);
             }
@@ -50,16 +106,21 @@ impl Launcher {
             }
             AccountMessage::LogoutConfirm => {
                 let username = self.accounts_selected.clone().unwrap();
-                let account_type = self
-                    .accounts
-                    .get(&username)
-                    .map_or(auth::AccountType::Microsoft, |n| n.account_type);
+                let account = self.accounts.get(&username);
+                let account_type = account.map_or(auth::AccountType::Microsoft, |n| n.account_type);
 
                 if let Err(err) = match account_type {
                     auth::AccountType::Microsoft => auth::ms::logout(&username),
                     auth::AccountType::ElyBy => {
                         auth::elyby::logout(username.strip_suffix(" (elyby)").unwrap_or(&username))
                     }
+                    auth::AccountType::AuthlibInjector => {
+                        let account = account.unwrap();
+                        auth::yggdrasil::logout(
+                            &account.username,
+                            account.auth_server.as_deref().unwrap_or_default(),
+                        )
+                    }
                 } {
                     self.set_error(err);
                 }
@@ -85,7 +146,9 @@ impl Launcher {
                 return self.go_to_launch_screen(Option::<String>::None);
             }
             AccountMessage::RefreshComplete(Ok(data)) => {
-                self.accounts.insert(data.get_username_modified(), data);
+                let username = data.get_username_modified();
+                self.accounts_refreshing.remove(&username);
+                self.accounts.insert(username, data);
 
                 let account_data = if let Some(account) = &self.accounts_selected {
                     if account == NEW_ACCOUNT_NAME || account == OFFLINE_ACCOUNT_NAME {
@@ -118,6 +181,7 @@ impl Launcher {
                 is_from_welcome_screen,
             } => {
                 self.state = State::LoginElyBy(MenuLoginElyBy {
+                    custom_server: None,
                     username: String::new(),
                     password: String::new(),
                     is_loading: false,
@@ -127,6 +191,41 @@ impl Launcher {
                 });
             }
 
+            AccountMessage::OfflineProfileSave => {
+                let username = self.config.username.clone();
+                let profiles = self.config.offline_profiles.get_or_insert_default();
+                if !username.is_empty() && !profiles.contains(&username) {
+                    profiles.push(username);
+                }
+            }
+            AccountMessage::OfflineProfileSelected(username) => {
+                self.config.username = username;
+            }
+            AccountMessage::OfflineProfileRemove => {
+                if let Some(profiles) = &mut self.config.offline_profiles {
+                    profiles.retain(|n| *n != self.config.username);
+                }
+            }
+
+            AccountMessage::OpenAuthlibInjector {
+                is_from_welcome_screen,
+            } => {
+                self.state = State::LoginElyBy(MenuLoginElyBy {
+                    custom_server: Some(String::new()),
+                    username: String::new(),
+                    password: String::new(),
+                    is_loading: false,
+                    otp: None,
+                    show_password: false,
+                    is_from_welcome_screen,
+                });
+            }
+            AccountMessage::AuthlibServerInput(server) => {
+                if let State::LoginElyBy(menu) = &mut self.state {
+                    menu.custom_server = Some(server);
+                }
+            }
+
             AccountMessage::ElyByUsernameInput(username) => {
                 if let State::LoginElyBy(menu) = &mut self.state {
                     menu.username = username;
@@ -150,6 +249,18 @@ impl Launcher {
 
             AccountMessage::ElyByLogin => {
                 if let State::LoginElyBy(menu) = &mut self.state {
+                    if let Some(server) = menu.custom_server.clone() {
+                        menu.is_loading = true;
+                        let (username, password) = (menu.username.clone(), menu.password.clone());
+                        return Task::perform(
+                            async move {
+                                let server = auth::yggdrasil::get_server_info(&server).await?;
+                                auth::yggdrasil::login_new(server.api_root, username, password)
+                                    .await
+                            },
+                            |n| Message::Account(AccountMessage::Response3(n.strerr())),
+                        );
+                    }
                     let mut password = menu.password.clone();
                     if let Some(otp) = &menu.otp {
                         password.push(':');
@@ -189,32 +300,72 @@ impl Launcher {
     }
 
     pub fn account_refresh(&mut self, account: &AccountData) -> Task<Message> {
-        match account.account_type {
-            auth::AccountType::Microsoft => {
-                let (sender, receiver) = std::sync::mpsc::channel();
-                self.state = State::AccountLoginProgress(ProgressBar::with_recv(receiver));
+        let sender = if let auth::AccountType::Microsoft = account.account_type {
+            let (sender, receiver) = std::sync::mpsc::channel();
+            self.state = State::AccountLoginProgress(ProgressBar::with_recv(receiver));
+            Some(sender)
+        } else {
+            None
+        };
 
-                Task::perform(
-                    auth::ms::login_refresh(
-                        account.username.clone(),
-                        account.refresh_token.clone(),
-                        Some(sender),
-                    ),
-                    |n| Message::Account(AccountMessage::RefreshComplete(n.strerr())),
-                )
-            }
-            auth::AccountType::ElyBy => Task::perform(
-                auth::elyby::login_refresh(account.username.clone(), account.refresh_token.clone()),
-                |n| Message::Account(AccountMessage::RefreshComplete(n.strerr())),
-            ),
+        let account = account.clone();
+        Task::perform(
+            async move { auth::lifecycle::refresh(&account, sender).await },
+            |n| Message::Account(AccountMessage::RefreshComplete(n)),
+        )
+    }
+
+    /// Refreshes access tokens that are about to expire,
+    /// so they're still valid by the time the game launches.
+    pub fn tick_refresh_accounts(&mut self) -> Task<Message> {
+        if download::is_offline() {
+            return Task::none();
+        }
+        let now = SystemTime::now();
+        let to_refresh: Vec<(String, AccountData)> = self
+            .accounts
+            .iter()
+            .filter(|(key, account)| {
+                !self.accounts_refreshing.contains(*key)
+                    && auth::lifecycle::should_refresh(account, now)
+            })
+            .map(|(key, account)| (key.clone(), account.clone()))
+            .collect();
+
+        Task::batch(to_refresh.into_iter().map(|(key, account)| {
+            self.accounts_refreshing.insert(key.clone());
+            Task::perform(
+                async move { auth::lifecycle::refresh(&account, None).await },
+                move |n| Message::Account(AccountMessage::BackgroundRefreshed(key.clone(), n)),
+            )
+        }))
+    }
+
+    /// Marks an account as needing to log in again,
+    /// after its login expired or was revoked.
+    fn account_mark_relogin(&mut self, account: &str) {
+        if let Some(config) = self
+            .config
+            .accounts
+            .as_mut()
+            .and_then(|n| n.get_mut(account))
+        {
+            config.needs_relogin = Some(true);
+        }
+        if let Some(data) = self.accounts.get_mut(account) {
+            data.access_token = None;
+            data.expires_at = None;
         }
     }
 
     fn account_response_3(&mut self, data: AccountData) -> Task<Message> {
-        self.accounts_dropdown.insert(0, data.username.clone());
+        let username = data.get_username_modified();
+        // Logging in again to an existing account
+        if !self.accounts_dropdown.contains(&username) {
+            self.accounts_dropdown.insert(0, username.clone());
+        }
 
         let accounts = self.config.accounts.get_or_insert_default();
-        let username = data.get_username_modified();
         accounts.insert(
             username.clone(),
             ConfigAccount {
@@ -222,10 +373,13 @@ impl Launcher {
                 skin: None,
                 account_type: Some(data.account_type.to_string()),
                 username_nice: Some(data.nice_username.clone()),
+                auth_server: data.auth_server.clone(),
+                needs_relogin: None,
             },
         );
 
         self.accounts_selected = Some(username.clone());
+        self.accounts_refreshing.remove(&username);
         self.accounts.insert(username.clone(), data);
 
         self.go_to_launch_screen::<String>(None)
@@ -253,6 +407,8 @@ impl Launcher {
             url: code.verification_uri,
             code: code.user_code,
             is_from_welcome_screen,
+            expires_at: std::time::Instant::now()
+                + std::time::Duration::from_secs(u64::try_from(code.expires_in).unwrap_or(0)),
             _cancel_handle: handle.abort_on_drop(),
         });
 
@@ -0,0 +1,218 @@
+use std::{sync::mpsc::Sender, sync::Arc};
+
+use iced::Task;
+use ql_core::{
+    err, json::InstanceConfigJson, DownloadProgress, GenericProgress, InstanceSelection,
+    IntoStringError, ListEntry, Loader, Progress,
+};
+use ql_mod_manager::loaders::{self, LoaderInstallResult};
+
+use crate::{
+    i18n::t,
+    state::{
+        ChangeVersionMessage, Launcher, MenuChangeVersion, Message, ProgressBar, State,
+        VersionChangeReport,
+    },
+};
+
+impl Launcher {
+    pub fn update_change_version(&mut self, msg: ChangeVersionMessage) -> Task<Message> {
+        match msg {
+            ChangeVersionMessage::Open => {
+                let Some(instance @ InstanceSelection::Instance(_)) = &self.selected_instance
+                else {
+                    return Task::none();
+                };
+                let current = ql_core::json::VersionDetails::load_s(&instance.get_instance_path())
+                    .map(|n| n.id)
+                    .unwrap_or_default();
+
+                self.state = State::ChangeVersion(MenuChangeVersion {
+                    current,
+                    combo_state: None,
+                    selected: None,
+                    progress: None,
+                });
+                if let Some(versions) = self.client_version_list_cache.clone() {
+                    return self
+                        .update_change_version(ChangeVersionMessage::VersionsLoaded(Ok(versions)));
+                }
+                return Task::perform(ql_instances::list_versions(), |n| {
+                    Message::ChangeVersion(ChangeVersionMessage::VersionsLoaded(n.strerr()))
+                });
+            }
+            ChangeVersionMessage::VersionsLoaded(Ok(versions)) => {
+                if let State::ChangeVersion(menu) = &mut self.state {
+                    menu.combo_state = Some(Box::new(iced::widget::combo_box::State::new(
+                        versions.clone(),
+                    )));
+                }
+                self.client_version_list_cache = Some(versions);
+            }
+            ChangeVersionMessage::VersionsLoaded(Err(err))
+            | ChangeVersionMessage::End(Err(err)) => self.set_error(err),
+            ChangeVersionMessage::Selected(version) => {
+                if let State::ChangeVersion(menu) = &mut self.state {
+                    menu.selected = Some(version);
+                }
+            }
+            ChangeVersionMessage::Start => {
+                let (State::ChangeVersion(menu), Some(InstanceSelection::Instance(instance))) =
+                    (&mut self.state, &self.selected_instance)
+                else {
+                    return Task::none();
+                };
+                let Some(version) = menu.selected.clone() else {
+                    return Task::none();
+                };
+                let (sender, receiver) = std::sync::mpsc::channel();
+                menu.progress = Some(ProgressBar::with_recv(receiver));
+
+                return Task::perform(change_version(instance.clone(), version, sender), |n| {
+                    Message::ChangeVersion(ChangeVersionMessage::End(n))
+                });
+            }
+            ChangeVersionMessage::End(Ok(report)) => {
+                return self.go_to_launch_screen(Some(report_message(&report)));
+            }
+        }
+        Task::none()
+    }
+}
+
+/// Changes the version of an instance, reinstalling its
+/// loader and swapping its mods for compatible ones.
+async fn change_version(
+    instance_name: String,
+    version: ListEntry,
+    sender: Sender<GenericProgress>,
+) -> Result<VersionChangeReport, String> {
+    let instance = InstanceSelection::Instance(instance_name.clone());
+    let config = InstanceConfigJson::read_from_dir(&instance.get_instance_path())
+        .await
+        .strerr()?;
+    let loader = Loader::try_from(config.mod_type.as_str()).ok();
+
+    // The loader's libraries are for the old version
+    match loader {
+        Some(Loader::Fabric | Loader::Quilt) => {
+            loaders::fabric::uninstall(instance.clone())
+                .await
+                .strerr()?;
+        }
+        Some(Loader::Forge | Loader::Neoforge) => {
+            loaders::forge::uninstall(instance.clone()).await.strerr()?;
+        }
+        Some(Loader::OptiFine) => {
+            loaders::optifine::uninstall(instance_name.clone())
+                .await
+                .strerr()?;
+        }
+        _ => {}
+    }
+
+    let (d_send, d_recv) = std::sync::mpsc::channel::<DownloadProgress>();
+    let g_send = sender.clone();
+    std::thread::spawn(move || {
+        for progress in d_recv {
+            _ = g_send.send(progress.into_generic());
+        }
+    });
+    if let Err(err) =
+        ql_instances::change_instance_version(instance_name, version.clone(), Some(d_send), true)
+            .await
+    {
+        // Don't leave the instance without its loader
+        let mut message = err.to_string();
+        if let Some(loader) = loader {
+            let reinstalled = reinstall_loader(&instance, loader, &sender)
+                .await
+                .inspect_err(|err| {
+                    err!("Couldn't reinstall {loader:?} after failing to change version: {err}");
+                });
+            if !matches!(reinstalled, Ok(false)) {
+                message.push('\n');
+                message.push_str(&t!(
+                    "change-version-reinstall-loader",
+                    loader = format!("{loader:?}")
+                ));
+            }
+        }
+        return Err(message);
+    }
+
+    let loader_not_reinstalled = match loader {
+        Some(loader) => reinstall_loader(&instance, loader, &sender)
+            .await?
+            .then_some(loader),
+        None => None,
+    };
+
+    let mods = ql_mod_manager::store::update_for_game_version(instance, Some(sender))
+        .await
+        .strerr();
+
+    Ok(VersionChangeReport {
+        version: version.name,
+        loader_not_reinstalled,
+        mods,
+    })
+}
+
+/// Installs `loader` again after its libraries were removed,
+/// returning whether it has to be installed manually instead
+/// (OptiFine needs the user to pick the installer).
+async fn reinstall_loader(
+    instance: &InstanceSelection,
+    loader: Loader,
+    sender: &Sender<GenericProgress>,
+) -> Result<bool, String> {
+    if !matches!(
+        loader,
+        Loader::Fabric | Loader::Quilt | Loader::Forge | Loader::Neoforge
+    ) {
+        return Ok(true);
+    }
+    let result = loaders::install_specified_loader(
+        instance.clone(),
+        loader,
+        Some(Arc::new(sender.clone())),
+        None,
+    )
+    .await?;
+    Ok(match result {
+        LoaderInstallResult::Ok => false,
+        LoaderInstallResult::NeedsOptifine | LoaderInstallResult::Unsupported => true,
+    })
+}
+
+fn report_message(report: &VersionChangeReport) -> String {
+    let mut lines = vec![t!("change-version-done", version = report.version.as_str())];
+    if let Some(loader) = report.loader_not_reinstalled {
+        lines.push(t!(
+            "change-version-reinstall-loader",
+            loader = format!("{loader:?}")
+        ));
+    }
+    match &report.mods {
+        Ok(mods) => {
+            if !mods.updated.is_empty() {
+                lines.push(t!(
+                    "change-version-mods-updated",
+                    mods = mods.updated.join(", ")
+                ));
+            }
+            if !mods.disabled.is_empty() {
+                lines.push(t!(
+                    "change-version-mods-disabled",
+                    version = report.version.as_str(),
+                    mods = mods.disabled.join(", ")
+                ));
+            }
+        }
+        Err(err) => {
+            lines.push(t!("change-version-mods-error", error = err.as_str()));
+        }
+    }
+    lines.join("\n")
+}
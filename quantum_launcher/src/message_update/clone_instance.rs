@@ -0,0 +1,84 @@
+use iced::Task;
+use ql_core::{InstanceSelection, IntoStringError};
+use ql_instances::CloneOptions;
+
+use super::edit_instance::clean_instance_name;
+use crate::{
+    i18n::t,
+    state::{CloneInstanceMessage, Launcher, MenuCloneInstance, Message, ProgressBar, State},
+};
+
+impl Launcher {
+    pub fn update_clone_instance(&mut self, msg: CloneInstanceMessage) -> Task<Message> {
+        match msg {
+            CloneInstanceMessage::Open => {
+                let Some(instance) = &self.selected_instance else {
+                    return Task::none();
+                };
+                self.state = State::CloneInstance(MenuCloneInstance {
+                    name: format!("{} (copy)", instance.get_name()),
+                    instance: instance.clone(),
+                    options: CloneOptions::default(),
+                    progress: None,
+                });
+            }
+            CloneInstanceMessage::NameInput(name) => {
+                if let State::CloneInstance(menu) = &mut self.state {
+                    menu.name = name;
+                }
+            }
+            CloneInstanceMessage::WorldsToggle(t) => {
+                if let State::CloneInstance(menu) = &mut self.state {
+                    menu.options.worlds = t;
+                }
+            }
+            CloneInstanceMessage::LogsToggle(t) => {
+                if let State::CloneInstance(menu) = &mut self.state {
+                    menu.options.logs = t;
+                }
+            }
+            CloneInstanceMessage::ScreenshotsToggle(t) => {
+                if let State::CloneInstance(menu) = &mut self.state {
+                    menu.options.screenshots = t;
+                }
+            }
+            CloneInstanceMessage::Start => {
+                let State::CloneInstance(menu) = &mut self.state else {
+                    return Task::none();
+                };
+                let name = clean_instance_name(&menu.name);
+                if name.is_empty() || menu.progress.is_some() {
+                    return Task::none();
+                }
+                let (sender, receiver) = std::sync::mpsc::channel();
+                menu.progress = Some(ProgressBar::with_recv(receiver));
+
+                let instance = menu.instance.clone();
+                let options = menu.options;
+                return Task::perform(
+                    async move {
+                        ql_instances::clone_instance(&instance, &name, options, Some(&sender))
+                            .await
+                            .strerr()
+                    },
+                    |n| Message::CloneInstance(CloneInstanceMessage::End(n)),
+                );
+            }
+            CloneInstanceMessage::End(Ok(instance)) => {
+                let message = t!("clone-done", name = instance.get_name());
+                return match instance {
+                    InstanceSelection::Server(name) => self.update(Message::ServerManageOpen {
+                        selected_server: Some(name),
+                        message: Some(message),
+                    }),
+                    InstanceSelection::Instance(_) => {
+                        self.selected_instance = Some(instance);
+                        self.go_to_launch_screen(Some(message))
+                    }
+                };
+            }
+            CloneInstanceMessage::End(Err(err)) => self.set_error(err),
+        }
+        Task::none()
+    }
+}
@@ -1,8 +1,12 @@
+use std::fmt::Write;
+
 use iced::Task;
 use ql_core::{pt, DownloadProgress, InstanceSelection, IntoStringError, ListEntry};
+use ql_instances::VersionFilter;
 
-use crate::state::{
-    CreateInstanceMessage, Launcher, MenuCreateInstance, Message, ProgressBar, State,
+use crate::{
+    i18n::t,
+    state::{CreateInstanceMessage, Launcher, MenuCreateInstance, Message, ProgressBar, State},
 };
 
 impl Launcher {
@@ -32,12 +36,25 @@ impl Launcher {
                     *download_assets = t;
                 }
             }
+            CreateInstanceMessage::FilterTypeToggled(kind, t) => {
+                self.update_version_filter(|filter| {
+                    filter.kinds.retain(|n| *n != kind);
+                    if t {
+                        filter.kinds.push(kind);
+                    }
+                });
+            }
+            CreateInstanceMessage::FilterSinceInput(since) => {
+                self.update_version_filter(|filter| {
+                    filter.since = (!since.is_empty()).then_some(since);
+                });
+            }
             CreateInstanceMessage::Cancel => {
                 return self.go_to_launch_screen(Option::<String>::None)
             }
             CreateInstanceMessage::Import => {
                 if let Some(file) = rfd::FileDialog::new()
-                    .set_title("Select an instance...")
+                    .set_title(t!("dialog-select-instance"))
                     .pick_file()
                 {
                     let (send, recv) = std::sync::mpsc::channel();
@@ -62,14 +79,17 @@ impl Launcher {
                 }
             }
             CreateInstanceMessage::ImportResult(res) => match res {
-                Ok(instance) => {
-                    let is_valid_modpack = instance.is_some();
-                    self.selected_instance = instance;
-                    if is_valid_modpack {
-                        return self.go_to_launch_screen(None::<String>);
-                    }
+                Ok(Some(imported)) => {
+                    self.selected_instance = Some(imported.instance);
+                    return self.go_to_launch_screen(
+                        (!imported.skipped.is_empty())
+                            .then(|| t!("import-skipped", skipped = imported.skipped.join(", "))),
+                    );
+                }
+                Ok(None) => {
                     self.set_error(
-                        r#"the file you imported isn't a valid QuantumLauncher/MultiMC instance.
+                        r#"the file you imported isn't a valid instance
+(QuantumLauncher, MultiMC/Prism, ATLauncher, GDLauncher, Curseforge app or official launcher).
 
 If you meant to import a Modrinth/Curseforge/Preset pack,
 create a instance with the matching version,
@@ -78,6 +98,71 @@ then go to "Mods->Add File""#,
                 }
                 Err(err) => self.set_error(err),
             },
+            CreateInstanceMessage::ImportFolder => {
+                if let Some(dir) = rfd::FileDialog::new()
+                    .set_title(t!("dialog-select-instances-folder"))
+                    .pick_folder()
+                {
+                    let (send, recv) = std::sync::mpsc::channel();
+                    let progress = ProgressBar::with_recv(recv);
+                    self.state = State::Create(MenuCreateInstance::ImportingInstance(progress));
+
+                    return Task::perform(
+                        ql_packager::import_instances_bulk(dir, true, Some(send)),
+                        |n| {
+                            Message::CreateInstance(CreateInstanceMessage::ImportFolderResult(
+                                n.strerr(),
+                            ))
+                        },
+                    );
+                }
+            }
+            CreateInstanceMessage::ImportFolderResult(res) => match res {
+                Ok(result) => {
+                    if let Some(imported) = result.imported.first() {
+                        self.selected_instance = Some(imported.instance.clone());
+                    }
+                    let skipped: Vec<String> = result
+                        .imported
+                        .iter()
+                        .filter(|n| !n.skipped.is_empty())
+                        .map(|n| format!("{}: {}", n.instance.get_name(), n.skipped.join(", ")))
+                        .collect();
+                    if result.failed.is_empty() && !result.imported.is_empty() {
+                        if !skipped.is_empty() {
+                            return self.go_to_launch_screen(Some(t!(
+                                "import-bulk-skipped",
+                                count = result.imported.len(),
+                                skipped = skipped.join("\n")
+                            )));
+                        }
+                        return self.go_to_launch_screen(Some(t!(
+                            "import-bulk",
+                            count = result.imported.len()
+                        )));
+                    }
+                    let mut message = format!(
+                        "imported {} instances, {} failed",
+                        result.imported.len(),
+                        result.failed.len()
+                    );
+                    for (name, err) in &result.failed {
+                        _ = write!(message, "\n\n{name}:\n{err}");
+                    }
+                    if !skipped.is_empty() {
+                        _ = write!(
+                            message,
+                            "\n\nNot supported yet (left out):\n{}",
+                            skipped.join("\n")
+                        );
+                    }
+                    if result.imported.is_empty() && result.failed.is_empty() {
+                        message.push_str("\n\nNo instances were found in that folder.");
+                    }
+                    self.set_error(message);
+                }
+                Err(err) => self.set_error(err),
+            },
         }
         Task::none()
     }
@@ -88,30 +173,18 @@ then go to "Mods->Add File""#,
     ) {
         match result {
             Ok(versions) => {
-                self.client_version_list_cache = Some(versions.clone());
-                let combo_state = iced::widget::combo_box::State::new(versions.clone());
                 if let State::Create(MenuCreateInstance::LoadingList { .. }) = &self.state {
-                    self.state = State::Create(MenuCreateInstance::Choosing {
-                        instance_name: String::new(),
-                        selected_version: None,
-                        download_assets: true,
-                        combo_state: Box::new(combo_state),
-                    });
+                    self.state = State::Create(MenuCreateInstance::choosing(&versions));
                 }
+                self.client_version_list_cache = Some(versions);
             }
             Err(n) => self.set_error(n),
         }
     }
 
     fn go_to_create_screen(&mut self) -> Task<Message> {
-        if let Some(versions) = self.client_version_list_cache.clone() {
-            let combo_state = iced::widget::combo_box::State::new(versions.clone());
-            self.state = State::Create(MenuCreateInstance::Choosing {
-                instance_name: String::new(),
-                selected_version: None,
-                download_assets: true,
-                combo_state: Box::new(combo_state),
-            });
+        if let Some(versions) = &self.client_version_list_cache {
+            self.state = State::Create(MenuCreateInstance::choosing(versions));
             Task::none()
         } else {
             let (task, handle) = Task::perform(ql_instances::list_versions(), |n| {
@@ -127,6 +200,22 @@ then go to "Mods->Add File""#,
         }
     }
 
+    fn update_version_filter(&mut self, f: impl FnOnce(&mut VersionFilter)) {
+        let (
+            State::Create(MenuCreateInstance::Choosing {
+                filter,
+                combo_state,
+                ..
+            }),
+            Some(versions),
+        ) = (&mut self.state, &self.client_version_list_cache)
+        else {
+            return;
+        };
+        f(filter);
+        **combo_state = iced::widget::combo_box::State::new(filter.apply(versions));
+    }
+
     fn select_created_instance_version(&mut self, entry: ListEntry) {
         if let State::Create(MenuCreateInstance::Choosing {
             selected_version, ..
@@ -177,3 +266,17 @@ then go to "Mods->Add File""#,
         Task::none()
     }
 }
+
+impl MenuCreateInstance {
+    fn choosing(versions: &[ListEntry]) -> Self {
+        let filter = VersionFilter::default();
+        let combo_state = iced::widget::combo_box::State::new(filter.apply(versions));
+        Self::Choosing {
+            instance_name: String::new(),
+            selected_version: None,
+            download_assets: true,
+            filter,
+            combo_state: Box::new(combo_state),
+        }
+    }
+}
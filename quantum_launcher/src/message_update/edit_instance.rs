@@ -2,6 +2,7 @@ use iced::Task;
 use ql_core::{err, IntoIoError, IntoStringError, LAUNCHER_DIR};
 
 use crate::{
+    i18n::t,
     message_handler::format_memory,
     state::{get_entries, EditInstanceMessage, Launcher, MenuLaunch, Message, State},
 };
@@ -91,6 +92,61 @@ impl Launcher {
             }
             EditInstanceMessage::RenameApply => return self.rename_instance(),
             EditInstanceMessage::ConfigSaved(res) => res?,
+            EditInstanceMessage::GroupInput(group) => {
+                if let State::Launch(MenuLaunch {
+                    edit_instance: Some(menu),
+                    ..
+                }) = &mut self.state
+                {
+                    menu.config.group = (!group.is_empty()).then_some(group);
+                }
+                self.refresh_selected_meta();
+            }
+            EditInstanceMessage::FavoriteToggle(t) => {
+                if let State::Launch(MenuLaunch {
+                    edit_instance: Some(menu),
+                    ..
+                }) = &mut self.state
+                {
+                    menu.config.is_favorite = Some(t);
+                }
+                self.refresh_selected_meta();
+            }
+            EditInstanceMessage::ColorSelected(color) => {
+                if let State::Launch(MenuLaunch {
+                    edit_instance: Some(menu),
+                    ..
+                }) = &mut self.state
+                {
+                    menu.config.color = color;
+                }
+                self.refresh_selected_meta();
+            }
+            EditInstanceMessage::NotesEdit(action) => {
+                if let State::Launch(MenuLaunch {
+                    edit_instance: Some(menu),
+                    ..
+                }) = &mut self.state
+                {
+                    menu.notes.perform(action);
+                    let notes = menu.notes.text();
+                    menu.config.notes = (!notes.trim().is_empty()).then_some(notes);
+                }
+            }
+            EditInstanceMessage::IconSelect => return self.select_instance_icon(),
+            EditInstanceMessage::IconRemove => {
+                if let Some(instance) = &self.selected_instance {
+                    let path = instance.get_icon_path();
+                    if path.exists() {
+                        std::fs::remove_file(&path).path(&path).strerr()?;
+                    }
+                }
+                self.refresh_selected_meta();
+            }
+            EditInstanceMessage::IconChanged(res) => {
+                res?;
+                self.refresh_selected_meta();
+            }
         }
         Ok(Task::none())
     }
@@ -103,18 +159,7 @@ impl Launcher {
         else {
             return Ok(Task::none());
         };
-        let mut disallowed = vec![
-            '/', '\\', ':', '*', '?', '"', '<', '>', '|', '\'', '\0', '\u{7F}',
-        ];
-
-        disallowed.extend('\u{1}'..='\u{1F}');
-
-        // Remove disallowed characters
-
-        let mut instance_name = menu.instance_name.clone();
-        instance_name.retain(|c| !disallowed.contains(&c));
-        let instance_name = instance_name.trim();
-
+        let instance_name = clean_instance_name(&menu.instance_name);
         if instance_name.is_empty() {
             err!("New name is empty or invalid");
             return Ok(Task::none());
@@ -157,6 +202,25 @@ impl Launcher {
         }
     }
 
+    fn select_instance_icon(&mut self) -> Result<Task<Message>, String> {
+        let Some(instance) = self.selected_instance.clone() else {
+            return Ok(Task::none());
+        };
+        let Some(file) = rfd::FileDialog::new()
+            .set_title(t!("dialog-select-icon"))
+            .add_filter("Image", &["png", "jpg", "jpeg", "webp", "gif", "ico"])
+            .pick_file()
+        else {
+            return Ok(Task::none());
+        };
+        let image = std::fs::read(&file).path(&file).strerr()?;
+
+        Ok(Task::perform(
+            async move { ql_mod_manager::store::set_instance_icon(&instance, &image).await },
+            |n| Message::EditInstance(EditInstanceMessage::IconChanged(n)),
+        ))
+    }
+
     fn e_java_arg_add(&mut self) {
         if let State::Launch(MenuLaunch {
             edit_instance: Some(menu),
@@ -311,3 +375,17 @@ fn add_to_arguments_list(msg: String, args: &mut Vec<String>, mut idx: usize) {
         *arg = msg;
     }
 }
+
+/// Removes characters that aren't allowed in
+/// file names (on any OS) from an instance name.
+pub fn clean_instance_name(name: &str) -> String {
+    let mut disallowed = vec![
+        '/', '\\', ':', '*', '?', '"', '<', '>', '|', '\'', '\0', '\u{7F}',
+    ];
+
+    disallowed.extend('\u{1}'..='\u{1F}');
+
+    let mut instance_name = name.to_owned();
+    instance_name.retain(|c| !disallowed.contains(&c));
+    instance_name.trim().to_owned()
+}
@@ -0,0 +1,117 @@
+use iced::{widget::text_input, Task};
+
+use crate::state::{KeyOverlay, KeybindMessage, Launcher, MenuInstanceSwitcher, Message, State};
+
+/// How many instances the switcher shows at once
+const SWITCHER_MAX_RESULTS: usize = 8;
+
+impl Launcher {
+    pub fn update_keybind(&mut self, msg: KeybindMessage) -> Task<Message> {
+        match msg {
+            KeybindMessage::SwitcherOpen => {
+                if let State::Launch(_) = &self.state {
+                    self.key_overlay = Some(KeyOverlay::Switcher(MenuInstanceSwitcher {
+                        query: String::new(),
+                        selected: 0,
+                    }));
+                    return text_input::focus(text_input::Id::new("KeyOverlay:switcher"));
+                }
+            }
+            KeybindMessage::SwitcherInput(query) => {
+                if let Some(KeyOverlay::Switcher(menu)) = &mut self.key_overlay {
+                    menu.query = query;
+                    menu.selected = 0;
+                }
+            }
+            KeybindMessage::SwitcherSubmit => {
+                if let Some(KeyOverlay::Switcher(menu)) = &self.key_overlay {
+                    if let Some(name) = self.switcher_results(&menu.query).get(menu.selected) {
+                        let name = (*name).clone();
+                        return self.update_keybind(KeybindMessage::SwitcherPick(name));
+                    }
+                }
+            }
+            KeybindMessage::SwitcherPick(name) => {
+                self.key_overlay = None;
+                let is_server =
+                    matches!(&self.state, State::Launch(menu) if menu.is_viewing_server);
+                return self.update(Message::LaunchInstanceSelected { name, is_server });
+            }
+            KeybindMessage::CheatsheetOpen => self.key_overlay = Some(KeyOverlay::Cheatsheet),
+            KeybindMessage::OverlayClose => self.key_overlay = None,
+        }
+        Task::none()
+    }
+
+    /// The instances (of the list being viewed)
+    /// matching `query`, best matches first.
+    pub fn switcher_results(&self, query: &str) -> Vec<&String> {
+        let is_server = matches!(&self.state, State::Launch(menu) if menu.is_viewing_server);
+        let list = if is_server {
+            &self.server_list
+        } else {
+            &self.client_list
+        };
+        let Some(list) = list else {
+            return Vec::new();
+        };
+
+        let mut results: Vec<(usize, &String)> = list
+            .iter()
+            .filter_map(|name| Some((fuzzy_score(query, name)?, name)))
+            .collect();
+        // Stable sort, so equally good matches
+        // stay in the sidebar's order
+        results.sort_by_key(|(score, _)| std::cmp::Reverse(*score));
+        results
+            .into_iter()
+            .take(SWITCHER_MAX_RESULTS)
+            .map(|(_, name)| name)
+            .collect()
+    }
+
+    /// Moves the highlighted entry of the instance switcher.
+    pub fn switcher_move(&mut self, down: bool) {
+        let Some(KeyOverlay::Switcher(menu)) = &self.key_overlay else {
+            return;
+        };
+        let len = self.switcher_results(&menu.query).len();
+        let Some(KeyOverlay::Switcher(menu)) = &mut self.key_overlay else {
+            return;
+        };
+        if down {
+            if menu.selected + 1 < len {
+                menu.selected += 1;
+            }
+        } else {
+            menu.selected = menu.selected.saturating_sub(1);
+        }
+    }
+}
+
+/// How well `name` matches `query`, with every character
+/// of `query` appearing in order in `name` (ignoring case).
+///
+/// Higher is better, `None` means it doesn't match.
+/// Consecutive characters and the starts
+/// of words count for more.
+fn fuzzy_score(query: &str, name: &str) -> Option<usize> {
+    let name: Vec<char> = name.to_lowercase().chars().collect();
+    let mut score = 0;
+    let mut pos = 0;
+    let mut last_match: Option<usize> = None;
+
+    for c in query.to_lowercase().chars().filter(|c| !c.is_whitespace()) {
+        let i = pos + name[pos..].iter().position(|n| *n == c)?;
+        score += 1;
+        if last_match.is_some_and(|n| n + 1 == i) {
+            score += 4;
+        }
+        if i == 0 || !name[i - 1].is_alphanumeric() {
+            score += 2;
+        }
+        last_match = Some(i);
+        pos = i + 1;
+    }
+    Some(score)
+}
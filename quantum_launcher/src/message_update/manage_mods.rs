@@ -7,9 +7,12 @@ use ql_core::{
 };
 use ql_mod_manager::store::ModIndex;
 
-use crate::state::{
-    Launcher, ManageJarModsMessage, ManageModsMessage, MenuCurseforgeManualDownload,
-    MenuEditJarMods, MenuEditMods, Message, ProgressBar, SelectedState, State,
+use crate::{
+    i18n::t,
+    state::{
+        Launcher, ManageJarModsMessage, ManageModsMessage, MenuCurseforgeManualDownload,
+        MenuEditJarMods, MenuEditMods, Message, ProgressBar, SelectedState, State,
+    },
 };
 
 impl Launcher {
@@ -183,6 +186,11 @@ impl Launcher {
                     self.update_mod_index();
                 }
             }
+            ManageModsMessage::UpdateReview(open) => {
+                if let State::EditMods(menu) = &mut self.state {
+                    menu.reviewing_updates = open;
+                }
+            }
             ManageModsMessage::UpdateMods => return self.update_mods(),
             ManageModsMessage::UpdateModsFinished(result) => {
                 if let Err(err) = result {
@@ -211,8 +219,7 @@ impl Launcher {
 
                 if let State::EditMods(menu) = &mut self.state {
                     menu.update_check_handle = None;
-                    menu.available_updates =
-                        updates.into_iter().map(|(a, b)| (a, b, true)).collect();
+                    menu.available_updates = updates.into_iter().map(|n| (n, true)).collect();
                 }
             }
             ManageModsMessage::UpdateCheckToggle(idx, t) => {
@@ -220,11 +227,67 @@ impl Launcher {
                     available_updates, ..
                 }) = &mut self.state
                 {
-                    if let Some((_, _, b)) = available_updates.get_mut(idx) {
+                    if let Some((_, b)) = available_updates.get_mut(idx) {
                         *b = t;
                     }
                 }
             }
+            ManageModsMessage::SnapshotsOpen => {
+                if let State::EditMods(menu) = &mut self.state {
+                    menu.snapshots = Some(Vec::new());
+                    let instance = self.selected_instance.clone().unwrap();
+                    return Task::perform(
+                        async move { ql_mod_manager::store::list_snapshots(&instance).await },
+                        |n| Message::ManageMods(ManageModsMessage::SnapshotsLoaded(n.strerr())),
+                    );
+                }
+            }
+            ManageModsMessage::SnapshotsClose => {
+                if let State::EditMods(menu) = &mut self.state {
+                    menu.snapshots = None;
+                }
+            }
+            ManageModsMessage::SnapshotsLoaded(res) => match res {
+                Ok(snapshots) => {
+                    if let State::EditMods(MenuEditMods {
+                        snapshots: Some(s), ..
+                    }) = &mut self.state
+                    {
+                        *s = snapshots;
+                    }
+                }
+                Err(err) => self.set_error(err),
+            },
+            ManageModsMessage::SnapshotRestore(id) => {
+                let instance = self.selected_instance.clone().unwrap();
+                self.state = State::GenericMessage(t!("mods-snapshot-restoring"));
+                return Task::perform(
+                    async move { ql_mod_manager::store::restore_snapshot(&instance, &id).await },
+                    |n| Message::ManageMods(ManageModsMessage::SnapshotRestored(n.strerr())),
+                );
+            }
+            ManageModsMessage::UndoLastChange => {
+                let instance = self.selected_instance.clone().unwrap();
+                self.state = State::GenericMessage(t!("mods-undoing"));
+                return Task::perform(
+                    async move {
+                        ql_mod_manager::store::undo_last_change(&instance)
+                            .await
+                            .map(|_| ())
+                    },
+                    |n| Message::ManageMods(ManageModsMessage::SnapshotRestored(n.strerr())),
+                );
+            }
+            ManageModsMessage::SnapshotRestored(res) => {
+                if let Err(err) = res {
+                    self.set_error(err);
+                } else {
+                    match self.go_to_edit_mods_menu_without_update_check() {
+                        Ok(command) => return command,
+                        Err(err) => self.set_error(err),
+                    }
+                }
+            }
             ManageModsMessage::SelectAll => {
                 if let State::EditMods(menu) = &mut self.state {
                     match menu.selected_state {
@@ -359,17 +422,13 @@ impl Launcher {
             for i in selected {
                 if i < menu.jarmods.mods.len() {
                     match msg {
-                        ManageJarModsMessage::MoveUp => {
-                            if i > 0 {
-                                let removed = menu.jarmods.mods.remove(i);
-                                menu.jarmods.mods.insert(i - 1, removed);
-                            }
+                        ManageJarModsMessage::MoveUp if i > 0 => {
+                            let removed = menu.jarmods.mods.remove(i);
+                            menu.jarmods.mods.insert(i - 1, removed);
                         }
-                        ManageJarModsMessage::MoveDown => {
-                            if i + 1 < menu.jarmods.mods.len() {
-                                let removed = menu.jarmods.mods.remove(i);
-                                menu.jarmods.mods.insert(i + 1, removed);
-                            }
+                        ManageJarModsMessage::MoveDown if i + 1 < menu.jarmods.mods.len() => {
+                            let removed = menu.jarmods.mods.remove(i);
+                            menu.jarmods.mods.insert(i + 1, removed);
                         }
                         _ => {}
                     }
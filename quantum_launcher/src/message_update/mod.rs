@@ -1,22 +1,33 @@
-use std::str::FromStr;
-
 use iced::{
     widget::{image::Handle, scrollable::AbsoluteOffset},
     Task,
 };
-use ql_core::{err, info, InstanceSelection, IntoStringError, ModId, OptifineUniqueVersion};
+use ql_core::{
+    download, err, err_no_log, info,
+    mirror::{self, MirrorSource},
+    network::{NetworkConfig, ProxyConfig},
+    InstanceSelection, IntoStringError, ModId, OptifineUniqueVersion,
+};
 use ql_mod_manager::{
     loaders,
     store::{get_description, QueryType},
 };
 
 mod accounts;
+mod change_version;
+mod clone_instance;
 mod create_instance;
 mod edit_instance;
+mod keybinds;
 mod manage_mods;
 mod presets;
+mod sidebar;
+mod skins;
+mod url_install;
 
 use crate::{
+    i18n::{self, t},
+    message_handler::format_size,
     state::{
         self, InstallFabricMessage, InstallModsMessage, InstallOptifineMessage, Launcher,
         LauncherSettingsMessage, MenuCurseforgeManualDownload, MenuInstallFabric,
@@ -364,7 +375,7 @@ impl Launcher {
             InstallOptifineMessage::SelectInstallerStart => {
                 if let Some(path) = rfd::FileDialog::new()
                     .add_filter("jar/zip", &["jar", "zip"])
-                    .set_title("Select OptiFine Installer")
+                    .set_title(t!("dialog-select-optifine"))
                     .pick_file()
                 {
                     let (p_sender, p_recv) = std::sync::mpsc::channel();
@@ -414,6 +425,22 @@ impl Launcher {
         Task::none()
     }
 
+    /// Picks up changes to the custom theme files,
+    /// re-applying the current theme if it's a custom one.
+    pub fn reload_custom_themes(&mut self) {
+        if !self.custom_themes.reload_if_changed() {
+            return;
+        }
+        if let Some(style) = self
+            .config
+            .style
+            .as_deref()
+            .filter(|n| !LauncherThemeColor::is_builtin_name(n))
+        {
+            self.theme.color = LauncherThemeColor::from_name(style, &self.custom_themes);
+        }
+    }
+
     pub fn update_launcher_settings(&mut self, msg: LauncherSettingsMessage) -> Task<Message> {
         match msg {
             LauncherSettingsMessage::ThemePicked(theme) => {
@@ -432,7 +459,11 @@ impl Launcher {
             LauncherSettingsMessage::StylePicked(style) => {
                 info!("Setting color scheme {style}");
                 self.config.style = Some(style.clone());
-                self.theme.color = LauncherThemeColor::from_str(&style).unwrap_or_default();
+                self.theme.color = LauncherThemeColor::from_name(&style, &self.custom_themes);
+            }
+            LauncherSettingsMessage::LanguagePicked(language) => {
+                i18n::set_language(language.as_deref());
+                self.config.language = language;
             }
             LauncherSettingsMessage::UiScale(scale) => {
                 if let State::LauncherSettings(menu) = &mut self.state {
@@ -444,6 +475,88 @@ impl Launcher {
                     self.config.ui_scale = Some(menu.temp_scale);
                 }
             }
+            LauncherSettingsMessage::DownloadConcurrency(n) => {
+                self.config.download_concurrency = Some(n as usize);
+                self.config.apply_download_settings();
+            }
+            LauncherSettingsMessage::DownloadSpeedLimit(limit) => {
+                let limit = limit.trim();
+                if limit.is_empty() {
+                    self.config.download_speed_limit = None;
+                } else if let Ok(limit) = limit.parse() {
+                    self.config.download_speed_limit = Some(limit);
+                } else {
+                    return Task::none();
+                }
+                self.config.apply_download_settings();
+            }
+            LauncherSettingsMessage::MirrorEdit(source, mirrors) => {
+                // Not trimmed here, so typing a trailing comma works
+                let mirrors = mirrors.split(',').map(str::to_owned).collect();
+                let config = self.config.mirrors.get_or_insert_with(Default::default);
+                config.mirrors.insert(source, mirrors);
+                self.config.apply_download_settings();
+            }
+            LauncherSettingsMessage::MirrorFallbackToggle(t) => {
+                let config = self.config.mirrors.get_or_insert_with(Default::default);
+                config.fallback_to_official = t;
+                self.config.apply_download_settings();
+            }
+            LauncherSettingsMessage::MirrorUseBmclapi => {
+                let config = self.config.mirrors.get_or_insert_with(Default::default);
+                for source in [
+                    MirrorSource::Mojang,
+                    MirrorSource::Fabric,
+                    MirrorSource::Quilt,
+                    MirrorSource::Forge,
+                    MirrorSource::NeoForge,
+                ] {
+                    config
+                        .mirrors
+                        .insert(source, vec![mirror::BMCLAPI_KEYWORD.to_owned()]);
+                }
+                self.config.apply_download_settings();
+            }
+            LauncherSettingsMessage::ToggleOfflineMode(t) => {
+                self.config.offline_mode = Some(t);
+                download::set_offline(t);
+            }
+            LauncherSettingsMessage::NetworkProxyUrl(url) => {
+                self.network_proxy_config().url = url;
+            }
+            LauncherSettingsMessage::NetworkProxyUsername(username) => {
+                self.network_proxy_config().username = Some(username);
+            }
+            LauncherSettingsMessage::NetworkProxyPassword(password) => {
+                self.network_proxy_config().password = Some(password);
+            }
+            LauncherSettingsMessage::NetworkNoProxy(hosts) => {
+                self.network_proxy_config().no_proxy =
+                    hosts.split(',').map(str::to_owned).collect();
+            }
+            LauncherSettingsMessage::NetworkAddCertificate => {
+                if let Some(file) = rfd::FileDialog::new()
+                    .add_filter("PEM Certificate", &["pem", "crt", "cer"])
+                    .set_title(t!("dialog-select-certificate"))
+                    .pick_file()
+                {
+                    self.network_config().ca_certificates.push(file);
+                }
+            }
+            LauncherSettingsMessage::NetworkClearCertificates => {
+                self.network_config().ca_certificates.clear();
+            }
+            LauncherSettingsMessage::NetworkTimeout(timeout) => {
+                let timeout = timeout.trim();
+                if timeout.is_empty() {
+                    self.network_config().timeout_secs = None;
+                } else if let Ok(timeout) = timeout.parse() {
+                    self.network_config().timeout_secs = Some(timeout);
+                }
+            }
+            LauncherSettingsMessage::NetworkUserAgent(user_agent) => {
+                self.network_config().user_agent = Some(user_agent);
+            }
             LauncherSettingsMessage::ClearJavaInstalls => {
                 self.state = State::ConfirmAction {
                     msg1: "delete auto-installed Java files".to_owned(),
@@ -459,16 +572,94 @@ impl Launcher {
             LauncherSettingsMessage::ClearJavaInstallsConfirm => {
                 return Task::perform(ql_instances::delete_java_installs(), |()| Message::Nothing);
             }
+            LauncherSettingsMessage::StorageScan => {
+                if let State::LauncherSettings(menu) = &mut self.state {
+                    menu.storage_status = Some(t!("settings-storage-scanning"));
+                }
+                return Task::perform(ql_core::disk_usage::get_disk_usage(), |n| {
+                    Message::LauncherSettings(LauncherSettingsMessage::StorageScanEnd(n.strerr()))
+                });
+            }
+            LauncherSettingsMessage::StorageScanEnd(result) => match result {
+                Ok(usage) => {
+                    if let State::LauncherSettings(menu) = &mut self.state {
+                        if menu.storage_status == Some(t!("settings-storage-scanning")) {
+                            menu.storage_status = None;
+                        }
+                        menu.disk_usage = Some(usage);
+                    }
+                }
+                Err(err) => self.set_error(err),
+            },
+            LauncherSettingsMessage::StoragePruneLogs => {
+                return self.storage_clean(ql_core::disk_usage::prune_logs());
+            }
+            LauncherSettingsMessage::StorageDeleteOrphanAssets => {
+                return self.storage_clean(ql_core::disk_usage::delete_orphan_assets());
+            }
+            LauncherSettingsMessage::StorageDeleteForgeLeftovers => {
+                return self.storage_clean(ql_core::disk_usage::delete_forge_leftovers());
+            }
+            LauncherSettingsMessage::CleanLibraryStore => {
+                return self.storage_clean(ql_instances::library_store::clean_library_store());
+            }
+            LauncherSettingsMessage::StorageCleanEnd(result) => match result {
+                Ok(freed) => {
+                    if let State::LauncherSettings(menu) = &mut self.state {
+                        menu.storage_status =
+                            Some(t!("settings-storage-freed", size = format_size(freed)));
+                        if menu.disk_usage.is_some() {
+                            return Task::done(Message::LauncherSettings(
+                                LauncherSettingsMessage::StorageScan,
+                            ));
+                        }
+                    }
+                }
+                Err(err) => {
+                    if let State::LauncherSettings(_) = &self.state {
+                        self.set_error(err);
+                    } else {
+                        err_no_log!("Couldn't clean up: {err}");
+                    }
+                }
+            },
             LauncherSettingsMessage::ChangeTab(tab) => {
                 self.go_to_launcher_settings();
                 if let State::LauncherSettings(menu) = &mut self.state {
                     menu.selected_tab = tab;
+                    if tab == state::LauncherSettingsTab::Storage && menu.disk_usage.is_none() {
+                        return Task::done(Message::LauncherSettings(
+                            LauncherSettingsMessage::StorageScan,
+                        ));
+                    }
                 }
             }
         }
         Task::none()
     }
 
+    fn storage_clean(
+        &mut self,
+        task: impl std::future::Future<Output = Result<u64, ql_core::IoError>> + Send + 'static,
+    ) -> Task<Message> {
+        if let State::LauncherSettings(menu) = &mut self.state {
+            menu.storage_status = Some(t!("settings-storage-cleaning"));
+        }
+        Task::perform(task, |n| {
+            Message::LauncherSettings(LauncherSettingsMessage::StorageCleanEnd(n.strerr()))
+        })
+    }
+
+    fn network_config(&mut self) -> &mut NetworkConfig {
+        self.config.network.get_or_insert_with(Default::default)
+    }
+
+    fn network_proxy_config(&mut self) -> &mut ProxyConfig {
+        self.network_config()
+            .proxy
+            .get_or_insert_with(Default::default)
+    }
+
     pub fn go_to_launcher_settings(&mut self) {
         if let State::LauncherSettings(_) = &self.state {
             return;
@@ -476,6 +667,8 @@ impl Launcher {
         self.state = State::LauncherSettings(state::MenuLauncherSettings {
             temp_scale: self.config.ui_scale.unwrap_or(1.0),
             selected_tab: state::LauncherSettingsTab::UserInterface,
+            storage_status: None,
+            disk_usage: None,
         });
     }
 }
@@ -6,10 +6,13 @@ use ql_core::{
 };
 use ql_mod_manager::store::{RecommendedMod, RECOMMENDED_MODS};
 
-use crate::state::{
-    EditPresetsMessage, Launcher, MenuCurseforgeManualDownload, MenuEditPresets,
-    MenuEditPresetsInner, Message, ProgressBar, SelectedState, State, PRESET_INNER_BUILD,
-    PRESET_INNER_RECOMMENDED,
+use crate::{
+    i18n::t,
+    state::{
+        EditPresetsMessage, Launcher, MenuCurseforgeManualDownload, MenuEditPresets,
+        MenuEditPresetsInner, Message, ProgressBar, SelectedState, State, PRESET_INNER_BUILD,
+        PRESET_INNER_RECOMMENDED,
+    },
 };
 
 macro_rules! iflet_manage_preset {
@@ -309,7 +312,7 @@ impl Launcher {
     fn load_preset(&mut self) -> Task<Message> {
         let Some(file) = rfd::FileDialog::new()
             .add_filter("QuantumLauncher Mod Preset", &["qmp"])
-            .set_title("Select Mod Preset to Load")
+            .set_title(t!("dialog-select-preset"))
             .pick_file()
         else {
             return Task::none();
@@ -0,0 +1,271 @@
+use std::collections::HashMap;
+
+use iced::{widget::image::Handle, Task};
+use ql_core::{json::InstanceConfigJson, InstanceSelection, IntoStringError};
+
+use crate::state::{
+    InstanceMeta, Launcher, MenuLaunch, Message, SidebarDrag, SidebarMessage, SidebarSection, State,
+};
+
+impl Launcher {
+    pub fn update_sidebar(&mut self, msg: SidebarMessage) -> Task<Message> {
+        match msg {
+            SidebarMessage::MetaLoaded(meta, is_server) => {
+                *self.get_meta_mut(is_server) = meta;
+            }
+            SidebarMessage::Press(name) => {
+                let State::Launch(menu) = &self.state else {
+                    return Task::none();
+                };
+                let is_server = menu.is_viewing_server;
+                let from = SidebarSection::of(self.get_meta(is_server).get(&name));
+
+                let task = self.update(Message::LaunchInstanceSelected {
+                    name: name.clone(),
+                    is_server,
+                });
+                if let State::Launch(menu) = &mut self.state {
+                    menu.sidebar_drag = Some(SidebarDrag {
+                        instance: name,
+                        to: Some(from.clone()),
+                        from,
+                    });
+                }
+                return task;
+            }
+            SidebarMessage::HoverEnter(name) => {
+                if let State::Launch(menu) = &mut self.state {
+                    menu.sidebar_hovered = Some(name);
+                }
+            }
+            SidebarMessage::HoverLeave(name) => {
+                if let State::Launch(menu) = &mut self.state {
+                    if menu.sidebar_hovered.as_ref() == Some(&name) {
+                        menu.sidebar_hovered = None;
+                    }
+                }
+            }
+            SidebarMessage::SectionEnter(section) => {
+                if let State::Launch(MenuLaunch {
+                    sidebar_drag: Some(drag),
+                    ..
+                }) = &mut self.state
+                {
+                    drag.to = Some(section);
+                }
+            }
+            SidebarMessage::SectionLeave(section) => {
+                if let State::Launch(MenuLaunch {
+                    sidebar_drag: Some(drag),
+                    ..
+                }) = &mut self.state
+                {
+                    // The next section may have been entered already
+                    if drag.to.as_ref() == Some(&section) {
+                        drag.to = None;
+                    }
+                }
+            }
+            SidebarMessage::SectionToggle(section) => {
+                let key = section.config_key();
+                let collapsed = self.config.sidebar_collapsed.get_or_insert_with(Vec::new);
+                if let Some(i) = collapsed.iter().position(|n| *n == key) {
+                    collapsed.remove(i);
+                } else {
+                    collapsed.push(key);
+                }
+            }
+            SidebarMessage::Dropped(Ok(())) => {}
+            SidebarMessage::Dropped(Err(err)) => self.set_error(err),
+        }
+        Task::none()
+    }
+
+    /// Called when the mouse is released. Moves the instance
+    /// being dragged to the sidebar section under the cursor.
+    pub fn sidebar_drop(&mut self) -> Task<Message> {
+        let State::Launch(menu) = &mut self.state else {
+            return Task::none();
+        };
+        let Some(SidebarDrag {
+            instance: name,
+            from,
+            to: Some(to),
+        }) = menu.sidebar_drag.take()
+        else {
+            return Task::none();
+        };
+        if from == to {
+            return Task::none();
+        }
+        let instance = InstanceSelection::new(&name, menu.is_viewing_server);
+
+        // The edit tab saves its copy of the config every tick,
+        // so it needs to be changed too.
+        let edited_config = menu
+            .edit_instance
+            .as_mut()
+            .filter(|_| {
+                self.selected_instance
+                    .as_ref()
+                    .is_some_and(|n| n.get_name() == name)
+            })
+            .map(|edit| {
+                move_to_section(&mut edit.config, &to);
+                edit.config.clone()
+            });
+
+        if let Some(meta) = self.get_meta_mut(instance.is_server()).get_mut(&name) {
+            let (group, is_favorite) = section_placement(&to, meta.group.take());
+            meta.group = group;
+            meta.is_favorite = is_favorite;
+        }
+
+        Task::perform(
+            async move {
+                let config = if let Some(config) = edited_config {
+                    config
+                } else {
+                    let mut config = InstanceConfigJson::read(&instance).await.strerr()?;
+                    move_to_section(&mut config, &to);
+                    config
+                };
+                config.save(&instance).await.strerr()
+            },
+            |n| Message::Sidebar(SidebarMessage::Dropped(n)),
+        )
+    }
+
+    /// Updates the sidebar after the group, colour
+    /// or icon of the selected instance is changed.
+    pub fn refresh_selected_meta(&mut self) {
+        let (
+            State::Launch(MenuLaunch {
+                edit_instance: Some(edit),
+                ..
+            }),
+            Some(instance),
+        ) = (&self.state, &self.selected_instance)
+        else {
+            return;
+        };
+        let icon = std::fs::read(instance.get_icon_path())
+            .ok()
+            .map(Handle::from_bytes);
+        let metas = if instance.is_server() {
+            &mut self.server_meta
+        } else {
+            &mut self.client_meta
+        };
+        metas.insert(
+            instance.get_name().to_owned(),
+            InstanceMeta::from_config(&edit.config, icon),
+        );
+    }
+
+    pub fn get_meta(&self, is_server: bool) -> &HashMap<String, InstanceMeta> {
+        if is_server {
+            &self.server_meta
+        } else {
+            &self.client_meta
+        }
+    }
+
+    fn get_meta_mut(&mut self, is_server: bool) -> &mut HashMap<String, InstanceMeta> {
+        if is_server {
+            &mut self.server_meta
+        } else {
+            &mut self.client_meta
+        }
+    }
+
+    /// The instances in the sidebar, split into sections:
+    /// favourites first, then groups (alphabetically),
+    /// then ungrouped ones. Empty sections are left out.
+    ///
+    /// `None` while the instance list is loading.
+    pub fn sidebar_sections(&self, is_server: bool) -> Option<Vec<(SidebarSection, Vec<&String>)>> {
+        let list = if is_server {
+            self.server_list.as_ref()
+        } else {
+            self.client_list.as_ref()
+        }?;
+        let metas = self.get_meta(is_server);
+
+        let mut favorites = Vec::new();
+        let mut groups: Vec<(String, Vec<&String>)> = Vec::new();
+        let mut ungrouped = Vec::new();
+        for name in list {
+            match SidebarSection::of(metas.get(name)) {
+                SidebarSection::Favorites => favorites.push(name),
+                SidebarSection::Group(group) => {
+                    if let Some((_, names)) = groups.iter_mut().find(|(n, _)| *n == group) {
+                        names.push(name);
+                    } else {
+                        groups.push((group, vec![name]));
+                    }
+                }
+                SidebarSection::Ungrouped => ungrouped.push(name),
+            }
+        }
+        groups.sort_by_key(|(n, _)| n.to_lowercase());
+
+        let mut sections = Vec::new();
+        if !favorites.is_empty() {
+            sections.push((SidebarSection::Favorites, favorites));
+        }
+        sections.extend(
+            groups
+                .into_iter()
+                .map(|(group, names)| (SidebarSection::Group(group), names)),
+        );
+        if !ungrouped.is_empty() {
+            sections.push((SidebarSection::Ungrouped, ungrouped));
+        }
+        Some(sections)
+    }
+
+    /// Whether the sidebar shows section headers. If every
+    /// instance is ungrouped, it's just a plain list.
+    pub fn sidebar_has_headers(sections: &[(SidebarSection, Vec<&String>)]) -> bool {
+        !matches!(sections, [] | [(SidebarSection::Ungrouped, _)])
+    }
+
+    pub fn is_section_collapsed(&self, section: &SidebarSection) -> bool {
+        let key = section.config_key();
+        self.config
+            .sidebar_collapsed
+            .as_ref()
+            .is_some_and(|n| n.contains(&key))
+    }
+
+    /// The instances in the order they're shown
+    /// in the sidebar (minus collapsed sections).
+    pub fn sidebar_visible_order(&self, is_server: bool) -> Option<Vec<String>> {
+        let sections = self.sidebar_sections(is_server)?;
+        let has_headers = Self::sidebar_has_headers(&sections);
+        Some(
+            sections
+                .into_iter()
+                .filter(|(section, _)| !(has_headers && self.is_section_collapsed(section)))
+                .flat_map(|(_, names)| names.into_iter().cloned())
+                .collect(),
+        )
+    }
+}
+
+fn move_to_section(config: &mut InstanceConfigJson, section: &SidebarSection) {
+    let (group, is_favorite) = section_placement(section, config.group.take());
+    config.group = group;
+    config.is_favorite = Some(is_favorite);
+}
+
+/// The group and favourite status of an instance moved into
+/// `section`. Moving into favourites keeps the old group.
+fn section_placement(section: &SidebarSection, group: Option<String>) -> (Option<String>, bool) {
+    match section {
+        SidebarSection::Favorites => (group, true),
+        SidebarSection::Group(group) => (Some(group.clone()), false),
+        SidebarSection::Ungrouped => (None, false),
+    }
+}
@@ -0,0 +1,265 @@
+use iced::{widget::image::Handle, Task};
+use ql_core::{err, IntoIoError, IntoStringError};
+use ql_instances::auth::{
+    self,
+    skin::{self, SkinModel, SkinProfile},
+    AccountData,
+};
+
+use crate::{
+    i18n::t,
+    state::{Launcher, MenuSkins, Message, SkinMessage, State},
+};
+
+/// How much bigger the preview is than the skin's pixels
+const PREVIEW_SCALE: u32 = 6;
+const LIBRARY_PREVIEW_SCALE: u32 = 3;
+
+impl Launcher {
+    pub fn update_skin(&mut self, msg: SkinMessage) -> Task<Message> {
+        match msg {
+            SkinMessage::Loaded(Err(err))
+            | SkinMessage::Updated(Err(err))
+            | SkinMessage::LibraryLoaded(Err(err))
+            | SkinMessage::LibraryChanged(Err(err)) => {
+                self.set_error(err);
+            }
+            SkinMessage::Open => {
+                let Some(username) = self.accounts_selected.clone() else {
+                    return Task::none();
+                };
+                let Some(account) = self.accounts.get(&username).cloned() else {
+                    return Task::none();
+                };
+                self.state = State::Skins(MenuSkins {
+                    account: username,
+                    profile: None,
+                    texture: None,
+                    preview: None,
+                    library: Vec::new(),
+                    import_model: SkinModel::Classic,
+                    is_loading: true,
+                });
+                return Task::batch([
+                    Task::perform(load_profile(account), |n| {
+                        Message::Skin(SkinMessage::Loaded(n))
+                    }),
+                    load_library(),
+                ]);
+            }
+            SkinMessage::Loaded(Ok((account, profile, texture))) => {
+                self.accounts
+                    .insert(account.get_username_modified(), account);
+                self.skin_profile_changed(profile, texture);
+            }
+            SkinMessage::Updated(Ok((profile, texture))) => {
+                self.skin_profile_changed(profile, texture);
+            }
+            SkinMessage::LibraryLoaded(Ok(skins)) => {
+                if let State::Skins(menu) = &mut self.state {
+                    menu.library = skins
+                        .into_iter()
+                        .map(|(skin, png)| {
+                            let preview = preview(&png, skin.model, LIBRARY_PREVIEW_SCALE);
+                            (skin, preview)
+                        })
+                        .collect();
+                }
+            }
+            SkinMessage::LibraryChanged(Ok(())) => return load_library(),
+            SkinMessage::ImportModelPicked(model) => {
+                if let State::Skins(menu) = &mut self.state {
+                    menu.import_model = model;
+                }
+            }
+            SkinMessage::Import => {
+                let State::Skins(menu) = &self.state else {
+                    return Task::none();
+                };
+                let Some(path) = rfd::FileDialog::new()
+                    .add_filter("PNG Image", &["png"])
+                    .set_title(t!("dialog-select-skin"))
+                    .pick_file()
+                else {
+                    return Task::none();
+                };
+                let model = menu.import_model;
+                return Task::perform(
+                    async move {
+                        let png = tokio::fs::read(&path).await.path(&path).strerr()?;
+                        let name = path
+                            .file_stem()
+                            .map_or("skin".into(), |n| n.to_string_lossy());
+                        skin::library_add(&name, &png, model).await.strerr()
+                    },
+                    |n| Message::Skin(SkinMessage::LibraryChanged(n)),
+                );
+            }
+            SkinMessage::SaveCurrent => {
+                let State::Skins(menu) = &self.state else {
+                    return Task::none();
+                };
+                let (Some(texture), Some(profile)) = (menu.texture.clone(), &menu.profile) else {
+                    return Task::none();
+                };
+                let model = profile.model;
+                let name = menu.account.clone();
+                return Task::perform(
+                    async move { skin::library_add(&name, &texture, model).await.strerr() },
+                    |n| Message::Skin(SkinMessage::LibraryChanged(n)),
+                );
+            }
+            SkinMessage::Delete(name) => {
+                return Task::perform(
+                    async move { skin::library_remove(&name).await.strerr() },
+                    |n| Message::Skin(SkinMessage::LibraryChanged(n)),
+                );
+            }
+            SkinMessage::Use(name) => {
+                let Some((account, menu)) = self.skin_menu_account() else {
+                    return Task::none();
+                };
+                let Some(library_skin) = menu
+                    .library
+                    .iter()
+                    .find(|(n, _)| n.name == name)
+                    .map(|(n, _)| n.clone())
+                else {
+                    return Task::none();
+                };
+                menu.is_loading = true;
+                return Task::perform(
+                    async move {
+                        let png = library_skin.read().await.strerr()?;
+                        let profile = skin::upload_skin(&account, png, library_skin.model)
+                            .await
+                            .strerr()?;
+                        with_texture(profile).await
+                    },
+                    |n| Message::Skin(SkinMessage::Updated(n)),
+                );
+            }
+            SkinMessage::Reset => {
+                let Some((account, menu)) = self.skin_menu_account() else {
+                    return Task::none();
+                };
+                menu.is_loading = true;
+                return Task::perform(
+                    async move {
+                        let profile = skin::reset_skin(&account).await.strerr()?;
+                        with_texture(profile).await
+                    },
+                    |n| Message::Skin(SkinMessage::Updated(n)),
+                );
+            }
+            SkinMessage::SetCape(id) => {
+                let Some((account, menu)) = self.skin_menu_account() else {
+                    return Task::none();
+                };
+                menu.is_loading = true;
+                return Task::perform(
+                    async move {
+                        let profile = skin::set_cape(&account, id).await.strerr()?;
+                        with_texture(profile).await
+                    },
+                    |n| Message::Skin(SkinMessage::Updated(n)),
+                );
+            }
+        }
+        Task::none()
+    }
+
+    fn skin_menu_account(&mut self) -> Option<(AccountData, &mut MenuSkins)> {
+        let State::Skins(menu) = &mut self.state else {
+            return None;
+        };
+        if menu.is_loading {
+            return None;
+        }
+        let account = self.accounts.get(&menu.account)?.clone();
+        Some((account, menu))
+    }
+
+    fn skin_profile_changed(&mut self, profile: SkinProfile, texture: Option<Vec<u8>>) {
+        let State::Skins(menu) = &mut self.state else {
+            return;
+        };
+        if let Some(account) = self
+            .config
+            .accounts
+            .as_mut()
+            .and_then(|n| n.get_mut(&menu.account))
+        {
+            account.skin.clone_from(&profile.skin_url);
+        }
+        menu.preview = texture
+            .as_deref()
+            .and_then(|n| preview(n, profile.model, PREVIEW_SCALE));
+        menu.texture = texture;
+        menu.profile = Some(profile);
+        menu.is_loading = false;
+    }
+}
+
+async fn load_profile(
+    account: AccountData,
+) -> Result<(AccountData, SkinProfile, Option<Vec<u8>>), String> {
+    // The profile API needs a valid access token.
+    // ely.by and authlib-injector skins are public,
+    // so no need to log in there.
+    let account = if matches!(account.account_type, auth::AccountType::Microsoft)
+        && (account.access_token.is_none() || account.needs_refresh)
+    {
+        auth::ms::login_refresh(
+            account.username.clone(),
+            account.refresh_token.clone(),
+            None,
+        )
+        .await
+        .strerr()?
+    } else {
+        account
+    };
+    let profile = skin::get_profile(&account).await.strerr()?;
+    let (profile, texture) = with_texture(profile).await?;
+    Ok((account, profile, texture))
+}
+
+async fn with_texture(profile: SkinProfile) -> Result<(SkinProfile, Option<Vec<u8>>), String> {
+    let texture = match &profile.skin_url {
+        Some(url) => Some(skin::download_texture(url).await.strerr()?),
+        None => None,
+    };
+    Ok((profile, texture))
+}
+
+fn load_library() -> Task<Message> {
+    Task::perform(
+        async move {
+            let mut skins = Vec::new();
+            for library_skin in skin::library_list().await.strerr()? {
+                // Don't lose the whole library over one missing file
+                match library_skin.read().await {
+                    Ok(png) => skins.push((library_skin, png)),
+                    Err(err) => err!("Skipping skin {:?} in library: {err}", library_skin.name),
+                }
+            }
+            Ok(skins)
+        },
+        |n| Message::Skin(SkinMessage::LibraryLoaded(n)),
+    )
+}
+
+fn preview(png: &[u8], model: SkinModel, scale: u32) -> Option<Handle> {
+    match skin::render_preview(png, model, scale) {
+        Ok(preview) => Some(Handle::from_rgba(
+            preview.width,
+            preview.height,
+            preview.rgba,
+        )),
+        Err(error) => {
+            err!("Couldn't render skin preview: {error}");
+            None
+        }
+    }
+}
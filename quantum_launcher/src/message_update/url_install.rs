@@ -0,0 +1,106 @@
+use iced::Task;
+use ql_core::{file_utils, InstanceSelection, IntoStringError};
+use ql_mod_manager::store;
+
+use crate::{
+    cli::UrlAction,
+    i18n::t,
+    state::{
+        Launcher, MenuCurseforgeManualDownload, MenuUrlInstall, Message, ProgressBar, State,
+        UrlInstallMessage,
+    },
+};
+
+impl Launcher {
+    pub fn update_url_install(&mut self, msg: UrlInstallMessage) -> Task<Message> {
+        match msg {
+            UrlInstallMessage::Open(link) => {
+                let action = match UrlAction::parse(&link) {
+                    Ok(n) => n,
+                    Err(err) => {
+                        self.set_error(err);
+                        return Task::none();
+                    }
+                };
+                // The link's instance if it exists,
+                // otherwise whatever is selected right now
+                let instance = action
+                    .instance()
+                    .filter(|n| {
+                        self.client_list
+                            .as_ref()
+                            .is_some_and(|list| list.iter().any(|i| i == n))
+                    })
+                    .map(str::to_owned)
+                    .or_else(|| match &self.selected_instance {
+                        Some(InstanceSelection::Instance(n)) => Some(n.clone()),
+                        _ => None,
+                    });
+                self.state = State::UrlInstall(MenuUrlInstall { action, instance });
+            }
+            UrlInstallMessage::InstanceSelected(instance) => {
+                if let State::UrlInstall(menu) = &mut self.state {
+                    menu.instance = Some(instance);
+                }
+            }
+            UrlInstallMessage::Confirm => {
+                let State::UrlInstall(MenuUrlInstall {
+                    action,
+                    instance: Some(instance),
+                }) = &self.state
+                else {
+                    return Task::none();
+                };
+                let action = action.clone();
+                let instance = InstanceSelection::Instance(instance.clone());
+                self.selected_instance = Some(instance.clone());
+
+                let (sender, receiver) = std::sync::mpsc::channel();
+                self.state = State::ImportModpack(ProgressBar::with_recv(receiver));
+
+                return Task::perform(
+                    async move {
+                        match action {
+                            UrlAction::Mod { id, .. } => {
+                                store::download_mod(&id, &instance, Some(sender))
+                                    .await
+                                    .strerr()
+                            }
+                            UrlAction::Pack { url, .. } => {
+                                let file = file_utils::download_file_to_bytes(&url, true)
+                                    .await
+                                    .strerr()?;
+                                store::install_modpack(file, instance, Some(&sender))
+                                    .await
+                                    .strerr()?
+                                    .ok_or_else(|| format!("{url} is not a modpack"))
+                            }
+                            UrlAction::File { path } => {
+                                ql_mod_manager::add_files(instance, vec![path], Some(sender))
+                                    .await
+                                    .strerr()
+                            }
+                        }
+                    },
+                    |n| Message::UrlInstall(UrlInstallMessage::End(n)),
+                );
+            }
+            UrlInstallMessage::End(Ok(not_allowed)) => {
+                if not_allowed.is_empty() {
+                    let name = self
+                        .selected_instance
+                        .as_ref()
+                        .map(|n| n.get_name().to_owned())
+                        .unwrap_or_default();
+                    return self.go_to_launch_screen(Some(t!("url-install-done", instance = name)));
+                }
+                self.state = State::CurseforgeManualDownload(MenuCurseforgeManualDownload {
+                    unsupported: not_allowed,
+                    is_store: false,
+                });
+            }
+            UrlInstallMessage::End(Err(err)) => self.set_error(err),
+        }
+        Task::none()
+    }
+}
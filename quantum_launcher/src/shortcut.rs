@@ -0,0 +1,311 @@
+//! Shortcuts that launch an instance directly
+//! (with `quantum_launcher --launch <instance>`).
+//!
+//! - Linux/BSD: a freedesktop `.desktop` entry in the
+//!   applications menu, also copied to the desktop
+//!   (like `assets/freedesktop/quantum-launcher.desktop`)
+//! - Windows: a `.lnk` shortcut on the desktop
+//! - macOS: a tiny `.app` bundle on the desktop
+//!
+//! The instance's icon is used on Linux, and on Windows
+//! (converted to `icon.ico` next to `icon.png`). On macOS the
+//! shortcut always has the launcher's icon, since bundles
+//! need an `.icns` file and there's no encoder for it here.
+
+use std::path::PathBuf;
+
+use ql_core::{InstanceSelection, IntoIoError, IntoStringError};
+
+use crate::state::Res;
+
+/// The icon of the launcher's own desktop entry
+#[cfg(not(any(target_os = "windows", target_os = "macos")))]
+const LAUNCHER_ICON_NAME: &str = "io.github.Mrmayman.QuantumLauncher";
+
+/// Creates a shortcut for `instance`,
+/// returning where it was put.
+pub async fn create(instance: String) -> Res<PathBuf> {
+    let selection = InstanceSelection::Instance(instance);
+    let exe = get_launcher_command()?;
+    create_w(&selection, &exe).await
+}
+
+/// How the shortcut should start the launcher.
+fn get_launcher_command() -> Res<Vec<String>> {
+    // The executable inside an AppImage is in a temporary mount
+    if let Ok(appimage) = std::env::var("APPIMAGE") {
+        return Ok(vec![appimage]);
+    }
+    if let Ok(flatpak) = std::env::var("FLATPAK_ID") {
+        return Ok(vec!["flatpak".to_owned(), "run".to_owned(), flatpak]);
+    }
+    let exe = std::env::current_exe()
+        .map_err(|err| format!("Couldn't find the launcher executable: {err}"))?;
+    Ok(vec![exe.to_string_lossy().into_owned()])
+}
+
+/// `instance` without characters that can't be in file names
+fn file_name(instance: &str) -> String {
+    instance
+        .chars()
+        .map(|c| {
+            if c.is_alphanumeric() || c == '-' || c == '_' || c == ' ' {
+                c
+            } else {
+                '_'
+            }
+        })
+        .collect()
+}
+
+#[cfg(not(any(target_os = "windows", target_os = "macos")))]
+async fn create_w(instance: &InstanceSelection, command: &[String]) -> Res<PathBuf> {
+    let name = instance.get_name();
+    let exec = command
+        .iter()
+        .map(String::as_str)
+        .chain(["--launch", name])
+        .map(desktop_exec_quote)
+        .collect::<Vec<_>>()
+        .join(" ");
+
+    let icon_path = instance.get_icon_path();
+    let icon = if icon_path.is_file() {
+        icon_path.to_string_lossy().into_owned()
+    } else {
+        LAUNCHER_ICON_NAME.to_owned()
+    };
+
+    let entry = format!(
+        r"[Desktop Entry]
+Type=Application
+Version=1.5
+
+Name={name}
+Comment=Play {name} with Quantum Launcher
+Categories=Game;
+Icon={icon}
+Exec={exec}
+PrefersNonDefaultGPU=true
+",
+        name = desktop_escape(name),
+        icon = desktop_escape(&icon),
+    );
+    let file = format!(
+        "io.github.Mrmayman.QuantumLauncher.{}.desktop",
+        file_name(name).replace(' ', "_")
+    );
+
+    let applications = dirs::data_dir()
+        .ok_or("Couldn't find the applications folder")?
+        .join("applications");
+    tokio::fs::create_dir_all(&applications)
+        .await
+        .path(&applications)
+        .strerr()?;
+    let path = applications.join(&file);
+    write_executable(&path, &entry).await?;
+
+    // Some desktops show `.desktop` files on the desktop as shortcuts
+    if let Some(desktop) = dirs::desktop_dir().filter(|n| n.is_dir()) {
+        let path = desktop.join(&file);
+        write_executable(&path, &entry).await?;
+        return Ok(path);
+    }
+    Ok(path)
+}
+
+/// Escapes a value in a `.desktop` file
+#[cfg(not(any(target_os = "windows", target_os = "macos")))]
+fn desktop_escape(value: &str) -> String {
+    value
+        .replace('\\', r"\\")
+        .replace('\n', r"\n")
+        .replace('\t', r"\t")
+}
+
+/// Quotes an argument of the `Exec` key, see
+/// <https://specifications.freedesktop.org/desktop-entry-spec/latest/exec-variables.html>
+#[cfg(not(any(target_os = "windows", target_os = "macos")))]
+fn desktop_exec_quote(arg: &str) -> String {
+    let mut quoted = String::from("\"");
+    for c in arg.chars() {
+        match c {
+            '"' | '`' | '$' | '\\' => {
+                quoted.push('\\');
+                quoted.push(c);
+            }
+            // Field codes like `%f`
+            '%' => quoted.push_str("%%"),
+            c => quoted.push(c),
+        }
+    }
+    quoted.push('"');
+    desktop_escape(&quoted)
+}
+
+#[cfg(target_os = "windows")]
+async fn create_w(instance: &InstanceSelection, command: &[String]) -> Res<PathBuf> {
+    let name = instance.get_name();
+    let desktop = dirs::desktop_dir().ok_or("Couldn't find the desktop folder")?;
+    let path = desktop.join(format!("{}.lnk", file_name(name)));
+    let icon = write_ico(&instance.get_icon_path())
+        .map_or_else(|| command[0].clone(), |n| n.to_string_lossy().into_owned());
+
+    // Passed through environment variables to avoid quoting issues
+    let output = tokio::process::Command::new("powershell")
+        .args([
+            "-NoProfile",
+            "-NonInteractive",
+            "-Command",
+            r"$s = (New-Object -ComObject WScript.Shell).CreateShortcut($env:QL_SHORTCUT_PATH);
+$s.TargetPath = $env:QL_SHORTCUT_TARGET;
+$s.Arguments = $env:QL_SHORTCUT_ARGS;
+$s.IconLocation = $env:QL_SHORTCUT_ICON;
+$s.Description = $env:QL_SHORTCUT_DESCRIPTION;
+$s.Save()",
+        ])
+        .env("QL_SHORTCUT_PATH", &path)
+        .env("QL_SHORTCUT_TARGET", &command[0])
+        .env("QL_SHORTCUT_ICON", icon)
+        .env("QL_SHORTCUT_ARGS", format!("--launch \"{name}\""))
+        .env(
+            "QL_SHORTCUT_DESCRIPTION",
+            format!("Play {name} with Quantum Launcher"),
+        )
+        .output()
+        .await
+        .map_err(|err| format!("Couldn't run powershell to create the shortcut: {err}"))?;
+    if !output.status.success() {
+        return Err(format!(
+            "Couldn't create the shortcut:\n{}",
+            String::from_utf8_lossy(&output.stderr)
+        ));
+    }
+    Ok(path)
+}
+
+/// Converts the instance icon (`png`) to an `.ico` next to it,
+/// since shortcuts can't use PNG icons.
+#[cfg(target_os = "windows")]
+fn write_ico(png: &std::path::Path) -> Option<PathBuf> {
+    if !png.is_file() {
+        return None;
+    }
+    let ico = png.with_extension("ico");
+    let image = image::open(png)
+        .inspect_err(|err| ql_core::err!("Couldn't read the instance icon: {err}"))
+        .ok()?;
+    // The largest size an `.ico` can hold
+    let image = if image.width() > 256 || image.height() > 256 {
+        image.resize(256, 256, image::imageops::FilterType::Lanczos3)
+    } else {
+        image
+    };
+    image
+        .save_with_format(&ico, image::ImageFormat::Ico)
+        .inspect_err(|err| ql_core::err!("Couldn't convert the instance icon: {err}"))
+        .ok()?;
+    Some(ico)
+}
+
+#[cfg(target_os = "macos")]
+async fn create_w(instance: &InstanceSelection, command: &[String]) -> Res<PathBuf> {
+    let name = instance.get_name();
+    let desktop = dirs::desktop_dir().ok_or("Couldn't find the desktop folder")?;
+    let app = desktop.join(format!("{}.app", file_name(name)));
+    let contents = app.join("Contents");
+    let macos_dir = contents.join("MacOS");
+    tokio::fs::create_dir_all(&macos_dir)
+        .await
+        .path(&macos_dir)
+        .strerr()?;
+
+    let script = format!(
+        "#!/bin/sh\nexec {} --launch {}\n",
+        command
+            .iter()
+            .map(|n| shell_quote(n))
+            .collect::<Vec<_>>()
+            .join(" "),
+        shell_quote(name)
+    );
+    write_executable(&macos_dir.join("launch"), &script).await?;
+
+    let has_icon = copy_launcher_icon(&command[0], &contents).await;
+    // Same layout as `assets/freedesktop/Info.plist`
+    let plist = format!(
+        r#"<?xml version="1.0" encoding="UTF-8" ?>
+<!DOCTYPE plist PUBLIC "-//Apple//DTD PLIST 1.0//EN"
+    "http://www.apple.com/DTDs/PropertyList-1.0.dtd">
+<plist version="1.0">
+<dict>
+    <key>CFBundleExecutable</key>
+    <string>launch</string>
+    <key>CFBundleIdentifier</key>
+    <string>io.github.Mrmayman.QuantumLauncher.{id}</string>
+    <key>CFBundleInfoDictionaryVersion</key>
+    <string>6.0</string>
+    <key>CFBundleName</key>
+    <string>{name}</string>
+    <key>CFBundlePackageType</key>
+    <string>APPL</string>{icon}
+</dict>
+</plist>
+"#,
+        id = file_name(name).replace([' ', '_'], "-"),
+        name = xml_escape(name),
+        icon = if has_icon {
+            "\n    <key>CFBundleIconFile</key>\n    <string>ql_logo</string>"
+        } else {
+            ""
+        },
+    );
+    let plist_path = contents.join("Info.plist");
+    tokio::fs::write(&plist_path, plist)
+        .await
+        .path(plist_path)
+        .strerr()?;
+    Ok(app)
+}
+
+/// Copies the launcher's icon if it's running from a `.app` bundle.
+#[cfg(target_os = "macos")]
+async fn copy_launcher_icon(exe: &str, contents: &std::path::Path) -> bool {
+    let Some(icon) = std::path::Path::new(exe)
+        .parent()
+        .and_then(std::path::Path::parent)
+        .map(|n| n.join("Resources").join("ql_logo.icns"))
+        .filter(|n| n.is_file())
+    else {
+        return false;
+    };
+    let resources = contents.join("Resources");
+    tokio::fs::create_dir_all(&resources).await.is_ok()
+        && tokio::fs::copy(&icon, resources.join("ql_logo.icns"))
+            .await
+            .is_ok()
+}
+
+#[cfg(target_os = "macos")]
+fn shell_quote(arg: &str) -> String {
+    format!("'{}'", arg.replace('\'', r"'\''"))
+}
+
+#[cfg(target_os = "macos")]
+fn xml_escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
+
+#[cfg(not(target_os = "windows"))]
+async fn write_executable(path: &std::path::Path, contents: &str) -> Res {
+    use std::os::unix::fs::PermissionsExt;
+
+    tokio::fs::write(path, contents).await.path(path).strerr()?;
+    tokio::fs::set_permissions(path, std::fs::Permissions::from_mode(0o755))
+        .await
+        .path(path)
+        .strerr()
+}
@@ -0,0 +1,281 @@
+//! Keeps only one launcher window running at a time,
+//! since two would race on `config.json`, `mod_index.json`
+//! and instance folders.
+//!
+//! The first launcher locks `launcher.lock` and listens on a
+//! local socket (a Unix socket next to the lock file, or a named
+//! pipe on Windows). Opening the launcher again sends a [`Request`]
+//! with its command-line arguments to the running one, and exits.
+//!
+//! This is also what makes opening `.mrpack`/`.qmp` files
+//! (file associations) and `quantumlauncher://` links reuse the
+//! window that's already open.
+//!
+//! Command-line actions that change files without opening a
+//! window (`--launch`, `account login`, ...) take the same lock,
+//! see [`lock_headless`].
+
+use std::{fs::File, io::Write, time::Duration};
+
+use iced::futures::{SinkExt, Stream};
+use ql_core::{err, info_no_log, LAUNCHER_DIR};
+use serde::{Deserialize, Serialize};
+use tokio::io::{AsyncBufReadExt, BufReader};
+
+/// What a second launch of the launcher asks the running one to do.
+///
+/// Sent as a line of JSON over the socket.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum Request {
+    /// Opened without any arguments, so just
+    /// bring the window to the front
+    Focus,
+    /// `--launch <instance>`
+    Launch(String),
+    /// A `quantumlauncher://` link, or the
+    /// (absolute) path of a file to import
+    Open(String),
+}
+
+/// Held by the running launcher for as long as it's open.
+pub struct Listener {
+    lock: File,
+    #[cfg(unix)]
+    socket: Option<std::os::unix::net::UnixListener>,
+}
+
+/// Makes this the running launcher, or if one is
+/// already running, sends it `request` and exits.
+///
+/// If the lock is held by something that doesn't respond
+/// (a command like `--launch`, or a stuck launcher), this
+/// shows an error and exits.
+///
+/// Returns `None` if locking isn't possible here
+/// (the launcher just runs without the check).
+pub fn acquire(request: &Request) -> Option<Listener> {
+    match lock() {
+        Lock::Acquired(lock) => Some(Listener {
+            lock,
+            #[cfg(unix)]
+            socket: bind(),
+        }),
+        Lock::Held => {
+            if forward_retrying(request) {
+                info_no_log!("QuantumLauncher is already open, switching to it");
+                std::process::exit(0);
+            }
+            let message = "QuantumLauncher (or a command like `--launch`) is already running, but isn't responding!\nClose it first.";
+            err!("{message}");
+            rfd::MessageDialog::new()
+                .set_level(rfd::MessageLevel::Error)
+                .set_title("QuantumLauncher")
+                .set_description(message)
+                .set_buttons(rfd::MessageButtons::Ok)
+                .show();
+            std::process::exit(1);
+        }
+        Lock::Unavailable => None,
+    }
+}
+
+/// Locks `launcher.lock` for a command-line action that
+/// changes the launcher's files without opening a window
+/// (`--launch`, `account login`, ...), so it can't race with
+/// an open launcher. Keep the returned file around until done.
+///
+/// If a launcher is already open, `request` is sent to it
+/// and this exits. If there's nothing to send, or it doesn't
+/// respond, this returns an error instead.
+pub fn lock_headless(request: Option<&Request>) -> Result<Option<File>, String> {
+    match lock() {
+        Lock::Acquired(lock) => Ok(Some(lock)),
+        Lock::Held => {
+            if request.is_some_and(forward_retrying) {
+                info_no_log!("QuantumLauncher is already open, switching to it");
+                std::process::exit(0);
+            }
+            Err(
+                "QuantumLauncher (or another command) is already running, close it first"
+                    .to_owned(),
+            )
+        }
+        Lock::Unavailable => Ok(None),
+    }
+}
+
+enum Lock {
+    Acquired(File),
+    /// Another launcher has it
+    Held,
+    /// Locking isn't possible here
+    Unavailable,
+}
+
+fn lock() -> Lock {
+    let lock_path = LAUNCHER_DIR.join("launcher.lock");
+    let lock = match File::options()
+        .create(true)
+        .truncate(false)
+        .write(true)
+        .open(&lock_path)
+    {
+        Ok(n) => n,
+        Err(err) => {
+            err!("Couldn't open {lock_path:?}, not checking for other launchers: {err}");
+            return Lock::Unavailable;
+        }
+    };
+
+    match lock.try_lock() {
+        Ok(()) => Lock::Acquired(lock),
+        Err(std::fs::TryLockError::WouldBlock) => Lock::Held,
+        Err(std::fs::TryLockError::Error(err)) => {
+            err!("Couldn't lock {lock_path:?}, not checking for other launchers: {err}");
+            Lock::Unavailable
+        }
+    }
+}
+
+fn forward_retrying(request: &Request) -> bool {
+    // The other launcher might still be starting up
+    for _ in 0..10 {
+        if forward(request) {
+            return true;
+        }
+        std::thread::sleep(Duration::from_millis(300));
+    }
+    false
+}
+
+/// Sends `request` to the running launcher (if any),
+/// returning whether it was sent.
+pub fn forward(request: &Request) -> bool {
+    let Ok(mut line) = serde_json::to_string(request) else {
+        return false;
+    };
+    line.push('\n');
+
+    #[cfg(unix)]
+    let connection = std::os::unix::net::UnixStream::connect(socket_path());
+    #[cfg(windows)]
+    let connection = File::options().write(true).open(pipe_name());
+
+    connection.is_ok_and(|mut n| {
+        n.write_all(line.as_bytes())
+            .and_then(|()| n.flush())
+            .is_ok()
+    })
+}
+
+/// The requests sent by [`forward`] from other launchers.
+pub fn requests(listener: Listener) -> impl Stream<Item = Request> {
+    iced::stream::channel(10, move |mut output| async move {
+        // Keeps the lock until the launcher closes
+        let _lock = listener.lock;
+
+        #[cfg(unix)]
+        {
+            let Some(socket) = listener
+                .socket
+                .and_then(|n| n.set_nonblocking(true).map(|()| n).ok())
+                .and_then(|n| tokio::net::UnixListener::from_std(n).ok())
+            else {
+                return;
+            };
+            loop {
+                match socket.accept().await {
+                    Ok((stream, _)) => {
+                        if let Some(request) = read_request(stream).await {
+                            _ = output.send(request).await;
+                        }
+                    }
+                    Err(err) => err!("Couldn't accept request from other launcher: {err}"),
+                }
+            }
+        }
+
+        #[cfg(windows)]
+        {
+            use tokio::net::windows::named_pipe::ServerOptions;
+
+            let name = pipe_name();
+            let mut server = match ServerOptions::new().first_pipe_instance(true).create(&name) {
+                Ok(n) => n,
+                Err(err) => {
+                    err!("Couldn't listen for other launchers: {err}");
+                    return;
+                }
+            };
+            loop {
+                if let Err(err) = server.connect().await {
+                    err!("Couldn't accept request from other launcher: {err}");
+                    continue;
+                }
+                // A new pipe for the next launcher, while reading this one
+                let connected = server;
+                server = match ServerOptions::new().create(&name) {
+                    Ok(n) => n,
+                    Err(err) => {
+                        err!("Couldn't listen for other launchers: {err}");
+                        return;
+                    }
+                };
+                if let Some(request) = read_request(connected).await {
+                    _ = output.send(request).await;
+                }
+            }
+        }
+    })
+}
+
+async fn read_request(stream: impl tokio::io::AsyncRead + Unpin) -> Option<Request> {
+    let mut line = String::new();
+    // So a client that never finishes its line
+    // doesn't block every launcher after it
+    match tokio::time::timeout(
+        Duration::from_secs(5),
+        BufReader::new(stream).read_line(&mut line),
+    )
+    .await
+    {
+        Ok(Ok(_)) => {}
+        Ok(Err(err)) => {
+            err!("Couldn't read request from other launcher: {err}");
+            return None;
+        }
+        Err(_) => {
+            err!("Other launcher didn't send its request in time");
+            return None;
+        }
+    }
+    serde_json::from_str(&line)
+        .inspect_err(|err| err!("Invalid request from other launcher: {err}\n{line}"))
+        .ok()
+}
+
+#[cfg(unix)]
+fn socket_path() -> std::path::PathBuf {
+    LAUNCHER_DIR.join("launcher.sock")
+}
+
+#[cfg(unix)]
+fn bind() -> Option<std::os::unix::net::UnixListener> {
+    let path = socket_path();
+    // Left behind if the last launcher crashed
+    // (we have the lock, so nobody's using it)
+    _ = std::fs::remove_file(&path);
+    std::os::unix::net::UnixListener::bind(&path)
+        .inspect_err(|err| err!("Couldn't listen for other launchers at {path:?}: {err}"))
+        .ok()
+}
+
+/// One pipe per launcher folder, so portable
+/// installs don't talk to each other
+#[cfg(windows)]
+fn pipe_name() -> String {
+    format!(
+        r"\\.\pipe\QuantumLauncher-{}",
+        LAUNCHER_DIR.to_string_lossy().replace('\\', "/")
+    )
+}
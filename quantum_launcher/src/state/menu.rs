@@ -4,22 +4,36 @@ use std::{
     time::Instant,
 };
 
-use iced::{widget::scrollable::AbsoluteOffset, Task};
+use iced::{
+    widget::{image::Handle, scrollable::AbsoluteOffset},
+    Task,
+};
 use ql_core::{
+    disk_usage::DiskUsage,
     file_utils::DirItem,
     jarmod::JarMods,
     json::{InstanceConfigJson, VersionDetails},
     DownloadProgress, GenericProgress, InstanceSelection, ListEntry, ModId, OptifineUniqueVersion,
     SelectedMod, StoreBackendType,
 };
+use ql_instances::{
+    auth::skin::{LibrarySkin, SkinModel, SkinProfile},
+    VersionFilter,
+};
 use ql_mod_manager::{
     loaders::{forge::ForgeInstallProgress, optifine::OptifineInstallProgress},
-    store::{CurseforgeNotAllowed, ModConfig, ModIndex, QueryType, RecommendedMod, SearchResult},
+    store::{
+        CurseforgeNotAllowed, ModConfig, ModIndex, ModSnapshot, ModUpdate, QueryType,
+        RecommendedMod, SearchResult,
+    },
 };
 
-use crate::{config::SIDEBAR_WIDTH_DEFAULT, message_handler::get_locally_installed_mods};
+use crate::{
+    cli::UrlAction, config::SIDEBAR_WIDTH_DEFAULT, i18n::t,
+    message_handler::get_locally_installed_mods,
+};
 
-use super::{ManageModsMessage, Message, ProgressBar};
+use super::{InstanceMeta, ManageModsMessage, Message, ProgressBar};
 
 #[derive(Clone, PartialEq, Eq, Debug, Default, Copy)]
 pub enum LaunchTabId {
@@ -35,9 +49,9 @@ impl std::fmt::Display for LaunchTabId {
             f,
             "{}",
             match self {
-                LaunchTabId::Buttons => "Play",
-                LaunchTabId::Log => "Log",
-                LaunchTabId::Edit => "Edit",
+                LaunchTabId::Buttons => t!("launch-tab-play"),
+                LaunchTabId::Log => t!("launch-tab-log"),
+                LaunchTabId::Edit => t!("launch-tab-edit"),
             }
         )
     }
@@ -56,6 +70,61 @@ pub struct MenuLaunch {
 
     pub is_viewing_server: bool,
     pub log_scroll: isize,
+
+    /// The sidebar entry under the cursor
+    pub sidebar_hovered: Option<String>,
+    pub sidebar_drag: Option<SidebarDrag>,
+}
+
+/// A section of instances in the sidebar.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SidebarSection {
+    Favorites,
+    Group(String),
+    Ungrouped,
+}
+
+impl SidebarSection {
+    pub fn of(meta: Option<&InstanceMeta>) -> Self {
+        match meta {
+            Some(InstanceMeta {
+                is_favorite: true, ..
+            }) => Self::Favorites,
+            Some(InstanceMeta {
+                group: Some(group), ..
+            }) => Self::Group(group.clone()),
+            _ => Self::Ungrouped,
+        }
+    }
+
+    /// How the section is stored in
+    /// [`crate::config::LauncherConfig::sidebar_collapsed`]
+    pub fn config_key(&self) -> String {
+        match self {
+            SidebarSection::Favorites => ":favorites".to_owned(),
+            SidebarSection::Group(name) => name.clone(),
+            SidebarSection::Ungrouped => ":ungrouped".to_owned(),
+        }
+    }
+}
+
+impl std::fmt::Display for SidebarSection {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SidebarSection::Favorites => write!(f, "{}", t!("launch-section-favorites")),
+            SidebarSection::Group(name) => write!(f, "{name}"),
+            SidebarSection::Ungrouped => write!(f, "{}", t!("launch-section-ungrouped")),
+        }
+    }
+}
+
+/// An instance being dragged (with the mouse)
+/// from one sidebar section to another.
+pub struct SidebarDrag {
+    pub instance: String,
+    pub from: SidebarSection,
+    /// The section under the cursor
+    pub to: Option<SidebarSection>,
 }
 
 impl Default for MenuLaunch {
@@ -76,13 +145,30 @@ impl MenuLaunch {
             sidebar_dragging: false,
             is_viewing_server: false,
             log_scroll: 0,
+            sidebar_hovered: None,
+            sidebar_drag: None,
         }
     }
 }
 
+/// A popup shown over the current menu,
+/// opened with a keyboard shortcut.
+pub enum KeyOverlay {
+    Switcher(MenuInstanceSwitcher),
+    Cheatsheet,
+}
+
+/// Fuzzy search to quickly jump to an instance.
+pub struct MenuInstanceSwitcher {
+    pub query: String,
+    /// Index into the search results
+    pub selected: usize,
+}
+
 /// The screen where you can edit an instance/server.
 pub struct MenuEditInstance {
     pub config: InstanceConfigJson,
+    pub notes: iced::widget::text_editor::Content,
     pub instance_name: String,
     pub old_instance_name: String,
     pub slider_value: f32,
@@ -142,7 +228,11 @@ pub struct MenuEditMods {
     pub selected_state: SelectedState,
 
     pub update_check_handle: Option<iced::task::Handle>,
-    pub available_updates: Vec<(ModId, String, bool)>,
+    pub available_updates: Vec<(ModUpdate, bool)>,
+    /// Whether the update review (changelog) screen is open
+    pub reviewing_updates: bool,
+    /// `Some` if the mod snapshots (rollback) screen is open
+    pub snapshots: Option<Vec<ModSnapshot>>,
     pub drag_and_drop_hovered: bool,
 }
 
@@ -213,6 +303,8 @@ pub enum MenuCreateInstance {
         instance_name: String,
         selected_version: Option<ListEntry>,
         download_assets: bool,
+        /// Versions shown in `combo_state`
+        filter: VersionFilter,
         combo_state: Box<iced::widget::combo_box::State<ListEntry>>,
     },
     DownloadingInstance(ProgressBar<DownloadProgress>),
@@ -278,12 +370,15 @@ pub struct MenuModsDownload {
 pub struct MenuLauncherSettings {
     pub temp_scale: f64,
     pub selected_tab: LauncherSettingsTab,
+    pub storage_status: Option<String>,
+    pub disk_usage: Option<DiskUsage>,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum LauncherSettingsTab {
     UserInterface,
     Internal,
+    Storage,
     About,
 }
 
@@ -293,16 +388,22 @@ impl std::fmt::Display for LauncherSettingsTab {
             f,
             "{}",
             match self {
-                LauncherSettingsTab::UserInterface => "Appearance",
-                LauncherSettingsTab::Internal => "Advanced",
-                LauncherSettingsTab::About => "About",
+                LauncherSettingsTab::UserInterface => t!("settings-tab-appearance"),
+                LauncherSettingsTab::Internal => t!("settings-tab-advanced"),
+                LauncherSettingsTab::Storage => t!("settings-tab-storage"),
+                LauncherSettingsTab::About => t!("settings-tab-about"),
             }
         )
     }
 }
 
 impl LauncherSettingsTab {
-    pub const ALL: &[Self] = &[Self::UserInterface, Self::Internal, Self::About];
+    pub const ALL: &[Self] = &[
+        Self::UserInterface,
+        Self::Internal,
+        Self::Storage,
+        Self::About,
+    ];
 }
 
 pub struct MenuEditPresets {
@@ -350,12 +451,45 @@ pub struct MenuCurseforgeManualDownload {
     pub is_store: bool,
 }
 
+/// Confirmation before installing something
+/// from a `quantumlauncher://` link
+pub struct MenuUrlInstall {
+    pub action: UrlAction,
+    pub instance: Option<String>,
+}
+
 pub struct MenuExportInstance {
     pub entries: Option<Vec<(DirItem, bool)>>,
     pub progress: Option<ProgressBar<GenericProgress>>,
+    pub format: ExportFormat,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExportFormat {
+    QuantumLauncher,
+    Modrinth,
+    Curseforge,
+}
+
+impl std::fmt::Display for ExportFormat {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            ExportFormat::QuantumLauncher => "QuantumLauncher",
+            ExportFormat::Modrinth => "Modrinth Pack (.mrpack)",
+            ExportFormat::Curseforge => "Curseforge Pack (.zip)",
+        };
+        write!(f, "{name}")
+    }
+}
+
+impl ExportFormat {
+    pub const ALL: &[Self] = &[Self::QuantumLauncher, Self::Modrinth, Self::Curseforge];
 }
 
 pub struct MenuLoginElyBy {
+    /// `Some(url)` when logging into a custom
+    /// authlib-injector server instead of ely.by
+    pub custom_server: Option<String>,
     pub username: String,
     pub password: String,
     pub show_password: bool,
@@ -370,9 +504,48 @@ pub struct MenuLoginMS {
     pub url: String,
     pub code: String,
     pub is_from_welcome_screen: bool,
+    /// When the code expires (and the login times out)
+    pub expires_at: std::time::Instant,
+    /// Dropping this (by leaving the menu) cancels the login
     pub _cancel_handle: iced::task::Handle,
 }
 
+/// Changing an instance's Minecraft version
+/// (see `message_update/change_version.rs`)
+pub struct MenuChangeVersion {
+    /// The instance's current version
+    pub current: String,
+    /// `None` while the version list is loading
+    pub combo_state: Option<Box<iced::widget::combo_box::State<ListEntry>>>,
+    pub selected: Option<ListEntry>,
+    pub progress: Option<ProgressBar<GenericProgress>>,
+}
+
+/// Making a copy of the selected instance
+/// (see `message_update/clone_instance.rs`)
+pub struct MenuCloneInstance {
+    /// The instance being cloned
+    pub instance: InstanceSelection,
+    pub name: String,
+    pub options: ql_instances::CloneOptions,
+    pub progress: Option<ProgressBar<GenericProgress>>,
+}
+
+pub struct MenuSkins {
+    /// The account being edited (key of `Launcher::accounts`)
+    pub account: String,
+    /// `None` while loading
+    pub profile: Option<SkinProfile>,
+    /// The current skin texture (PNG), for saving to the library
+    pub texture: Option<Vec<u8>>,
+    pub preview: Option<Handle>,
+    /// Skins in the local library, with their previews
+    pub library: Vec<(LibrarySkin, Option<Handle>)>,
+    /// Model to use for newly imported skins
+    pub import_model: SkinModel,
+    pub is_loading: bool,
+}
+
 /// The enum that represents which menu is opened currently.
 pub enum State {
     /// Default home screen
@@ -410,6 +583,11 @@ pub enum State {
     AccountLogin,
     LoginMS(MenuLoginMS),
     LoginElyBy(MenuLoginElyBy),
+    /// Skin and cape management for the selected account
+    Skins(MenuSkins),
+    ChangeVersion(MenuChangeVersion),
+    CloneInstance(MenuCloneInstance),
+    UrlInstall(MenuUrlInstall),
 
     InstallPaper,
     InstallFabric(MenuInstallFabric),
@@ -1,5 +1,5 @@
 use std::{
-    collections::HashSet,
+    collections::{HashMap, HashSet},
     path::PathBuf,
     process::ExitStatus,
     sync::{Arc, Mutex},
@@ -7,22 +7,31 @@ use std::{
 
 use iced::widget;
 use ql_core::{
-    file_utils::DirItem, jarmod::JarMods, InstanceSelection, ListEntry, ModId, StoreBackendType,
+    disk_usage::DiskUsage, file_utils::DirItem, jarmod::JarMods, mirror::MirrorSource,
+    InstanceSelection, ListEntry, Loader, ModId, StoreBackendType, VersionType,
 };
 use ql_instances::{
     auth::{
+        lifecycle::RefreshError,
         ms::{AuthCodeResponse, AuthTokenResponse},
+        skin::{LibrarySkin, SkinModel, SkinProfile},
         AccountData,
     },
     UpdateCheckInfo,
 };
 use ql_mod_manager::{
     loaders::fabric::FabricVersionListItem,
-    store::{CurseforgeNotAllowed, ImageResult, ModIndex, QueryType, RecommendedMod, SearchResult},
+    store::{
+        CurseforgeNotAllowed, GameVersionModReport, ImageResult, ModIndex, ModSnapshot, ModUpdate,
+        QueryType, RecommendedMod, SearchResult,
+    },
 };
+use ql_packager::{BulkImportResult, ImportedInstance};
 use tokio::process::Child;
 
-use super::{LaunchTabId, LauncherSettingsTab, LicenseTab, Res};
+use super::{
+    ExportFormat, InstanceMeta, LaunchTabId, LauncherSettingsTab, LicenseTab, Res, SidebarSection,
+};
 
 #[derive(Debug, Clone)]
 pub enum InstallFabricMessage {
@@ -41,13 +50,18 @@ pub enum CreateInstanceMessage {
     VersionSelected(ListEntry),
     NameInput(String),
     ChangeAssetToggle(bool),
+    FilterTypeToggled(VersionType, bool),
+    /// Only show versions since this one
+    FilterSinceInput(String),
 
     Start,
     End(Res<String>),
     Cancel,
 
     Import,
-    ImportResult(Res<Option<InstanceSelection>>),
+    ImportResult(Res<Option<ImportedInstance>>),
+    ImportFolder,
+    ImportFolderResult(Res<BulkImportResult>),
 }
 
 #[derive(Debug, Clone)]
@@ -69,6 +83,37 @@ pub enum EditInstanceMessage {
     GameArgShiftDown(usize),
     RenameEdit(String),
     RenameApply,
+    GroupInput(String),
+    FavoriteToggle(bool),
+    ColorSelected(Option<String>),
+    NotesEdit(widget::text_editor::Action),
+    IconSelect,
+    IconRemove,
+    IconChanged(Res),
+}
+
+#[derive(Debug, Clone)]
+pub enum SidebarMessage {
+    MetaLoaded(HashMap<String, InstanceMeta>, bool),
+    /// Selects the instance, and starts
+    /// dragging it (see [`super::SidebarDrag`])
+    Press(String),
+    HoverEnter(String),
+    HoverLeave(String),
+    SectionEnter(SidebarSection),
+    SectionLeave(SidebarSection),
+    SectionToggle(SidebarSection),
+    Dropped(Res),
+}
+
+#[derive(Debug, Clone)]
+pub enum KeybindMessage {
+    SwitcherOpen,
+    SwitcherInput(String),
+    SwitcherSubmit,
+    SwitcherPick(String),
+    CheatsheetOpen,
+    OverlayClose,
 }
 
 #[derive(Debug, Clone)]
@@ -87,14 +132,22 @@ pub enum ManageModsMessage {
     ToggleSelected,
     ToggleFinished(Res),
 
+    UpdateReview(bool),
     UpdateMods,
     UpdateModsFinished(Res),
-    UpdateCheckResult(Res<Vec<(ModId, String)>>),
+    UpdateCheckResult(Res<Vec<ModUpdate>>),
     UpdateCheckToggle(usize, bool),
 
     SelectAll,
     AddFile,
     AddFileDone(Res<HashSet<CurseforgeNotAllowed>>),
+
+    SnapshotsOpen,
+    SnapshotsClose,
+    SnapshotsLoaded(Res<Vec<ModSnapshot>>),
+    SnapshotRestore(String),
+    UndoLastChange,
+    SnapshotRestored(Res),
 }
 
 #[derive(Debug, Clone)]
@@ -164,7 +217,12 @@ pub enum AccountMessage {
     Response3(Res<AccountData>),
     LogoutCheck,
     LogoutConfirm,
-    RefreshComplete(Res<AccountData>),
+    RefreshComplete(Result<AccountData, RefreshError>),
+    /// An access token about to expire was refreshed
+    /// in the background (account key, result)
+    BackgroundRefreshed(String, Result<AccountData, RefreshError>),
+    /// Log in again to an account whose login expired
+    Relogin(String),
 
     OpenMicrosoft {
         is_from_welcome_screen: bool,
@@ -172,7 +230,18 @@ pub enum AccountMessage {
     OpenElyBy {
         is_from_welcome_screen: bool,
     },
+    OpenAuthlibInjector {
+        is_from_welcome_screen: bool,
+    },
+
+    /// Save the current offline username as a profile
+    OfflineProfileSave,
+    OfflineProfileSelected(String),
+    /// Remove the current offline username from the profiles
+    OfflineProfileRemove,
 
+    /// Server URL, for authlib-injector accounts
+    AuthlibServerInput(String),
     ElyByUsernameInput(String),
     ElyByPasswordInput(String),
     ElyByOtpInput(String),
@@ -181,15 +250,107 @@ pub enum AccountMessage {
     ElyByLoginResponse(Res<ql_instances::auth::elyby::Account>),
 }
 
+#[derive(Debug, Clone)]
+pub enum ChangeVersionMessage {
+    Open,
+    VersionsLoaded(Res<Vec<ListEntry>>),
+    Selected(ListEntry),
+    Start,
+    End(Res<VersionChangeReport>),
+}
+
+#[derive(Debug, Clone)]
+pub enum CloneInstanceMessage {
+    Open,
+    NameInput(String),
+    WorldsToggle(bool),
+    LogsToggle(bool),
+    ScreenshotsToggle(bool),
+    Start,
+    End(Res<InstanceSelection>),
+}
+
+/// What happened while changing an instance's version
+#[derive(Debug, Clone)]
+pub struct VersionChangeReport {
+    pub version: String,
+    /// The loader couldn't be reinstalled
+    /// automatically (eg: OptiFine needs its installer)
+    pub loader_not_reinstalled: Option<Loader>,
+    /// Store mods swapped to compatible versions,
+    /// or disabled if there are none
+    pub mods: Res<GameVersionModReport>,
+}
+
+/// Installing something from a `quantumlauncher://` link
+#[derive(Debug, Clone)]
+pub enum UrlInstallMessage {
+    /// A link or file path, see [`crate::cli::UrlAction::parse`]
+    Open(String),
+    InstanceSelected(String),
+    Confirm,
+    End(Res<HashSet<CurseforgeNotAllowed>>),
+}
+
+#[derive(Debug, Clone)]
+pub enum SkinMessage {
+    Open,
+    /// The (possibly refreshed) account, its skin profile
+    /// and the current skin texture
+    Loaded(Res<(AccountData, SkinProfile, Option<Vec<u8>>)>),
+    /// After changing the skin or cape
+    Updated(Res<(SkinProfile, Option<Vec<u8>>)>),
+    LibraryLoaded(Res<Vec<(LibrarySkin, Vec<u8>)>>),
+    /// After adding/removing skins from the library
+    LibraryChanged(Res<()>),
+
+    ImportModelPicked(SkinModel),
+    /// Pick a PNG file to add to the library
+    Import,
+    /// Save the current skin to the library
+    SaveCurrent,
+    /// Upload a skin from the library (by name)
+    Use(String),
+    Delete(String),
+    Reset,
+    /// Show a cape (by id), or hide it if `None`
+    SetCape(Option<String>),
+}
+
 #[derive(Debug, Clone)]
 pub enum LauncherSettingsMessage {
     Open,
     ThemePicked(String),
     StylePicked(String),
+    /// `None` follows the system language.
+    LanguagePicked(Option<String>),
     UiScale(f64),
     UiScaleApply,
     ClearJavaInstalls,
     ClearJavaInstallsConfirm,
+    DownloadConcurrency(u32),
+    DownloadSpeedLimit(String),
+    /// Comma-separated list of mirrors for a source.
+    MirrorEdit(MirrorSource, String),
+    MirrorFallbackToggle(bool),
+    MirrorUseBmclapi,
+    ToggleOfflineMode(bool),
+    NetworkProxyUrl(String),
+    NetworkProxyUsername(String),
+    NetworkProxyPassword(String),
+    /// Comma-separated list of hosts.
+    NetworkNoProxy(String),
+    NetworkAddCertificate,
+    NetworkClearCertificates,
+    NetworkTimeout(String),
+    NetworkUserAgent(String),
+    StorageScan,
+    StorageScanEnd(Res<DiskUsage>),
+    StoragePruneLogs,
+    StorageDeleteOrphanAssets,
+    StorageDeleteForgeLeftovers,
+    CleanLibraryStore,
+    StorageCleanEnd(Res<u64>),
     ChangeTab(LauncherSettingsTab),
 }
 
@@ -202,6 +363,12 @@ pub enum Message {
     WelcomeContinueToAuth,
 
     Account(AccountMessage),
+    Skin(SkinMessage),
+    ChangeVersion(ChangeVersionMessage),
+    CloneInstance(CloneInstanceMessage),
+    Sidebar(SidebarMessage),
+    Keybind(KeybindMessage),
+    UrlInstall(UrlInstallMessage),
     CreateInstance(CreateInstanceMessage),
     EditInstance(EditInstanceMessage),
     ManageMods(ManageModsMessage),
@@ -248,10 +415,16 @@ pub enum Message {
 
     ExportInstanceOpen,
     ExportInstanceToggleItem(usize, bool),
+    ExportInstanceFormat(ExportFormat),
     ExportInstanceStart,
     ExportInstanceFinished(Res<Vec<u8>>),
     ExportInstanceLoaded(Res<Vec<DirItem>>),
 
+    /// Creates a desktop shortcut that
+    /// launches the selected instance directly.
+    ShortcutCreate,
+    ShortcutCreateEnd(Res<PathBuf>),
+
     CoreErrorCopy,
     CoreErrorCopyLog,
     CoreOpenLink(String),
@@ -264,6 +437,9 @@ pub enum Message {
     CoreOpenIntro,
     CoreEvent(iced::Event, iced::event::Status),
     CoreLogCleanComplete(Res),
+    /// Sent by another launcher that was opened
+    /// while this one is running
+    CoreForwarded(crate::single_instance::Request),
 
     CoreLogToggle,
     CoreLogScroll(isize),
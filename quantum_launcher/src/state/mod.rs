@@ -1,14 +1,13 @@
 use std::{
     collections::{HashMap, HashSet},
     fmt::Display,
-    str::FromStr,
     sync::{mpsc::Receiver, Arc, Mutex},
 };
 
 use iced::{widget::image::Handle, Task};
 use ql_core::{
-    err, file_utils, GenericProgress, InstanceSelection, IntoIoError, IntoStringError,
-    JsonFileError, ListEntry, Progress, LAUNCHER_DIR, LAUNCHER_VERSION_NAME,
+    err, file_utils, json::InstanceConfigJson, GenericProgress, InstanceSelection, IntoIoError,
+    IntoStringError, JsonFileError, ListEntry, Progress, LAUNCHER_DIR, LAUNCHER_VERSION_NAME,
 };
 use ql_instances::{
     auth::{ms::CLIENT_ID, AccountData, AccountType},
@@ -18,7 +17,11 @@ use tokio::process::{Child, ChildStdin};
 
 use crate::{
     config::LauncherConfig,
-    stylesheet::styles::{LauncherTheme, LauncherThemeColor, LauncherThemeLightness},
+    keybinds::Keybinds,
+    stylesheet::{
+        custom::CustomThemes,
+        styles::{LauncherTheme, LauncherThemeColor, LauncherThemeLightness},
+    },
     WINDOW_HEIGHT, WINDOW_WIDTH,
 };
 
@@ -30,7 +33,7 @@ pub use message::*;
 pub const OFFLINE_ACCOUNT_NAME: &str = "(Offline)";
 pub const NEW_ACCOUNT_NAME: &str = "+ Add Account";
 
-type Res<T = ()> = Result<T, String>;
+pub type Res<T = ()> = Result<T, String>;
 
 pub struct InstanceLog {
     pub log: Vec<String>,
@@ -43,6 +46,7 @@ pub struct Launcher {
     pub selected_instance: Option<InstanceSelection>,
     pub config: LauncherConfig,
     pub theme: LauncherTheme,
+    pub custom_themes: CustomThemes,
     pub images: ImageState,
 
     pub is_log_open: bool,
@@ -55,11 +59,19 @@ pub struct Launcher {
     pub accounts: HashMap<String, AccountData>,
     pub accounts_dropdown: Vec<String>,
     pub accounts_selected: Option<String>,
+    /// Accounts (keys of `accounts`) being refreshed in the
+    /// background, or whose background refresh failed
+    /// (until they're refreshed on launch or logged into again)
+    pub accounts_refreshing: HashSet<String>,
 
     pub client_version_list_cache: Option<Vec<ListEntry>>,
     pub server_version_list_cache: Option<Vec<ListEntry>>,
     pub client_list: Option<Vec<String>>,
     pub server_list: Option<Vec<String>>,
+    /// Sidebar info (groups, icons, etc.)
+    /// of the instances in `client_list`
+    pub client_meta: HashMap<String, InstanceMeta>,
+    pub server_meta: HashMap<String, InstanceMeta>,
     pub client_processes: HashMap<String, ClientProcess>,
     pub server_processes: HashMap<String, ServerProcess>,
     pub client_logs: HashMap<String, InstanceLog>,
@@ -68,6 +80,8 @@ pub struct Launcher {
     pub window_size: (f32, f32),
     pub mouse_pos: (f32, f32),
     pub keys_pressed: HashSet<iced::keyboard::Key>,
+    pub keybinds: Keybinds,
+    pub key_overlay: Option<KeyOverlay>,
 }
 
 #[derive(Default)]
@@ -78,6 +92,27 @@ pub struct ImageState {
     pub to_load: Mutex<HashSet<String>>,
 }
 
+/// What the sidebar shows about an instance,
+/// from its `config.json` and icon.
+#[derive(Debug, Clone, Default)]
+pub struct InstanceMeta {
+    pub group: Option<String>,
+    pub is_favorite: bool,
+    pub color: Option<iced::Color>,
+    pub icon: Option<Handle>,
+}
+
+impl InstanceMeta {
+    pub fn from_config(config: &InstanceConfigJson, icon: Option<Handle>) -> Self {
+        Self {
+            group: config.group.clone().filter(|n| !n.trim().is_empty()),
+            is_favorite: config.is_favorite.unwrap_or(false),
+            color: config.color.as_deref().and_then(iced::Color::parse),
+            icon,
+        }
+    }
+}
+
 pub struct ClientProcess {
     pub child: Arc<Mutex<Child>>,
     pub receiver: Option<Receiver<LogLine>>,
@@ -105,7 +140,9 @@ impl Launcher {
         }
 
         let mut config = config?;
-        let theme = get_theme(&config);
+        let custom_themes = CustomThemes::load();
+        let keybinds = Keybinds::load(config.keybinds.as_ref());
+        let theme = get_theme(&config, &custom_themes);
 
         let mut launch = if let Some(message) = message {
             MenuLaunch::with_message(message)
@@ -162,6 +199,8 @@ impl Launcher {
         Ok(Self {
             client_list: None,
             server_list: None,
+            client_meta: HashMap::new(),
+            server_meta: HashMap::new(),
             java_recv: None,
             is_log_open: false,
             log_scroll: 0,
@@ -172,6 +211,7 @@ impl Launcher {
             selected_instance: None,
             images: ImageState::default(),
             theme,
+            custom_themes,
             is_launching_game: false,
             client_version_list_cache: None,
             server_version_list_cache: None,
@@ -182,8 +222,11 @@ impl Launcher {
             accounts,
             accounts_dropdown,
             accounts_selected: Some(selected_account),
+            accounts_refreshing: HashSet::new(),
             keys_pressed: HashSet::new(),
             tick_timer: 0,
+            keybinds,
+            key_overlay: None,
         })
     }
 
@@ -195,11 +238,15 @@ impl Launcher {
             Some(LAUNCHER_DIR.clone())
         };
 
+        let custom_themes = launcher_dir
+            .as_ref()
+            .map(|_| CustomThemes::load())
+            .unwrap_or_default();
         let (config, theme) = launcher_dir
             .as_ref()
             .and_then(|_| {
                 match LauncherConfig::load_s().map(|n| {
-                    let theme = get_theme(&n);
+                    let theme = get_theme(&n, &custom_themes);
                     (n, theme)
                 }) {
                     Ok(n) => Some(n),
@@ -219,12 +266,14 @@ impl Launcher {
             is_launching_game: false,
             client_list: None,
             server_list: None,
-            config,
+            client_meta: HashMap::new(),
+            server_meta: HashMap::new(),
             client_processes: HashMap::new(),
             client_logs: HashMap::new(),
             selected_instance: None,
             images: ImageState::default(),
             theme,
+            custom_themes,
             client_version_list_cache: None,
             server_processes: HashMap::new(),
             server_logs: HashMap::new(),
@@ -234,8 +283,12 @@ impl Launcher {
             accounts: HashMap::new(),
             accounts_dropdown: vec![OFFLINE_ACCOUNT_NAME.to_owned(), NEW_ACCOUNT_NAME.to_owned()],
             accounts_selected: Some(OFFLINE_ACCOUNT_NAME.to_owned()),
+            accounts_refreshing: HashSet::new(),
             keys_pressed: HashSet::new(),
             tick_timer: 0,
+            keybinds: Keybinds::load(config.keybinds.as_ref()),
+            key_overlay: None,
+            config,
         }
     }
 
@@ -262,25 +315,38 @@ impl Launcher {
     }
 }
 
-fn load_account(
+pub fn load_account(
     accounts: &mut HashMap<String, AccountData>,
     accounts_dropdown: &mut Vec<String>,
     accounts_to_remove: &mut Vec<String>,
     username: &str,
     account: &mut crate::config::ConfigAccount,
 ) {
-    let username_stripped = username.strip_suffix(" (elyby)").unwrap_or(username);
-
-    let (account_type, refresh_token) =
-        if account.account_type.as_deref() == Some("ElyBy") || username.ends_with(" (elyby)") {
+    let (account_type, username_stripped, refresh_token) =
+        if let (Some("AuthlibInjector"), Some(api_root)) =
+            (account.account_type.as_deref(), &account.auth_server)
+        {
+            let suffix = format!(" ({})", ql_instances::auth::yggdrasil::host(api_root));
+            let username_stripped = username.strip_suffix(&suffix).unwrap_or(username);
+            (
+                AccountType::AuthlibInjector,
+                username_stripped,
+                ql_instances::auth::yggdrasil::read_refresh_token(username_stripped, api_root)
+                    .strerr(),
+            )
+        } else if account.account_type.as_deref() == Some("ElyBy") || username.ends_with(" (elyby)")
+        {
+            let username_stripped = username.strip_suffix(" (elyby)").unwrap_or(username);
             (
                 AccountType::ElyBy,
+                username_stripped,
                 ql_instances::auth::elyby::read_refresh_token(username_stripped).strerr(),
             )
         } else {
             (
                 AccountType::Microsoft,
-                ql_instances::auth::ms::read_refresh_token(username_stripped).strerr(),
+                username,
+                ql_instances::auth::ms::read_refresh_token(username).strerr(),
             )
         };
 
@@ -295,6 +361,8 @@ fn load_account(
                     refresh_token,
                     needs_refresh: true,
                     account_type,
+                    auth_server: account.auth_server.clone(),
+                    expires_at: None,
 
                     username: username_stripped.to_owned(),
                     nice_username: account
@@ -314,7 +382,7 @@ fn load_account(
     }
 }
 
-fn get_theme(config: &LauncherConfig) -> LauncherTheme {
+fn get_theme(config: &LauncherConfig, custom_themes: &CustomThemes) -> LauncherTheme {
     let theme = match config.theme.as_deref() {
         Some("Dark") => LauncherThemeLightness::Dark,
         Some("Light") => LauncherThemeLightness::Light,
@@ -327,7 +395,7 @@ fn get_theme(config: &LauncherConfig) -> LauncherTheme {
     let style = config
         .style
         .as_deref()
-        .and_then(|n| LauncherThemeColor::from_str(n).ok())
+        .map(|n| LauncherThemeColor::from_name(n, custom_themes))
         .unwrap_or_default();
     LauncherTheme::from_vals(style, theme)
 }
@@ -350,6 +418,28 @@ pub async fn get_entries(path: String, is_server: bool) -> Res<(Vec<String>, boo
     ))
 }
 
+/// Loads the sidebar info of the instances in a list
+/// (from [`get_entries`]). Instances with a broken
+/// `config.json` are left out (shown as ungrouped).
+pub async fn get_entries_meta(
+    names: Vec<String>,
+    is_server: bool,
+) -> (HashMap<String, InstanceMeta>, bool) {
+    let mut metas = HashMap::new();
+    for name in names {
+        let instance = InstanceSelection::new(&name, is_server);
+        let Ok(config) = InstanceConfigJson::read(&instance).await else {
+            continue;
+        };
+        let icon = tokio::fs::read(instance.get_icon_path())
+            .await
+            .ok()
+            .map(Handle::from_bytes);
+        metas.insert(name, InstanceMeta::from_config(&config, icon));
+    }
+    (metas, is_server)
+}
+
 pub struct ProgressBar<T: Progress> {
     pub num: f32,
     pub message: Option<String>,
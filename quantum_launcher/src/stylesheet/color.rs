@@ -1,15 +1,47 @@
-use super::styles::{BORDER_RADIUS, BORDER_WIDTH};
+use serde::{Deserialize, Deserializer};
 
+use super::styles::BORDER_WIDTH;
+
+/// The seven colours a theme is made of, from darkest
+/// to lightest. In theme files each one is written
+/// as a hex string (`"#rrggbb"`).
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
 pub struct Pallete {
+    #[serde(deserialize_with = "de_hex")]
     extra_dark: [u8; 3],
+    #[serde(deserialize_with = "de_hex")]
     dark: [u8; 3],
+    #[serde(deserialize_with = "de_hex")]
     second_dark: [u8; 3],
+    #[serde(deserialize_with = "de_hex")]
     mid: [u8; 3],
+    #[serde(deserialize_with = "de_hex")]
     second_light: [u8; 3],
+    #[serde(deserialize_with = "de_hex")]
     light: [u8; 3],
+    #[serde(deserialize_with = "de_hex")]
     white: [u8; 3],
 }
 
+fn de_hex<'de, D: Deserializer<'de>>(deserializer: D) -> Result<[u8; 3], D::Error> {
+    let s = String::deserialize(deserializer)?;
+    parse_hex(&s).ok_or_else(|| {
+        serde::de::Error::custom(format!(
+            "invalid colour {s:?}, expected a hex colour like \"#3a2436\""
+        ))
+    })
+}
+
+fn parse_hex(s: &str) -> Option<[u8; 3]> {
+    let hex = s.strip_prefix('#').unwrap_or(s);
+    if hex.len() != 6 || !hex.is_ascii() {
+        return None;
+    }
+    let channel = |i: usize| u8::from_str_radix(&hex[i..i + 2], 16).ok();
+    Some([channel(0)?, channel(2)?, channel(4)?])
+}
+
 pub const PURPLE: Pallete = Pallete {
     extra_dark: [0x22, 0x19, 0x20],
     dark: [0x3a, 0x24, 0x36],
@@ -111,11 +143,11 @@ impl Pallete {
         iced::Background::Color(self.get(color))
     }
 
-    pub fn get_border(&self, color: Color) -> iced::Border {
+    pub fn get_border(&self, color: Color, radius: f32) -> iced::Border {
         iced::Border {
             color: self.get(color),
             width: BORDER_WIDTH,
-            radius: BORDER_RADIUS.into(),
+            radius: radius.into(),
         }
     }
 }
@@ -0,0 +1,204 @@
+//! Custom themes, loaded from `QuantumLauncher/themes/`.
+//!
+//! Each theme is a `.toml` or `.json` file with the seven
+//! palette colours (darkest to lightest) and a few optional settings:
+//!
+//! ```toml
+//! name = "House Colours"  # optional, defaults to the file name
+//! font = "JetBrains Mono" # optional, an installed font family
+//! border_radius = 4.0     # optional, 0 to 20 (default 8)
+//!
+//! [palette]
+//! extra_dark = "#221920"
+//! dark = "#3a2436"
+//! second_dark = "#664769"
+//! mid = "#cc76c5"
+//! second_light = "#f9b1e6"
+//! light = "#ffc7f0"
+//! white = "#ffdaf5"
+//! ```
+//!
+//! Theme files are re-read whenever they change, so edits
+//! show up right away (except the font, which needs a restart).
+
+use std::{
+    path::{Path, PathBuf},
+    sync::Arc,
+    time::SystemTime,
+};
+
+use ql_core::{err, LAUNCHER_DIR};
+use serde::Deserialize;
+
+use super::{color::Pallete, styles::LauncherThemeColor};
+
+const MAX_BORDER_RADIUS: f32 = 20.0;
+
+#[derive(Debug)]
+pub struct CustomTheme {
+    pub name: String,
+    pub palette: Pallete,
+    /// Font family name, only applied on startup
+    pub font: Option<String>,
+    pub border_radius: Option<f32>,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct ThemeFile {
+    name: Option<String>,
+    font: Option<String>,
+    border_radius: Option<f32>,
+    palette: Pallete,
+}
+
+#[derive(Default)]
+pub struct CustomThemes {
+    pub themes: Vec<Arc<CustomTheme>>,
+    /// Problems with theme files (shown in settings)
+    pub errors: Vec<String>,
+    /// Theme files and their modification times,
+    /// to find out when they change.
+    files: Vec<(PathBuf, Option<SystemTime>)>,
+}
+
+impl CustomThemes {
+    pub fn load() -> Self {
+        let dir = Self::get_dir();
+        if let Err(err) = std::fs::create_dir_all(&dir) {
+            err!("Could not create themes dir {dir:?}: {err}");
+        }
+        let mut themes = Self::default();
+        themes.reload(list_files());
+        themes
+    }
+
+    pub fn get_dir() -> PathBuf {
+        LAUNCHER_DIR.join("themes")
+    }
+
+    pub fn get(&self, name: &str) -> Option<Arc<CustomTheme>> {
+        self.themes.iter().find(|n| n.name == name).cloned()
+    }
+
+    /// Re-reads the theme files if any were added, removed
+    /// or modified. Returns whether anything changed.
+    pub fn reload_if_changed(&mut self) -> bool {
+        let files = list_files();
+        if files == self.files {
+            return false;
+        }
+        self.reload(files);
+        true
+    }
+
+    fn reload(&mut self, files: Vec<(PathBuf, Option<SystemTime>)>) {
+        self.themes.clear();
+        self.errors.clear();
+
+        for (path, _) in &files {
+            let file_name = path.file_name().unwrap_or_default().to_string_lossy();
+            let theme = load_theme(path).and_then(|theme| {
+                if LauncherThemeColor::is_builtin_name(&theme.name) {
+                    Err(format!(
+                        "the name {:?} is already used by a built-in theme",
+                        theme.name
+                    ))
+                } else if self.get(&theme.name).is_some() {
+                    Err(format!("another theme is already named {:?}", theme.name))
+                } else {
+                    Ok(theme)
+                }
+            });
+            match theme {
+                Ok(theme) => self.themes.push(Arc::new(theme)),
+                Err(error) => {
+                    err!("Could not load theme {file_name}: {error}");
+                    self.errors.push(format!("{file_name}: {error}"));
+                }
+            }
+        }
+        self.files = files;
+    }
+}
+
+/// The font family of the custom theme named `style`, if any.
+///
+/// Fonts can't be changed while the launcher
+/// is running, so this is only used on startup.
+pub fn get_startup_font(style: Option<&str>) -> Option<&'static str> {
+    let style = style.filter(|n| !LauncherThemeColor::is_builtin_name(n))?;
+    let font = CustomThemes::load().get(style)?.font.clone()?;
+    // iced needs a `&'static str`, this only happens once
+    Some(Box::leak(font.into_boxed_str()))
+}
+
+fn list_files() -> Vec<(PathBuf, Option<SystemTime>)> {
+    let Ok(dir) = std::fs::read_dir(CustomThemes::get_dir()) else {
+        return Vec::new();
+    };
+    let mut files: Vec<_> = dir
+        .filter_map(Result::ok)
+        .map(|n| n.path())
+        .filter(|n| n.is_file() && get_format(n).is_some())
+        .map(|path| {
+            let modified = std::fs::metadata(&path).and_then(|n| n.modified()).ok();
+            (path, modified)
+        })
+        .collect();
+    files.sort();
+    files
+}
+
+#[derive(Clone, Copy)]
+enum Format {
+    Toml,
+    Json,
+}
+
+fn get_format(path: &Path) -> Option<Format> {
+    match path.extension()?.to_str()? {
+        "toml" => Some(Format::Toml),
+        "json" => Some(Format::Json),
+        _ => None,
+    }
+}
+
+fn load_theme(path: &Path) -> Result<CustomTheme, String> {
+    let format = get_format(path).ok_or("not a .toml or .json file")?;
+    let text = std::fs::read_to_string(path).map_err(|n| n.to_string())?;
+    let file: ThemeFile = match format {
+        Format::Toml => toml::from_str(&text).map_err(|n| n.to_string().trim().to_owned())?,
+        Format::Json => serde_json::from_str(&text).map_err(|n| n.to_string())?,
+    };
+
+    let name = match file.name {
+        Some(name) => name.trim().to_owned(),
+        None => path
+            .file_stem()
+            .unwrap_or_default()
+            .to_string_lossy()
+            .into_owned(),
+    };
+    if name.is_empty() {
+        return Err("name can't be empty".to_owned());
+    }
+    if let Some(radius) = file.border_radius {
+        if !(0.0..=MAX_BORDER_RADIUS).contains(&radius) {
+            return Err(format!(
+                "border_radius must be between 0 and {MAX_BORDER_RADIUS} (got {radius})"
+            ));
+        }
+    }
+    let font = file.font.map(|n| n.trim().to_owned());
+    if font.as_ref().is_some_and(String::is_empty) {
+        return Err("font can't be empty (remove it to use the default font)".to_owned());
+    }
+
+    Ok(CustomTheme {
+        name,
+        palette: file.palette,
+        font,
+        border_radius: file.border_radius,
+    })
+}
@@ -1,3 +1,4 @@
 pub mod color;
+pub mod custom;
 pub mod styles;
 pub mod widgets;
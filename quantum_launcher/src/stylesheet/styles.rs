@@ -1,17 +1,18 @@
-use std::{fmt::Display, str::FromStr};
+use std::{fmt::Display, str::FromStr, sync::Arc};
 
 use iced::widget;
 use ql_core::err;
 
 use super::{
     color::{Color, BROWN, CATPPUCCIN, PURPLE, SKY_BLUE, TEAL},
+    custom::{CustomTheme, CustomThemes},
     widgets::{IsFlat, StyleButton, StyleScrollable},
 };
 
 pub const BORDER_WIDTH: f32 = 2.0;
 pub const BORDER_RADIUS: f32 = 8.0;
 
-#[derive(Copy, Clone, Debug, Default)]
+#[derive(Clone, Debug, Default)]
 pub enum LauncherThemeColor {
     Brown,
     #[default]
@@ -19,6 +20,8 @@ pub enum LauncherThemeColor {
     SkyBlue,
     Catppuccin,
     Teal,
+    /// Loaded from `QuantumLauncher/themes/`
+    Custom(Arc<CustomTheme>),
 }
 
 impl LauncherThemeColor {
@@ -30,6 +33,20 @@ impl LauncherThemeColor {
         Self::Catppuccin,
         Self::Teal,
     ];
+
+    /// Finds a built-in or custom theme by name,
+    /// falling back to the default one.
+    pub fn from_name(name: &str, custom: &CustomThemes) -> Self {
+        if let Some(theme) = custom.get(name) {
+            Self::Custom(theme)
+        } else {
+            Self::from_str(name).unwrap_or_default()
+        }
+    }
+
+    pub fn is_builtin_name(name: &str) -> bool {
+        Self::ALL.iter().any(|n| n.to_string() == name)
+    }
 }
 
 impl Display for LauncherThemeColor {
@@ -43,6 +60,7 @@ impl Display for LauncherThemeColor {
                 LauncherThemeColor::SkyBlue => "Sky Blue",
                 LauncherThemeColor::Catppuccin => "Catppuccin",
                 LauncherThemeColor::Teal => "Teal",
+                LauncherThemeColor::Custom(theme) => &theme.name,
             },
         )
     }
@@ -90,12 +108,13 @@ impl LauncherTheme {
     }
 
     fn get_base(&self, invert: bool, mut color: Color) -> (&super::color::Pallete, Color) {
-        let palette = match self.color {
+        let palette = match &self.color {
             LauncherThemeColor::Brown => &BROWN,
             LauncherThemeColor::Purple => &PURPLE,
             LauncherThemeColor::SkyBlue => &SKY_BLUE,
             LauncherThemeColor::Catppuccin => &CATPPUCCIN,
             LauncherThemeColor::Teal => &TEAL,
+            LauncherThemeColor::Custom(theme) => &theme.palette,
         };
         if let LauncherThemeLightness::Light = self.lightness {
             if let Color::ExtraDark = color {
@@ -125,7 +144,14 @@ impl LauncherTheme {
 
     pub fn get_border(&self, color: Color, invert: bool) -> iced::Border {
         let (palette, color) = self.get_base(invert, color);
-        palette.get_border(color)
+        palette.get_border(color, self.border_radius())
+    }
+
+    fn border_radius(&self) -> f32 {
+        match &self.color {
+            LauncherThemeColor::Custom(theme) => theme.border_radius.unwrap_or(BORDER_RADIUS),
+            _ => BORDER_RADIUS,
+        }
     }
 
     fn get_border_sharp(&self, color: Color, invert: bool) -> iced::Border {
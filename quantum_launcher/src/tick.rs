@@ -13,9 +13,9 @@ use ql_mod_manager::store::{ModConfig, ModIndex};
 
 use crate::state::{
     EditInstanceMessage, ImageState, InstallModsMessage, InstanceLog, LaunchTabId, Launcher,
-    ManageJarModsMessage, MenuCreateInstance, MenuEditMods, MenuEditPresetsInner,
-    MenuExportInstance, MenuInstallFabric, MenuLaunch, MenuLoginMS, MenuModsDownload,
-    MenuServerCreate, Message, ModListEntry, ServerProcess, State,
+    ManageJarModsMessage, MenuChangeVersion, MenuCloneInstance, MenuCreateInstance, MenuEditMods,
+    MenuEditPresetsInner, MenuExportInstance, MenuInstallFabric, MenuLaunch, MenuLoginMS,
+    MenuModsDownload, MenuServerCreate, Message, ModListEntry, ServerProcess, State,
 };
 
 impl Launcher {
@@ -39,6 +39,7 @@ impl Launcher {
                 }
                 self.tick_client_processes_and_logs();
                 self.tick_server_processes_and_logs();
+                commands.push(self.tick_refresh_accounts());
 
                 let launcher_config = self.config.clone();
                 commands.push(Task::perform(
@@ -130,6 +131,14 @@ impl Launcher {
             | State::ExportInstance(MenuExportInstance {
                 progress: Some(progress),
                 ..
+            })
+            | State::ChangeVersion(MenuChangeVersion {
+                progress: Some(progress),
+                ..
+            })
+            | State::CloneInstance(MenuCloneInstance {
+                progress: Some(progress),
+                ..
             }) => {
                 progress.tick();
             }
@@ -137,6 +146,10 @@ impl Launcher {
             // These menus don't require background ticking
             State::Error { .. }
             | State::LoginElyBy(_)
+            | State::Skins(_)
+            | State::ChangeVersion(_)
+            | State::CloneInstance(_)
+            | State::UrlInstall(_)
             | State::AccountLogin
             | State::ExportInstance(_)
             | State::ConfirmAction { .. }
@@ -7,9 +7,13 @@ use ql_instances::UpdateCheckInfo;
 use ql_mod_manager::loaders;
 use tokio::io::AsyncWriteExt;
 
-use crate::state::{
-    LaunchTabId, Launcher, ManageModsMessage, MenuExportInstance, MenuLaunch, MenuLauncherUpdate,
-    MenuLicense, MenuServerCreate, MenuWelcome, Message, ProgressBar, ServerProcess, State,
+use crate::{
+    i18n::t,
+    state::{
+        get_entries_meta, ExportFormat, LaunchTabId, Launcher, ManageModsMessage,
+        MenuExportInstance, MenuLaunch, MenuLauncherUpdate, MenuLicense, MenuServerCreate,
+        MenuWelcome, Message, ProgressBar, ServerProcess, SidebarMessage, State,
+    },
 };
 
 impl Launcher {
@@ -46,6 +50,13 @@ impl Launcher {
             }
 
             Message::Account(msg) => return self.update_account(msg),
+            Message::Skin(msg) => return self.update_skin(msg),
+            Message::ChangeVersion(msg) => return self.update_change_version(msg),
+            Message::CloneInstance(msg) => return self.update_clone_instance(msg),
+            Message::Sidebar(msg) => return self.update_sidebar(msg),
+            Message::Keybind(msg) => return self.update_keybind(msg),
+            Message::UrlInstall(msg) => return self.update_url_install(msg),
+            Message::CoreForwarded(request) => return self.handle_forwarded(request),
             Message::ManageMods(message) => return self.update_manage_mods(message),
             Message::ManageJarMods(message) => return self.update_manage_jar_mods(message),
             Message::LaunchInstanceSelected { name, is_server } => {
@@ -58,6 +69,7 @@ impl Launcher {
                             *edit_instance = None;
                         }
                     }
+                    self.select_last_account();
                 }
             }
             Message::LaunchUsernameSet(username) => {
@@ -108,6 +120,9 @@ impl Launcher {
             }
             Message::CoreTick => {
                 self.tick_timer = self.tick_timer.wrapping_add(1);
+                if self.tick_timer.is_multiple_of(5) {
+                    self.reload_custom_themes();
+                }
                 let mut commands = self.get_imgs_to_load();
                 let command = self.tick();
                 commands.push(command);
@@ -351,11 +366,15 @@ impl Launcher {
                 );
             }
             Message::CoreListLoaded(Ok((list, is_server))) => {
+                let names = list.clone();
                 if is_server {
                     self.server_list = Some(list);
                 } else {
                     self.client_list = Some(list);
                 }
+                return Task::perform(get_entries_meta(names, is_server), |(meta, is_server)| {
+                    Message::Sidebar(SidebarMessage::MetaLoaded(meta, is_server))
+                });
             }
             Message::CoreCopyText(txt) => {
                 return iced::clipboard::write(txt);
@@ -428,6 +447,7 @@ impl Launcher {
                 self.state = State::ExportInstance(MenuExportInstance {
                     entries: None,
                     progress: None,
+                    format: ExportFormat::QuantumLauncher,
                 });
                 return Task::perform(
                     ql_core::file_utils::read_filenames_from_dir_ext(
@@ -485,10 +505,16 @@ impl Launcher {
                     }
                 }
             }
+            Message::ExportInstanceFormat(f) => {
+                if let State::ExportInstance(menu) = &mut self.state {
+                    menu.format = f;
+                }
+            }
             Message::ExportInstanceStart => {
                 if let State::ExportInstance(MenuExportInstance {
                     entries: Some(entries),
                     progress,
+                    format,
                 }) = &mut self.state
                 {
                     let (send, recv) = std::sync::mpsc::channel();
@@ -499,19 +525,51 @@ impl Launcher {
                         .filter_map(|(n, b)| (!b).then_some(format!(".minecraft/{}", n.name)))
                         .collect();
 
+                    let instance = self.selected_instance.clone().unwrap();
+                    let format = *format;
                     return Task::perform(
-                        ql_packager::export_instance(
-                            self.selected_instance.clone().unwrap(),
-                            exceptions,
-                            Some(send),
-                        ),
+                        async move {
+                            match format {
+                                ExportFormat::QuantumLauncher => {
+                                    ql_packager::export_instance(instance, exceptions, Some(send))
+                                        .await
+                                }
+                                ExportFormat::Modrinth => {
+                                    ql_packager::export_modrinth_pack(
+                                        instance,
+                                        exceptions,
+                                        Some(send),
+                                    )
+                                    .await
+                                }
+                                ExportFormat::Curseforge => {
+                                    ql_packager::export_curseforge_pack(
+                                        instance,
+                                        exceptions,
+                                        Some(send),
+                                    )
+                                    .await
+                                }
+                            }
+                        },
                         |n| Message::ExportInstanceFinished(n.strerr()),
                     );
                 }
             }
             Message::ExportInstanceFinished(res) => match res {
                 Ok(bytes) => {
-                    if let Some(path) = rfd::FileDialog::new().save_file() {
+                    let mut dialog = rfd::FileDialog::new();
+                    if let (State::ExportInstance(menu), Some(instance)) =
+                        (&self.state, &self.selected_instance)
+                    {
+                        let extension = match menu.format {
+                            ExportFormat::QuantumLauncher | ExportFormat::Curseforge => "zip",
+                            ExportFormat::Modrinth => "mrpack",
+                        };
+                        dialog =
+                            dialog.set_file_name(format!("{}.{extension}", instance.get_name()));
+                    }
+                    if let Some(path) = dialog.save_file() {
                         if let Err(err) = std::fs::write(&path, bytes).path(path) {
                             self.set_error(err);
                         } else {
@@ -521,6 +579,23 @@ impl Launcher {
                 }
                 Err(err) => self.set_error(err),
             },
+            Message::ShortcutCreate => {
+                if let Some(InstanceSelection::Instance(instance)) = &self.selected_instance {
+                    return Task::perform(
+                        crate::shortcut::create(instance.clone()),
+                        Message::ShortcutCreateEnd,
+                    );
+                }
+            }
+            Message::ShortcutCreateEnd(res) => match res {
+                Ok(path) => {
+                    info!("Created shortcut at {path:?}");
+                    if let State::Launch(menu) = &mut self.state {
+                        menu.message = t!("launch-shortcut-created", path = path.to_string_lossy());
+                    }
+                }
+                Err(err) => self.set_error(err),
+            },
             Message::LicenseOpen => {
                 self.go_to_licenses_menu();
             }
@@ -1,7 +1,9 @@
 use iced::{widget, Length};
 use ql_core::LOGGER;
+use ql_instances::auth::AccountType;
 
 use crate::{
+    i18n::t,
     icon_manager,
     menu_renderer::{
         button_with_icon, changelog::changelog_0_4_1, view_account_login, Element, DISCORD,
@@ -12,12 +14,13 @@ use crate::{
 };
 
 impl Launcher {
-    pub fn view(&self) -> Element {
+    pub fn view(&self) -> Element<'_> {
         widget::column![
-            widget::column![self.view_menu()].height(
-                (self.window_size.1 / if self.is_log_open { 2.0 } else { 1.0 })
-                    - DEBUG_LOG_BUTTON_HEIGHT
-            ),
+            widget::column![widget::stack![self.view_menu()].push_maybe(self.view_key_overlay())]
+                .height(
+                    (self.window_size.1 / if self.is_log_open { 2.0 } else { 1.0 })
+                        - DEBUG_LOG_BUTTON_HEIGHT
+                ),
             widget::tooltip(
                 widget::button(widget::row![
                     widget::horizontal_space(),
@@ -29,9 +32,9 @@ impl Launcher {
                 .style(|n: &LauncherTheme, status| n.style_button(status, StyleButton::FlatDark))
                 .on_press(Message::CoreLogToggle),
                 widget::text(if self.is_log_open {
-                    "Close launcher log"
+                    t!("log-close")
                 } else {
-                    "Open launcher debug log (troubleshooting)"
+                    t!("log-open")
                 })
                 .size(12),
                 widget::tooltip::Position::Top
@@ -61,7 +64,7 @@ impl Launcher {
         .into()
     }
 
-    fn view_menu(&self) -> Element {
+    fn view_menu(&self) -> Element<'_> {
         match &self.state {
             State::Launch(menu) => self.view_main_menu(menu),
             State::AccountLoginProgress(progress) => widget::column![
@@ -74,9 +77,12 @@ impl Launcher {
             State::GenericMessage(msg) => widget::column![widget::text(msg)].padding(10).into(),
             State::LoginMS(menu) => menu.view(),
             State::AccountLogin => view_account_login(),
-            State::EditMods(menu) => {
-                menu.view(self.selected_instance.as_ref().unwrap(), self.tick_timer)
-            }
+            State::EditMods(menu) => menu.view(
+                self.selected_instance.as_ref().unwrap(),
+                self.tick_timer,
+                &self.images,
+                self.window_size,
+            ),
             State::Create(menu) => menu.view(),
             State::ConfirmAction {
                 msg1,
@@ -94,15 +100,19 @@ impl Launcher {
             .into(),
             State::Error { error } => widget::scrollable(
                 widget::column!(
-                    widget::text!("Error: {error}"),
+                    widget::text(t!("error-message", error = error.as_str())),
                     widget::row![
-                        widget::button("Back").on_press(Message::LaunchScreenOpen {
-                            message: None,
-                            clear_selection: true
-                        }),
-                        widget::button("Copy Error").on_press(Message::CoreErrorCopy),
-                        widget::button("Copy Error + Log").on_press(Message::CoreErrorCopyLog),
-                        widget::button("Join Discord for help")
+                        widget::button(widget::text(t!("common-back"))).on_press(
+                            Message::LaunchScreenOpen {
+                                message: None,
+                                clear_selection: true
+                            }
+                        ),
+                        widget::button(widget::text(t!("error-copy")))
+                            .on_press(Message::CoreErrorCopy),
+                        widget::button(widget::text(t!("error-copy-log")))
+                            .on_press(Message::CoreErrorCopyLog),
+                        widget::button(widget::text(t!("error-discord")))
                             .on_press(Message::CoreOpenLink(DISCORD.to_owned()))
                     ]
                     .spacing(5)
@@ -128,7 +138,7 @@ impl Launcher {
             // TODO: maybe remove window_size argument?
             // It's not needed right now, but could be in the future.
             State::ModsDownload(menu) => menu.view(&self.images, self.window_size, self.tick_timer),
-            State::LauncherSettings(menu) => menu.view(&self.config),
+            State::LauncherSettings(menu) => menu.view(&self.config, &self.custom_themes),
             State::InstallOptifine(menu) => menu.view(),
             State::ServerCreate(menu) => menu.view(),
             State::InstallPaper => {
@@ -169,6 +179,15 @@ impl Launcher {
                     .into()
             }
             State::LoginElyBy(menu) => menu.view(self.tick_timer),
+            State::Skins(menu) => menu.view(
+                self.accounts
+                    .get(&menu.account)
+                    .map_or(AccountType::Microsoft, |n| n.account_type),
+                self.tick_timer,
+            ),
+            State::ChangeVersion(menu) => menu.view(self.tick_timer),
+            State::CloneInstance(menu) => menu.view(),
+            State::UrlInstall(menu) => menu.view(self.client_list.as_deref()),
             State::CurseforgeManualDownload(menu) => menu.view(),
             State::ExportInstance(menu) => menu.view(self.tick_timer),
             State::License(menu) => menu.view(),